@@ -0,0 +1,1002 @@
+-- the fake users for development, the password of all of them is 123
+insert into user (mail, username, password, salt, create_time) values
+('bsievewright0@yellowpages.com', 'gbrookshaw0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-18 12:52:34'),
+('knodin1@cbc.ca', 'klouder1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-29 15:36:32'),
+('gmcelane2@nsw.gov.au', 'njeffryes2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-06 20:11:08'),
+('mzottoli3@bizjournals.com', 'rtrevenu3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-10 04:07:08'),
+('mballinghall4@mysql.com', 'eunderwood4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-20 04:58:28'),
+('cclaus5@whitehouse.gov', 'cjozefiak5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-06 17:02:04'),
+('etegler6@ow.ly', 'nmckiddin6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-29 00:17:49'),
+('pberanek7@washingtonpost.com', 'blitton7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-31 10:09:07'),
+('adumigan8@sphinn.com', 'mwesthofer8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-13 17:15:48'),
+('nlear9@vimeo.com', 'mbockin9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-09 19:10:50'),
+('clummasanaa@cnn.com', 'rhalla', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-09 16:18:38'),
+('tcandelinb@patch.com', 'sfrenchb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-18 01:41:21'),
+('oamiesc@smugmug.com', 'mnickolsc', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-13 06:13:47'),
+('epellingd@harvard.edu', 'cfearesd', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-31 12:36:30'),
+('mbenoise@so-net.ne.jp', 'mmckeurtone', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-04 23:44:09'),
+('dstotherfieldf@webmd.com', 'fgorstf', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-23 18:10:22'),
+('tlivardg@google.com.br', 'jpundyg', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-10 20:09:00'),
+('rgooddieh@privacy.gov.au', 'mhawkinsh', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-27 00:29:24'),
+('iswaytei@google.co.uk', 'eparleti', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-02 02:04:27'),
+('flemonj@umich.edu', 'tgilstonj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-14 01:15:55'),
+('afawcittk@rediff.com', 'cgrimsdalek', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-08 10:00:39'),
+('bskingleyl@ifeng.com', 'mcathell', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-19 23:59:12'),
+('istrewthersm@zimbio.com', 'agengem', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-04 23:57:44'),
+('ajojicn@europa.eu', 'lknockern', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-30 15:13:43'),
+('asturgiso@weebly.com', 'cduthieo', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-21 17:07:36'),
+('bsimonp@mashable.com', 'awetterp', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-06 17:07:33'),
+('plindoresq@blogs.com', 'idymidowskiq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-15 00:25:58'),
+('ekrishtopaittisr@netvibes.com', 'rpatingtonr', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-21 07:34:55'),
+('mmallans@wiley.com', 'ctathacotts', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-15 22:11:55'),
+('dromert@dagondesign.com', 'amaccreet', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-15 17:27:43'),
+('ofenixu@economist.com', 'hjayumeu', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-27 01:52:22'),
+('kgrossmanv@ustream.tv', 'cmayzesv', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-22 05:38:46'),
+('gchittemw@bloglines.com', 'tjohnkew', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-05 12:29:43'),
+('agunnerx@cam.ac.uk', 'rdennistonx', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-02 22:18:57'),
+('zconnichiey@soundcloud.com', 'lcocky', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-15 09:28:11'),
+('jmollattz@google.es', 'barnholzz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-19 18:30:15'),
+('ibrinded10@foxnews.com', 'lblewett10', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-02 09:52:57'),
+('bdaenen11@arizona.edu', 'nbaughan11', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-17 10:09:12'),
+('kjoddens12@lulu.com', 'oduffield12', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-28 21:56:20'),
+('afierro13@berkeley.edu', 'epughe13', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-26 09:37:22'),
+('rthowless14@sitemeter.com', 'fkordt14', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-02 19:44:35'),
+('jivain15@blinklist.com', 'ncreffield15', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-04 09:44:23'),
+('htowey16@drupal.org', 'hdurrant16', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-24 05:50:15'),
+('kplum17@weebly.com', 'ikemble17', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-06 15:42:21'),
+('fladbrooke18@oakley.com', 'jhaithwaite18', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-21 18:25:19'),
+('cblonfield19@npr.org', 'rwrinch19', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-19 23:28:18'),
+('dbeernaert1a@tumblr.com', 'rshieber1a', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-27 16:39:31'),
+('lpymm1b@deliciousdays.com', 'bsheriff1b', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-21 13:14:16'),
+('jbaggally1c@noaa.gov', 'fbelasco1c', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-23 04:57:38'),
+('arowlands1d@google.fr', 'callsworth1d', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-18 00:21:14'),
+('cmohamed1e@columbia.edu', 'fwhelpton1e', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-20 16:32:24'),
+('mluttgert1f@t-online.de', 'gcrossingham1f', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-12 03:47:22'),
+('jwinger1g@hao123.com', 'tbirch1g', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-20 08:21:22'),
+('anurden1h@bloglovin.com', 'mroder1h', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-01 21:25:45'),
+('jasbery1i@netscape.com', 'vlove1i', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-30 16:32:23'),
+('jdjurkovic1j@walmart.com', 'acawthron1j', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-08 05:37:07'),
+('eatterley1k@state.gov', 'demmot1k', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-08 07:52:06'),
+('bgaskell1l@auda.org.au', 'nmallalieu1l', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-29 00:04:30'),
+('kdemullett1m@pcworld.com', 'cpenney1m', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-09 20:30:09'),
+('acreffeild1n@jigsy.com', 'mgeroldini1n', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-18 02:13:39'),
+('nstrutton1o@yellowpages.com', 'mjaques1o', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-03 00:46:03'),
+('hjandl1p@diigo.com', 'agillman1p', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-20 08:21:13'),
+('yhobbing1q@parallels.com', 'sjaume1q', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-07 07:17:35'),
+('cforsyde1r@angelfire.com', 'bmcalinden1r', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-18 01:11:40'),
+('tmackaig1s@parallels.com', 'afrayn1s', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-26 12:59:32'),
+('rtrowl1t@shutterfly.com', 'ahargess1t', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-22 11:30:01'),
+('rsalvati1u@nytimes.com', 'hglede1u', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-07 23:49:02'),
+('mbraunfeld1v@who.int', 'bsams1v', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-03 00:11:07'),
+('dmcvicar1w@typepad.com', 'anelsey1w', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-08 18:19:55'),
+('tspratling1x@acquirethisname.com', 'cbrandenburg1x', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-09 09:02:11'),
+('eedland1y@cocolog-nifty.com', 'dbeaven1y', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-20 14:03:48'),
+('ehandrick1z@skype.com', 'mswayte1z', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-30 11:00:46'),
+('themeret20@sohu.com', 'asalway20', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-03 06:33:17'),
+('alubomirski21@newsvine.com', 'vworstall21', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-17 08:28:22'),
+('gpizer22@hatena.ne.jp', 'dprisley22', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-05 15:00:01'),
+('asyers23@multiply.com', 'tberesfore23', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-31 00:12:13'),
+('jbyrd24@nsw.gov.au', 'lvan24', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-26 00:08:59'),
+('lgrimsditch25@paginegialle.it', 'rredgrove25', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-30 13:13:02'),
+('gkennsley26@apache.org', 'ssimonato26', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-26 22:44:08'),
+('jbickerdicke27@github.com', 'cesser27', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-12 03:57:07'),
+('kfollos28@reuters.com', 'emccreath28', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-26 16:32:37'),
+('jstoyle29@stumbleupon.com', 'vtollit29', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-16 07:03:27'),
+('akelley2a@deviantart.com', 'udragge2a', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-30 04:50:54'),
+('arenhard2b@examiner.com', 'jkrimmer2b', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-06 13:34:56'),
+('alias2c@cornell.edu', 'dsimmans2c', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-14 21:02:38'),
+('teaglestone2d@scribd.com', 'pjames2d', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-17 04:00:19'),
+('jleas2e@delicious.com', 'bgorgl2e', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-07 20:10:49'),
+('hriddler2f@opensource.org', 'fcraw2f', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-25 02:10:58'),
+('odecleyne2g@scientificamerican.com', 'ebangham2g', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-15 07:55:18'),
+('jcassey2h@xrea.com', 'ecorder2h', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-30 11:22:15'),
+('jblayd2i@whitehouse.gov', 'rtolman2i', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-18 22:03:46'),
+('aroutledge2j@businesswire.com', 'hpumfrett2j', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-29 15:16:57'),
+('coscanlan2k@twitpic.com', 'jpinkard2k', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-27 05:17:01'),
+('odagleas2l@sphinn.com', 'jvinnicombe2l', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-01 04:42:57'),
+('slemonby2m@fema.gov', 'ntofanini2m', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-27 19:26:25'),
+('knevins2n@skyrock.com', 'nbollins2n', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-26 11:34:56'),
+('lworswick2o@google.cn', 'cdameisele2o', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-20 17:28:42'),
+('gselwyne2p@state.gov', 'cbusch2p', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-05 07:58:17'),
+('gbrown2q@independent.co.uk', 'medghinn2q', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-19 23:57:55'),
+('lcolliber2r@vistaprint.com', 'djanuszkiewicz2r', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-20 11:39:14'),
+('dgarvey2s@umn.edu', 'rrawstron2s', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-03 15:12:57'),
+('hchatainier2t@cmu.edu', 'wchafney2t', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-11-02 19:43:32'),
+('emills2u@sfgate.com', 'bedgworth2u', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-23 11:05:41'),
+('wronaldson2v@g.co', 'ybarriball2v', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-12 04:26:55'),
+('sbeeble2w@about.com', 'fatherley2w', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-12 09:30:52'),
+('aabethell2x@altervista.org', 'balbisser2x', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-15 22:50:34'),
+('chawtry2y@yahoo.co.jp', 'bwandless2y', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-31 14:51:10'),
+('tbrenstuhl2z@ed.gov', 'eleadbitter2z', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-30 03:37:31'),
+('carmfirld30@cnet.com', 'mfaherty30', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-18 02:32:02'),
+('emoger31@phoca.cz', 'mgarlinge31', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-18 12:45:39'),
+('fgreatrakes32@google.fr', 'rmadgin32', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-20 21:53:06'),
+('bhevey33@nhs.uk', 'qaxe33', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-20 02:48:19'),
+('bbrosenius34@fda.gov', 'ltowlson34', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-03 04:44:06'),
+('mmacak35@cnet.com', 'ctaffarello35', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-05 18:50:48'),
+('cwink36@google.co.uk', 'lsandbrook36', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-14 13:30:50'),
+('hstonard37@gizmodo.com', 'nsmalridge37', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-18 01:40:06'),
+('mhardiman38@nba.com', 'jbastian38', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-27 15:08:47'),
+('mmichurin39@clickbank.net', 'cwignall39', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-01 05:46:40'),
+('dhulson3a@a8.net', 'shamp3a', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-06 15:30:56'),
+('apfeiffer3b@statcounter.com', 'csly3b', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-07 01:10:32'),
+('asearson3c@google.co.jp', 'gcorn3c', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-02 22:51:04'),
+('blehoux3d@china.com.cn', 'cschout3d', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-27 02:39:23'),
+('gofeeney3e@elpais.com', 'tcastagnone3e', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-06 03:27:05'),
+('shedgeley3f@princeton.edu', 'claroze3f', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-09 20:02:58'),
+('arubenov3g@simplemachines.org', 'klidell3g', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-02 06:32:42'),
+('brosander3h@youku.com', 'aboyda3h', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-28 10:15:33'),
+('mmiche3i@berkeley.edu', 'bcuer3i', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-12 04:46:34'),
+('rbessey3j@seesaa.net', 'lcondy3j', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-21 01:10:15'),
+('rganforthe3k@time.com', 'rcardello3k', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-04 09:27:51'),
+('rfoale3l@toplist.cz', 'mimrie3l', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-25 19:48:36'),
+('abegley3m@digg.com', 'mlammerich3m', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-24 11:46:42'),
+('mcoast3n@yellowbook.com', 'raugie3n', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-25 19:29:19'),
+('rloynton3o@illinois.edu', 'aheffernan3o', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-18 07:03:55'),
+('soloshkin3p@samsung.com', 'gpoundesford3p', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-26 10:43:50'),
+('rshelton3q@theglobeandmail.com', 'strench3q', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-12 16:25:04'),
+('rbucknill3r@msu.edu', 'acrinidge3r', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-22 16:51:14'),
+('kstede3s@comcast.net', 'nrodmell3s', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-09 17:08:07'),
+('bbreakspear3t@ebay.com', 'zchalcot3t', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-17 10:38:05'),
+('mbrownscombe3u@themeforest.net', 'sseckom3u', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-10 19:40:40'),
+('reast3v@baidu.com', 'slamie3v', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-20 02:37:08'),
+('btowns3w@t.co', 'jstaunton3w', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-13 13:56:16'),
+('preddick3x@drupal.org', 'cgonin3x', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-02 21:03:12'),
+('bsweet3y@google.pl', 'emilch3y', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-29 15:31:18'),
+('lmacken3z@hao123.com', 'bcorkett3z', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-11 11:47:40'),
+('dcoleborn40@printfriendly.com', 'abinton40', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-25 20:06:57'),
+('bkean41@macromedia.com', 'dlepiscopi41', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-26 17:30:04'),
+('nvanleijs42@nsw.gov.au', 'awinspar42', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-17 03:17:18'),
+('jklink43@ocn.ne.jp', 'tgeraldo43', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-18 09:42:57'),
+('sfarr44@stanford.edu', 'ksegar44', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-08 07:04:44'),
+('nhuckster45@webnode.com', 'mmacellar45', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-11 00:35:10'),
+('jariss46@illinois.edu', 'cskerratt46', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-11-03 02:51:39'),
+('cloachhead47@cisco.com', 'dsirmond47', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-30 06:57:20'),
+('jduetschens48@sbwire.com', 'hpaolini48', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-08 12:01:51'),
+('bmillar49@jigsy.com', 'ttutt49', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-06 06:48:18'),
+('soldacres4a@netscape.com', 'pspritt4a', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-22 16:15:22'),
+('blivermore4b@scientificamerican.com', 'emoro4b', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-26 23:10:04'),
+('bfrancais4c@etsy.com', 'amanuely4c', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-10 03:37:11'),
+('bkuhlen4d@trellian.com', 'pdeaconson4d', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-02 00:52:16'),
+('rreynish4e@ocn.ne.jp', 'mmurby4e', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-23 14:59:34'),
+('hbritney4f@mit.edu', 'rdowty4f', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-25 17:49:28'),
+('sduffree4g@arstechnica.com', 'bmarcam4g', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-04 12:23:31'),
+('emadigan4h@live.com', 'srentoll4h', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-07 13:19:03'),
+('kminer4i@illinois.edu', 'dpoltone4i', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-29 12:08:08'),
+('iharsnep4j@linkedin.com', 'sorcott4j', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-22 07:41:26'),
+('jmanifould4k@so-net.ne.jp', 'hbushnell4k', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-10 22:37:13'),
+('agobolos4l@so-net.ne.jp', 'kwest4l', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-31 17:47:52'),
+('ltreneer4m@ning.com', 'jkinnaird4m', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-11 21:20:20'),
+('cwolfit4n@qq.com', 'asudell4n', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-11 07:03:21'),
+('bkulver4o@google.co.jp', 'pgulliman4o', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-23 02:19:26'),
+('msumers4p@issuu.com', 'daujean4p', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-11 21:29:48'),
+('bfidgett4q@yellowbook.com', 'fchorley4q', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-18 12:47:14'),
+('eseedman4r@alibaba.com', 'ssango4r', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-27 12:55:08'),
+('borlton4s@themeforest.net', 'dgraith4s', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-09 10:33:23'),
+('vfaulder4t@wikispaces.com', 'lanchor4t', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-11 12:49:34'),
+('echatel4u@foxnews.com', 'bzupa4u', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-23 07:17:11'),
+('dvinick4v@princeton.edu', 'cgerriet4v', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-04 06:49:36'),
+('swilbor4w@dailymotion.com', 'jdrummer4w', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-18 12:11:28'),
+('tdilleston4x@xrea.com', 'cleyden4x', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-26 07:41:18'),
+('nbroadbent4y@sciencedaily.com', 'dglander4y', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-16 14:05:18'),
+('dbarczewski4z@lulu.com', 'etreadaway4z', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-09 03:59:28'),
+('mlapree50@so-net.ne.jp', 'dduddle50', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-15 07:54:04'),
+('aodyvoy51@europa.eu', 'apanons51', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-13 15:04:18'),
+('brankmore52@jimdo.com', 'saudenis52', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-10 19:39:24'),
+('kgownge53@businessweek.com', 'vwilce53', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-11 03:36:42'),
+('meales54@altervista.org', 'mmatias54', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-13 02:35:18'),
+('pcaudwell55@whitehouse.gov', 'rbruntjen55', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-30 01:05:10'),
+('enewbury56@free.fr', 'sgillies56', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-11 10:44:37'),
+('pfiske57@sitemeter.com', 'sdeye57', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-08 03:01:34'),
+('rdemalchar58@apple.com', 'cwinsor58', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-25 09:41:26'),
+('ndyster59@upenn.edu', 'mredgewell59', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-16 05:10:12'),
+('tstredwick5a@ameblo.jp', 'mellingsworth5a', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-22 10:12:38'),
+('qbrik5b@stumbleupon.com', 'hbottle5b', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-12 14:01:41'),
+('sproven5c@t.co', 'bloding5c', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-09 18:12:06'),
+('cmarian5d@lulu.com', 'dscholtz5d', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-22 12:01:22'),
+('rcastagnone5e@unc.edu', 'jsterley5e', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-30 03:32:51'),
+('tlatan5f@ask.com', 'bsherington5f', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-12 12:09:29'),
+('jjahnke5g@blog.com', 'mbesset5g', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-21 18:43:22'),
+('awhooley5h@bandcamp.com', 'asheahan5h', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-08 16:28:53'),
+('kaprahamian5i@e-recht24.de', 'dcluckie5i', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-22 13:01:31'),
+('lgoodsal5j@lulu.com', 'sletherbury5j', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-13 15:46:22'),
+('zerrigo5k@freewebs.com', 'aadamovich5k', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-12 16:46:56'),
+('vwelsby5l@example.com', 'fosboldstone5l', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-02 06:33:35'),
+('dmalarkey5m@purevolume.com', 'ehiddsley5m', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-16 23:34:49'),
+('rgrice5n@marketwatch.com', 'msunners5n', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-08 11:20:31'),
+('mloreit5o@google.ca', 'hvader5o', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-26 09:28:28'),
+('epyffe5p@cnet.com', 'sarnecke5p', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-09 01:20:33'),
+('mbartoleyn5q@biblegateway.com', 'gclapison5q', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-27 19:54:58'),
+('nmotto5r@foxnews.com', 'hmillier5r', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-03 06:15:21'),
+('oivory5s@omniture.com', 'mdunkersley5s', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-25 04:25:51'),
+('csidlow5t@goo.gl', 'amurra5t', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-12 20:30:56'),
+('rcarnew5u@unc.edu', 'erhubottom5u', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-02 13:31:29'),
+('bfearnsides5v@cyberchimps.com', 'bmateev5v', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-10 13:29:32'),
+('wsheringham5w@umn.edu', 'kheaton5w', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-06 10:28:53'),
+('sedgeley5x@ucoz.com', 'dfibbings5x', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-22 01:39:15'),
+('esedgeman5y@delicious.com', 'lweldon5y', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-13 12:27:31'),
+('mronan5z@tuttocitta.it', 'galfonso5z', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-29 15:43:40'),
+('vsilliman60@who.int', 'cweeke60', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-11 19:45:40'),
+('wdugdale61@reddit.com', 'atoy61', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-08 10:30:45'),
+('rcock62@spiegel.de', 'ktallboy62', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-26 18:15:59'),
+('aarnaldo63@sciencedirect.com', 'pterese63', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-30 03:58:24'),
+('ltugwell64@senate.gov', 'jvasiliu64', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-22 22:51:33'),
+('gpeskin65@amazon.com', 'tdunbleton65', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-15 22:04:33'),
+('sagius66@census.gov', 'cchecchi66', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-28 12:45:59'),
+('ibromell67@engadget.com', 'lbarnwill67', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-20 19:22:55'),
+('dnowakowska68@examiner.com', 'rpavlik68', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-23 22:16:27'),
+('iennion69@businessweek.com', 'freiners69', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-14 21:57:51'),
+('wchavey6a@npr.org', 'oreuter6a', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-27 04:38:48'),
+('nchristophersen6b@auda.org.au', 'bcopper6b', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-26 10:51:29'),
+('jmacaree6c@amazonaws.com', 'ndytham6c', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-06 02:46:13'),
+('nstandall6d@cyberchimps.com', 'kciobotaro6d', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-14 14:41:15'),
+('jschott6e@t.co', 'jhansom6e', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-10 12:54:49'),
+('sprickett6f@fema.gov', 'bvandendael6f', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-02 04:24:09'),
+('mbudgeon6g@yahoo.com', 'ndurtnal6g', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-04 00:48:57'),
+('cbuckler6h@hostgator.com', 'ssultana6h', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-03 16:01:17'),
+('okarpeev6i@gnu.org', 'kradborn6i', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-13 05:08:33'),
+('dtie6j@a8.net', 'fluckham6j', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-05 02:23:43'),
+('eding6k@uol.com.br', 'gglenn6k', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-09 01:03:42'),
+('dgrzegorzewski6l@blogs.com', 'frosendall6l', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-11-01 08:40:43'),
+('wscreach6m@xinhuanet.com', 'mwaghorne6m', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-06 22:44:12'),
+('ibreming6n@altervista.org', 'dmccarrick6n', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-24 18:55:48'),
+('alarmouth6o@virginia.edu', 'cwyles6o', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-01 17:54:24'),
+('dbauld6p@bravesites.com', 'igathercole6p', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-03 12:08:55'),
+('adeeming6q@accuweather.com', 'wismay6q', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-09 18:23:32'),
+('rgeyton6r@qq.com', 'bcricket6r', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-13 21:39:38'),
+('lcasillas6s@uol.com.br', 'gkavanagh6s', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-09 13:02:19'),
+('jgustus6t@people.com.cn', 'jbartolini6t', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-23 23:38:52'),
+('nlempenny6u@tripadvisor.com', 'olocksley6u', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-18 13:39:38'),
+('battryde6v@sitemeter.com', 'mburfield6v', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-08 07:17:01'),
+('okastel6w@parallels.com', 'hfrancescoccio6w', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-25 13:23:03'),
+('ndelleschi6x@mit.edu', 'fpoacher6x', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-20 13:26:04'),
+('nstalf6y@eventbrite.com', 'sphillpotts6y', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-27 14:03:32'),
+('hedis6z@illinois.edu', 'lbilborough6z', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-28 19:47:38'),
+('fmollindinia70@apple.com', 'gkase70', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-12 07:01:35'),
+('bfaers71@tripadvisor.com', 'gcrosetto71', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-30 09:27:34'),
+('cjanse72@photobucket.com', 'olatan72', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-05 11:59:42'),
+('mfarny73@joomla.org', 'kgealle73', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-26 15:43:24'),
+('lbradneck74@ow.ly', 'golin74', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-25 23:07:40'),
+('jottawell75@cdc.gov', 'tpetasch75', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-11 18:38:46'),
+('bkarlolczak76@nature.com', 'civanishchev76', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-09 06:16:32'),
+('jkeningley77@cafepress.com', 'felintune77', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-16 20:16:21'),
+('ibartolini78@fotki.com', 'klampert78', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-19 08:53:49'),
+('bcandlish79@cbsnews.com', 'jbinnes79', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-01 18:00:37'),
+('jbrittle7a@fastcompany.com', 'sraiker7a', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-19 09:22:14'),
+('ewrench7b@webs.com', 'zdearness7b', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-26 08:02:26'),
+('mwrought7c@nymag.com', 'mswinerd7c', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-06 00:48:41'),
+('hpostance7d@ebay.co.uk', 'cvalencia7d', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-11-04 19:49:50'),
+('mhelversen7e@apache.org', 'vabramof7e', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-12 01:46:32'),
+('lgrandisson7f@pbs.org', 'mentissle7f', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-19 15:01:39'),
+('jfines7g@narod.ru', 'famphlett7g', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-24 06:31:16'),
+('jmattessen7h@tamu.edu', 'rvispo7h', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-20 17:49:09'),
+('hbeards7i@wikipedia.org', 'obasketfield7i', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-29 18:42:22'),
+('aloosmore7j@addthis.com', 'psmylie7j', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-09 11:13:29'),
+('jlangstrath7k@hatena.ne.jp', 'mlarne7k', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-15 06:13:14'),
+('bmandrake7l@telegraph.co.uk', 'aludlam7l', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-06 07:50:11'),
+('cbeasley7m@chronoengine.com', 'mmaber7m', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-25 07:36:55'),
+('wcater7n@umich.edu', 'qpacht7n', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-28 22:54:18'),
+('pbartolozzi7o@icio.us', 'tannis7o', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-07 07:15:04'),
+('jgallardo7p@rambler.ru', 'pmelato7p', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-29 11:14:28'),
+('coakly7q@hud.gov', 'bluckin7q', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-24 14:05:16'),
+('okippen7r@icq.com', 'tbarense7r', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-09 04:14:26'),
+('etagg7s@cornell.edu', 'otagg7s', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-07 05:02:54'),
+('mhearthfield7t@walmart.com', 'rocarroll7t', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-29 03:10:28'),
+('broyds7u@ebay.com', 'ygarred7u', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-17 16:55:42'),
+('uplace7v@va.gov', 'cdivine7v', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-11 20:09:06'),
+('aroutley7w@columbia.edu', 'vmacdavitt7w', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-28 11:23:45'),
+('byokley7x@moonfruit.com', 'udominick7x', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-27 08:37:31'),
+('soharney7y@usda.gov', 'dgronav7y', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-02 07:34:55'),
+('sfrean7z@nymag.com', 'dhegerty7z', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-08 08:59:52'),
+('zdrohan80@cisco.com', 'apendred80', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-27 23:46:03'),
+('dbatterton81@friendfeed.com', 'bferran81', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-19 06:06:48'),
+('uforrest82@hhs.gov', 'hvernay82', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-07 11:44:52'),
+('ohourston83@discovery.com', 'eleftwich83', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-19 10:33:03'),
+('ecoggon84@desdev.cn', 'sdurston84', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-22 07:11:05'),
+('idonaghie85@house.gov', 'wropkins85', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-09 00:40:44'),
+('nmoorwood86@walmart.com', 'rhandscomb86', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-31 01:24:06'),
+('ldartnall87@discuz.net', 'bmantz87', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-18 12:49:43'),
+('xsimioli88@tumblr.com', 'ccradoc88', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-30 10:53:05'),
+('lstreatfeild89@apache.org', 'vadel89', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-19 20:51:13'),
+('nmoseley8a@cnet.com', 'pcrampsey8a', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-04 04:33:37'),
+('mreames8b@ehow.com', 'rsibbet8b', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-30 02:00:17'),
+('htookill8c@over-blog.com', 'tcastard8c', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-22 17:29:41'),
+('fpridden8d@disqus.com', 'rhawksworth8d', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-09 16:02:57'),
+('lblaver8e@ucoz.com', 'gclare8e', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-30 05:13:31'),
+('ebrimicombe8f@goo.gl', 'swillows8f', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-28 04:45:42'),
+('kclaus8g@washington.edu', 'tausher8g', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-30 09:18:42'),
+('icamois8h@sfgate.com', 'mdondon8h', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-02 19:33:17'),
+('hpedrollo8i@sourceforge.net', 'jpenwright8i', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-27 13:28:15'),
+('boene8j@tamu.edu', 'wtipler8j', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-20 22:36:03'),
+('bgerok8k@storify.com', 'gkorous8k', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-23 15:53:12'),
+('tkelling8l@princeton.edu', 'gbobasch8l', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-03 12:01:31'),
+('ssex8m@imdb.com', 'mfranzewitch8m', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-19 04:33:20'),
+('dlester8n@wikimedia.org', 'vbyass8n', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-25 21:49:30'),
+('tdysart8o@plala.or.jp', 'hreah8o', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-24 11:03:22'),
+('dgillino8p@webeden.co.uk', 'jstredwick8p', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-12 07:52:21'),
+('cnorthley8q@purevolume.com', 'moffill8q', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-23 16:29:31'),
+('dkacheler8r@cisco.com', 'rspinas8r', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-09 08:59:52'),
+('dsymonds8s@google.es', 'wjarrelt8s', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-15 15:47:41'),
+('msamsworth8t@miibeian.gov.cn', 'gmoyler8t', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-27 19:51:48'),
+('cbuntin8u@mit.edu', 'rdeclairmont8u', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-07 01:32:40'),
+('bfehner8v@eventbrite.com', 'kfitzsimon8v', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-13 12:32:16'),
+('eluton8w@adobe.com', 'mjuggins8w', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-14 03:37:15'),
+('smiskimmon8x@statcounter.com', 'vgeaveny8x', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-15 23:15:46'),
+('abenaharon8y@apple.com', 'idrinkall8y', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-25 04:52:17'),
+('ftewkesberry8z@unc.edu', 'jgayton8z', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-23 10:55:27'),
+('pbottrell90@nationalgeographic.com', 'rblakey90', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-29 20:57:23'),
+('yperch91@ucsd.edu', 'bsouter91', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-23 10:58:10'),
+('slangridge92@uol.com.br', 'rverrills92', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-05 08:02:40'),
+('efeathers93@answers.com', 'apickless93', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-26 14:38:57'),
+('cbazely94@wufoo.com', 'ahaldene94', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-29 15:29:41'),
+('wphilpault95@patch.com', 'mnazair95', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-07 04:18:41'),
+('eoswal96@technorati.com', 'lmcgilvray96', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-03 20:16:47'),
+('apagett97@hp.com', 'doldey97', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-26 22:46:58'),
+('ekittel98@com.com', 'lhazelhurst98', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-23 06:53:11'),
+('mambrozewicz99@imageshack.us', 'rschiersch99', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-30 10:15:50'),
+('pwarratt9a@smh.com.au', 'hhawtrey9a', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-15 13:54:00'),
+('mheitz9b@washingtonpost.com', 'ttetford9b', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-01 14:09:14'),
+('gdevereux9c@unicef.org', 'ivolkes9c', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-14 08:09:48'),
+('nwitchard9d@businessinsider.com', 'hkilian9d', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-17 03:13:39'),
+('cruzek9e@virginia.edu', 'ecartlidge9e', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-13 22:07:28'),
+('mfullard9f@vinaora.com', 'hmccoughan9f', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-28 11:40:22'),
+('lodooghaine9g@rediff.com', 'zbakewell9g', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-18 14:41:30'),
+('olyosik9h@hatena.ne.jp', 'rolley9h', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-14 02:37:22'),
+('mdymoke9i@netlog.com', 'srieflin9i', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-12 16:48:25'),
+('lmckendry9j@wordpress.com', 'cstocks9j', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-22 23:10:23'),
+('bmatejka9k@mlb.com', 'ttesto9k', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-28 03:19:41'),
+('icrennell9l@delicious.com', 'asailes9l', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-20 13:05:35'),
+('bcammock9m@nationalgeographic.com', 'kduffree9m', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-15 07:54:02'),
+('dlemasney9n@businessweek.com', 'askittrell9n', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-29 19:35:36'),
+('cwedderburn9o@shutterfly.com', 'rlittlecote9o', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-05 02:33:02'),
+('csollas9p@merriam-webster.com', 'ppetegre9p', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-23 10:39:21'),
+('aharbottle9q@unblog.fr', 'jreichhardt9q', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-31 12:26:46'),
+('sandreu9r@census.gov', 'jstandbrook9r', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-19 21:34:10'),
+('sfermer9s@sun.com', 'bboays9s', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-14 16:45:50'),
+('jspofford9t@edublogs.org', 'chorsefield9t', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-24 22:37:45'),
+('ebottom9u@issuu.com', 'tpottage9u', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-13 10:55:55'),
+('gallerton9v@guardian.co.uk', 'efrenchum9v', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-19 23:26:28'),
+('smerner9w@godaddy.com', 'tgrzeskowski9w', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-30 16:49:35'),
+('dfosbraey9x@zdnet.com', 'pthraves9x', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-21 00:45:58'),
+('emoogan9y@berkeley.edu', 'ewhittet9y', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-03 07:32:54'),
+('kfensome9z@usatoday.com', 'hkain9z', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-28 23:19:14'),
+('aayliffea0@twitpic.com', 'ctinwella0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-24 21:02:02'),
+('bgallamorea1@about.me', 'hberga1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-12 01:13:19'),
+('dellacotta2@netscape.com', 'rriguarda2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-21 16:19:50'),
+('aaveyarda3@chronoengine.com', 'sscholtza3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-10 11:13:36'),
+('okunaa4@earthlink.net', 'eliklya4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-17 17:09:07'),
+('dgribbona5@wired.com', 'gdenesa5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-26 13:01:08'),
+('gantrama6@blogtalkradio.com', 'cportmana6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-25 16:47:29'),
+('fworgena7@discovery.com', 'clittleprouda7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-09 09:08:22'),
+('dpurrya8@globo.com', 'hgurerya8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-29 01:24:29'),
+('awillmera9@wikimedia.org', 'eurrya9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-22 21:50:51'),
+('msculpheraa@house.gov', 'gtallboyaa', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-02 12:24:25'),
+('cshurmerab@harvard.edu', 'hkneaphseyab', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-05 20:23:06'),
+('jdonoherac@omniture.com', 'ereelyac', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-26 05:51:15'),
+('sstrassead@weibo.com', 'nquartermainead', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-11 08:20:27'),
+('hsimmgenae@tripadvisor.com', 'dhowsanae', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-10 14:32:06'),
+('rcombenaf@reverbnation.com', 'syanovaf', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-28 10:46:46'),
+('klindroag@cpanel.net', 'dscoatesag', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-22 16:21:57'),
+('kgwynah@bing.com', 'bspriggah', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-28 16:52:54'),
+('gbaumertai@desdev.cn', 'cchaffinai', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-03 02:29:33'),
+('bkimmingsaj@cmu.edu', 'dcutfordaj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-15 10:58:13'),
+('randererak@washington.edu', 'rlampreyak', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-28 15:59:19'),
+('ksidwellal@nbcnews.com', 'lgilardengoal', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-26 23:46:37'),
+('mflockhartam@europa.eu', 'sclowneyam', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-27 20:42:37'),
+('mbraffertonan@aboutads.info', 'kjewessan', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-13 06:38:48'),
+('hwindleyao@sina.com.cn', 'ahanbyao', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-04 15:32:45'),
+('nnieassap@tinypic.com', 'jyakobowitchap', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-20 21:12:52'),
+('malmonaq@vimeo.com', 'ctropmanaq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-10 22:16:56'),
+('hbelvinar@typepad.com', 'kraincinar', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-04 18:28:00'),
+('aqueyeiroas@gnu.org', 'cbonhomeas', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-16 17:41:20'),
+('rellitat@google.fr', 'chucksteppat', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-24 13:15:55'),
+('sgodilingtonau@chron.com', 'aantleyau', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-11 07:20:49'),
+('mmoylesav@who.int', 'ralmonav', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-14 16:06:27'),
+('bazemaraw@infoseek.co.jp', 'akirkbrideaw', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-30 17:16:07'),
+('rmeakeax@google.pl', 'mjakubowskyax', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-18 21:31:42'),
+('dlorrimanay@example.com', 'cmasdonay', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-11-05 08:34:01'),
+('cnisbithaz@stanford.edu', 'wrobottomaz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-14 19:26:19'),
+('spigramb0@latimes.com', 'cliversidgeb0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-15 00:14:22'),
+('lwoollinb1@ocn.ne.jp', 'elittlejohnsb1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-19 10:10:20'),
+('jalbisserb2@devhub.com', 'grissonb2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-21 20:32:05'),
+('dardlingb3@gravatar.com', 'bcreamerb3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-09 20:26:53'),
+('egillicuddyb4@1688.com', 'lhousegob4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-27 13:02:32'),
+('cmoralisb5@businessweek.com', 'fmurisb5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-13 13:33:29'),
+('amattisb6@last.fm', 'btitterellb6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-05 04:01:12'),
+('mstpierreb7@blogspot.com', 'pparkeb7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-25 16:18:05'),
+('dpengelleyb8@ustream.tv', 'tmahargb8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-04 10:29:52'),
+('cborkinb9@bing.com', 'cpetranb9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-12 06:43:42'),
+('yeickhoffba@wordpress.org', 'hmosedallba', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-09 23:01:38'),
+('kstambridgebb@redcross.org', 'mpohlkebb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-27 22:24:31'),
+('hmcauleybc@answers.com', 'rwelsbybc', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-17 12:17:15'),
+('rbartolettibd@parallels.com', 'dtonkinsonbd', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-13 17:49:00'),
+('bhyslopbe@google.com.au', 'gbuerbe', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-15 23:18:31'),
+('npiscopellobf@craigslist.org', 'taustonbf', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-14 20:56:53'),
+('elummusbg@tinypic.com', 'emcclenanbg', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-06 19:44:38'),
+('fwalklotbh@wsj.com', 'fbraggerbh', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-22 02:52:04'),
+('rspridgeonbi@icq.com', 'etzarbi', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-14 03:07:40'),
+('cverrechiabj@comsenz.com', 'tbenetbj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-25 10:30:04'),
+('ngummoebk@salon.com', 'cbreslauerbk', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-16 10:27:18'),
+('madolthinebl@paypal.com', 'sdowntonbl', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-30 17:42:38'),
+('ajorgerbm@about.com', 'jdwyrbm', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-05 19:22:04'),
+('kboutflourbn@qq.com', 'mpowisbn', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-30 05:19:19'),
+('wmozzinibo@purevolume.com', 'smattionibo', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-22 11:00:14'),
+('ctrigglebp@hhs.gov', 'tfaldobp', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-27 21:01:59'),
+('utesseymanbq@businessinsider.com', 'fwraggbq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-01 19:42:44'),
+('lhirsthousebr@imageshack.us', 'aizakoffbr', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-28 18:33:37'),
+('pmocherbs@imgur.com', 'tstilldalebs', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-19 17:40:10'),
+('csedmanbt@geocities.com', 'gskainsbt', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-07 20:41:17'),
+('corwellbu@youtube.com', 'vlenihanbu', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-20 15:53:37'),
+('dulsterbv@chronoengine.com', 'fgonzalesbv', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-27 07:50:26'),
+('gaitonbw@netlog.com', 'rzavatterobw', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-11-02 19:32:10'),
+('afaggebx@disqus.com', 'wsarchwellbx', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-04 20:30:17'),
+('mmorpheyby@myspace.com', 'dhainningby', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-15 00:45:16'),
+('gbreetonbz@china.com.cn', 'stesterbz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-16 16:50:40'),
+('atooleyc0@example.com', 'nkaresc0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-10 19:39:34'),
+('tmullisc1@hostgator.com', 'cdalyc1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-17 21:18:12'),
+('fmctiernanc2@home.pl', 'nacunac2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-21 10:10:45'),
+('bidiensc3@domainmarket.com', 'ushillabearec3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-11 23:36:55'),
+('rsudronc4@nih.gov', 'kgurnayc4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-30 01:26:59'),
+('jmulchronec5@nydailynews.com', 'iagerc5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-11 18:47:02'),
+('gmilleac6@blinklist.com', 'vsimakovc6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-05 13:02:47'),
+('yksandrac7@arstechnica.com', 'amuttockc7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-08 08:55:38'),
+('dridenc8@psu.edu', 'eferrarinic8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-16 05:28:00'),
+('greichardtc9@canalblog.com', 'hplessingc9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-15 21:18:33'),
+('rliepinaca@cocolog-nifty.com', 'smoyceca', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-12 14:38:51'),
+('gmussallicb@addthis.com', 'okennlercb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-28 11:53:56'),
+('cbouldercc@bigcartel.com', 'obarlthropcc', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-23 23:45:02'),
+('mbrislawncd@engadget.com', 'garnoutcd', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-17 22:27:27'),
+('elameyce@nydailynews.com', 'emustince', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-17 06:41:27'),
+('rkeedwellcf@uiuc.edu', 'cswanwickcf', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-27 11:30:39'),
+('bhellwichcg@boston.com', 'ltrimnellcg', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-31 16:47:33'),
+('ltreskech@homestead.com', 'nbestch', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-25 15:08:30'),
+('mdadswellci@prweb.com', 'fcantuaci', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-08 08:23:50'),
+('aenburycj@biglobe.ne.jp', 'ovillaroncj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-18 08:53:02'),
+('hdanihelkack@blogtalkradio.com', 'hauchterlonyck', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-13 00:51:44'),
+('rsmorfitcl@prlog.org', 'bpirouetcl', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-25 15:33:03'),
+('sslidderscm@bizjournals.com', 'lgooldingcm', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-08 18:34:06'),
+('sbrelsfordcn@mediafire.com', 'jkrahlcn', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-04 03:24:24'),
+('csulmanco@acquirethisname.com', 'ysyceco', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-15 15:53:53'),
+('dgehrelscp@cloudflare.com', 'bjonathoncp', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-18 19:57:18'),
+('dcutmarecq@pagesperso-orange.fr', 'educarnecq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-17 03:13:02'),
+('wsiemenscr@nydailynews.com', 'nquantickcr', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-24 01:52:01'),
+('dingersoncs@jimdo.com', 'lcastillacs', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-17 16:03:13'),
+('jpaddlect@lulu.com', 'gstariesct', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-14 12:53:34'),
+('bcaskiecu@dot.gov', 'jargonttcu', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-06 05:48:39'),
+('cflippellicv@gov.uk', 'tvercruyssecv', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-17 00:58:53'),
+('rjoysoncw@issuu.com', 'mdallicottcw', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-07 06:10:48'),
+('gwincercx@ask.com', 'lkeemscx', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-16 11:18:36'),
+('rdiamondcy@miibeian.gov.cn', 'wmacenellycy', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-11-05 18:45:58'),
+('coliverascz@state.gov', 'lriggeardcz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-31 07:34:13'),
+('ygrested0@ameblo.jp', 'pzellickd0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-22 09:07:37'),
+('olippittd1@rediff.com', 'hraffind1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-03 20:23:45'),
+('acollingtond2@nba.com', 'cflaxmand2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-17 03:11:39'),
+('bstarbeckd3@barnesandnoble.com', 'cflockhartd3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-27 18:50:49'),
+('owolfed4@goodreads.com', 'bubsdalld4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-03 21:32:42'),
+('apaskelld5@reference.com', 'bgajownikd5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-20 11:57:25'),
+('smcgreyd6@hp.com', 'mmazonowiczd6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-01 17:32:21'),
+('pbeasantd7@uiuc.edu', 'jkennsleyd7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-20 12:32:02'),
+('tstockoed8@list-manage.com', 'cdaverand8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-17 04:38:57'),
+('pshermard9@mozilla.com', 'aturnockd9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-13 21:41:08'),
+('nthurgoodda@1688.com', 'sfynnda', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-26 07:39:56'),
+('dgethousedb@odnoklassniki.ru', 'ajansedb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-13 21:31:41'),
+('skulveardc@google.ru', 'vcattrolldc', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-14 17:09:06'),
+('bschottdd@last.fm', 'aduffyndd', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-23 07:21:18'),
+('qkaufmande@topsy.com', 'mobyde', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-28 01:48:10'),
+('fderilldf@4shared.com', 'kgavriellydf', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-16 03:56:57'),
+('gcorredg@un.org', 'adeversdg', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-11 06:04:29'),
+('drapindh@noaa.gov', 'mdawedh', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-18 18:49:49'),
+('amaskewdi@opensource.org', 'jbahldi', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-24 18:32:48'),
+('caudissdj@histats.com', 'mdroughtdj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-09 03:53:31'),
+('obalcombedk@oaic.gov.au', 'dtinceydk', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-12 21:11:32'),
+('kbassilldl@imdb.com', 'jlatchforddl', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-09 16:37:35'),
+('ddobbinsdm@plala.or.jp', 'hbausdm', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-05 12:00:58'),
+('tfreckeltondn@alibaba.com', 'schamdn', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-05 04:05:40'),
+('bhalcrodo@nyu.edu', 'olystedo', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-22 01:03:51'),
+('cwynchdp@t.co', 'welluldp', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-28 03:33:39'),
+('mtynemouthdq@fotki.com', 'kslowgravedq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-05 23:58:48'),
+('cfawkedr@1und1.de', 'ablackmandr', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-13 02:30:49'),
+('fbourhillds@seattletimes.com', 'psalmonds', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-31 16:27:22'),
+('jbrownsteindt@nps.gov', 'ploadesdt', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-09 04:33:27'),
+('haubrydu@cam.ac.uk', 'nsowtendu', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-19 04:42:32'),
+('bbrewettdv@google.cn', 'cpaolazzidv', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-26 07:23:45'),
+('tdarydw@aol.com', 'mkirdschdw', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-15 01:06:32'),
+('cbonnesendx@hatena.ne.jp', 'fmicallefdx', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-06 14:58:37'),
+('lhatchettedy@myspace.com', 'gtanslydy', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-17 06:12:31'),
+('dcollisdz@nydailynews.com', 'dtrusteydz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-18 18:08:56'),
+('jnailee0@howstuffworks.com', 'bsaffelle0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-18 07:47:51'),
+('tdibsdalee1@earthlink.net', 'fcarlislee1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-28 12:24:56'),
+('jgrimleye2@guardian.co.uk', 'ureedicke2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-21 05:07:02'),
+('lmepstede3@yellowpages.com', 'jrosene3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-07 17:23:45'),
+('ekelinge4@google.com.au', 'ayitshake4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-31 01:30:55'),
+('rcawsbye5@bbb.org', 'mschwandermanne5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-04 01:26:34'),
+('dholseye6@nasa.gov', 'jtolemane6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-23 10:31:51'),
+('mdochone7@twitpic.com', 'rbensone7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-25 15:42:00'),
+('mbemande8@chicagotribune.com', 'ngimblete8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-20 20:07:02'),
+('wyeae9@google.com.hk', 'vhalifaxe9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-18 12:30:17'),
+('sbinionea@dropbox.com', 'sarisea', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-08 03:52:20'),
+('ifalooneb@wisc.edu', 'acowburneb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-07 06:22:57'),
+('gtillec@deviantart.com', 'lvasiltsovec', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-04 14:36:15'),
+('twaslined@about.com', 'ksedgered', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-23 11:57:00'),
+('rmckillopee@weather.com', 'emckiddinee', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-19 01:23:10'),
+('rheggesef@desdev.cn', 'mherrerosef', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-07 19:44:04'),
+('vthornborroweg@purevolume.com', 'jchastoneg', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-22 16:41:26'),
+('phrinchenkoeh@usatoday.com', 'shollymaneh', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-13 15:38:48'),
+('gsawlei@ox.ac.uk', 'dcalytonei', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-08 18:46:05'),
+('skindleysidesej@webeden.co.uk', 'sstrelitzerej', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-24 21:30:59'),
+('pbrunottiek@creativecommons.org', 'mlapennaek', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-14 16:19:26'),
+('kshackleel@toplist.cz', 'hgladdorel', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-15 12:22:03'),
+('mhowlettem@flavors.me', 'kmacbeyem', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-26 07:36:27'),
+('apovallen@studiopress.com', 'eharkeren', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-12 11:00:28'),
+('kdiffleyeo@w3.org', 'rbreslauereo', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-23 01:02:46'),
+('ckeightleyep@upenn.edu', 'balcoranep', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-18 01:07:59'),
+('tmcsporoneq@whitehouse.gov', 'churleeq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-02 19:35:48'),
+('shalwooder@ibm.com', 'ahamnerer', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-29 15:22:37'),
+('gtiltmanes@storify.com', 'fhamshawes', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-25 06:34:50'),
+('bduinbletonet@fema.gov', 'lhouseagoet', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-07 07:32:17'),
+('nberzoneu@seesaa.net', 'hhellmoreeu', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-14 18:13:42'),
+('cwayonev@linkedin.com', 'bcobainev', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-29 14:18:03'),
+('istegelew@dailymail.co.uk', 'tfruchonew', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-24 01:04:42'),
+('pmorterex@google.fr', 'dcockshttex', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-26 00:33:33'),
+('ccolerickey@usda.gov', 'cgovaney', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-10 17:49:41'),
+('cspightez@elegantthemes.com', 'wdonneez', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-15 08:39:24'),
+('fhigbinf0@wsj.com', 'csheersf0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-16 08:21:02'),
+('jmaskreyf1@apache.org', 'aprichetf1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-11 10:14:51'),
+('akingslandf2@loc.gov', 'mteazf2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-21 23:13:34'),
+('ttreherf3@dell.com', 'rgoddingf3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-12 19:49:20'),
+('lgilfoyf4@census.gov', 'phoulisonf4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-28 01:37:14'),
+('cclarkinf5@ning.com', 'pcornfordf5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-04 09:49:56'),
+('lprendevillef6@hatena.ne.jp', 'mstockbridgef6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-22 06:47:21'),
+('gdronsfieldf7@dedecms.com', 'lmcguffief7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-22 09:10:04'),
+('dkuhnelf8@mashable.com', 'lmclarnonf8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-18 03:39:30'),
+('esmowtonf9@disqus.com', 'eknollerf9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-28 16:48:14'),
+('ecullrfordfa@illinois.edu', 'gjerroltfa', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-19 16:33:24'),
+('hfriattfb@japanpost.jp', 'ipiddickfb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-19 22:45:00'),
+('lcarwithenfc@businessinsider.com', 'edillewayfc', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-06 12:09:40'),
+('pdomanfd@nytimes.com', 'cdoftyfd', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-18 21:06:28'),
+('sverrillofe@odnoklassniki.ru', 'dgranhamfe', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-24 01:08:30'),
+('bberthomieuff@clickbank.net', 'wbabinff', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-19 05:18:42'),
+('vhallowellfg@flavors.me', 'wbougenfg', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-26 04:21:29'),
+('bbaldinottifh@dailymotion.com', 'afountainefh', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-16 09:02:39'),
+('wdriscollfi@oracle.com', 'ecorainfi', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-23 03:27:33'),
+('zholbornfj@nytimes.com', 'kcratfj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-26 19:46:05'),
+('mhalworthfk@usa.gov', 'ighionifk', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-31 01:43:44'),
+('jcairnsfl@nyu.edu', 'ctommeifl', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-08 04:10:53'),
+('ybrimilcombefm@yahoo.co.jp', 'msammonsfm', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-25 13:53:18'),
+('tfootefn@ed.gov', 'jbatcockfn', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-18 09:55:49'),
+('glacottefo@newyorker.com', 'dscortonfo', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-25 02:19:57'),
+('etrulockefp@wikipedia.org', 'cheinricifp', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-27 05:11:06'),
+('bslackfq@hexun.com', 'gellacombefq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-03 14:24:58'),
+('odoylandfr@samsung.com', 'ttrasslerfr', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-09 00:14:36'),
+('jfryettfs@lulu.com', 'mcastaignetfs', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-17 12:01:03'),
+('beclesft@miibeian.gov.cn', 'mbartlomieczakft', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-06 18:36:47'),
+('atelfordfu@infoseek.co.jp', 'cmappfu', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-18 14:23:59'),
+('kormesherfv@wisc.edu', 'vreeveleyfv', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-06 00:23:17'),
+('jthomafw@tumblr.com', 'mburnellfw', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-29 15:51:28'),
+('mmckellenfx@xing.com', 'khudspithfx', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-09 21:42:31'),
+('jyoudellfy@redcross.org', 'apymfy', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-15 12:34:39'),
+('mlaycockfz@pcworld.com', 'kspiersfz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-07 13:43:58'),
+('hormistong0@columbia.edu', 'istoadeg0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-08 06:43:25'),
+('ntreadwayg1@businesswire.com', 'kiddong1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-13 18:12:04'),
+('nhalshawg2@biblegateway.com', 'pconaghang2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-11 08:52:37'),
+('bantliffg3@ehow.com', 'kmacaneg3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-12 17:53:14'),
+('ktidboldg4@mysql.com', 'kbuckthorpg4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-08 11:59:30'),
+('mrountreeg5@ebay.co.uk', 'etruderg5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-23 22:16:46'),
+('bfitzharrisg6@google.cn', 'igeorgiusg6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-21 10:10:27'),
+('mgosnellg7@upenn.edu', 'bskynerg7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-07 03:00:43'),
+('setong8@t-online.de', 'laverillg8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-28 02:32:43'),
+('afeldsteing9@prweb.com', 'guccellig9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-13 06:41:31'),
+('hhumphersonga@smugmug.com', 'mnordassga', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-23 07:31:00'),
+('aberryclothgb@woothemes.com', 'smccandiegb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-06 07:27:09'),
+('mbrickgc@imageshack.us', 'ebailessgc', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-17 10:40:02'),
+('jcoastergd@senate.gov', 'tpaternogd', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-08 04:52:31'),
+('ajarrattge@bloglovin.com', 'tspittlege', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-31 09:41:35'),
+('ylorentzengf@mit.edu', 'rtothacotgf', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-16 15:26:13'),
+('gwatkissgg@auda.org.au', 'mbromidgegg', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-27 05:22:00'),
+('spybworthgh@dot.gov', 'rconnuegh', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-02 15:35:26'),
+('erodingergi@tripadvisor.com', 'ccarsegi', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-26 12:04:16'),
+('bardleygj@squarespace.com', 'otrevettgj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-17 16:12:45'),
+('ideeringgk@loc.gov', 'lwhartongk', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-13 11:38:37'),
+('againsburygl@pen.io', 'cdebruingl', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-09 11:47:32'),
+('alarrattgm@amazonaws.com', 'pmoresidegm', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-28 10:14:09'),
+('gbritchergn@is.gd', 'gkwietakgn', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-15 04:18:07'),
+('vjaraygo@walmart.com', 'clethieuliergo', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-10 11:26:53'),
+('scoatsworthgp@ibm.com', 'hgilbeartgp', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-13 15:06:12'),
+('mmoraledagq@accuweather.com', 'etrousdellgq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-25 09:34:31'),
+('gdigglesgr@alexa.com', 'gbooijgr', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-28 22:43:34'),
+('wfilyukovgs@woothemes.com', 'elefrancgs', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-21 09:23:32'),
+('kfairfoulgt@digg.com', 'akovelmanngt', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-18 06:59:48'),
+('akerslakegu@last.fm', 'jshoardgu', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-29 18:15:49'),
+('rdenisongv@tripadvisor.com', 'kpooleygv', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-30 22:40:59'),
+('tskipseagw@ucoz.ru', 'acogergw', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-28 23:30:11'),
+('zsimounetgx@meetup.com', 'jfencottgx', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-25 11:51:07'),
+('dduchangy@senate.gov', 'bmichiegy', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-11-02 23:59:06'),
+('bbetoniagz@usnews.com', 'mbecerilgz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-21 02:08:32'),
+('gpotierh0@virginia.edu', 'kocarrollh0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-20 10:35:14'),
+('plennardeh1@cdbaby.com', 'ibraidwoodh1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-19 06:43:03'),
+('gboulgerh2@simplemachines.org', 'btindallh2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-28 14:12:16'),
+('scorretth3@prweb.com', 'hchritchlowh3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-17 12:26:53'),
+('lwannh4@irs.gov', 'cdrinanh4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-08 06:00:53'),
+('dweddeburnh5@harvard.edu', 'browesbyh5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-11 15:09:30'),
+('mtizardh6@time.com', 'szanellih6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-14 05:19:16'),
+('cgradonh7@imdb.com', 'bbrysonh7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-11 00:37:10'),
+('sklosh8@chron.com', 'cwigfallh8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-11 19:58:16'),
+('pchampkinh9@cdbaby.com', 'doliveh9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-27 00:45:05'),
+('mpratleyha@squidoo.com', 'jcamolettoha', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-04 05:12:50'),
+('ccantihb@typepad.com', 'vambrosinihb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-14 05:11:37'),
+('gblinckohc@rediff.com', 'tsalmondhc', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-11 03:24:40'),
+('sconkeyhd@ovh.net', 'msweetinghd', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-12 12:43:55'),
+('smccaskillhe@1688.com', 'rdemarehe', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-01 17:21:25'),
+('tarundellhf@usnews.com', 'hhouldenhf', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-12 16:46:26'),
+('tjohananovhg@indiatimes.com', 'abayntonhg', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-11-05 16:06:31'),
+('jpotteridgehh@opera.com', 'spestellhh', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-09 07:08:52'),
+('rgaitleyhi@ox.ac.uk', 'lmaileyhi', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-26 18:36:39'),
+('cbelitzhj@is.gd', 'edominiakhj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-06 16:02:24'),
+('jescalahk@devhub.com', 'adarkohk', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-21 06:59:16'),
+('cgaywoodhl@google.co.jp', 'dbearwardhl', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-14 04:21:22'),
+('shardwellhm@scribd.com', 'hpeckhamhm', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-07 18:06:00'),
+('ksitwellhn@theglobeandmail.com', 'pivshinhn', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-09 01:09:06'),
+('bsandercockho@unc.edu', 'jpearceyho', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-25 11:22:29'),
+('epittwoodhp@cloudflare.com', 'rroehp', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-23 07:18:10'),
+('dvilehq@cam.ac.uk', 'qbarneshq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-29 09:55:15'),
+('dchandlarhr@over-blog.com', 'bdavidshr', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-21 03:13:26'),
+('aweekshs@discuz.net', 'tclegghs', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-02 20:58:09'),
+('vriseamht@geocities.com', 'gbudnikht', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-05 02:38:25'),
+('dantalhu@go.com', 'mfranklinghu', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-20 11:26:23'),
+('bjahnerhv@foxnews.com', 'mmcgeachyhv', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-16 04:43:14'),
+('uskainhw@apple.com', 'vkenwortheyhw', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-18 22:27:31'),
+('bpawlynhx@google.co.jp', 'jangelihx', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-14 05:21:30'),
+('dstinsonhy@feedburner.com', 'gackermannhy', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-31 13:41:26'),
+('aoverpoolhz@com.com', 'lpurlehz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-08 20:02:55'),
+('szutei0@earthlink.net', 'fsolowayi0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-22 13:41:00'),
+('kfenechi1@auda.org.au', 'rdalwisi1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-24 05:19:15'),
+('kmigueli2@mediafire.com', 'celandi2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-01 11:55:01'),
+('pwhitewoodi3@chicagotribune.com', 'ctwinbrowi3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-30 02:28:56'),
+('kmostyni4@buzzfeed.com', 'odumbletoni4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-29 05:26:27'),
+('uortigai5@elpais.com', 'hbaythorpi5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-26 22:35:00'),
+('jebbrelli6@hexun.com', 'nkieldi6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-20 20:49:43'),
+('rthomanni7@auda.org.au', 'bworlandi7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-23 15:25:19'),
+('dboulesi8@seesaa.net', 'bbendixi8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-28 11:50:45'),
+('dcupperi9@timesonline.co.uk', 'schrispini9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-10 03:39:43'),
+('ychildsia@infoseek.co.jp', 'ilinnia', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-24 08:28:42'),
+('gpetrassoib@angelfire.com', 'abutterfillib', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-18 05:33:09'),
+('ddewickeic@amazon.com', 'ocadwalladeric', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-18 04:26:58'),
+('ekhidrid@mozilla.org', 'hbedinnid', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-03 00:28:45'),
+('sgenneyie@exblog.jp', 'tdownieie', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-01 07:19:46'),
+('gsaltmarsheif@nifty.com', 'ktolfreyif', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-17 05:04:50'),
+('nmaclucaisig@google.com.au', 'ckiteig', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-11 08:01:09'),
+('llitelih@alibaba.com', 'ctolcherih', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-25 10:04:24'),
+('cbingallii@mediafire.com', 'pwoodfineii', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-27 20:25:37'),
+('slanceleyij@1und1.de', 'acrollmanij', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-16 10:45:18'),
+('nshrimplinik@is.gd', 'hwiddowsonik', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-13 11:34:26'),
+('eeidelmanil@amazon.de', 'tdurnellil', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-12 21:45:43'),
+('btitmussim@netvibes.com', 'zmclurgim', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-29 20:57:00'),
+('lbaldocciin@wp.com', 'pduchartin', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-04 19:17:06'),
+('ocounihanio@oakley.com', 'kgermainio', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-30 18:51:22'),
+('mducketip@umn.edu', 'wmulliganip', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-20 15:08:46'),
+('shitteriq@economist.com', 'vlocockiq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-18 14:41:08'),
+('cgarmonsir@usnews.com', 'iflochir', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-30 07:41:04'),
+('ctowseis@bbb.org', 'esmoughtonis', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-13 03:33:36'),
+('dvanweedenburgit@constantcontact.com', 'jfeldererit', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-14 16:10:29'),
+('ttreleveniu@china.com.cn', 'raguirrezabaliu', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-11 11:16:59'),
+('sdelepineiv@instagram.com', 'bmckevanyiv', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-28 04:26:24'),
+('mdecayetteiw@go.com', 'fterrazzoiw', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-25 02:27:46'),
+('efidlerix@wisc.edu', 'mfelgateix', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-28 08:39:58'),
+('fulyattiy@tripod.com', 'murlingiy', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-20 18:12:47'),
+('lmcdugaliz@php.net', 'afyldesiz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-26 19:19:20'),
+('oballsdonj0@earthlink.net', 'lberzonj0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-03 22:32:39'),
+('yheafordj1@go.com', 'mmenatj1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-03 09:05:13'),
+('dmcnaughtonj2@blinklist.com', 'bstoneyj2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-05 22:45:06'),
+('mpittendreighj3@reddit.com', 'nmackrillj3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-04 14:37:44'),
+('dcurraghj4@t.co', 'cnaresj4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-21 16:34:57'),
+('mferrerj5@google.ru', 'gcasarilij5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-02 11:00:47'),
+('twyrillj6@indiatimes.com', 'ckinnierj6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-28 14:57:13'),
+('rriddingj7@edublogs.org', 'kpaskej7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-04 00:46:03'),
+('klarensonj8@shareasale.com', 'akurdanij8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-26 13:13:32'),
+('jgalletleyj9@friendfeed.com', 'yrichenj9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-17 13:05:59'),
+('jedghinnja@geocities.com', 'ddillestonja', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-17 09:57:26'),
+('rinnocentjb@w3.org', 'hricharzjb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-26 00:32:04'),
+('dcoggenjc@wufoo.com', 'wkilbanejc', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-25 07:10:51'),
+('kpickavantjd@bluehost.com', 'rcarnoghanjd', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-29 19:24:53'),
+('bjahndelje@dot.gov', 'tpoweje', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-24 15:40:33'),
+('shatonjf@aboutads.info', 'emurchjf', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-22 08:55:06'),
+('kfontellesjg@techcrunch.com', 'cgreenhalghjg', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-26 06:41:17'),
+('oshallojh@nymag.com', 'cprewjh', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-17 16:25:32'),
+('ddunridgeji@umich.edu', 'smerrileesji', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-17 21:05:43'),
+('bhawkswoodjj@dailymail.co.uk', 'cprettyjohnsjj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-08 16:55:05'),
+('fhornungjk@prlog.org', 'ehansiejk', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-08 07:04:49'),
+('npietersenjl@berkeley.edu', 'mmuldowneyjl', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-25 14:59:24'),
+('alatchmorejm@vistaprint.com', 'jllorensjm', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-03 03:06:24'),
+('msheachjn@odnoklassniki.ru', 'skesperjn', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-29 18:40:30'),
+('ssallissjo@freewebs.com', 'fdyballjo', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-04 18:07:40'),
+('dparlotjp@livejournal.com', 'rgopselljp', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-11 18:55:19'),
+('ccarncrossjq@umich.edu', 'bpickoverjq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-17 19:11:51'),
+('fbaggallyjr@flavors.me', 'mpryelljr', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-01 02:26:58'),
+('bcraskejs@ovh.net', 'fpattullojs', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-26 11:54:08'),
+('lmelrossjt@cargocollective.com', 'kwormleightonjt', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-25 15:45:35'),
+('mnewensju@psu.edu', 'sgwytherju', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-08 23:12:46'),
+('jprentyjv@purevolume.com', 'tboarleysonjv', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-01 08:40:44'),
+('flehrjw@dailymail.co.uk', 'ablazijw', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-24 18:56:13'),
+('bspalljx@nba.com', 'ssyderjx', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-11 11:08:08'),
+('tbernardellijy@fotki.com', 'acustjy', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-28 03:11:03'),
+('cjudkinjz@homestead.com', 'dwellwoodjz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-28 18:30:13'),
+('tboaysk0@rambler.ru', 'tpainswickk0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-31 16:06:41'),
+('mborrowsk1@dot.gov', 'bfreebornk1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-06 07:44:35'),
+('sgoldak2@ed.gov', 'ezaniolettik2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-05 00:31:18'),
+('trutherfoordk3@fc2.com', 'gcorradettik3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-30 15:51:09'),
+('bkilliamk4@cafepress.com', 'ljohananoffk4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-06 00:22:30'),
+('qborkettk5@seesaa.net', 'ahowlingsk5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-30 11:14:24'),
+('ebaudinellik6@bloglovin.com', 'dschistlk6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-14 21:31:31'),
+('propertk7@twitter.com', 'olaurischk7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-08 02:33:12'),
+('stilerk8@about.me', 'edeeveyk8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-06 15:42:44'),
+('bsapsfordk9@youku.com', 'hhorderk9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-28 00:53:27'),
+('aoliverka@lulu.com', 'rparrettka', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-22 06:45:56'),
+('tbuckinghamkb@indiatimes.com', 'fdustkb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-29 21:33:28'),
+('ywedgbrowkc@storify.com', 'saslettkc', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-08 12:45:05'),
+('rphairkd@a8.net', 'kstakerkd', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-24 07:40:43'),
+('sdomesdayke@time.com', 'hporrittke', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-07 15:07:08'),
+('fivermeekf@angelfire.com', 'mhaycroftkf', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-02 14:26:43'),
+('giacapuccikg@privacy.gov.au', 'rannakinkg', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-04 00:01:26'),
+('sbutterwickkh@adobe.com', 'dwhytockkh', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-11 03:56:17'),
+('ldoerlingki@google.co.uk', 'yblackeyki', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-31 19:30:06'),
+('vayerkj@fda.gov', 'kremerkj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-04 19:38:38'),
+('gheretykk@wunderground.com', 'amemmorykk', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-26 05:35:59'),
+('bnorcopkl@example.com', 'menriqueskl', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-26 15:47:27'),
+('cmurrhardtkm@usatoday.com', 'egreensteadkm', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-06 14:51:36'),
+('ctaffiekn@lycos.com', 'tdacostakn', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-12 07:30:27'),
+('mprettymanko@vistaprint.com', 'rcanlinko', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-11-01 15:37:41'),
+('abeardskp@deliciousdays.com', 'fcamamillkp', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-04 05:31:14'),
+('hbeswetherickkq@jigsy.com', 'bserjentkq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-26 23:33:49'),
+('taronoffkr@posterous.com', 'mchalcroftkr', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-02 12:15:46'),
+('tackensonks@fc2.com', 'wgrzegorekks', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-21 13:26:21'),
+('fhappertkt@surveymonkey.com', 'stillotkt', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-28 23:55:58'),
+('dstollsteimerku@php.net', 'pitterku', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-03 00:44:49'),
+('bcostanzakv@clickbank.net', 'scarolankv', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-09 13:12:42'),
+('sengelkw@digg.com', 'dkilfordkw', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-03 15:33:55'),
+('mjenninskx@jugem.jp', 'jmatzkekx', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-10 11:39:31'),
+('gstannahky@odnoklassniki.ru', 'cminardsky', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-24 19:49:43'),
+('nolenchikovkz@163.com', 'coverillkz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-09 15:20:09'),
+('hmarrowsl0@cpanel.net', 'gtavernorl0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-29 07:16:52'),
+('khellmerl1@domainmarket.com', 'hwoosnaml1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-25 11:05:29'),
+('tsarjeantl2@twitpic.com', 'chubbockl2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-13 14:36:27'),
+('sflindalll3@drupal.org', 'bellinl3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-08 03:33:47'),
+('fcristoforl4@abc.net.au', 'gmcrobbiel4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-22 18:26:43'),
+('ftipensl5@123-reg.co.uk', 'jnorthcottl5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-21 19:59:00'),
+('kfurtadol6@bizjournals.com', 'srapseyl6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-25 21:43:32'),
+('imcclauriel7@zdnet.com', 'gwingerl7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-07 02:02:24'),
+('bconfortl8@rediff.com', 'oeastmondl8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-10 19:55:48'),
+('cbuntainl9@google.de', 'kinglesantl9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-07 10:38:25'),
+('jvalentala@ftc.gov', 'clackintonla', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-02 22:37:58'),
+('aclaysonlb@scientificamerican.com', 'ysawoodlb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-11 22:37:25'),
+('sdeyelc@un.org', 'pwestberglc', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-10 19:23:37'),
+('rredhollsld@gmpg.org', 'mbonnetteld', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-19 13:39:26'),
+('jhurlle@hhs.gov', 'ecobleyle', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-06 01:09:36'),
+('lhaffendenlf@amazon.de', 'smocklerlf', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-23 08:08:58'),
+('jcandeylg@devhub.com', 'rpellerlg', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-05 11:36:53'),
+('sgreginelh@fda.gov', 'mkidmanlh', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-11 05:03:14'),
+('amcgeaneyli@alexa.com', 'ezealli', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-13 22:29:15'),
+('lsemradlj@issuu.com', 'tschalllj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-26 09:32:19'),
+('rkeechlk@posterous.com', 'hdowdenlk', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-31 17:19:46'),
+('tkielyll@independent.co.uk', 'cfarbrotherll', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-24 23:27:29'),
+('gwightmanlm@washingtonpost.com', 'femminslm', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-07 19:55:08'),
+('lphilottln@sakura.ne.jp', 'broebottomln', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-20 21:17:40'),
+('hephsonlo@jiathis.com', 'astricklandlo', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-15 11:31:50'),
+('agellylp@booking.com', 'mjohansenlp', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-29 07:01:39'),
+('scogarlq@digg.com', 'handrzejczaklq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-22 12:26:31'),
+('kriseleylr@soup.io', 'cabellalr', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-23 17:02:00'),
+('lwittsls@utexas.edu', 'hkeseyls', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-11 21:49:54'),
+('pwheelerlt@last.fm', 'ssimmslt', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-22 04:37:30'),
+('rabbatelu@walmart.com', 'tgarmslu', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-16 15:11:35'),
+('hansteelv@squarespace.com', 'zfadianlv', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-21 19:37:58'),
+('coliverlw@mashable.com', 'eorislw', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-05 07:00:59'),
+('hstreatleylx@jiathis.com', 'hmonellilx', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-31 08:16:49'),
+('wmclarnonly@vistaprint.com', 'madrianily', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-29 20:14:19'),
+('amacveanlz@prweb.com', 'lyewdelllz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-02 19:17:46'),
+('mcleaverm0@businessinsider.com', 'wrecordm0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-06 08:24:42'),
+('rdykasm1@networksolutions.com', 'tlesperm1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-18 11:44:34'),
+('mbolducm2@bandcamp.com', 'krubim2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-03 14:14:20'),
+('dorwinm3@admin.ch', 'mjoustm3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-08 09:29:19'),
+('sjennawaym4@bravesites.com', 'cferraram4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-28 23:15:19'),
+('gbaressm5@walmart.com', 'astuehmeyerm5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-10 08:51:33'),
+('lwhiskerdm6@ucla.edu', 'vlyfem6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-12 15:27:45'),
+('wpatchettm7@washingtonpost.com', 'bpuddiferm7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-13 21:12:09'),
+('bgalvinm8@php.net', 'ilonsdalem8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-18 08:06:28'),
+('sjohlm9@github.com', 'thawkesm9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-26 06:09:52'),
+('coloshkinma@marketwatch.com', 'bhumfreyma', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-06 09:41:20'),
+('ialbasinymb@umn.edu', 'meggersmb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-18 07:21:48'),
+('sformiglimc@123-reg.co.uk', 'ahallyburtonmc', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-25 22:48:53'),
+('lsiaskowskimd@webmd.com', 'gcunningtonmd', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-06 11:54:16'),
+('twetherillme@ezinearticles.com', 'skraftme', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-01 02:07:26'),
+('cjablonskimf@istockphoto.com', 'bringmf', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-05 17:35:34'),
+('fkellermg@pen.io', 'kbrookhousemg', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-26 21:26:43'),
+('hmatijasevicmh@about.me', 'kstuckemh', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-16 13:28:07'),
+('jkirvinmi@sakura.ne.jp', 'fmeadermi', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-16 20:15:47'),
+('cbufferymj@cmu.edu', 'fflintoffmj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-24 07:46:32'),
+('ncoltherdmk@amazonaws.com', 'tbutterleymk', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-03 02:04:14'),
+('lcurrmml@prnewswire.com', 'cdumbrillml', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-13 04:06:41'),
+('akilshallmm@boston.com', 'ymcgarelmm', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-21 09:40:35'),
+('mrosebymn@flickr.com', 'pduffmn', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-11 15:44:52'),
+('bdittsmo@cnet.com', 'pbranthwaitemo', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-25 10:55:50'),
+('gsnalhammp@wp.com', 'dfermermp', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-14 07:24:12'),
+('lleckymq@google.ca', 'scovetmq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-07 23:19:08'),
+('kmcbaymr@google.nl', 'asollememr', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-29 18:27:46'),
+('bkobms@europa.eu', 'agregms', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-16 15:57:43'),
+('frogansmt@blogspot.com', 'bmackaigmt', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-24 00:57:17'),
+('earnottmu@flavors.me', 'ascowcroftmu', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-21 02:55:31'),
+('edebenedittimv@51.la', 'tkupecmv', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-18 18:44:01'),
+('jkillmw@msn.com', 'astallebrassmw', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-23 01:55:20'),
+('sstermanmx@ovh.net', 'bdelislemx', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-18 07:11:50'),
+('crubinsohnmy@edublogs.org', 'cgaisfordmy', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-21 17:14:42'),
+('cpeerlessmz@biblegateway.com', 'ndrillingcourtmz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-11 15:17:20'),
+('dcammishn0@bloglovin.com', 'dvelldenn0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-15 02:29:26'),
+('bsternn1@kickstarter.com', 'mkrzysztofn1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-27 22:27:34'),
+('vharvardn2@dailymotion.com', 'shaymann2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-14 13:14:20'),
+('hhutchcraftn3@who.int', 'nfrin3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-01 10:42:20'),
+('aparchmentn4@bravesites.com', 'abramhilln4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-23 09:27:01'),
+('sleacyn5@liveinternet.ru', 'bdinisn5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-28 13:31:33'),
+('kleggettn6@craigslist.org', 'apalistern6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-15 15:47:09'),
+('kmatantsevn7@cargocollective.com', 'dgeffen7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-02 04:59:51'),
+('awyardn8@oaic.gov.au', 'kbrycen8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-06 21:58:21'),
+('rdomenicin9@fda.gov', 'lbarenskyn9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-15 01:42:11'),
+('lmcgunleyna@gov.uk', 'bbalentynena', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-28 05:13:47'),
+('jmccannnb@miibeian.gov.cn', 'tcoalenb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-02 15:01:36'),
+('bjupenc@smugmug.com', 'jgealync', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-17 10:59:27'),
+('ccancellernd@paginegialle.it', 'hfalveynd', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-11 06:12:56'),
+('jfattorinine@addthis.com', 'hbamfordne', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-27 14:07:10'),
+('vscarlinnf@sciencedirect.com', 'tbaythropnf', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-28 04:35:11'),
+('aphillippong@wisc.edu', 'nlongegang', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-03 18:20:12'),
+('brapkinsnh@nasa.gov', 'bgrabbamnh', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-29 16:06:40'),
+('rjeynessni@macromedia.com', 'gmcpheeni', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-22 06:19:46'),
+('nwhittletonnj@hatena.ne.jp', 'mfontenotnj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-14 10:46:42'),
+('kguiderank@1688.com', 'cdinglenk', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-14 21:28:54'),
+('ghursthousenl@sina.com.cn', 'rblownl', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-03 23:39:49'),
+('mbowrynm@canalblog.com', 'ukummerlowenm', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-13 15:36:34'),
+('memneynn@sun.com', 'tlonnonnn', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-01 01:56:20'),
+('ppattenno@theglobeandmail.com', 'pgoatmanno', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-13 21:56:43'),
+('flighternessnp@howstuffworks.com', 'oscoullernp', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-14 01:07:25'),
+('joverburynq@ucoz.ru', 'kpendellnq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-03 08:44:14'),
+('awolledgenr@bizjournals.com', 'dderrnr', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-24 08:20:34'),
+('fironns@twitpic.com', 'ubiglinns', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-09 18:30:58'),
+('aburchessnt@google.com.br', 'etitmussnt', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-22 01:06:42'),
+('fhawkennu@amazon.co.uk', 'kgoodspeednu', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-21 20:59:01'),
+('rhillyattnv@dailymotion.com', 'lspollennv', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-21 14:27:40'),
+('gsmithendnw@cmu.edu', 'alandernw', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-09 22:56:06'),
+('dmourniannx@blinklist.com', 'gfraczkiewicznx', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-15 03:02:12'),
+('vgrzegorczykny@dion.ne.jp', 'gtabertny', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-17 06:20:55'),
+('rpreshawnz@cloudflare.com', 'jkinnarnz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-15 19:45:44'),
+('efreako0@bbb.org', 'tfosseyo0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-24 06:53:32'),
+('rpragnallo1@cloudflare.com', 'sliddelo1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-07 11:39:30'),
+('tfeighryo2@examiner.com', 'sdeightono2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-09 17:25:25'),
+('gmollitto3@ameblo.jp', 'twanklingo3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-08 09:27:36'),
+('chargraveo4@jiathis.com', 'odenningtono4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-02 11:33:22'),
+('spedrazzio5@kickstarter.com', 'mmeugenso5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-11 15:03:49'),
+('bpurkisso6@unblog.fr', 'mrummero6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-28 23:05:45'),
+('mduncano7@geocities.jp', 'skittelo7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-08 00:57:28'),
+('gschimanno8@mashable.com', 'acasino8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-06 12:44:18'),
+('agisborneo9@godaddy.com', 'llangmuiro9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-04 05:55:02'),
+('hbradnickoa@scribd.com', 'rfiggeroa', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-24 02:46:08'),
+('ecuellob@ihg.com', 'jdreyeob', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-10 22:09:23'),
+('jabbattoc@mozilla.org', 'reckoc', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-01 10:14:12'),
+('gkeningleyod@godaddy.com', 'rarborod', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-10 18:24:39'),
+('agravettoe@newsvine.com', 'pcarineoe', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-19 22:58:36'),
+('bivanchovof@cornell.edu', 'nbendinof', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-13 09:18:37'),
+('dschopsog@wiley.com', 'clengletog', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-15 22:36:41'),
+('gpoxsonoh@sohu.com', 'mcallanoh', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-21 22:19:14'),
+('bcohalanoi@g.co', 'gcappoi', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-12 02:51:29'),
+('rgurysoj@dion.ne.jp', 'dmosdelloj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-20 20:16:38'),
+('fphippok@nymag.com', 'efrowok', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-22 08:09:25'),
+('rlevineol@ustream.tv', 'mpawelecol', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-02 20:57:35'),
+('elawesom@google.fr', 'bcurrorom', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-13 22:57:23'),
+('jbaulcombeon@163.com', 'pbriggdaleon', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-31 20:27:36'),
+('kbonannooo@umich.edu', 'hgatecliffoo', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-30 16:22:14'),
+('dsibthorpop@storify.com', 'mlukashop', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-29 02:23:41'),
+('hpembertonoq@craigslist.org', 'skillockoq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-08 23:11:00'),
+('sphythonor@globo.com', 'kantoniouor', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-27 00:47:49'),
+('acoxos@cargocollective.com', 'cdeathridgeos', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-28 05:40:37'),
+('nsymonesot@tuttocitta.it', 'cprovenot', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-09 17:48:38'),
+('lblankmanou@arizona.edu', 'earnoudou', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-25 11:29:46'),
+('pgaiterov@google.fr', 'hsmytheov', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-26 16:47:09'),
+('krudeyeardow@a8.net', 'hashborneow', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-08 17:33:47'),
+('dacoryox@weibo.com', 'vbirchnerox', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-01 04:09:07'),
+('ebenedicteoy@amazon.co.uk', 'icasserlyoy', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-21 00:02:40'),
+('esaladineoz@is.gd', 'avankeevoz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-19 02:48:18'),
+('cdeekesp0@businessinsider.com', 'afullerp0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-18 19:12:26'),
+('dderhamp1@bluehost.com', 'egaythwaitep1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-01 03:43:47'),
+('bdutsonp2@go.com', 'tgresp2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-18 12:36:14'),
+('sshorthillp3@toplist.cz', 'mriccop3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-14 01:14:30'),
+('akopacekp4@imdb.com', 'edruelp4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-20 06:42:58'),
+('hrableyp5@sohu.com', 'cdudleyp5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-28 16:59:19'),
+('dhrishanokp6@theglobeandmail.com', 'bgowdridgep6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-29 00:28:20'),
+('mdavidovicip7@mit.edu', 'idarreep7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-17 20:07:59'),
+('bgiacomuccip8@t-online.de', 'jbeekmanp8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-04 22:54:57'),
+('ishannahanp9@amazon.de', 'olanferp9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-19 00:13:10'),
+('vlebrumpa@oracle.com', 'bcarberrypa', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-19 16:11:56'),
+('ayushkovpb@netscape.com', 'fswiggerpb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-06 11:17:27'),
+('cvillaretpc@google.com', 'csharplesspc', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-08 22:29:04'),
+('mderrickpd@dot.gov', 'aduckinfieldpd', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-06 11:54:33'),
+('jchestertonpe@fda.gov', 'emacafeepe', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-18 06:40:16'),
+('lmeinpf@state.gov', 'kungarettipf', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-07 22:20:11'),
+('ctyronepg@scribd.com', 'cdigiacomettinopg', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-30 23:54:46'),
+('sjolinph@google.com.br', 'kpadgintonph', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-25 13:44:55'),
+('mjedrykapi@gizmodo.com', 'mkinmanpi', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-14 00:26:11'),
+('rrubinowitchpj@phoca.cz', 'iblasdalepj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-25 09:45:23'),
+('mleaveypk@creativecommons.org', 'hlevispk', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-19 19:10:11'),
+('bkisbeypl@qq.com', 'ebyrespl', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-25 09:58:43'),
+('kridleypm@cmu.edu', 'soddeypm', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-19 06:54:50'),
+('jlavispn@ifeng.com', 'bbleythinpn', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-30 20:19:05'),
+('edonisthorpepo@intel.com', 'hstooderspo', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-28 17:01:38'),
+('erootespp@redcross.org', 'etristrampp', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-03 19:39:45'),
+('ddorypq@yelp.com', 'sparadycepq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-27 16:18:40'),
+('pbimspr@umn.edu', 'tdousepr', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-16 03:14:20'),
+('jlingardps@unicef.org', 'lbargps', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-30 10:27:22'),
+('soxerpt@nih.gov', 'nerikssonpt', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-27 00:08:55'),
+('vhacquardpu@unc.edu', 'ecollatonpu', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-17 07:34:03'),
+('amawmanpv@hatena.ne.jp', 'aphilippartpv', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-04 05:46:49'),
+('slatekpw@netlog.com', 'gelsmorpw', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-23 18:55:04'),
+('chebditchpx@unicef.org', 'nmcneelypx', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-22 20:16:44'),
+('nmounsiepy@tuttocitta.it', 'dolehanepy', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-05 19:06:55'),
+('kscolapz@sbwire.com', 'cjankowskipz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-07 07:17:34'),
+('hlimeburnerq0@sciencedirect.com', 'spreshousq0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-19 01:14:50'),
+('kfavelq1@theatlantic.com', 'tmourtonq1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-07 09:25:24'),
+('lsobczakq2@photobucket.com', 'mbuddockq2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-11 19:19:11'),
+('cbaslerq3@163.com', 'mcawtheraq3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-03 00:34:44'),
+('cmendenhallq4@answers.com', 'iglennq4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-29 02:39:20'),
+('eorteauq5@zdnet.com', 'nbotwrightq5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-26 06:29:03'),
+('lbirkq6@cbslocal.com', 'ctullethq6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-14 19:03:26'),
+('ssentanceq7@shop-pro.jp', 'bochiltreeq7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-17 04:37:51'),
+('fjoeq8@foxnews.com', 'maitkinq8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-11 09:03:33'),
+('ztingleq9@webmd.com', 'rchiechioq9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-28 02:09:08'),
+('calnerqa@hibu.com', 'nrowberryqa', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-12 11:13:44'),
+('bbearneqb@aboutads.info', 'mantoszczykqb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-26 10:46:16'),
+('rminkinqc@mysql.com', 'ppirsonqc', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-20 02:17:48'),
+('ffoulserqd@nymag.com', 'mlovegroveqd', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-20 20:28:32'),
+('dsheweryqe@com.com', 'mpetticrewqe', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-26 21:21:37'),
+('risgarqf@xrea.com', 'mdunnionqf', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-16 21:29:38'),
+('rshottonqg@google.co.uk', 'jallkinsqg', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-31 01:16:41'),
+('lfidgeonqh@skyrock.com', 'ahareqh', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-14 23:05:56'),
+('ljostanqi@cafepress.com', 'vtamlettqi', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-10 19:17:34'),
+('ncarmichaelqj@istockphoto.com', 'agriffittsqj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-23 02:30:29'),
+('ddadyqk@topsy.com', 'wchmarnyqk', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-09-11 09:04:00'),
+('ebyforthql@mysql.com', 'dmulhallql', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-17 05:17:35'),
+('rbeachamqm@privacy.gov.au', 'tgilberthorpeqm', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-06 21:09:47'),
+('ojaffraqn@mac.com', 'glaunderqn', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-01 06:08:51'),
+('cmitchensonqo@paginegialle.it', 'smcilwrickqo', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-04 05:08:30'),
+('kmuffqp@instagram.com', 'sparcellsqp', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-18 20:02:21'),
+('tfultonqq@cnet.com', 'npaneqq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-24 20:07:57'),
+('rommundsenqr@tiny.cc', 'inorganqr', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-27 22:48:36'),
+('sborgnolqs@ox.ac.uk', 'slarsqs', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-29 08:16:59'),
+('rphilsonqt@ucsd.edu', 'pwillattqt', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-26 10:42:23'),
+('jmerryqu@wiley.com', 'rtregonaqu', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-10-11 17:16:20'),
+('bfogdenqv@angelfire.com', 'sblackshawqv', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-12-14 15:18:12'),
+('dreyneqw@yahoo.co.jp', 'kspelsburyqw', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-22 07:59:28'),
+('cdensonqx@deviantart.com', 'rderrettqx', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-05 22:24:30'),
+('nansillqy@sina.com.cn', 'crollandqy', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-19 17:15:53'),
+('hjouandetqz@tinyurl.com', 'gotteyqz', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-27 17:02:16'),
+('mfishwickr0@miitbeian.gov.cn', 'gglackenr0', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-06-02 12:31:11'),
+('jnormadellr1@examiner.com', 'ghedgemanr1', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-02 16:55:51'),
+('jwarlandr2@yellowbook.com', 'cfaker2', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-28 15:50:51'),
+('dpetracekr3@wordpress.com', 'jkearnr3', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-18 18:58:45'),
+('dmundwellr4@a8.net', 'dtamlettr4', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-10 10:23:50'),
+('mboomr5@ucsd.edu', 'ccausbyr5', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-05 03:28:46'),
+('itraser6@sbwire.com', 'gkingabyr6', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-21 07:11:36'),
+('jaubinr7@ted.com', 'rharroldr7', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-17 21:31:42'),
+('fyounger8@dropbox.com', 'dbissr8', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-06 09:12:52'),
+('nsmallsmanr9@slideshare.net', 'ybenardeaur9', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-19 03:06:04'),
+('kcalleyra@hp.com', 'bklossra', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-08-16 23:30:07'),
+('tmasterrb@amazonaws.com', 'aromerilrb', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-08 23:14:49'),
+('jphilippaultrc@salon.com', 'lmccambridgerc', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-02 21:44:10'),
+('pbuckleerd@slate.com', 'scornilsrd', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-13 09:09:28'),
+('cfincherre@jalbum.net', 'iskadere', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-24 04:36:52'),
+('jhynsonrf@godaddy.com', 'eswatheridgerf', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-06 18:55:49'),
+('rfallowfieldrg@domainmarket.com', 'stockellrg', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-07 12:09:19'),
+('ysinnierh@gmpg.org', 'afourmanrh', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-16 19:44:49'),
+('loffellri@youtube.com', 'lgoodrickeri', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-03-22 02:52:45'),
+('mskrzynskirj@goo.gl', 'fkitcherrj', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-11-03 11:20:41'),
+('kwindaybankrk@ehow.com', 'aclymork', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-05-10 10:32:08'),
+('atenderrl@tamu.edu', 'ctullochrl', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-11-05 01:49:45'),
+('spieruccirm@adobe.com', 'jquinneyrm', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-18 21:46:53'),
+('fpalombirn@java.com', 'wkollaschrn', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-01-20 10:42:05'),
+('eslidesro@multiply.com', 'khabbeshawro', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2021-11-09 22:33:36'),
+('aseftonrp@usa.gov', 'czanicchirp', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-04-22 04:18:34'),
+('msmicklerq@dailymotion.com', 'rnyssensrq', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-07-19 23:11:17'),
+('ecasselyrr@dailymotion.com', 'rblunderfieldrr', 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=', '2022-02-09 00:26:21');
@@ -1,1004 +0,0 @@
--- Add up migration script here
--- fake data
-insert into user (mail, username, password, salt, create_time) values ('bsievewright0@yellowpages.com', 'gbrookshaw0', '9BEvrsU4', 'vBtPT1M5Y', '2022-02-18 12:52:34');
-insert into user (mail, username, password, salt, create_time) values ('knodin1@cbc.ca', 'klouder1', 'IderOH', '19aAhKTZShmi', '2022-06-29 15:36:32');
-insert into user (mail, username, password, salt, create_time) values ('gmcelane2@nsw.gov.au', 'njeffryes2', 'ZdFOVx6', 'Szcm8RtsG', '2022-01-06 20:11:08');
-insert into user (mail, username, password, salt, create_time) values ('mzottoli3@bizjournals.com', 'rtrevenu3', 'yr481fm', 'I8E6uJXZ8U3', '2021-11-10 04:07:08');
-insert into user (mail, username, password, salt, create_time) values ('mballinghall4@mysql.com', 'eunderwood4', '3IXUYH', 'Y5yUylSv2qf', '2021-11-20 04:58:28');
-insert into user (mail, username, password, salt, create_time) values ('cclaus5@whitehouse.gov', 'cjozefiak5', 'O5gxg2em', 'epwPuDJ8u0n1', '2021-12-06 17:02:04');
-insert into user (mail, username, password, salt, create_time) values ('etegler6@ow.ly', 'nmckiddin6', 'jObjLyY8Na0', 'PiPMVOzO', '2022-09-29 00:17:49');
-insert into user (mail, username, password, salt, create_time) values ('pberanek7@washingtonpost.com', 'blitton7', 'bouMdkNKmRgd', 'G2GjiSx', '2021-12-31 10:09:07');
-insert into user (mail, username, password, salt, create_time) values ('adumigan8@sphinn.com', 'mwesthofer8', 'Rd0DMJC', 'QxLU7ryK', '2022-03-13 17:15:48');
-insert into user (mail, username, password, salt, create_time) values ('nlear9@vimeo.com', 'mbockin9', 'zwDcrPsRJBE', 'CsZgmpbHTca', '2022-01-09 19:10:50');
-insert into user (mail, username, password, salt, create_time) values ('clummasanaa@cnn.com', 'rhalla', 'QY1uE5nF', 'tQ0C4bAmeR', '2022-02-09 16:18:38');
-insert into user (mail, username, password, salt, create_time) values ('tcandelinb@patch.com', 'sfrenchb', '6hKdutJ0', 'bFx9Gr1', '2022-07-18 01:41:21');
-insert into user (mail, username, password, salt, create_time) values ('oamiesc@smugmug.com', 'mnickolsc', 'ruUT3IMsK4', 'ZE8AxS', '2022-03-13 06:13:47');
-insert into user (mail, username, password, salt, create_time) values ('epellingd@harvard.edu', 'cfearesd', 'uBfRKHiqLFzK', 'JYoKOEDCI', '2022-07-31 12:36:30');
-insert into user (mail, username, password, salt, create_time) values ('mbenoise@so-net.ne.jp', 'mmckeurtone', 'S2NvvuH', '5j5Mg5m', '2022-03-04 23:44:09');
-insert into user (mail, username, password, salt, create_time) values ('dstotherfieldf@webmd.com', 'fgorstf', 'xzk23IuN', 'ICbKsxnvBsW', '2022-03-23 18:10:22');
-insert into user (mail, username, password, salt, create_time) values ('tlivardg@google.com.br', 'jpundyg', '85BdrGTgm0d', 'xyhRk81qU2T', '2022-02-10 20:09:00');
-insert into user (mail, username, password, salt, create_time) values ('rgooddieh@privacy.gov.au', 'mhawkinsh', 'thUoq4hj62v', 'gGkZQj60Ne', '2022-03-27 00:29:24');
-insert into user (mail, username, password, salt, create_time) values ('iswaytei@google.co.uk', 'eparleti', '6M2XRZymLkbs', 'xXtl7Jn5Dd', '2022-05-02 02:04:27');
-insert into user (mail, username, password, salt, create_time) values ('flemonj@umich.edu', 'tgilstonj', 'bPzX2LNqok', 'yGppIaFcOBCe', '2022-08-14 01:15:55');
-insert into user (mail, username, password, salt, create_time) values ('afawcittk@rediff.com', 'cgrimsdalek', '0maGU21U2sha', 'vJgaPbK9fd', '2022-01-08 10:00:39');
-insert into user (mail, username, password, salt, create_time) values ('bskingleyl@ifeng.com', 'mcathell', 'EobLttxNu', 'D9HatWvMz', '2022-03-19 23:59:12');
-insert into user (mail, username, password, salt, create_time) values ('istrewthersm@zimbio.com', 'agengem', 'qtnKPw7M8', 'ZoFCj70F', '2022-03-04 23:57:44');
-insert into user (mail, username, password, salt, create_time) values ('ajojicn@europa.eu', 'lknockern', 'neBMrj', 'tdBsvrc', '2021-11-30 15:13:43');
-insert into user (mail, username, password, salt, create_time) values ('asturgiso@weebly.com', 'cduthieo', '7NpFbf', '9GiwRHR8Tq', '2022-02-21 17:07:36');
-insert into user (mail, username, password, salt, create_time) values ('bsimonp@mashable.com', 'awetterp', '8mpVcyc', 'w81dqjbRkQB', '2022-07-06 17:07:33');
-insert into user (mail, username, password, salt, create_time) values ('plindoresq@blogs.com', 'idymidowskiq', 'tpz22UJQr', 'hOnSZ9E8ZaD', '2021-11-15 00:25:58');
-insert into user (mail, username, password, salt, create_time) values ('ekrishtopaittisr@netvibes.com', 'rpatingtonr', 'UvQZVu9aIQS', 'HmXSIvMnazwq', '2021-11-21 07:34:55');
-insert into user (mail, username, password, salt, create_time) values ('mmallans@wiley.com', 'ctathacotts', 'ke5nCpEq', 'AdiZKt', '2022-01-15 22:11:55');
-insert into user (mail, username, password, salt, create_time) values ('dromert@dagondesign.com', 'amaccreet', 'Ljm7kDu39CV', 'N85uMb', '2021-12-15 17:27:43');
-insert into user (mail, username, password, salt, create_time) values ('ofenixu@economist.com', 'hjayumeu', 'XE7yRIp', 'xCKibEoZ8', '2022-07-27 01:52:22');
-insert into user (mail, username, password, salt, create_time) values ('kgrossmanv@ustream.tv', 'cmayzesv', 'hBipt9', 'lvpqW39', '2022-03-22 05:38:46');
-insert into user (mail, username, password, salt, create_time) values ('gchittemw@bloglines.com', 'tjohnkew', 'q6gXv8CA', 'iaGorpjzO4', '2022-06-05 12:29:43');
-insert into user (mail, username, password, salt, create_time) values ('agunnerx@cam.ac.uk', 'rdennistonx', 'd7ndz6onMsO', 'IgQx2C3bJn', '2021-12-02 22:18:57');
-insert into user (mail, username, password, salt, create_time) values ('zconnichiey@soundcloud.com', 'lcocky', 'rogRAMGkVa8x', 'Q7lMGHD1WwQ', '2022-04-15 09:28:11');
-insert into user (mail, username, password, salt, create_time) values ('jmollattz@google.es', 'barnholzz', 'mirG10', 'H1bBATH9UQtH', '2021-11-19 18:30:15');
-insert into user (mail, username, password, salt, create_time) values ('ibrinded10@foxnews.com', 'lblewett10', 'K4o5tjNAbBg', 'l4IuZLReOya', '2022-04-02 09:52:57');
-insert into user (mail, username, password, salt, create_time) values ('bdaenen11@arizona.edu', 'nbaughan11', 'Isv23Tm', '0LlC4wgX', '2022-08-17 10:09:12');
-insert into user (mail, username, password, salt, create_time) values ('kjoddens12@lulu.com', 'oduffield12', 'AWTvQbq7hB', 'ivlTtCxc9sKJ', '2022-08-28 21:56:20');
-insert into user (mail, username, password, salt, create_time) values ('afierro13@berkeley.edu', 'epughe13', 'SBUipKIGmgwS', 'hM89vtyF6', '2022-05-26 09:37:22');
-insert into user (mail, username, password, salt, create_time) values ('rthowless14@sitemeter.com', 'fkordt14', '1ejw7SGpe', 'uiBaW0omv', '2022-02-02 19:44:35');
-insert into user (mail, username, password, salt, create_time) values ('jivain15@blinklist.com', 'ncreffield15', 'tsFUNf', 'qTFWfgKKeD', '2022-10-04 09:44:23');
-insert into user (mail, username, password, salt, create_time) values ('htowey16@drupal.org', 'hdurrant16', 'kxsIQz', '4x02DhL', '2021-11-24 05:50:15');
-insert into user (mail, username, password, salt, create_time) values ('kplum17@weebly.com', 'ikemble17', 'jyXvTI8ES', 'rjarji3D', '2022-09-06 15:42:21');
-insert into user (mail, username, password, salt, create_time) values ('fladbrooke18@oakley.com', 'jhaithwaite18', '9OKrx91EE5fw', 'GgF4IB6E49', '2022-02-21 18:25:19');
-insert into user (mail, username, password, salt, create_time) values ('cblonfield19@npr.org', 'rwrinch19', 'Eufa68vbG', 'wn0RwP4m', '2021-12-19 23:28:18');
-insert into user (mail, username, password, salt, create_time) values ('dbeernaert1a@tumblr.com', 'rshieber1a', 'Omu2d2b', 'Mw39NUZ9', '2022-09-27 16:39:31');
-insert into user (mail, username, password, salt, create_time) values ('lpymm1b@deliciousdays.com', 'bsheriff1b', 'XtEl0gN', '9sYMT6WXc', '2022-01-21 13:14:16');
-insert into user (mail, username, password, salt, create_time) values ('jbaggally1c@noaa.gov', 'fbelasco1c', '2exFRI', 'oLI9l3DUl', '2021-11-23 04:57:38');
-insert into user (mail, username, password, salt, create_time) values ('arowlands1d@google.fr', 'callsworth1d', 'nAI4yCT', '869GoJyz', '2022-01-18 00:21:14');
-insert into user (mail, username, password, salt, create_time) values ('cmohamed1e@columbia.edu', 'fwhelpton1e', 'H9SyW6B1Jo', 'cLnJYIp', '2022-06-20 16:32:24');
-insert into user (mail, username, password, salt, create_time) values ('mluttgert1f@t-online.de', 'gcrossingham1f', 'cwgeoXkyzk', 'kQUxUTdprxw', '2022-01-12 03:47:22');
-insert into user (mail, username, password, salt, create_time) values ('jwinger1g@hao123.com', 'tbirch1g', 'YGdUbXRykl4', 'bfSwLQy24DqA', '2022-02-20 08:21:22');
-insert into user (mail, username, password, salt, create_time) values ('anurden1h@bloglovin.com', 'mroder1h', 'X9YttVG', 'Ho8nHzDzQf', '2022-05-01 21:25:45');
-insert into user (mail, username, password, salt, create_time) values ('jasbery1i@netscape.com', 'vlove1i', 'zn8dHMNpdnj', 'W8CgLmcTNO', '2022-03-30 16:32:23');
-insert into user (mail, username, password, salt, create_time) values ('jdjurkovic1j@walmart.com', 'acawthron1j', 'ncnknX4OAd', 'BuFKABfURY', '2022-02-08 05:37:07');
-insert into user (mail, username, password, salt, create_time) values ('eatterley1k@state.gov', 'demmot1k', '8Nu3IaXx', 'rq4Q1wV', '2022-03-08 07:52:06');
-insert into user (mail, username, password, salt, create_time) values ('bgaskell1l@auda.org.au', 'nmallalieu1l', 'kMofhpliOS', 'VSb6ryYB', '2022-07-29 00:04:30');
-insert into user (mail, username, password, salt, create_time) values ('kdemullett1m@pcworld.com', 'cpenney1m', 'NliDhg', 'WoXxz53HmaN', '2022-05-09 20:30:09');
-insert into user (mail, username, password, salt, create_time) values ('acreffeild1n@jigsy.com', 'mgeroldini1n', '0VbbZVwY', 'morR9l8fH', '2022-10-18 02:13:39');
-insert into user (mail, username, password, salt, create_time) values ('nstrutton1o@yellowpages.com', 'mjaques1o', 'WfkcHZ9j', '6wHNzfghMYRR', '2022-07-03 00:46:03');
-insert into user (mail, username, password, salt, create_time) values ('hjandl1p@diigo.com', 'agillman1p', 'ZygYrFp2a', '2J6FEqzAvoq', '2022-02-20 08:21:13');
-insert into user (mail, username, password, salt, create_time) values ('yhobbing1q@parallels.com', 'sjaume1q', 'qBE5xH', 'dYJ2l1sYMdAd', '2022-03-07 07:17:35');
-insert into user (mail, username, password, salt, create_time) values ('cforsyde1r@angelfire.com', 'bmcalinden1r', 'kizfrpyMoW', 'PNQXv6YRit', '2022-10-18 01:11:40');
-insert into user (mail, username, password, salt, create_time) values ('tmackaig1s@parallels.com', 'afrayn1s', 'kX30m4duhB', 'rH01zaAEr3H', '2022-10-26 12:59:32');
-insert into user (mail, username, password, salt, create_time) values ('rtrowl1t@shutterfly.com', 'ahargess1t', 'dJFlf9lHhLC', 'JBVIuzgZC', '2022-01-22 11:30:01');
-insert into user (mail, username, password, salt, create_time) values ('rsalvati1u@nytimes.com', 'hglede1u', 'F1lPAK9Cg', 'FuO14mfKbR', '2022-04-07 23:49:02');
-insert into user (mail, username, password, salt, create_time) values ('mbraunfeld1v@who.int', 'bsams1v', 'Bac4MHJTwA', 'aAy3Q6Zib70U', '2022-07-03 00:11:07');
-insert into user (mail, username, password, salt, create_time) values ('dmcvicar1w@typepad.com', 'anelsey1w', 'RlJANlPya', 'M7EAe4ePY', '2022-01-08 18:19:55');
-insert into user (mail, username, password, salt, create_time) values ('tspratling1x@acquirethisname.com', 'cbrandenburg1x', '1RMkGvb8lyx', 'B5jV2hKXX', '2022-07-09 09:02:11');
-insert into user (mail, username, password, salt, create_time) values ('eedland1y@cocolog-nifty.com', 'dbeaven1y', 'joUU7mS', '4HDNWvEY', '2022-04-20 14:03:48');
-insert into user (mail, username, password, salt, create_time) values ('ehandrick1z@skype.com', 'mswayte1z', 'HKu0cO', 'pWRwx2', '2022-09-30 11:00:46');
-insert into user (mail, username, password, salt, create_time) values ('themeret20@sohu.com', 'asalway20', 'cyWzSOXuT4FX', '6DidvVc', '2022-10-03 06:33:17');
-insert into user (mail, username, password, salt, create_time) values ('alubomirski21@newsvine.com', 'vworstall21', '0oUfsYS6', '5MHEmVj', '2022-08-17 08:28:22');
-insert into user (mail, username, password, salt, create_time) values ('gpizer22@hatena.ne.jp', 'dprisley22', 'lg1jQvYavQ5', 'KPAdjhdcu', '2022-06-05 15:00:01');
-insert into user (mail, username, password, salt, create_time) values ('asyers23@multiply.com', 'tberesfore23', 'U4fZFahdJNqU', 'ePi4JRkzxA9G', '2022-08-31 00:12:13');
-insert into user (mail, username, password, salt, create_time) values ('jbyrd24@nsw.gov.au', 'lvan24', 'kZVInv6', 'VzmOwOh', '2022-01-26 00:08:59');
-insert into user (mail, username, password, salt, create_time) values ('lgrimsditch25@paginegialle.it', 'rredgrove25', '45Bxj950H', 'a5SGbY9', '2022-07-30 13:13:02');
-insert into user (mail, username, password, salt, create_time) values ('gkennsley26@apache.org', 'ssimonato26', 'Pvrs1lsetc8', 'qNKaIT9hY6zU', '2022-02-26 22:44:08');
-insert into user (mail, username, password, salt, create_time) values ('jbickerdicke27@github.com', 'cesser27', 'lF1Ld6eL', 'fHGLfoT54j', '2021-12-12 03:57:07');
-insert into user (mail, username, password, salt, create_time) values ('kfollos28@reuters.com', 'emccreath28', 'YXgbYvEnk1', 'sGt7Tcz7xWhw', '2022-05-26 16:32:37');
-insert into user (mail, username, password, salt, create_time) values ('jstoyle29@stumbleupon.com', 'vtollit29', 'CiugiXjTX', 'eXubrOo0', '2022-04-16 07:03:27');
-insert into user (mail, username, password, salt, create_time) values ('akelley2a@deviantart.com', 'udragge2a', 'akjBIhIZFEMK', 'lQVK05t', '2022-01-30 04:50:54');
-insert into user (mail, username, password, salt, create_time) values ('arenhard2b@examiner.com', 'jkrimmer2b', 'mGOVq7J', 'iOGNks', '2022-08-06 13:34:56');
-insert into user (mail, username, password, salt, create_time) values ('alias2c@cornell.edu', 'dsimmans2c', 'w5tnENmWrT', 'OSrqUx', '2022-02-14 21:02:38');
-insert into user (mail, username, password, salt, create_time) values ('teaglestone2d@scribd.com', 'pjames2d', 'J1rDDi4wonH', 'cqbNdlSt', '2022-02-17 04:00:19');
-insert into user (mail, username, password, salt, create_time) values ('jleas2e@delicious.com', 'bgorgl2e', 'qjN8oX', 'i8cRrazoyy', '2022-03-07 20:10:49');
-insert into user (mail, username, password, salt, create_time) values ('hriddler2f@opensource.org', 'fcraw2f', 'ogeuSKhP', 'MTmEWJ8', '2022-07-25 02:10:58');
-insert into user (mail, username, password, salt, create_time) values ('odecleyne2g@scientificamerican.com', 'ebangham2g', 'Fx717hLU9nB', 'L0F63Y', '2021-11-15 07:55:18');
-insert into user (mail, username, password, salt, create_time) values ('jcassey2h@xrea.com', 'ecorder2h', 'ULX20FI', 'lOk1iWgoW6pG', '2022-07-30 11:22:15');
-insert into user (mail, username, password, salt, create_time) values ('jblayd2i@whitehouse.gov', 'rtolman2i', 'PUwGbduAJVE', 'FvmWOu96NZ1R', '2021-11-18 22:03:46');
-insert into user (mail, username, password, salt, create_time) values ('aroutledge2j@businesswire.com', 'hpumfrett2j', 'FnFptm', 'wNYxHd', '2022-03-29 15:16:57');
-insert into user (mail, username, password, salt, create_time) values ('coscanlan2k@twitpic.com', 'jpinkard2k', 'RYYXqf', 'XF8ibuwxsueD', '2022-04-27 05:17:01');
-insert into user (mail, username, password, salt, create_time) values ('odagleas2l@sphinn.com', 'jvinnicombe2l', '8g16AF2', 'U4cnkb9Bmbd', '2022-08-01 04:42:57');
-insert into user (mail, username, password, salt, create_time) values ('slemonby2m@fema.gov', 'ntofanini2m', 'Lt3M3B', 'U7v52bfEHzR', '2022-06-27 19:26:25');
-insert into user (mail, username, password, salt, create_time) values ('knevins2n@skyrock.com', 'nbollins2n', 'Px8kU4b9VI', 'G7wb3SP7qDzp', '2022-07-26 11:34:56');
-insert into user (mail, username, password, salt, create_time) values ('lworswick2o@google.cn', 'cdameisele2o', 'LDL3pRvVo', 'E82AjmN8HWwO', '2022-03-20 17:28:42');
-insert into user (mail, username, password, salt, create_time) values ('gselwyne2p@state.gov', 'cbusch2p', 'A2qdOeMtFm', 'UElxS25oc', '2022-01-05 07:58:17');
-insert into user (mail, username, password, salt, create_time) values ('gbrown2q@independent.co.uk', 'medghinn2q', 'qqzw7x4h6', 'ypFYk71R', '2022-05-19 23:57:55');
-insert into user (mail, username, password, salt, create_time) values ('lcolliber2r@vistaprint.com', 'djanuszkiewicz2r', 'bXU1RpKRJBvW', 'xy8y2T', '2022-09-20 11:39:14');
-insert into user (mail, username, password, salt, create_time) values ('dgarvey2s@umn.edu', 'rrawstron2s', '5dxvn0', '4OYVrca0', '2022-02-03 15:12:57');
-insert into user (mail, username, password, salt, create_time) values ('hchatainier2t@cmu.edu', 'wchafney2t', 'x1OhIQlSW', 'aafA033Pt', '2022-11-02 19:43:32');
-insert into user (mail, username, password, salt, create_time) values ('emills2u@sfgate.com', 'bedgworth2u', 'efqsID', 'GHwWD5pv', '2022-02-23 11:05:41');
-insert into user (mail, username, password, salt, create_time) values ('wronaldson2v@g.co', 'ybarriball2v', 'apnzJLdNr1w', 'p7qbrYcs6G', '2022-07-12 04:26:55');
-insert into user (mail, username, password, salt, create_time) values ('sbeeble2w@about.com', 'fatherley2w', 'i5FaBk6t', 'dxnyx3XAqx', '2022-09-12 09:30:52');
-insert into user (mail, username, password, salt, create_time) values ('aabethell2x@altervista.org', 'balbisser2x', 'aUpxVn', 'evn5WetSu', '2022-02-15 22:50:34');
-insert into user (mail, username, password, salt, create_time) values ('chawtry2y@yahoo.co.jp', 'bwandless2y', 'KUTtqCqeT0rf', 'Zkrg39R', '2022-03-31 14:51:10');
-insert into user (mail, username, password, salt, create_time) values ('tbrenstuhl2z@ed.gov', 'eleadbitter2z', 'AfOfWi9O', 'BNWrRdC', '2022-04-30 03:37:31');
-insert into user (mail, username, password, salt, create_time) values ('carmfirld30@cnet.com', 'mfaherty30', 'iFXSdF6', 'EB9LCs', '2022-10-18 02:32:02');
-insert into user (mail, username, password, salt, create_time) values ('emoger31@phoca.cz', 'mgarlinge31', 'QY4KqsjL', 'pJNRB4', '2022-01-18 12:45:39');
-insert into user (mail, username, password, salt, create_time) values ('fgreatrakes32@google.fr', 'rmadgin32', 'og3BMHFOYKz', 'ZLm5F0', '2022-02-20 21:53:06');
-insert into user (mail, username, password, salt, create_time) values ('bhevey33@nhs.uk', 'qaxe33', 'S7R1x1v', 'JiquyuW6byG', '2022-01-20 02:48:19');
-insert into user (mail, username, password, salt, create_time) values ('bbrosenius34@fda.gov', 'ltowlson34', 'XldEwDVti', '48aW9RKNUlcZ', '2021-12-03 04:44:06');
-insert into user (mail, username, password, salt, create_time) values ('mmacak35@cnet.com', 'ctaffarello35', 'iusdSqjZ', 'kaMdif', '2021-12-05 18:50:48');
-insert into user (mail, username, password, salt, create_time) values ('cwink36@google.co.uk', 'lsandbrook36', 'OtExfwi1U7w', 'xciyXm', '2021-12-14 13:30:50');
-insert into user (mail, username, password, salt, create_time) values ('hstonard37@gizmodo.com', 'nsmalridge37', 'cY2wvXxPj', 'q7uFsR2', '2022-03-18 01:40:06');
-insert into user (mail, username, password, salt, create_time) values ('mhardiman38@nba.com', 'jbastian38', 'uWJr76ucu', 'rjJUfwbZcW', '2022-05-27 15:08:47');
-insert into user (mail, username, password, salt, create_time) values ('mmichurin39@clickbank.net', 'cwignall39', 'r338Vd', 'Ry9K2zTe', '2022-06-01 05:46:40');
-insert into user (mail, username, password, salt, create_time) values ('dhulson3a@a8.net', 'shamp3a', 'hUtsoxMXLtz', '9RbbM7pD', '2022-10-06 15:30:56');
-insert into user (mail, username, password, salt, create_time) values ('apfeiffer3b@statcounter.com', 'csly3b', '6nFMJkJ', 'aSMuXsH0gVmy', '2022-01-07 01:10:32');
-insert into user (mail, username, password, salt, create_time) values ('asearson3c@google.co.jp', 'gcorn3c', 'QZO92roAF', 'jexZihcC', '2022-05-02 22:51:04');
-insert into user (mail, username, password, salt, create_time) values ('blehoux3d@china.com.cn', 'cschout3d', 'Qc8NFeiYf6Ho', 'O6H87ca', '2022-03-27 02:39:23');
-insert into user (mail, username, password, salt, create_time) values ('gofeeney3e@elpais.com', 'tcastagnone3e', '86klNoj', 'TXcJkv', '2022-01-06 03:27:05');
-insert into user (mail, username, password, salt, create_time) values ('shedgeley3f@princeton.edu', 'claroze3f', 'dOOa8ic2Yp1', 'meMDvYv', '2022-05-09 20:02:58');
-insert into user (mail, username, password, salt, create_time) values ('arubenov3g@simplemachines.org', 'klidell3g', '0mL0yDqQXRK', 'I304FrO', '2022-03-02 06:32:42');
-insert into user (mail, username, password, salt, create_time) values ('brosander3h@youku.com', 'aboyda3h', 'dbdN1A', 'i4HHJP6FIqy', '2022-05-28 10:15:33');
-insert into user (mail, username, password, salt, create_time) values ('mmiche3i@berkeley.edu', 'bcuer3i', 'KP5oC3rhkXl', 's5elBXNfDHlR', '2022-09-12 04:46:34');
-insert into user (mail, username, password, salt, create_time) values ('rbessey3j@seesaa.net', 'lcondy3j', 'ikdUI8fIS50', 'jXlSfQu', '2022-02-21 01:10:15');
-insert into user (mail, username, password, salt, create_time) values ('rganforthe3k@time.com', 'rcardello3k', 'tdhyhXX0ln', 'O6Ec3zYrfG4', '2022-08-04 09:27:51');
-insert into user (mail, username, password, salt, create_time) values ('rfoale3l@toplist.cz', 'mimrie3l', 'z0kHRnzM', 'BUGfO3', '2021-11-25 19:48:36');
-insert into user (mail, username, password, salt, create_time) values ('abegley3m@digg.com', 'mlammerich3m', 'PP3jrGZBL', 'mnxsikDfNq', '2021-12-24 11:46:42');
-insert into user (mail, username, password, salt, create_time) values ('mcoast3n@yellowbook.com', 'raugie3n', '6PnNby', 'xqlU73', '2022-07-25 19:29:19');
-insert into user (mail, username, password, salt, create_time) values ('rloynton3o@illinois.edu', 'aheffernan3o', 'pUB15GniJVXc', '98ptMyn3qvS4', '2022-05-18 07:03:55');
-insert into user (mail, username, password, salt, create_time) values ('soloshkin3p@samsung.com', 'gpoundesford3p', 'icxtkGYigZI', 'Khomr25', '2022-08-26 10:43:50');
-insert into user (mail, username, password, salt, create_time) values ('rshelton3q@theglobeandmail.com', 'strench3q', 'ThdHS5mlUwKY', 'MWA3RN4bF', '2022-09-12 16:25:04');
-insert into user (mail, username, password, salt, create_time) values ('rbucknill3r@msu.edu', 'acrinidge3r', 'eoeAD6WUpKj', 'POrGnjoQFjN', '2022-01-22 16:51:14');
-insert into user (mail, username, password, salt, create_time) values ('kstede3s@comcast.net', 'nrodmell3s', 'rwqbOP19Z', 'np0CMMGa', '2022-06-09 17:08:07');
-insert into user (mail, username, password, salt, create_time) values ('bbreakspear3t@ebay.com', 'zchalcot3t', 'PItYhb2Thb3', 'GTvnwX', '2022-07-17 10:38:05');
-insert into user (mail, username, password, salt, create_time) values ('mbrownscombe3u@themeforest.net', 'sseckom3u', 'tMgAE4tfLQkr', 'xxq8HgrHc', '2022-06-10 19:40:40');
-insert into user (mail, username, password, salt, create_time) values ('reast3v@baidu.com', 'slamie3v', 'n4G7eblS', '66OcVo', '2022-02-20 02:37:08');
-insert into user (mail, username, password, salt, create_time) values ('btowns3w@t.co', 'jstaunton3w', 'zo3rXScJE', 'lcjJjlt', '2022-08-13 13:56:16');
-insert into user (mail, username, password, salt, create_time) values ('preddick3x@drupal.org', 'cgonin3x', '9h2dQWuy', 'WOy2yXAOb', '2022-04-02 21:03:12');
-insert into user (mail, username, password, salt, create_time) values ('bsweet3y@google.pl', 'emilch3y', 'qAVSdXAyI2', '7V2k2eeXXBjw', '2022-07-29 15:31:18');
-insert into user (mail, username, password, salt, create_time) values ('lmacken3z@hao123.com', 'bcorkett3z', 'DdlJwQCAnx', 'ki81BE4PnKN', '2021-11-11 11:47:40');
-insert into user (mail, username, password, salt, create_time) values ('dcoleborn40@printfriendly.com', 'abinton40', 'nHirdJg', 'FiVmnpRPzdh', '2022-01-25 20:06:57');
-insert into user (mail, username, password, salt, create_time) values ('bkean41@macromedia.com', 'dlepiscopi41', '1CkjXRD', 'yVoIrkzWTed', '2021-12-26 17:30:04');
-insert into user (mail, username, password, salt, create_time) values ('nvanleijs42@nsw.gov.au', 'awinspar42', 'VjefTuXp', '5fHnmv', '2022-06-17 03:17:18');
-insert into user (mail, username, password, salt, create_time) values ('jklink43@ocn.ne.jp', 'tgeraldo43', 'X5i9oBG', 'Kv22ERyJOBz', '2022-03-18 09:42:57');
-insert into user (mail, username, password, salt, create_time) values ('sfarr44@stanford.edu', 'ksegar44', 'XgnLiFnoe', 'b9UY7Xj0qsG', '2022-06-08 07:04:44');
-insert into user (mail, username, password, salt, create_time) values ('nhuckster45@webnode.com', 'mmacellar45', 'HBP162WIxPcL', 'xxOdnAymAl', '2022-04-11 00:35:10');
-insert into user (mail, username, password, salt, create_time) values ('jariss46@illinois.edu', 'cskerratt46', '4mh4Gyxc', 'bjqqHyg', '2022-11-03 02:51:39');
-insert into user (mail, username, password, salt, create_time) values ('cloachhead47@cisco.com', 'dsirmond47', 'U46Pkk', '6KlFYqSQVCN', '2022-09-30 06:57:20');
-insert into user (mail, username, password, salt, create_time) values ('jduetschens48@sbwire.com', 'hpaolini48', 'EveX4ta6GHB', 'spXXguQCXP', '2022-09-08 12:01:51');
-insert into user (mail, username, password, salt, create_time) values ('bmillar49@jigsy.com', 'ttutt49', 'w5XnxKMc', 'kQ9yuJ4TY', '2022-06-06 06:48:18');
-insert into user (mail, username, password, salt, create_time) values ('soldacres4a@netscape.com', 'pspritt4a', 'HGMDx3rRh', 'Gl8IKcJX', '2022-02-22 16:15:22');
-insert into user (mail, username, password, salt, create_time) values ('blivermore4b@scientificamerican.com', 'emoro4b', 'aprW0FZkR', 'a95dHDyjG', '2021-11-26 23:10:04');
-insert into user (mail, username, password, salt, create_time) values ('bfrancais4c@etsy.com', 'amanuely4c', 'xVl6iPTKnEXD', 'EmsCPMKP', '2021-12-10 03:37:11');
-insert into user (mail, username, password, salt, create_time) values ('bkuhlen4d@trellian.com', 'pdeaconson4d', 'YqAm2Giz', 'Lo3w7u', '2022-09-02 00:52:16');
-insert into user (mail, username, password, salt, create_time) values ('rreynish4e@ocn.ne.jp', 'mmurby4e', '5lPw87a', 'agllYF2v9nO', '2022-10-23 14:59:34');
-insert into user (mail, username, password, salt, create_time) values ('hbritney4f@mit.edu', 'rdowty4f', 'XOFnTb5lW', '7YGmB8u', '2022-09-25 17:49:28');
-insert into user (mail, username, password, salt, create_time) values ('sduffree4g@arstechnica.com', 'bmarcam4g', 'vEKkRCQ', 'eUCr2njD4ibW', '2022-06-04 12:23:31');
-insert into user (mail, username, password, salt, create_time) values ('emadigan4h@live.com', 'srentoll4h', 'S47LuDro', 'a8goNf3DNY', '2022-10-07 13:19:03');
-insert into user (mail, username, password, salt, create_time) values ('kminer4i@illinois.edu', 'dpoltone4i', 'BeiYf5diW', 'GkITsOkL', '2022-08-29 12:08:08');
-insert into user (mail, username, password, salt, create_time) values ('iharsnep4j@linkedin.com', 'sorcott4j', 'oMPF24TfSxJT', 'Su3R4vh8byhx', '2021-11-22 07:41:26');
-insert into user (mail, username, password, salt, create_time) values ('jmanifould4k@so-net.ne.jp', 'hbushnell4k', 'lMpzfnxeMT', 'Cxd9jtxtk7bH', '2022-01-10 22:37:13');
-insert into user (mail, username, password, salt, create_time) values ('agobolos4l@so-net.ne.jp', 'kwest4l', 'A6UrkGjnN', '1ESr5WsPg', '2022-07-31 17:47:52');
-insert into user (mail, username, password, salt, create_time) values ('ltreneer4m@ning.com', 'jkinnaird4m', 'mnsLlzyG8Tnu', 'u7fWDMODCd4', '2022-07-11 21:20:20');
-insert into user (mail, username, password, salt, create_time) values ('cwolfit4n@qq.com', 'asudell4n', 'llA7DS', 'wCBhgXuTCVy', '2021-11-11 07:03:21');
-insert into user (mail, username, password, salt, create_time) values ('bkulver4o@google.co.jp', 'pgulliman4o', 'VkfNMUODOz', '66JhdPobX', '2022-05-23 02:19:26');
-insert into user (mail, username, password, salt, create_time) values ('msumers4p@issuu.com', 'daujean4p', 'KKgoOqf', 'Cl96RP1PNvy', '2022-08-11 21:29:48');
-insert into user (mail, username, password, salt, create_time) values ('bfidgett4q@yellowbook.com', 'fchorley4q', 'SDtnMueKSU1', 'iagPix4GX3', '2022-03-18 12:47:14');
-insert into user (mail, username, password, salt, create_time) values ('eseedman4r@alibaba.com', 'ssango4r', 'OXQy9aW', 'If6P5WZNJ', '2022-09-27 12:55:08');
-insert into user (mail, username, password, salt, create_time) values ('borlton4s@themeforest.net', 'dgraith4s', 'YHfOeeV', 'pwqoxxshwT', '2022-02-09 10:33:23');
-insert into user (mail, username, password, salt, create_time) values ('vfaulder4t@wikispaces.com', 'lanchor4t', '4jYZPj81aAd5', 'dlgqjC', '2022-08-11 12:49:34');
-insert into user (mail, username, password, salt, create_time) values ('echatel4u@foxnews.com', 'bzupa4u', 'oyhyMjYS', 'K2ctUiikcj', '2022-03-23 07:17:11');
-insert into user (mail, username, password, salt, create_time) values ('dvinick4v@princeton.edu', 'cgerriet4v', 'XABJNGi4vn', 'UZMFWiIcy', '2022-10-04 06:49:36');
-insert into user (mail, username, password, salt, create_time) values ('swilbor4w@dailymotion.com', 'jdrummer4w', '2GRlMEXxC4Qe', 'mijkDGT', '2022-02-18 12:11:28');
-insert into user (mail, username, password, salt, create_time) values ('tdilleston4x@xrea.com', 'cleyden4x', 'pABeEl5AlZ', 'fUq765E41', '2022-07-26 07:41:18');
-insert into user (mail, username, password, salt, create_time) values ('nbroadbent4y@sciencedaily.com', 'dglander4y', '6AkWH1', '8eRqfH', '2022-08-16 14:05:18');
-insert into user (mail, username, password, salt, create_time) values ('dbarczewski4z@lulu.com', 'etreadaway4z', '8p95LbwwSC3U', 'hUxAvM', '2022-10-09 03:59:28');
-insert into user (mail, username, password, salt, create_time) values ('mlapree50@so-net.ne.jp', 'dduddle50', 'Oo2Nzn', 'WRQsjRL9qc8', '2022-01-15 07:54:04');
-insert into user (mail, username, password, salt, create_time) values ('aodyvoy51@europa.eu', 'apanons51', 'Q4kNGn', 'ftmoJhE', '2021-11-13 15:04:18');
-insert into user (mail, username, password, salt, create_time) values ('brankmore52@jimdo.com', 'saudenis52', 'apoumhIRo', 'FgM9G09bwtz', '2022-02-10 19:39:24');
-insert into user (mail, username, password, salt, create_time) values ('kgownge53@businessweek.com', 'vwilce53', '0n8RfNlmP', 'KX51056taF0', '2021-11-11 03:36:42');
-insert into user (mail, username, password, salt, create_time) values ('meales54@altervista.org', 'mmatias54', 'TSXHis8Gwb', 'P0RLDLQXMxl', '2022-09-13 02:35:18');
-insert into user (mail, username, password, salt, create_time) values ('pcaudwell55@whitehouse.gov', 'rbruntjen55', 'TMyXrqSxnO', 'FhQUFzg7ol', '2022-10-30 01:05:10');
-insert into user (mail, username, password, salt, create_time) values ('enewbury56@free.fr', 'sgillies56', 'TDWGZUSVcwl0', 'M7HXKgtna', '2021-11-11 10:44:37');
-insert into user (mail, username, password, salt, create_time) values ('pfiske57@sitemeter.com', 'sdeye57', '4SNGwPJ', '0ZcICMrEvw', '2022-08-08 03:01:34');
-insert into user (mail, username, password, salt, create_time) values ('rdemalchar58@apple.com', 'cwinsor58', 'cM7QLwSUiBk1', 'opQ7txje', '2022-02-25 09:41:26');
-insert into user (mail, username, password, salt, create_time) values ('ndyster59@upenn.edu', 'mredgewell59', 'DyJx5pIn', 'qmiVkN', '2022-08-16 05:10:12');
-insert into user (mail, username, password, salt, create_time) values ('tstredwick5a@ameblo.jp', 'mellingsworth5a', 'Vc4RjUKyyN', 'Flia8781i1', '2021-11-22 10:12:38');
-insert into user (mail, username, password, salt, create_time) values ('qbrik5b@stumbleupon.com', 'hbottle5b', 'OpXwOX0Cyui', 'JL6Lg5HPn', '2022-03-12 14:01:41');
-insert into user (mail, username, password, salt, create_time) values ('sproven5c@t.co', 'bloding5c', '6uDnq1khoyU', 'pfAsEnaSs', '2022-03-09 18:12:06');
-insert into user (mail, username, password, salt, create_time) values ('cmarian5d@lulu.com', 'dscholtz5d', 'uyMV5OmJwTp', 'zmZTngC65gUo', '2022-05-22 12:01:22');
-insert into user (mail, username, password, salt, create_time) values ('rcastagnone5e@unc.edu', 'jsterley5e', 'UHaeDsWjBxtJ', 'tb2Xqh', '2022-08-30 03:32:51');
-insert into user (mail, username, password, salt, create_time) values ('tlatan5f@ask.com', 'bsherington5f', 'oi1EUf82JX', 'UT1lS9XtPZ', '2021-11-12 12:09:29');
-insert into user (mail, username, password, salt, create_time) values ('jjahnke5g@blog.com', 'mbesset5g', 'q0fB24', 'GeEC8zqRBG', '2022-03-21 18:43:22');
-insert into user (mail, username, password, salt, create_time) values ('awhooley5h@bandcamp.com', 'asheahan5h', 'hwdrJyh', '3xJQEFRd5JeJ', '2022-01-08 16:28:53');
-insert into user (mail, username, password, salt, create_time) values ('kaprahamian5i@e-recht24.de', 'dcluckie5i', 'WmflHCC1', 'HC35xDfh', '2021-11-22 13:01:31');
-insert into user (mail, username, password, salt, create_time) values ('lgoodsal5j@lulu.com', 'sletherbury5j', 'U8JMUnzj18', 's957Valv3', '2021-12-13 15:46:22');
-insert into user (mail, username, password, salt, create_time) values ('zerrigo5k@freewebs.com', 'aadamovich5k', 'deLHPzO', 'lbDoo6DK9', '2022-08-12 16:46:56');
-insert into user (mail, username, password, salt, create_time) values ('vwelsby5l@example.com', 'fosboldstone5l', 'nC2qZi', 'xBczkYSrxis', '2022-03-02 06:33:35');
-insert into user (mail, username, password, salt, create_time) values ('dmalarkey5m@purevolume.com', 'ehiddsley5m', 'RvmOSfvowTKl', '9wP4Jo2A3j6e', '2021-12-16 23:34:49');
-insert into user (mail, username, password, salt, create_time) values ('rgrice5n@marketwatch.com', 'msunners5n', 'OOhPrVKO', 'GrCary', '2021-11-08 11:20:31');
-insert into user (mail, username, password, salt, create_time) values ('mloreit5o@google.ca', 'hvader5o', 'yPKvPZI', 'kWQmLU5', '2022-09-26 09:28:28');
-insert into user (mail, username, password, salt, create_time) values ('epyffe5p@cnet.com', 'sarnecke5p', 'y8x1ZaK4p', 'qPXUOaaFC', '2022-10-09 01:20:33');
-insert into user (mail, username, password, salt, create_time) values ('mbartoleyn5q@biblegateway.com', 'gclapison5q', 'UbnRF1J3Eh', 'GSlmBXx1vo', '2022-06-27 19:54:58');
-insert into user (mail, username, password, salt, create_time) values ('nmotto5r@foxnews.com', 'hmillier5r', 'j9zAtb', 'XHwxDnhYPro', '2022-02-03 06:15:21');
-insert into user (mail, username, password, salt, create_time) values ('oivory5s@omniture.com', 'mdunkersley5s', 'xDlSKzMIz', 'GX9w8FdOkS0', '2022-03-25 04:25:51');
-insert into user (mail, username, password, salt, create_time) values ('csidlow5t@goo.gl', 'amurra5t', 'ZR7B3pIfQET', 'Cj5jVLnMo', '2022-10-12 20:30:56');
-insert into user (mail, username, password, salt, create_time) values ('rcarnew5u@unc.edu', 'erhubottom5u', 'xjYVcv3HG', 'be0KqBtESItT', '2022-04-02 13:31:29');
-insert into user (mail, username, password, salt, create_time) values ('bfearnsides5v@cyberchimps.com', 'bmateev5v', 'W8Xnid', '9xedggJqDLa', '2021-11-10 13:29:32');
-insert into user (mail, username, password, salt, create_time) values ('wsheringham5w@umn.edu', 'kheaton5w', 'JeSyL5YY', '7Hr1HHD6pTdD', '2022-07-06 10:28:53');
-insert into user (mail, username, password, salt, create_time) values ('sedgeley5x@ucoz.com', 'dfibbings5x', 'Bnm8cxf', '0iQPdc', '2022-03-22 01:39:15');
-insert into user (mail, username, password, salt, create_time) values ('esedgeman5y@delicious.com', 'lweldon5y', 'wafx0AMSN', 'RGklyPzGDpp', '2022-09-13 12:27:31');
-insert into user (mail, username, password, salt, create_time) values ('mronan5z@tuttocitta.it', 'galfonso5z', 'OxI0Bjtl', 'mEBMqQLd', '2022-06-29 15:43:40');
-insert into user (mail, username, password, salt, create_time) values ('vsilliman60@who.int', 'cweeke60', 'EFpK0c', 'PmBs0a', '2021-12-11 19:45:40');
-insert into user (mail, username, password, salt, create_time) values ('wdugdale61@reddit.com', 'atoy61', '2JiFEP', 'VLkzbdhAZdd', '2021-12-08 10:30:45');
-insert into user (mail, username, password, salt, create_time) values ('rcock62@spiegel.de', 'ktallboy62', 'fztPhQG', 'lz8ZQhgFM', '2022-06-26 18:15:59');
-insert into user (mail, username, password, salt, create_time) values ('aarnaldo63@sciencedirect.com', 'pterese63', 'UjCHfgZp7t', 'PCFfEVHAQXni', '2022-04-30 03:58:24');
-insert into user (mail, username, password, salt, create_time) values ('ltugwell64@senate.gov', 'jvasiliu64', 'Kgorcb', 'HYaPKeCLKuQa', '2021-11-22 22:51:33');
-insert into user (mail, username, password, salt, create_time) values ('gpeskin65@amazon.com', 'tdunbleton65', 'fbTWn2ae', 'tTYIbsa', '2022-10-15 22:04:33');
-insert into user (mail, username, password, salt, create_time) values ('sagius66@census.gov', 'cchecchi66', 'JeGC2q', 'NNRsJIKF2', '2022-08-28 12:45:59');
-insert into user (mail, username, password, salt, create_time) values ('ibromell67@engadget.com', 'lbarnwill67', 'RgANY8t', 'SFTCwqBgfLBU', '2022-02-20 19:22:55');
-insert into user (mail, username, password, salt, create_time) values ('dnowakowska68@examiner.com', 'rpavlik68', 'I787HS9zwSmB', 'D16r6eNhVa9', '2022-10-23 22:16:27');
-insert into user (mail, username, password, salt, create_time) values ('iennion69@businessweek.com', 'freiners69', 'im3MQTAU2', 'a1tsn5OiD', '2022-09-14 21:57:51');
-insert into user (mail, username, password, salt, create_time) values ('wchavey6a@npr.org', 'oreuter6a', 'nQ2SYEq', 'OryBYZSGNTID', '2022-04-27 04:38:48');
-insert into user (mail, username, password, salt, create_time) values ('nchristophersen6b@auda.org.au', 'bcopper6b', '5viRbD9Agwx', 'aJ47xaZ', '2022-07-26 10:51:29');
-insert into user (mail, username, password, salt, create_time) values ('jmacaree6c@amazonaws.com', 'ndytham6c', '3c5IMLlN', '3jcocLRW5', '2021-11-06 02:46:13');
-insert into user (mail, username, password, salt, create_time) values ('nstandall6d@cyberchimps.com', 'kciobotaro6d', '1nnwANDkpT', 'fyNbEHvIrY', '2022-08-14 14:41:15');
-insert into user (mail, username, password, salt, create_time) values ('jschott6e@t.co', 'jhansom6e', 'vOvgT7', 'G7e58Ngnq', '2022-10-10 12:54:49');
-insert into user (mail, username, password, salt, create_time) values ('sprickett6f@fema.gov', 'bvandendael6f', 'LsSkFxZ', 'jMIps98Rh', '2022-07-02 04:24:09');
-insert into user (mail, username, password, salt, create_time) values ('mbudgeon6g@yahoo.com', 'ndurtnal6g', '902u1uD3Z1m', 'Jcpjaho7Vvd', '2022-07-04 00:48:57');
-insert into user (mail, username, password, salt, create_time) values ('cbuckler6h@hostgator.com', 'ssultana6h', 'mSPo6krnBBN', 'Rb5Dh2ZjYvPK', '2022-08-03 16:01:17');
-insert into user (mail, username, password, salt, create_time) values ('okarpeev6i@gnu.org', 'kradborn6i', 'qoyFbE', 'Zuc7Rsq6WA', '2022-05-13 05:08:33');
-insert into user (mail, username, password, salt, create_time) values ('dtie6j@a8.net', 'fluckham6j', '1Qc21O5lp', 'aSG30wq6X', '2022-07-05 02:23:43');
-insert into user (mail, username, password, salt, create_time) values ('eding6k@uol.com.br', 'gglenn6k', 'Ip6vmeOh4K', 'X0sELm', '2022-02-09 01:03:42');
-insert into user (mail, username, password, salt, create_time) values ('dgrzegorzewski6l@blogs.com', 'frosendall6l', 'BdTWb4Z', 'dxqfNbkZ', '2022-11-01 08:40:43');
-insert into user (mail, username, password, salt, create_time) values ('wscreach6m@xinhuanet.com', 'mwaghorne6m', 'G9tPlZnDSTM', 'xRMJK6NI5buQ', '2022-01-06 22:44:12');
-insert into user (mail, username, password, salt, create_time) values ('ibreming6n@altervista.org', 'dmccarrick6n', '9s3KNdL8d', 'lqGL9Q8', '2022-07-24 18:55:48');
-insert into user (mail, username, password, salt, create_time) values ('alarmouth6o@virginia.edu', 'cwyles6o', 'imjpZwz', 'RyUwPsCDq', '2022-07-01 17:54:24');
-insert into user (mail, username, password, salt, create_time) values ('dbauld6p@bravesites.com', 'igathercole6p', 'WE4gfemDl', 'M2oMgGw', '2022-02-03 12:08:55');
-insert into user (mail, username, password, salt, create_time) values ('adeeming6q@accuweather.com', 'wismay6q', 'bWKQwDQ', '4DdIzEPNzLfX', '2022-03-09 18:23:32');
-insert into user (mail, username, password, salt, create_time) values ('rgeyton6r@qq.com', 'bcricket6r', '1om15LKY', '5FP2ZbS', '2022-04-13 21:39:38');
-insert into user (mail, username, password, salt, create_time) values ('lcasillas6s@uol.com.br', 'gkavanagh6s', 'EwBnzgC0wUj', '8lmdRp8J', '2022-04-09 13:02:19');
-insert into user (mail, username, password, salt, create_time) values ('jgustus6t@people.com.cn', 'jbartolini6t', 'KUjf0aA8', '05uJ9BYq4', '2021-11-23 23:38:52');
-insert into user (mail, username, password, salt, create_time) values ('nlempenny6u@tripadvisor.com', 'olocksley6u', 'BAR3v9GeeMI', 'UDDXrazuCDK', '2022-03-18 13:39:38');
-insert into user (mail, username, password, salt, create_time) values ('battryde6v@sitemeter.com', 'mburfield6v', 'wr6tte', '2eLQrceoshG', '2022-03-08 07:17:01');
-insert into user (mail, username, password, salt, create_time) values ('okastel6w@parallels.com', 'hfrancescoccio6w', '8SHU5LOQ6WA', 'weZCfie', '2021-12-25 13:23:03');
-insert into user (mail, username, password, salt, create_time) values ('ndelleschi6x@mit.edu', 'fpoacher6x', 'j2PiHx41Mu', 'DpD2OCX', '2022-10-20 13:26:04');
-insert into user (mail, username, password, salt, create_time) values ('nstalf6y@eventbrite.com', 'sphillpotts6y', '6kAo8nYz', 'ngJBQ9', '2022-02-27 14:03:32');
-insert into user (mail, username, password, salt, create_time) values ('hedis6z@illinois.edu', 'lbilborough6z', 'MxJDjYtT', 'W3kDOc', '2022-10-28 19:47:38');
-insert into user (mail, username, password, salt, create_time) values ('fmollindinia70@apple.com', 'gkase70', 'RuMxBw', 'lwSYrodHJk6', '2022-01-12 07:01:35');
-insert into user (mail, username, password, salt, create_time) values ('bfaers71@tripadvisor.com', 'gcrosetto71', 'pGNC2kVl', '3CEHfoIYQ3K', '2022-07-30 09:27:34');
-insert into user (mail, username, password, salt, create_time) values ('cjanse72@photobucket.com', 'olatan72', 'btVpJ3PrrCI', 'sgTP5tezaIms', '2022-07-05 11:59:42');
-insert into user (mail, username, password, salt, create_time) values ('mfarny73@joomla.org', 'kgealle73', 'iiGnAK0', 'z4J5Ag4j', '2022-03-26 15:43:24');
-insert into user (mail, username, password, salt, create_time) values ('lbradneck74@ow.ly', 'golin74', 'B2C274V6mG1', 'aUeFcFs', '2021-12-25 23:07:40');
-insert into user (mail, username, password, salt, create_time) values ('jottawell75@cdc.gov', 'tpetasch75', '08rdQwET', 'X4kWi4HPCr1n', '2022-01-11 18:38:46');
-insert into user (mail, username, password, salt, create_time) values ('bkarlolczak76@nature.com', 'civanishchev76', 'pR27St', 'S4i4klC', '2022-09-09 06:16:32');
-insert into user (mail, username, password, salt, create_time) values ('jkeningley77@cafepress.com', 'felintune77', 'husFwVV3', 'xZFP6LetvAe', '2022-06-16 20:16:21');
-insert into user (mail, username, password, salt, create_time) values ('ibartolini78@fotki.com', 'klampert78', 's3PwO5O', 's2g2wZyYr', '2022-09-19 08:53:49');
-insert into user (mail, username, password, salt, create_time) values ('bcandlish79@cbsnews.com', 'jbinnes79', 'O6Zvmh', 'kRyzPDGo', '2022-03-01 18:00:37');
-insert into user (mail, username, password, salt, create_time) values ('jbrittle7a@fastcompany.com', 'sraiker7a', 'GDBGD0Bt4pp0', 'lI9q931gxiT', '2022-10-19 09:22:14');
-insert into user (mail, username, password, salt, create_time) values ('ewrench7b@webs.com', 'zdearness7b', 'uNEgonUkG1y', 'OpERAw74aW', '2021-11-26 08:02:26');
-insert into user (mail, username, password, salt, create_time) values ('mwrought7c@nymag.com', 'mswinerd7c', 'X3yTI9', '0ybwMvnxDt', '2022-08-06 00:48:41');
-insert into user (mail, username, password, salt, create_time) values ('hpostance7d@ebay.co.uk', 'cvalencia7d', 'tCdeq9O', 'D6FE2P', '2022-11-04 19:49:50');
-insert into user (mail, username, password, salt, create_time) values ('mhelversen7e@apache.org', 'vabramof7e', '7XFDyEzJtHE', 'v6A8dhr8Y', '2022-05-12 01:46:32');
-insert into user (mail, username, password, salt, create_time) values ('lgrandisson7f@pbs.org', 'mentissle7f', 'XFHN8DySXaj', 'bEJi0Mvd2ldG', '2022-05-19 15:01:39');
-insert into user (mail, username, password, salt, create_time) values ('jfines7g@narod.ru', 'famphlett7g', 'cN15iAUs', 'HWgvj78iPcXY', '2022-05-24 06:31:16');
-insert into user (mail, username, password, salt, create_time) values ('jmattessen7h@tamu.edu', 'rvispo7h', 'm5aU1G', 'rQJP6nAVp', '2022-07-20 17:49:09');
-insert into user (mail, username, password, salt, create_time) values ('hbeards7i@wikipedia.org', 'obasketfield7i', 'T6KXeHo5', 'uxU6HijzC', '2022-08-29 18:42:22');
-insert into user (mail, username, password, salt, create_time) values ('aloosmore7j@addthis.com', 'psmylie7j', 'q41c6ZHsm', 'ZLuvVCaoWcwz', '2022-06-09 11:13:29');
-insert into user (mail, username, password, salt, create_time) values ('jlangstrath7k@hatena.ne.jp', 'mlarne7k', 'moKnzOt89aD', 'UGbnOF8Jo', '2022-06-15 06:13:14');
-insert into user (mail, username, password, salt, create_time) values ('bmandrake7l@telegraph.co.uk', 'aludlam7l', 'kJu1mZNXbD', 'zFC0TsZ', '2022-09-06 07:50:11');
-insert into user (mail, username, password, salt, create_time) values ('cbeasley7m@chronoengine.com', 'mmaber7m', 'hGjjRUc46akR', 'Iakjv0m', '2022-10-25 07:36:55');
-insert into user (mail, username, password, salt, create_time) values ('wcater7n@umich.edu', 'qpacht7n', 'ro8p5NXNsn', 'uixxLPBf', '2022-05-28 22:54:18');
-insert into user (mail, username, password, salt, create_time) values ('pbartolozzi7o@icio.us', 'tannis7o', 'otJ1Hm7', 'APRmzQTPrY', '2022-02-07 07:15:04');
-insert into user (mail, username, password, salt, create_time) values ('jgallardo7p@rambler.ru', 'pmelato7p', 'o4BS12h', 'Byu7tojTjmjg', '2022-10-29 11:14:28');
-insert into user (mail, username, password, salt, create_time) values ('coakly7q@hud.gov', 'bluckin7q', 'rc65jZrVG9U', 'jHxfjn1KlXun', '2022-08-24 14:05:16');
-insert into user (mail, username, password, salt, create_time) values ('okippen7r@icq.com', 'tbarense7r', 'zJsLexfsk', 'HRvewa', '2022-03-09 04:14:26');
-insert into user (mail, username, password, salt, create_time) values ('etagg7s@cornell.edu', 'otagg7s', 'p65dPW1US0F', '3AJ2s99a', '2022-10-07 05:02:54');
-insert into user (mail, username, password, salt, create_time) values ('mhearthfield7t@walmart.com', 'rocarroll7t', '4ppg8tYbBuQ', 'WebSxHw', '2022-05-29 03:10:28');
-insert into user (mail, username, password, salt, create_time) values ('broyds7u@ebay.com', 'ygarred7u', 'Plh8f2no', 'b1pN0Q5O', '2021-12-17 16:55:42');
-insert into user (mail, username, password, salt, create_time) values ('uplace7v@va.gov', 'cdivine7v', 'r82J6VGorc', 'oSCcKixthDG1', '2022-04-11 20:09:06');
-insert into user (mail, username, password, salt, create_time) values ('aroutley7w@columbia.edu', 'vmacdavitt7w', '68Za7k18pO', '2eHU3lYxD', '2022-09-28 11:23:45');
-insert into user (mail, username, password, salt, create_time) values ('byokley7x@moonfruit.com', 'udominick7x', 'jMGeqQCsqBv8', 'bBQDASK', '2022-03-27 08:37:31');
-insert into user (mail, username, password, salt, create_time) values ('soharney7y@usda.gov', 'dgronav7y', 'iRMRLX', 'FvRbs2I', '2022-02-02 07:34:55');
-insert into user (mail, username, password, salt, create_time) values ('sfrean7z@nymag.com', 'dhegerty7z', 'Vv0lfVB', 'jwvmie', '2022-06-08 08:59:52');
-insert into user (mail, username, password, salt, create_time) values ('zdrohan80@cisco.com', 'apendred80', 'Ap8nMUt', 'CVRq5FoH1t', '2022-04-27 23:46:03');
-insert into user (mail, username, password, salt, create_time) values ('dbatterton81@friendfeed.com', 'bferran81', '4ejRfnB2yna', '7zXBon4T', '2021-11-19 06:06:48');
-insert into user (mail, username, password, salt, create_time) values ('uforrest82@hhs.gov', 'hvernay82', '2Mwdjkz3pO', 'INueYZ12B', '2022-10-07 11:44:52');
-insert into user (mail, username, password, salt, create_time) values ('ohourston83@discovery.com', 'eleftwich83', 'mQueQJUATQVt', 'bmF17krKzAuH', '2022-04-19 10:33:03');
-insert into user (mail, username, password, salt, create_time) values ('ecoggon84@desdev.cn', 'sdurston84', 'XBo14wqNxYc2', 'BIHpkqezH', '2022-02-22 07:11:05');
-insert into user (mail, username, password, salt, create_time) values ('idonaghie85@house.gov', 'wropkins85', 'LT515cGZE', '8kYnBZynmL', '2022-09-09 00:40:44');
-insert into user (mail, username, password, salt, create_time) values ('nmoorwood86@walmart.com', 'rhandscomb86', '2OM5C7', '9ow6Usf', '2021-12-31 01:24:06');
-insert into user (mail, username, password, salt, create_time) values ('ldartnall87@discuz.net', 'bmantz87', 'IxkI6UmE', 'ccYBCbYE5', '2022-05-18 12:49:43');
-insert into user (mail, username, password, salt, create_time) values ('xsimioli88@tumblr.com', 'ccradoc88', 'qabu4A7AVNSw', 'nNzcNtvjz2e', '2022-01-30 10:53:05');
-insert into user (mail, username, password, salt, create_time) values ('lstreatfeild89@apache.org', 'vadel89', 'kMLgTB', 'P2kAEg', '2022-10-19 20:51:13');
-insert into user (mail, username, password, salt, create_time) values ('nmoseley8a@cnet.com', 'pcrampsey8a', 'xkLrZqqFxwU4', 'CsFhN8e1Of', '2022-09-04 04:33:37');
-insert into user (mail, username, password, salt, create_time) values ('mreames8b@ehow.com', 'rsibbet8b', '1IZSx1', 'dA8yFOG0lrS', '2022-10-30 02:00:17');
-insert into user (mail, username, password, salt, create_time) values ('htookill8c@over-blog.com', 'tcastard8c', 'YXtfZ1', 'hlINP8hbl', '2021-12-22 17:29:41');
-insert into user (mail, username, password, salt, create_time) values ('fpridden8d@disqus.com', 'rhawksworth8d', 'Ikzu7a', 'eYmWJO0xyq9', '2022-03-09 16:02:57');
-insert into user (mail, username, password, salt, create_time) values ('lblaver8e@ucoz.com', 'gclare8e', 'Wixm90S5Fj2F', 'eo5mKdEVLXZ', '2022-10-30 05:13:31');
-insert into user (mail, username, password, salt, create_time) values ('ebrimicombe8f@goo.gl', 'swillows8f', 'G0D0joNfrb', 'MGcN1OBFcHT4', '2022-08-28 04:45:42');
-insert into user (mail, username, password, salt, create_time) values ('kclaus8g@washington.edu', 'tausher8g', 'dvk7TIN02i5', 'eExenb0jTRx', '2021-12-30 09:18:42');
-insert into user (mail, username, password, salt, create_time) values ('icamois8h@sfgate.com', 'mdondon8h', 'DWDh06lY0', 'vYc3JrxYVSp', '2021-12-02 19:33:17');
-insert into user (mail, username, password, salt, create_time) values ('hpedrollo8i@sourceforge.net', 'jpenwright8i', 'yJvxr3', 'g2lIhXN0', '2022-05-27 13:28:15');
-insert into user (mail, username, password, salt, create_time) values ('boene8j@tamu.edu', 'wtipler8j', 'tXUlp2', '2RX0UtG', '2021-11-20 22:36:03');
-insert into user (mail, username, password, salt, create_time) values ('bgerok8k@storify.com', 'gkorous8k', '7NbBh7jTq', 'kFuco6', '2022-07-23 15:53:12');
-insert into user (mail, username, password, salt, create_time) values ('tkelling8l@princeton.edu', 'gbobasch8l', 'JqsiWTJlGw', 'Bc2YNB', '2022-10-03 12:01:31');
-insert into user (mail, username, password, salt, create_time) values ('ssex8m@imdb.com', 'mfranzewitch8m', 'UEyUzNwqXN', 'mvi6Zria0Ch', '2022-02-19 04:33:20');
-insert into user (mail, username, password, salt, create_time) values ('dlester8n@wikimedia.org', 'vbyass8n', 'i4BsrBWog', 'vG8JWKB', '2021-12-25 21:49:30');
-insert into user (mail, username, password, salt, create_time) values ('tdysart8o@plala.or.jp', 'hreah8o', 'rIjvCApH', 'Cky2S2M0Zez', '2022-07-24 11:03:22');
-insert into user (mail, username, password, salt, create_time) values ('dgillino8p@webeden.co.uk', 'jstredwick8p', '91aJfkw', 'ts1PI2o', '2021-12-12 07:52:21');
-insert into user (mail, username, password, salt, create_time) values ('cnorthley8q@purevolume.com', 'moffill8q', 'Aef4XtHT62HP', '6j9fAsPD', '2022-04-23 16:29:31');
-insert into user (mail, username, password, salt, create_time) values ('dkacheler8r@cisco.com', 'rspinas8r', 'nOkKCR0fgB7E', '1QMJL3', '2022-04-09 08:59:52');
-insert into user (mail, username, password, salt, create_time) values ('dsymonds8s@google.es', 'wjarrelt8s', 'sII6neZp', 'CfaYryV', '2021-11-15 15:47:41');
-insert into user (mail, username, password, salt, create_time) values ('msamsworth8t@miibeian.gov.cn', 'gmoyler8t', 'EOEaz9t0NI', 'CCHsEGqR', '2022-07-27 19:51:48');
-insert into user (mail, username, password, salt, create_time) values ('cbuntin8u@mit.edu', 'rdeclairmont8u', '9UEnO0kyk', 'SUwpVzfEJ', '2021-12-07 01:32:40');
-insert into user (mail, username, password, salt, create_time) values ('bfehner8v@eventbrite.com', 'kfitzsimon8v', '6wmFVs56rJ', 'VGRplD53', '2022-04-13 12:32:16');
-insert into user (mail, username, password, salt, create_time) values ('eluton8w@adobe.com', 'mjuggins8w', '3m7hfWc', 'Lr3JmRAyyCb', '2022-07-14 03:37:15');
-insert into user (mail, username, password, salt, create_time) values ('smiskimmon8x@statcounter.com', 'vgeaveny8x', '9S5WNIz3Wel', 'Vsq2YIv7', '2021-12-15 23:15:46');
-insert into user (mail, username, password, salt, create_time) values ('abenaharon8y@apple.com', 'idrinkall8y', 'vR5xKRX', '1xTymfG', '2022-04-25 04:52:17');
-insert into user (mail, username, password, salt, create_time) values ('ftewkesberry8z@unc.edu', 'jgayton8z', '5gAkhr3KTl', 'GteCICxNb', '2021-12-23 10:55:27');
-insert into user (mail, username, password, salt, create_time) values ('pbottrell90@nationalgeographic.com', 'rblakey90', 'Gel39GzR6CEc', 'lOIeo4', '2022-03-29 20:57:23');
-insert into user (mail, username, password, salt, create_time) values ('yperch91@ucsd.edu', 'bsouter91', 'nMwYbZpjB78p', 'tbpypI', '2022-09-23 10:58:10');
-insert into user (mail, username, password, salt, create_time) values ('slangridge92@uol.com.br', 'rverrills92', 'E49Z4n', 'T8PxdrDUH', '2022-03-05 08:02:40');
-insert into user (mail, username, password, salt, create_time) values ('efeathers93@answers.com', 'apickless93', 'ov68eFQ', 'AE79aJGu74a', '2022-06-26 14:38:57');
-insert into user (mail, username, password, salt, create_time) values ('cbazely94@wufoo.com', 'ahaldene94', 'A4JDPqD', '63mOKQRaWN', '2022-03-29 15:29:41');
-insert into user (mail, username, password, salt, create_time) values ('wphilpault95@patch.com', 'mnazair95', 'iosrnQPdQ', 'TBv6emr', '2021-12-07 04:18:41');
-insert into user (mail, username, password, salt, create_time) values ('eoswal96@technorati.com', 'lmcgilvray96', 'NBnQ2I', 'Ae1WUJm3awIU', '2022-04-03 20:16:47');
-insert into user (mail, username, password, salt, create_time) values ('apagett97@hp.com', 'doldey97', 'pj72QIbgC', 'SojWDxoz9Qhp', '2022-02-26 22:46:58');
-insert into user (mail, username, password, salt, create_time) values ('ekittel98@com.com', 'lhazelhurst98', 'Ww640gvXSZm', 'vTQbV67Mds', '2022-10-23 06:53:11');
-insert into user (mail, username, password, salt, create_time) values ('mambrozewicz99@imageshack.us', 'rschiersch99', 'EXFAqd0LkQ', 'L6yVWdHQ1Vtc', '2021-11-30 10:15:50');
-insert into user (mail, username, password, salt, create_time) values ('pwarratt9a@smh.com.au', 'hhawtrey9a', 'CeyipDKu', 'Katjyl', '2022-03-15 13:54:00');
-insert into user (mail, username, password, salt, create_time) values ('mheitz9b@washingtonpost.com', 'ttetford9b', 'tfi8Z6K8AMEP', 'rfPTkKGI9d', '2022-05-01 14:09:14');
-insert into user (mail, username, password, salt, create_time) values ('gdevereux9c@unicef.org', 'ivolkes9c', 'P0ahKkDH', 'xWcZmSXy9XT', '2022-10-14 08:09:48');
-insert into user (mail, username, password, salt, create_time) values ('nwitchard9d@businessinsider.com', 'hkilian9d', 'wFf5E3R0po', 'UD9zskCAGei8', '2022-06-17 03:13:39');
-insert into user (mail, username, password, salt, create_time) values ('cruzek9e@virginia.edu', 'ecartlidge9e', 'pmH1zmlfX', 'UrarKRn', '2022-07-13 22:07:28');
-insert into user (mail, username, password, salt, create_time) values ('mfullard9f@vinaora.com', 'hmccoughan9f', '1Z59gh6wPb', 'Mjdhvl', '2022-03-28 11:40:22');
-insert into user (mail, username, password, salt, create_time) values ('lodooghaine9g@rediff.com', 'zbakewell9g', 'SGqcHS', 'p2dcj9', '2022-07-18 14:41:30');
-insert into user (mail, username, password, salt, create_time) values ('olyosik9h@hatena.ne.jp', 'rolley9h', 'Jjo5n6qDUO', 'LR6CSB', '2022-05-14 02:37:22');
-insert into user (mail, username, password, salt, create_time) values ('mdymoke9i@netlog.com', 'srieflin9i', 's7cIMCwCIh', 'CzL1XQ', '2022-06-12 16:48:25');
-insert into user (mail, username, password, salt, create_time) values ('lmckendry9j@wordpress.com', 'cstocks9j', 'g4lu9lUD', 'P5SFx9mDxx6z', '2022-02-22 23:10:23');
-insert into user (mail, username, password, salt, create_time) values ('bmatejka9k@mlb.com', 'ttesto9k', 'w2zcQKDb', 'I7EbBjq', '2022-08-28 03:19:41');
-insert into user (mail, username, password, salt, create_time) values ('icrennell9l@delicious.com', 'asailes9l', '4PvMfW7Rl', 'F94tS5T4P', '2022-02-20 13:05:35');
-insert into user (mail, username, password, salt, create_time) values ('bcammock9m@nationalgeographic.com', 'kduffree9m', 'l9qYpGnR', 'QHNYIgGYHERU', '2021-11-15 07:54:02');
-insert into user (mail, username, password, salt, create_time) values ('dlemasney9n@businessweek.com', 'askittrell9n', 'DGrc5bTF', 'KsBL2h', '2022-03-29 19:35:36');
-insert into user (mail, username, password, salt, create_time) values ('cwedderburn9o@shutterfly.com', 'rlittlecote9o', 'AcPrcWCPE', 'Dtl1ZEiIL', '2021-12-05 02:33:02');
-insert into user (mail, username, password, salt, create_time) values ('csollas9p@merriam-webster.com', 'ppetegre9p', 'kxMwE3oFNkW', 'Rx7bBHGmVJu', '2022-04-23 10:39:21');
-insert into user (mail, username, password, salt, create_time) values ('aharbottle9q@unblog.fr', 'jreichhardt9q', 'ADUKY4d', 'XWnSvBpI', '2022-10-31 12:26:46');
-insert into user (mail, username, password, salt, create_time) values ('sandreu9r@census.gov', 'jstandbrook9r', 'rmwgb7JKFF', 'l2x9t43f', '2022-08-19 21:34:10');
-insert into user (mail, username, password, salt, create_time) values ('sfermer9s@sun.com', 'bboays9s', 'uN1NXJ5lY0hl', '21GnH42Y7L', '2022-02-14 16:45:50');
-insert into user (mail, username, password, salt, create_time) values ('jspofford9t@edublogs.org', 'chorsefield9t', 'ICzb5T0', 'QRskaydqt', '2022-10-24 22:37:45');
-insert into user (mail, username, password, salt, create_time) values ('ebottom9u@issuu.com', 'tpottage9u', 'M5P1MGG8A', 'gToQ0P7f6gOW', '2022-09-13 10:55:55');
-insert into user (mail, username, password, salt, create_time) values ('gallerton9v@guardian.co.uk', 'efrenchum9v', 'b5BEVDe', '7LLuQdyCxRmh', '2022-07-19 23:26:28');
-insert into user (mail, username, password, salt, create_time) values ('smerner9w@godaddy.com', 'tgrzeskowski9w', 'bnfeTo', 'OOlgUNQFTnN', '2021-11-30 16:49:35');
-insert into user (mail, username, password, salt, create_time) values ('dfosbraey9x@zdnet.com', 'pthraves9x', '0dDI7c93', 'VrSDSxQ', '2022-09-21 00:45:58');
-insert into user (mail, username, password, salt, create_time) values ('emoogan9y@berkeley.edu', 'ewhittet9y', 'VTIsN9KIhRp0', 'hjAdKsBB', '2021-12-03 07:32:54');
-insert into user (mail, username, password, salt, create_time) values ('kfensome9z@usatoday.com', 'hkain9z', 'QRSCODc', 'UucJJzKV2', '2022-09-28 23:19:14');
-insert into user (mail, username, password, salt, create_time) values ('aayliffea0@twitpic.com', 'ctinwella0', 'tX3f0QB', '9VyLcVhG', '2021-11-24 21:02:02');
-insert into user (mail, username, password, salt, create_time) values ('bgallamorea1@about.me', 'hberga1', 'ceOF7CSESFXV', 'ehNNQ16A', '2022-09-12 01:13:19');
-insert into user (mail, username, password, salt, create_time) values ('dellacotta2@netscape.com', 'rriguarda2', 'CjyxWzM', 'd1CaXo7XoiKO', '2021-12-21 16:19:50');
-insert into user (mail, username, password, salt, create_time) values ('aaveyarda3@chronoengine.com', 'sscholtza3', 'vM06odYHZ', 'WQGdJd2hu', '2022-10-10 11:13:36');
-insert into user (mail, username, password, salt, create_time) values ('okunaa4@earthlink.net', 'eliklya4', 'tOSloP', '6anr8VlQ', '2022-07-17 17:09:07');
-insert into user (mail, username, password, salt, create_time) values ('dgribbona5@wired.com', 'gdenesa5', '4Fd1i1bXWS', 'QIRsU8J0', '2022-06-26 13:01:08');
-insert into user (mail, username, password, salt, create_time) values ('gantrama6@blogtalkradio.com', 'cportmana6', 'xAJie15', 'YS25aH2nxX5h', '2022-02-25 16:47:29');
-insert into user (mail, username, password, salt, create_time) values ('fworgena7@discovery.com', 'clittleprouda7', '4PhfJC7', 'E2P9YOsWB', '2021-12-09 09:08:22');
-insert into user (mail, username, password, salt, create_time) values ('dpurrya8@globo.com', 'hgurerya8', 'nRBeL3D3', 'SVNRs2J', '2022-07-29 01:24:29');
-insert into user (mail, username, password, salt, create_time) values ('awillmera9@wikimedia.org', 'eurrya9', 'TeEum9n', 'JeBCKhjf5qrF', '2021-11-22 21:50:51');
-insert into user (mail, username, password, salt, create_time) values ('msculpheraa@house.gov', 'gtallboyaa', 'fqurruyD', 'eUvASUDglv', '2022-03-02 12:24:25');
-insert into user (mail, username, password, salt, create_time) values ('cshurmerab@harvard.edu', 'hkneaphseyab', 'b6xaNseHMhmG', 'jya6yyCnZ5d', '2022-01-05 20:23:06');
-insert into user (mail, username, password, salt, create_time) values ('jdonoherac@omniture.com', 'ereelyac', '3PtGMU0AkZwy', 'rW4U5EdJDGx', '2022-09-26 05:51:15');
-insert into user (mail, username, password, salt, create_time) values ('sstrassead@weibo.com', 'nquartermainead', 'GoWl4rGXat', 'qgP4uyvpK', '2022-03-11 08:20:27');
-insert into user (mail, username, password, salt, create_time) values ('hsimmgenae@tripadvisor.com', 'dhowsanae', 'L6LoYsMUG', '1TQWp9Y', '2022-08-10 14:32:06');
-insert into user (mail, username, password, salt, create_time) values ('rcombenaf@reverbnation.com', 'syanovaf', '7xg01t', '6fCMPg', '2022-10-28 10:46:46');
-insert into user (mail, username, password, salt, create_time) values ('klindroag@cpanel.net', 'dscoatesag', 'Yg5BJ0xQ', 'GGXQWWTHzE', '2022-03-22 16:21:57');
-insert into user (mail, username, password, salt, create_time) values ('kgwynah@bing.com', 'bspriggah', 'R4SzOih', '3u56xgn', '2021-12-28 16:52:54');
-insert into user (mail, username, password, salt, create_time) values ('gbaumertai@desdev.cn', 'cchaffinai', '3weHhamS', '49NwkKeBpW5a', '2022-08-03 02:29:33');
-insert into user (mail, username, password, salt, create_time) values ('bkimmingsaj@cmu.edu', 'dcutfordaj', 'ahSSGNGPn', 'gZ5llLsT0', '2022-05-15 10:58:13');
-insert into user (mail, username, password, salt, create_time) values ('randererak@washington.edu', 'rlampreyak', 'vniynGPoe', 'iSp5sqgNYtIH', '2022-09-28 15:59:19');
-insert into user (mail, username, password, salt, create_time) values ('ksidwellal@nbcnews.com', 'lgilardengoal', 'blSgoC', 'vkQKmxGu', '2022-10-26 23:46:37');
-insert into user (mail, username, password, salt, create_time) values ('mflockhartam@europa.eu', 'sclowneyam', '8NcWXLcHAD', 'qgKClc87Hkl', '2022-01-27 20:42:37');
-insert into user (mail, username, password, salt, create_time) values ('mbraffertonan@aboutads.info', 'kjewessan', 'aUryJzh', 'O45M0oQ', '2022-02-13 06:38:48');
-insert into user (mail, username, password, salt, create_time) values ('hwindleyao@sina.com.cn', 'ahanbyao', 'MAWlzfO', 'aaRwkS9M53FG', '2022-06-04 15:32:45');
-insert into user (mail, username, password, salt, create_time) values ('nnieassap@tinypic.com', 'jyakobowitchap', '3Ft2USRE2B', 'QX5uW45RVgI', '2022-01-20 21:12:52');
-insert into user (mail, username, password, salt, create_time) values ('malmonaq@vimeo.com', 'ctropmanaq', 'CIC2G4', 'suTJAIQr', '2022-09-10 22:16:56');
-insert into user (mail, username, password, salt, create_time) values ('hbelvinar@typepad.com', 'kraincinar', 'yxt1M5q8Q4WP', 'c7ouH7hR', '2022-06-04 18:28:00');
-insert into user (mail, username, password, salt, create_time) values ('aqueyeiroas@gnu.org', 'cbonhomeas', '4lmT3v0x6', '4Zlwiu', '2022-03-16 17:41:20');
-insert into user (mail, username, password, salt, create_time) values ('rellitat@google.fr', 'chucksteppat', 'JgEnIl', 'TIkMcW0HUD0t', '2022-04-24 13:15:55');
-insert into user (mail, username, password, salt, create_time) values ('sgodilingtonau@chron.com', 'aantleyau', 'HEt5D778', 'wnRCbBgC', '2022-02-11 07:20:49');
-insert into user (mail, username, password, salt, create_time) values ('mmoylesav@who.int', 'ralmonav', 'aIFsfV', '1e5sOZFipEJB', '2022-02-14 16:06:27');
-insert into user (mail, username, password, salt, create_time) values ('bazemaraw@infoseek.co.jp', 'akirkbrideaw', '7bX6ZzjVM', 'CHBPSakp', '2022-08-30 17:16:07');
-insert into user (mail, username, password, salt, create_time) values ('rmeakeax@google.pl', 'mjakubowskyax', '5qkXtFI', 'hmEFhchy', '2022-10-18 21:31:42');
-insert into user (mail, username, password, salt, create_time) values ('dlorrimanay@example.com', 'cmasdonay', '5CBzHf6MMSA', 'Yiyba5ypjn', '2022-11-05 08:34:01');
-insert into user (mail, username, password, salt, create_time) values ('cnisbithaz@stanford.edu', 'wrobottomaz', 'ATJImEY5WRfF', 'IbN63xyB', '2022-09-14 19:26:19');
-insert into user (mail, username, password, salt, create_time) values ('spigramb0@latimes.com', 'cliversidgeb0', 'H5lmBj', 'u2R8PW3Et6E9', '2022-05-15 00:14:22');
-insert into user (mail, username, password, salt, create_time) values ('lwoollinb1@ocn.ne.jp', 'elittlejohnsb1', 'ixj1QhMU', 's2Bd5vpl', '2022-01-19 10:10:20');
-insert into user (mail, username, password, salt, create_time) values ('jalbisserb2@devhub.com', 'grissonb2', 'vicLGnseIu', 'tFKPbY89', '2021-11-21 20:32:05');
-insert into user (mail, username, password, salt, create_time) values ('dardlingb3@gravatar.com', 'bcreamerb3', 'px1PHbKJ3MHX', 'e8BWQTIPzLcW', '2021-11-09 20:26:53');
-insert into user (mail, username, password, salt, create_time) values ('egillicuddyb4@1688.com', 'lhousegob4', 'to8253', 'kgRoggWsQc', '2022-06-27 13:02:32');
-insert into user (mail, username, password, salt, create_time) values ('cmoralisb5@businessweek.com', 'fmurisb5', 'iAW8Na', '3WqfZVjiuA', '2022-01-13 13:33:29');
-insert into user (mail, username, password, salt, create_time) values ('amattisb6@last.fm', 'btitterellb6', 'QG4z23T5bcix', 'Ua57hlD', '2022-02-05 04:01:12');
-insert into user (mail, username, password, salt, create_time) values ('mstpierreb7@blogspot.com', 'pparkeb7', 'EnI2hrMNh2N', 'FSe9NFXaQxW', '2022-02-25 16:18:05');
-insert into user (mail, username, password, salt, create_time) values ('dpengelleyb8@ustream.tv', 'tmahargb8', 'QUdsOo9KfMKN', 'ApZuwGQ8H', '2022-06-04 10:29:52');
-insert into user (mail, username, password, salt, create_time) values ('cborkinb9@bing.com', 'cpetranb9', 'acl0WJCv76', 'sGvbtH', '2022-09-12 06:43:42');
-insert into user (mail, username, password, salt, create_time) values ('yeickhoffba@wordpress.org', 'hmosedallba', 'HB3Jek2', 'ApVNOLC', '2022-07-09 23:01:38');
-insert into user (mail, username, password, salt, create_time) values ('kstambridgebb@redcross.org', 'mpohlkebb', 'uqPKJfT', 'WTcphi4', '2022-03-27 22:24:31');
-insert into user (mail, username, password, salt, create_time) values ('hmcauleybc@answers.com', 'rwelsbybc', 'CuDb8Hd', 'e6lvJxL', '2022-05-17 12:17:15');
-insert into user (mail, username, password, salt, create_time) values ('rbartolettibd@parallels.com', 'dtonkinsonbd', 'zbvvtf', 'K8lZVIEzjPq', '2022-06-13 17:49:00');
-insert into user (mail, username, password, salt, create_time) values ('bhyslopbe@google.com.au', 'gbuerbe', 'Fa2xVNjM0', 'iJGwRxyWHEjb', '2022-01-15 23:18:31');
-insert into user (mail, username, password, salt, create_time) values ('npiscopellobf@craigslist.org', 'taustonbf', '7hRFber89Da', 'A1pLS7', '2022-07-14 20:56:53');
-insert into user (mail, username, password, salt, create_time) values ('elummusbg@tinypic.com', 'emcclenanbg', 'RTc7bjF', '3ayfm403m', '2022-03-06 19:44:38');
-insert into user (mail, username, password, salt, create_time) values ('fwalklotbh@wsj.com', 'fbraggerbh', 'rm6pCW', '9PHk3i8b', '2021-11-22 02:52:04');
-insert into user (mail, username, password, salt, create_time) values ('rspridgeonbi@icq.com', 'etzarbi', 'VepD4Lz', 'eZvSEhuYaK', '2022-06-14 03:07:40');
-insert into user (mail, username, password, salt, create_time) values ('cverrechiabj@comsenz.com', 'tbenetbj', '7xhlyrdy', 'HUyAHLqPe', '2022-09-25 10:30:04');
-insert into user (mail, username, password, salt, create_time) values ('ngummoebk@salon.com', 'cbreslauerbk', 'Ihv8PQ5LSj', 'w6W0SLq', '2022-06-16 10:27:18');
-insert into user (mail, username, password, salt, create_time) values ('madolthinebl@paypal.com', 'sdowntonbl', '9CV54ch39', '3V6MDR5', '2021-12-30 17:42:38');
-insert into user (mail, username, password, salt, create_time) values ('ajorgerbm@about.com', 'jdwyrbm', 'VN5sHXZJJn', 'TyEpLtv4gb8s', '2022-04-05 19:22:04');
-insert into user (mail, username, password, salt, create_time) values ('kboutflourbn@qq.com', 'mpowisbn', 'EUELUNECsJ', 'Rd7k87G', '2022-10-30 05:19:19');
-insert into user (mail, username, password, salt, create_time) values ('wmozzinibo@purevolume.com', 'smattionibo', 'aRN6ZfqX', 'YzniZ3ccsz', '2022-01-22 11:00:14');
-insert into user (mail, username, password, salt, create_time) values ('ctrigglebp@hhs.gov', 'tfaldobp', 'JJUVO0MVGJx', 'bLPihQi0', '2022-01-27 21:01:59');
-insert into user (mail, username, password, salt, create_time) values ('utesseymanbq@businessinsider.com', 'fwraggbq', 'okg7Hd42S', '2gCq9A', '2022-07-01 19:42:44');
-insert into user (mail, username, password, salt, create_time) values ('lhirsthousebr@imageshack.us', 'aizakoffbr', 'JGLS29u2ojVb', '3VK5DyCzs', '2022-01-28 18:33:37');
-insert into user (mail, username, password, salt, create_time) values ('pmocherbs@imgur.com', 'tstilldalebs', 'Ph0t8Q', '3MaU2qUG', '2022-07-19 17:40:10');
-insert into user (mail, username, password, salt, create_time) values ('csedmanbt@geocities.com', 'gskainsbt', 'LgWcNM4ZemU', '3A9AieE1xW2o', '2022-07-07 20:41:17');
-insert into user (mail, username, password, salt, create_time) values ('corwellbu@youtube.com', 'vlenihanbu', 'f7VEix', 'cpLMthYF4L', '2022-03-20 15:53:37');
-insert into user (mail, username, password, salt, create_time) values ('dulsterbv@chronoengine.com', 'fgonzalesbv', 'BaYQX7Nao', '29dbEVb', '2022-03-27 07:50:26');
-insert into user (mail, username, password, salt, create_time) values ('gaitonbw@netlog.com', 'rzavatterobw', 'AdLH1dX86V1S', 'doAC75AXIGz', '2022-11-02 19:32:10');
-insert into user (mail, username, password, salt, create_time) values ('afaggebx@disqus.com', 'wsarchwellbx', '3aNRZN3', 'A1aQMUWeaGl8', '2021-12-04 20:30:17');
-insert into user (mail, username, password, salt, create_time) values ('mmorpheyby@myspace.com', 'dhainningby', 'JlWmG8b', '8Wret9erkK', '2022-08-15 00:45:16');
-insert into user (mail, username, password, salt, create_time) values ('gbreetonbz@china.com.cn', 'stesterbz', '27TYPTUC', 'IU7pyM5trUu', '2022-03-16 16:50:40');
-insert into user (mail, username, password, salt, create_time) values ('atooleyc0@example.com', 'nkaresc0', 'lcHRy94', '71K5y3W', '2022-04-10 19:39:34');
-insert into user (mail, username, password, salt, create_time) values ('tmullisc1@hostgator.com', 'cdalyc1', 'lBchRWgD6N', 'Zjj4ox7D', '2021-11-17 21:18:12');
-insert into user (mail, username, password, salt, create_time) values ('fmctiernanc2@home.pl', 'nacunac2', 'L0r0CQlKX1', '1Fdfm9TA', '2022-07-21 10:10:45');
-insert into user (mail, username, password, salt, create_time) values ('bidiensc3@domainmarket.com', 'ushillabearec3', 'Uz5uZRCRIr2C', 'WnXwR16i0', '2022-03-11 23:36:55');
-insert into user (mail, username, password, salt, create_time) values ('rsudronc4@nih.gov', 'kgurnayc4', 'o8faweL', 'UtQ79l2Q', '2022-04-30 01:26:59');
-insert into user (mail, username, password, salt, create_time) values ('jmulchronec5@nydailynews.com', 'iagerc5', 'PkpLvDp', '7ZfZFoC3pofx', '2022-05-11 18:47:02');
-insert into user (mail, username, password, salt, create_time) values ('gmilleac6@blinklist.com', 'vsimakovc6', 'TVWq37', 'nOkf63lzoVhO', '2022-08-05 13:02:47');
-insert into user (mail, username, password, salt, create_time) values ('yksandrac7@arstechnica.com', 'amuttockc7', 'YkPMqZ4', 'dP3QCRw5dC', '2022-01-08 08:55:38');
-insert into user (mail, username, password, salt, create_time) values ('dridenc8@psu.edu', 'eferrarinic8', 'Gx8q10SJOqdO', 'tWstXWLrf', '2022-04-16 05:28:00');
-insert into user (mail, username, password, salt, create_time) values ('greichardtc9@canalblog.com', 'hplessingc9', 'E5fGEf175LtG', '4LRioFE3g', '2022-07-15 21:18:33');
-insert into user (mail, username, password, salt, create_time) values ('rliepinaca@cocolog-nifty.com', 'smoyceca', 'OuL2lWVNn', 'UTe7UAKh', '2022-08-12 14:38:51');
-insert into user (mail, username, password, salt, create_time) values ('gmussallicb@addthis.com', 'okennlercb', 'aCNY2b', 'hj5Kuz0rF', '2022-10-28 11:53:56');
-insert into user (mail, username, password, salt, create_time) values ('cbouldercc@bigcartel.com', 'obarlthropcc', 'CXwil3', 'TqrJ69PKcVAI', '2022-08-23 23:45:02');
-insert into user (mail, username, password, salt, create_time) values ('mbrislawncd@engadget.com', 'garnoutcd', '4RISOhj', 'T9JpEMNjtfZ', '2022-10-17 22:27:27');
-insert into user (mail, username, password, salt, create_time) values ('elameyce@nydailynews.com', 'emustince', 'nMchjQe0YN', 'fVXcPuIgWtm', '2022-07-17 06:41:27');
-insert into user (mail, username, password, salt, create_time) values ('rkeedwellcf@uiuc.edu', 'cswanwickcf', 'NpSXGPAd2', 'Xh9CA3B', '2022-10-27 11:30:39');
-insert into user (mail, username, password, salt, create_time) values ('bhellwichcg@boston.com', 'ltrimnellcg', 'SnbWGXEq', 'MDaCzXs7', '2022-05-31 16:47:33');
-insert into user (mail, username, password, salt, create_time) values ('ltreskech@homestead.com', 'nbestch', 'GJSYj19h', 'VU5Exabcmh', '2022-05-25 15:08:30');
-insert into user (mail, username, password, salt, create_time) values ('mdadswellci@prweb.com', 'fcantuaci', 'n37WMJ1VB0y', '7gUQU1iLio', '2022-01-08 08:23:50');
-insert into user (mail, username, password, salt, create_time) values ('aenburycj@biglobe.ne.jp', 'ovillaroncj', 'xSb7swNx', '6ZyY55K8Qn5', '2021-11-18 08:53:02');
-insert into user (mail, username, password, salt, create_time) values ('hdanihelkack@blogtalkradio.com', 'hauchterlonyck', '0283sW99E', 'uwDLF2', '2022-02-13 00:51:44');
-insert into user (mail, username, password, salt, create_time) values ('rsmorfitcl@prlog.org', 'bpirouetcl', 'n6BzJ7', 'sQLrenQGPJ4d', '2022-01-25 15:33:03');
-insert into user (mail, username, password, salt, create_time) values ('sslidderscm@bizjournals.com', 'lgooldingcm', 'SK2RndoV6', 'h0EPxdCmNrA', '2021-11-08 18:34:06');
-insert into user (mail, username, password, salt, create_time) values ('sbrelsfordcn@mediafire.com', 'jkrahlcn', 'fPfTgngqRfE', 'GK922Qbiz', '2022-02-04 03:24:24');
-insert into user (mail, username, password, salt, create_time) values ('csulmanco@acquirethisname.com', 'ysyceco', 'dWH82ITWp', 'NWZjBCm', '2022-06-15 15:53:53');
-insert into user (mail, username, password, salt, create_time) values ('dgehrelscp@cloudflare.com', 'bjonathoncp', '2gyTl7xCsKQR', 'vSdjEyHb92r', '2021-12-18 19:57:18');
-insert into user (mail, username, password, salt, create_time) values ('dcutmarecq@pagesperso-orange.fr', 'educarnecq', 'iJvuHlPgxC', 'SnWK5x9s', '2022-06-17 03:13:02');
-insert into user (mail, username, password, salt, create_time) values ('wsiemenscr@nydailynews.com', 'nquantickcr', '3jHVn1KMBtX', 'Kdt13Ra0R', '2022-01-24 01:52:01');
-insert into user (mail, username, password, salt, create_time) values ('dingersoncs@jimdo.com', 'lcastillacs', 'WDbhyWN', 'GipKTLcwa6', '2022-09-17 16:03:13');
-insert into user (mail, username, password, salt, create_time) values ('jpaddlect@lulu.com', 'gstariesct', '7woPeOVFSyk', 'OAKPZwI8Be', '2022-02-14 12:53:34');
-insert into user (mail, username, password, salt, create_time) values ('bcaskiecu@dot.gov', 'jargonttcu', 'WfWTW59AMpy', '0HXvKj2', '2022-09-06 05:48:39');
-insert into user (mail, username, password, salt, create_time) values ('cflippellicv@gov.uk', 'tvercruyssecv', 'evm0agrDFDb', 'cWMoXX6E3OhZ', '2022-10-17 00:58:53');
-insert into user (mail, username, password, salt, create_time) values ('rjoysoncw@issuu.com', 'mdallicottcw', 'fZXlg6IVL', 'tI8MnIvMKSe', '2022-02-07 06:10:48');
-insert into user (mail, username, password, salt, create_time) values ('gwincercx@ask.com', 'lkeemscx', 'XZ9F3xu829', 'rANx0LQEsUwf', '2022-07-16 11:18:36');
-insert into user (mail, username, password, salt, create_time) values ('rdiamondcy@miibeian.gov.cn', 'wmacenellycy', 'pbxzoVxCr', 'u7tJI4y4oxL', '2022-11-05 18:45:58');
-insert into user (mail, username, password, salt, create_time) values ('coliverascz@state.gov', 'lriggeardcz', '8bG8VAA9Setl', 'RFzueJTyEGt', '2022-01-31 07:34:13');
-insert into user (mail, username, password, salt, create_time) values ('ygrested0@ameblo.jp', 'pzellickd0', 'NF9za1e', 'hlRTb9G08', '2022-02-22 09:07:37');
-insert into user (mail, username, password, salt, create_time) values ('olippittd1@rediff.com', 'hraffind1', 'Xbwoplwmi', 'LNR5wYWcZF', '2022-08-03 20:23:45');
-insert into user (mail, username, password, salt, create_time) values ('acollingtond2@nba.com', 'cflaxmand2', 'gh8IdDVgUnW', '6qnqdpERweGT', '2022-01-17 03:11:39');
-insert into user (mail, username, password, salt, create_time) values ('bstarbeckd3@barnesandnoble.com', 'cflockhartd3', 'ldAvBv', 'ksFPZZYt9oD', '2021-11-27 18:50:49');
-insert into user (mail, username, password, salt, create_time) values ('owolfed4@goodreads.com', 'bubsdalld4', 'uR4zQQ8L6q', 'C861wJ1e9fpJ', '2022-04-03 21:32:42');
-insert into user (mail, username, password, salt, create_time) values ('apaskelld5@reference.com', 'bgajownikd5', 'NIClSIsg90u', 'T7uwu4ETbNP', '2021-12-20 11:57:25');
-insert into user (mail, username, password, salt, create_time) values ('smcgreyd6@hp.com', 'mmazonowiczd6', 'i4gsEpU', '6v4uaVHWE', '2022-10-01 17:32:21');
-insert into user (mail, username, password, salt, create_time) values ('pbeasantd7@uiuc.edu', 'jkennsleyd7', 'lyJqJtS', 'D2Eqo2', '2022-05-20 12:32:02');
-insert into user (mail, username, password, salt, create_time) values ('tstockoed8@list-manage.com', 'cdaverand8', 'EDZjMlR2nux6', '1UlX0g', '2022-06-17 04:38:57');
-insert into user (mail, username, password, salt, create_time) values ('pshermard9@mozilla.com', 'aturnockd9', 'TWo8hFx', 'UyFez7', '2022-06-13 21:41:08');
-insert into user (mail, username, password, salt, create_time) values ('nthurgoodda@1688.com', 'sfynnda', '0tODfV8t', 'Psyid8', '2022-06-26 07:39:56');
-insert into user (mail, username, password, salt, create_time) values ('dgethousedb@odnoklassniki.ru', 'ajansedb', 'a2QzI3', 'RUa4AtogW6M3', '2022-09-13 21:31:41');
-insert into user (mail, username, password, salt, create_time) values ('skulveardc@google.ru', 'vcattrolldc', 'p5LntLo', 'Xpk7uWVSzPfz', '2021-11-14 17:09:06');
-insert into user (mail, username, password, salt, create_time) values ('bschottdd@last.fm', 'aduffyndd', 'FrcZYgoH', '6LoUixU32bgc', '2022-10-23 07:21:18');
-insert into user (mail, username, password, salt, create_time) values ('qkaufmande@topsy.com', 'mobyde', 'YHIXo9EEiK4', 'dX24z2', '2022-06-28 01:48:10');
-insert into user (mail, username, password, salt, create_time) values ('fderilldf@4shared.com', 'kgavriellydf', 'BJfSzceLoW06', 'SYI1WA0Rd', '2022-05-16 03:56:57');
-insert into user (mail, username, password, salt, create_time) values ('gcorredg@un.org', 'adeversdg', 'qQ7gOJe6r', '2B0fIP', '2022-09-11 06:04:29');
-insert into user (mail, username, password, salt, create_time) values ('drapindh@noaa.gov', 'mdawedh', 'HotZAUAaA62', 'DaezamaJP', '2022-06-18 18:49:49');
-insert into user (mail, username, password, salt, create_time) values ('amaskewdi@opensource.org', 'jbahldi', 'n3KEJ8HiEIic', 'CJ6ASq', '2022-03-24 18:32:48');
-insert into user (mail, username, password, salt, create_time) values ('caudissdj@histats.com', 'mdroughtdj', 'OVBvMVI', 'ixmkAJ', '2022-09-09 03:53:31');
-insert into user (mail, username, password, salt, create_time) values ('obalcombedk@oaic.gov.au', 'dtinceydk', 'VtegdTIa3iAJ', 'EqoUAZFhLg', '2022-07-12 21:11:32');
-insert into user (mail, username, password, salt, create_time) values ('kbassilldl@imdb.com', 'jlatchforddl', 'c6xbV7g0nW', '3u10fUc', '2022-06-09 16:37:35');
-insert into user (mail, username, password, salt, create_time) values ('ddobbinsdm@plala.or.jp', 'hbausdm', 'Kw7TyGlHQbA0', 'Nmbv805', '2021-12-05 12:00:58');
-insert into user (mail, username, password, salt, create_time) values ('tfreckeltondn@alibaba.com', 'schamdn', 'sz3Kw5', '7qHHxA6', '2022-03-05 04:05:40');
-insert into user (mail, username, password, salt, create_time) values ('bhalcrodo@nyu.edu', 'olystedo', 'yAdXh8e3', 'IFNAjWO', '2022-07-22 01:03:51');
-insert into user (mail, username, password, salt, create_time) values ('cwynchdp@t.co', 'welluldp', 'q7FfyCahz1k', 'SHvUIEA', '2022-07-28 03:33:39');
-insert into user (mail, username, password, salt, create_time) values ('mtynemouthdq@fotki.com', 'kslowgravedq', 'uGr5OV', 'jTGKXO3j9', '2022-02-05 23:58:48');
-insert into user (mail, username, password, salt, create_time) values ('cfawkedr@1und1.de', 'ablackmandr', 'I7F7SkGkwwDg', 'URZ3htk8DVt1', '2022-05-13 02:30:49');
-insert into user (mail, username, password, salt, create_time) values ('fbourhillds@seattletimes.com', 'psalmonds', 'O649G07xAC', 'ZO8d35dXTev', '2022-03-31 16:27:22');
-insert into user (mail, username, password, salt, create_time) values ('jbrownsteindt@nps.gov', 'ploadesdt', 'jvHnqjYqaEx', 'WRQpOPHX', '2022-03-09 04:33:27');
-insert into user (mail, username, password, salt, create_time) values ('haubrydu@cam.ac.uk', 'nsowtendu', 'qK2rs2DwlbWu', 'yOEbVE9x2D', '2022-10-19 04:42:32');
-insert into user (mail, username, password, salt, create_time) values ('bbrewettdv@google.cn', 'cpaolazzidv', 'Jgt3ceOnv', 'wIAl9AF7FA', '2022-07-26 07:23:45');
-insert into user (mail, username, password, salt, create_time) values ('tdarydw@aol.com', 'mkirdschdw', 'zbPbnWXJ', 'HHUAuJj', '2022-07-15 01:06:32');
-insert into user (mail, username, password, salt, create_time) values ('cbonnesendx@hatena.ne.jp', 'fmicallefdx', 'flvqPsryt', 'KKe61j7zhd7', '2022-06-06 14:58:37');
-insert into user (mail, username, password, salt, create_time) values ('lhatchettedy@myspace.com', 'gtanslydy', 'Ejibj6q', 'vBV8ZeFK', '2022-05-17 06:12:31');
-insert into user (mail, username, password, salt, create_time) values ('dcollisdz@nydailynews.com', 'dtrusteydz', '9najB2njd2bA', 'bpsijxAX7R', '2022-04-18 18:08:56');
-insert into user (mail, username, password, salt, create_time) values ('jnailee0@howstuffworks.com', 'bsaffelle0', 'iISueYoRMQ', 'c6QprkFr7Evx', '2022-07-18 07:47:51');
-insert into user (mail, username, password, salt, create_time) values ('tdibsdalee1@earthlink.net', 'fcarlislee1', 'Ruo1mhbyjM', 'lsQQbi', '2022-06-28 12:24:56');
-insert into user (mail, username, password, salt, create_time) values ('jgrimleye2@guardian.co.uk', 'ureedicke2', 'csZBbCDf', 'ivmdM4s', '2022-10-21 05:07:02');
-insert into user (mail, username, password, salt, create_time) values ('lmepstede3@yellowpages.com', 'jrosene3', 'vmW50inSOV72', 'OCaTMG', '2022-03-07 17:23:45');
-insert into user (mail, username, password, salt, create_time) values ('ekelinge4@google.com.au', 'ayitshake4', '4pYoOi', 'IybPSUY', '2021-12-31 01:30:55');
-insert into user (mail, username, password, salt, create_time) values ('rcawsbye5@bbb.org', 'mschwandermanne5', 'dypcd9a', '2XESdv', '2022-08-04 01:26:34');
-insert into user (mail, username, password, salt, create_time) values ('dholseye6@nasa.gov', 'jtolemane6', 'viVqtEKNvxtD', 'y3typnXp', '2022-10-23 10:31:51');
-insert into user (mail, username, password, salt, create_time) values ('mdochone7@twitpic.com', 'rbensone7', 'eNU4VoJF', 'hCIXbptvkS', '2022-06-25 15:42:00');
-insert into user (mail, username, password, salt, create_time) values ('mbemande8@chicagotribune.com', 'ngimblete8', 'CJcDBchrVy', '4QWWmgJxNQ', '2022-01-20 20:07:02');
-insert into user (mail, username, password, salt, create_time) values ('wyeae9@google.com.hk', 'vhalifaxe9', 'gDM0N5BCtlZ', 'QmVapNDif2GU', '2022-01-18 12:30:17');
-insert into user (mail, username, password, salt, create_time) values ('sbinionea@dropbox.com', 'sarisea', 'j8PmAKH0', 'P2nstounXUZ', '2022-06-08 03:52:20');
-insert into user (mail, username, password, salt, create_time) values ('ifalooneb@wisc.edu', 'acowburneb', 'wrF865NT', 'EcDBdod', '2022-09-07 06:22:57');
-insert into user (mail, username, password, salt, create_time) values ('gtillec@deviantart.com', 'lvasiltsovec', 'ulXNJZR8qa', '1qIKle0fl', '2022-10-04 14:36:15');
-insert into user (mail, username, password, salt, create_time) values ('twaslined@about.com', 'ksedgered', 't6qeKaJy', 'Sfxo7bX6EMUe', '2022-10-23 11:57:00');
-insert into user (mail, username, password, salt, create_time) values ('rmckillopee@weather.com', 'emckiddinee', 'w8dVEx1uB', 'n1268QN7CRAD', '2022-08-19 01:23:10');
-insert into user (mail, username, password, salt, create_time) values ('rheggesef@desdev.cn', 'mherrerosef', 'yLxdq6gMGChe', 'MQjMl84TTMUu', '2022-06-07 19:44:04');
-insert into user (mail, username, password, salt, create_time) values ('vthornborroweg@purevolume.com', 'jchastoneg', '1j6bE25wZ', 'bAUHBu', '2022-09-22 16:41:26');
-insert into user (mail, username, password, salt, create_time) values ('phrinchenkoeh@usatoday.com', 'shollymaneh', 'egkHTA', 'L9DC8SETsCz', '2022-04-13 15:38:48');
-insert into user (mail, username, password, salt, create_time) values ('gsawlei@ox.ac.uk', 'dcalytonei', 'f6WsaqMn', 'DRNApf', '2022-07-08 18:46:05');
-insert into user (mail, username, password, salt, create_time) values ('skindleysidesej@webeden.co.uk', 'sstrelitzerej', 'FL3P5m', 'R5clLlSdWTP', '2021-12-24 21:30:59');
-insert into user (mail, username, password, salt, create_time) values ('pbrunottiek@creativecommons.org', 'mlapennaek', 'mxwuOD9SLnP', 'FFuSlgSb', '2022-05-14 16:19:26');
-insert into user (mail, username, password, salt, create_time) values ('kshackleel@toplist.cz', 'hgladdorel', 'JgI8iO', 'JGfLXAsRBlb', '2022-08-15 12:22:03');
-insert into user (mail, username, password, salt, create_time) values ('mhowlettem@flavors.me', 'kmacbeyem', 'zxHkIjuA93', 'FICmkEPI9', '2022-03-26 07:36:27');
-insert into user (mail, username, password, salt, create_time) values ('apovallen@studiopress.com', 'eharkeren', 'kPiaFoVy0', 'SGmE3J1Bbth', '2022-08-12 11:00:28');
-insert into user (mail, username, password, salt, create_time) values ('kdiffleyeo@w3.org', 'rbreslauereo', 'pSFGlHZKSNN8', 'Nvvc6c7bglz', '2022-03-23 01:02:46');
-insert into user (mail, username, password, salt, create_time) values ('ckeightleyep@upenn.edu', 'balcoranep', 'zIGszmbXs', 'T5bYR4', '2022-03-18 01:07:59');
-insert into user (mail, username, password, salt, create_time) values ('tmcsporoneq@whitehouse.gov', 'churleeq', 'U9HW21Hb', 'w0dSaj', '2022-03-02 19:35:48');
-insert into user (mail, username, password, salt, create_time) values ('shalwooder@ibm.com', 'ahamnerer', 'LkEfX7SOA', 'Mtqwgz', '2022-08-29 15:22:37');
-insert into user (mail, username, password, salt, create_time) values ('gtiltmanes@storify.com', 'fhamshawes', 'GoF52VfPkM', 'K8TBH06JVsC', '2022-05-25 06:34:50');
-insert into user (mail, username, password, salt, create_time) values ('bduinbletonet@fema.gov', 'lhouseagoet', 'rJ6k3EFRMAs9', '2meHYD3DU8x', '2022-10-07 07:32:17');
-insert into user (mail, username, password, salt, create_time) values ('nberzoneu@seesaa.net', 'hhellmoreeu', 'ZITt4rxwL', 'P44EIbM', '2022-02-14 18:13:42');
-insert into user (mail, username, password, salt, create_time) values ('cwayonev@linkedin.com', 'bcobainev', 'QrNtQZm', 'ojhwx1j7', '2022-01-29 14:18:03');
-insert into user (mail, username, password, salt, create_time) values ('istegelew@dailymail.co.uk', 'tfruchonew', 'jAAIeQa', 'PkwsUGEP', '2022-03-24 01:04:42');
-insert into user (mail, username, password, salt, create_time) values ('pmorterex@google.fr', 'dcockshttex', 'aNIWLrWsVmV', '5jJDkD4U9', '2021-12-26 00:33:33');
-insert into user (mail, username, password, salt, create_time) values ('ccolerickey@usda.gov', 'cgovaney', 'oTGjX2', 'clfmNScaEYAr', '2022-03-10 17:49:41');
-insert into user (mail, username, password, salt, create_time) values ('cspightez@elegantthemes.com', 'wdonneez', 'GVKz06Sq', 'Rs6eLvWAyKqS', '2022-04-15 08:39:24');
-insert into user (mail, username, password, salt, create_time) values ('fhigbinf0@wsj.com', 'csheersf0', 'CpDQGP1', 'tGt95IV2', '2022-07-16 08:21:02');
-insert into user (mail, username, password, salt, create_time) values ('jmaskreyf1@apache.org', 'aprichetf1', 'MtVVn2x', 'XqS86ZPE4zzn', '2022-07-11 10:14:51');
-insert into user (mail, username, password, salt, create_time) values ('akingslandf2@loc.gov', 'mteazf2', 'abX2eiUkiWqZ', '52A0vcH', '2022-09-21 23:13:34');
-insert into user (mail, username, password, salt, create_time) values ('ttreherf3@dell.com', 'rgoddingf3', 'TvhnhMWKL', 'myxfdQxU2A3', '2022-09-12 19:49:20');
-insert into user (mail, username, password, salt, create_time) values ('lgilfoyf4@census.gov', 'phoulisonf4', '5riIXVON0', 'zxIvhL9Ny', '2022-01-28 01:37:14');
-insert into user (mail, username, password, salt, create_time) values ('cclarkinf5@ning.com', 'pcornfordf5', '7Ig6KPTqKT', 'qKeKXSV', '2022-02-04 09:49:56');
-insert into user (mail, username, password, salt, create_time) values ('lprendevillef6@hatena.ne.jp', 'mstockbridgef6', 'N7lsSjUwaBG', 'g0ZFQF', '2022-05-22 06:47:21');
-insert into user (mail, username, password, salt, create_time) values ('gdronsfieldf7@dedecms.com', 'lmcguffief7', 'iqRHGrkd', '8qvT74Gat2v', '2022-04-22 09:10:04');
-insert into user (mail, username, password, salt, create_time) values ('dkuhnelf8@mashable.com', 'lmclarnonf8', 'Cp2l2rtsv9M', 'UgobRNAwRtn', '2022-05-18 03:39:30');
-insert into user (mail, username, password, salt, create_time) values ('esmowtonf9@disqus.com', 'eknollerf9', 'hnAXdwbY', 'aEbAinklBrbD', '2022-09-28 16:48:14');
-insert into user (mail, username, password, salt, create_time) values ('ecullrfordfa@illinois.edu', 'gjerroltfa', 'TMsByDCge5J', 'PuIV9cTGa9', '2022-02-19 16:33:24');
-insert into user (mail, username, password, salt, create_time) values ('hfriattfb@japanpost.jp', 'ipiddickfb', 'ngIKQhJ', 'pxQjNEo', '2022-10-19 22:45:00');
-insert into user (mail, username, password, salt, create_time) values ('lcarwithenfc@businessinsider.com', 'edillewayfc', 'MacDkSt', 'KBrVgVxZr', '2022-06-06 12:09:40');
-insert into user (mail, username, password, salt, create_time) values ('pdomanfd@nytimes.com', 'cdoftyfd', 'YRTWLYSk6', '5PANUeE', '2021-12-18 21:06:28');
-insert into user (mail, username, password, salt, create_time) values ('sverrillofe@odnoklassniki.ru', 'dgranhamfe', 'TKRSIP34Pqd', '3fEDZcPW2', '2022-01-24 01:08:30');
-insert into user (mail, username, password, salt, create_time) values ('bberthomieuff@clickbank.net', 'wbabinff', 'AIcEvUdZuTs', 'kxZfkF', '2022-02-19 05:18:42');
-insert into user (mail, username, password, salt, create_time) values ('vhallowellfg@flavors.me', 'wbougenfg', '3chtg4ghVHR', 'O4wNaLxbI1Z', '2021-11-26 04:21:29');
-insert into user (mail, username, password, salt, create_time) values ('bbaldinottifh@dailymotion.com', 'afountainefh', 'UwNSBNB', 'F4jRacSmCRn8', '2021-12-16 09:02:39');
-insert into user (mail, username, password, salt, create_time) values ('wdriscollfi@oracle.com', 'ecorainfi', 'iGndPZxBRO', '0pa4gORWNG', '2022-07-23 03:27:33');
-insert into user (mail, username, password, salt, create_time) values ('zholbornfj@nytimes.com', 'kcratfj', 'YtG3ueZlV', 'a7JYzTJ', '2022-06-26 19:46:05');
-insert into user (mail, username, password, salt, create_time) values ('mhalworthfk@usa.gov', 'ighionifk', 'TR4r1vIW3Mc', 'CfJ4e18W', '2022-08-31 01:43:44');
-insert into user (mail, username, password, salt, create_time) values ('jcairnsfl@nyu.edu', 'ctommeifl', 'UUeZMuGvuPSH', 'fVz3vo1', '2022-08-08 04:10:53');
-insert into user (mail, username, password, salt, create_time) values ('ybrimilcombefm@yahoo.co.jp', 'msammonsfm', 'WVDiN31Lelc', 'uhdB05', '2022-03-25 13:53:18');
-insert into user (mail, username, password, salt, create_time) values ('tfootefn@ed.gov', 'jbatcockfn', 'qypJGcDazo', '9s2gTfb', '2022-04-18 09:55:49');
-insert into user (mail, username, password, salt, create_time) values ('glacottefo@newyorker.com', 'dscortonfo', 'PnpUHLG', 'x1CqKyy', '2021-11-25 02:19:57');
-insert into user (mail, username, password, salt, create_time) values ('etrulockefp@wikipedia.org', 'cheinricifp', 'lwqGc8hI', 'dS1BGt5gLlW', '2022-04-27 05:11:06');
-insert into user (mail, username, password, salt, create_time) values ('bslackfq@hexun.com', 'gellacombefq', '0WFohK', 'UyVaGTiaGa', '2022-09-03 14:24:58');
-insert into user (mail, username, password, salt, create_time) values ('odoylandfr@samsung.com', 'ttrasslerfr', 'KVdr7EsFtPc', 'm1Nr15Srej', '2022-10-09 00:14:36');
-insert into user (mail, username, password, salt, create_time) values ('jfryettfs@lulu.com', 'mcastaignetfs', 'tok33RO2', 'SJ5YReFAK', '2021-11-17 12:01:03');
-insert into user (mail, username, password, salt, create_time) values ('beclesft@miibeian.gov.cn', 'mbartlomieczakft', '4DbkPTeY', 'Ia3ngIC5X', '2022-01-06 18:36:47');
-insert into user (mail, username, password, salt, create_time) values ('atelfordfu@infoseek.co.jp', 'cmappfu', 'Qrsfu3HQjs', 'aL8lu8VJ', '2022-03-18 14:23:59');
-insert into user (mail, username, password, salt, create_time) values ('kormesherfv@wisc.edu', 'vreeveleyfv', 'lgKU14v', 'V8UkWn', '2022-02-06 00:23:17');
-insert into user (mail, username, password, salt, create_time) values ('jthomafw@tumblr.com', 'mburnellfw', 'vd6I0sROn', 'fOERJ9o4', '2022-07-29 15:51:28');
-insert into user (mail, username, password, salt, create_time) values ('mmckellenfx@xing.com', 'khudspithfx', 'yKqzbBkSk', 'NrIXcq', '2022-05-09 21:42:31');
-insert into user (mail, username, password, salt, create_time) values ('jyoudellfy@redcross.org', 'apymfy', 'lxwJjY3FtRo', 'OXASL5', '2022-10-15 12:34:39');
-insert into user (mail, username, password, salt, create_time) values ('mlaycockfz@pcworld.com', 'kspiersfz', '5IkYKHDxZs', 'WQAfGtXNJkVe', '2022-02-07 13:43:58');
-insert into user (mail, username, password, salt, create_time) values ('hormistong0@columbia.edu', 'istoadeg0', 'szvsSES8AMw', 'O4V7Dot0BW', '2021-11-08 06:43:25');
-insert into user (mail, username, password, salt, create_time) values ('ntreadwayg1@businesswire.com', 'kiddong1', 'lfaUIJJ', 'jjoDUr1ZIFh3', '2022-06-13 18:12:04');
-insert into user (mail, username, password, salt, create_time) values ('nhalshawg2@biblegateway.com', 'pconaghang2', 'qXduowZFJ5', '2EvtuW5BecWV', '2022-06-11 08:52:37');
-insert into user (mail, username, password, salt, create_time) values ('bantliffg3@ehow.com', 'kmacaneg3', 'qeVY2sM2r', 'OBVQYAs', '2022-03-12 17:53:14');
-insert into user (mail, username, password, salt, create_time) values ('ktidboldg4@mysql.com', 'kbuckthorpg4', 'oCGE5BDSF9e', 'IoNZ6S', '2022-06-08 11:59:30');
-insert into user (mail, username, password, salt, create_time) values ('mrountreeg5@ebay.co.uk', 'etruderg5', 'pdPOuQp', 'RHF7KEIjuLw', '2021-11-23 22:16:46');
-insert into user (mail, username, password, salt, create_time) values ('bfitzharrisg6@google.cn', 'igeorgiusg6', 'Kw6X94c', 'bLavCkF5mlzp', '2022-09-21 10:10:27');
-insert into user (mail, username, password, salt, create_time) values ('mgosnellg7@upenn.edu', 'bskynerg7', 'eDmdRxAj', 'YQlUYqLc', '2021-12-07 03:00:43');
-insert into user (mail, username, password, salt, create_time) values ('setong8@t-online.de', 'laverillg8', '9mBDIl', 'MbCeGFR7Z', '2022-01-28 02:32:43');
-insert into user (mail, username, password, salt, create_time) values ('afeldsteing9@prweb.com', 'guccellig9', 'f6oFF8Xtsq8', 'H9OjtvQeW', '2022-08-13 06:41:31');
-insert into user (mail, username, password, salt, create_time) values ('hhumphersonga@smugmug.com', 'mnordassga', 'xKf9wJtl', '1ks9C3c1PU5F', '2021-11-23 07:31:00');
-insert into user (mail, username, password, salt, create_time) values ('aberryclothgb@woothemes.com', 'smccandiegb', 'ZwWIc2F1aIL', 'xqLgxSpCW', '2022-07-06 07:27:09');
-insert into user (mail, username, password, salt, create_time) values ('mbrickgc@imageshack.us', 'ebailessgc', 'oX3VpylZyuh', 'EkNf17g', '2022-02-17 10:40:02');
-insert into user (mail, username, password, salt, create_time) values ('jcoastergd@senate.gov', 'tpaternogd', '4xxPvQ', 'xvmHYNqT0m', '2022-01-08 04:52:31');
-insert into user (mail, username, password, salt, create_time) values ('ajarrattge@bloglovin.com', 'tspittlege', 'Ygr773ClTJdw', 'UChyuV17N', '2022-01-31 09:41:35');
-insert into user (mail, username, password, salt, create_time) values ('ylorentzengf@mit.edu', 'rtothacotgf', 'vIjqgdsHWSC', 'JqkvOw8', '2022-01-16 15:26:13');
-insert into user (mail, username, password, salt, create_time) values ('gwatkissgg@auda.org.au', 'mbromidgegg', 'U9aI3D9TU', 'jvsfY0hF', '2022-03-27 05:22:00');
-insert into user (mail, username, password, salt, create_time) values ('spybworthgh@dot.gov', 'rconnuegh', '6g7Z4pmWAQ3G', 'PGEPpwh', '2022-08-02 15:35:26');
-insert into user (mail, username, password, salt, create_time) values ('erodingergi@tripadvisor.com', 'ccarsegi', 'v3XVcDSv', 'dTO6SeMTib', '2022-03-26 12:04:16');
-insert into user (mail, username, password, salt, create_time) values ('bardleygj@squarespace.com', 'otrevettgj', 'qWdtSs7', '0h3YnT', '2021-12-17 16:12:45');
-insert into user (mail, username, password, salt, create_time) values ('ideeringgk@loc.gov', 'lwhartongk', 'ctVuZeJ', 'w0AJy7E', '2022-06-13 11:38:37');
-insert into user (mail, username, password, salt, create_time) values ('againsburygl@pen.io', 'cdebruingl', 'CaUsyn7', 'dYIOVpz', '2022-07-09 11:47:32');
-insert into user (mail, username, password, salt, create_time) values ('alarrattgm@amazonaws.com', 'pmoresidegm', 'h9PUYHX1J', 'YoSJE4Wj36E2', '2022-01-28 10:14:09');
-insert into user (mail, username, password, salt, create_time) values ('gbritchergn@is.gd', 'gkwietakgn', 'ifVpoz', 'kvVQzc0ZU', '2022-10-15 04:18:07');
-insert into user (mail, username, password, salt, create_time) values ('vjaraygo@walmart.com', 'clethieuliergo', 'EvDbsOm6YJz8', 'gpiaCu0lK3', '2022-03-10 11:26:53');
-insert into user (mail, username, password, salt, create_time) values ('scoatsworthgp@ibm.com', 'hgilbeartgp', 'o4zG7Qw8noNM', '0bRxn0cMY', '2022-05-13 15:06:12');
-insert into user (mail, username, password, salt, create_time) values ('mmoraledagq@accuweather.com', 'etrousdellgq', '41o6GJSr', 'rAHxEljFgg', '2022-02-25 09:34:31');
-insert into user (mail, username, password, salt, create_time) values ('gdigglesgr@alexa.com', 'gbooijgr', '6kfsqKRVv', 'S5Ukh2fAfe', '2022-09-28 22:43:34');
-insert into user (mail, username, password, salt, create_time) values ('wfilyukovgs@woothemes.com', 'elefrancgs', 'h1i6y5G9W', 'nQQt21nKAqPf', '2022-02-21 09:23:32');
-insert into user (mail, username, password, salt, create_time) values ('kfairfoulgt@digg.com', 'akovelmanngt', '94aZjm', 'WVy6THS', '2022-01-18 06:59:48');
-insert into user (mail, username, password, salt, create_time) values ('akerslakegu@last.fm', 'jshoardgu', 'XSR22Je2xs', 'LBmjFmQD', '2022-10-29 18:15:49');
-insert into user (mail, username, password, salt, create_time) values ('rdenisongv@tripadvisor.com', 'kpooleygv', 'KDNpTJbz5T', 'scUFqfQ', '2022-01-30 22:40:59');
-insert into user (mail, username, password, salt, create_time) values ('tskipseagw@ucoz.ru', 'acogergw', '6GIKTzDyfYd', 'DElM1T5fkc', '2022-08-28 23:30:11');
-insert into user (mail, username, password, salt, create_time) values ('zsimounetgx@meetup.com', 'jfencottgx', '5Z0Q7g', '66ymRV', '2022-06-25 11:51:07');
-insert into user (mail, username, password, salt, create_time) values ('dduchangy@senate.gov', 'bmichiegy', 'KGTnrnUmIzp', 'Stj5ohXz6Im', '2022-11-02 23:59:06');
-insert into user (mail, username, password, salt, create_time) values ('bbetoniagz@usnews.com', 'mbecerilgz', 'jCMjqxflpe', 'dXBEI3DF', '2022-02-21 02:08:32');
-insert into user (mail, username, password, salt, create_time) values ('gpotierh0@virginia.edu', 'kocarrollh0', 'vbdrVNCh6ToI', 'EoFFmWH6BVW', '2022-10-20 10:35:14');
-insert into user (mail, username, password, salt, create_time) values ('plennardeh1@cdbaby.com', 'ibraidwoodh1', 'gUjO2x4W', 'BZaqKvchjy', '2022-05-19 06:43:03');
-insert into user (mail, username, password, salt, create_time) values ('gboulgerh2@simplemachines.org', 'btindallh2', 'XdBjm7sD02ne', 'wKDyaD6w6pAA', '2022-10-28 14:12:16');
-insert into user (mail, username, password, salt, create_time) values ('scorretth3@prweb.com', 'hchritchlowh3', 'm305t8T', 'Mq1kywrg', '2022-07-17 12:26:53');
-insert into user (mail, username, password, salt, create_time) values ('lwannh4@irs.gov', 'cdrinanh4', 'Q1kxnL8l', '592mVBH5Sca', '2022-02-08 06:00:53');
-insert into user (mail, username, password, salt, create_time) values ('dweddeburnh5@harvard.edu', 'browesbyh5', 'nLjjO7ifMi', 'tWjLLu', '2022-09-11 15:09:30');
-insert into user (mail, username, password, salt, create_time) values ('mtizardh6@time.com', 'szanellih6', 'SS9pdE0Og', 'ltwwk6o8aEa', '2022-10-14 05:19:16');
-insert into user (mail, username, password, salt, create_time) values ('cgradonh7@imdb.com', 'bbrysonh7', 'HWzSYixSIqDZ', 'ww4A3A5b29aG', '2022-03-11 00:37:10');
-insert into user (mail, username, password, salt, create_time) values ('sklosh8@chron.com', 'cwigfallh8', 'BADFoNwV', 'W1YZXmw', '2022-02-11 19:58:16');
-insert into user (mail, username, password, salt, create_time) values ('pchampkinh9@cdbaby.com', 'doliveh9', 'st0hNjsxYTDs', 'iFkoEj', '2022-08-27 00:45:05');
-insert into user (mail, username, password, salt, create_time) values ('mpratleyha@squidoo.com', 'jcamolettoha', 'BZK9GJ', 'RA01IWnevL', '2022-10-04 05:12:50');
-insert into user (mail, username, password, salt, create_time) values ('ccantihb@typepad.com', 'vambrosinihb', 'UbAYn0JvCMK', 'FjbS37Op3', '2022-03-14 05:11:37');
-insert into user (mail, username, password, salt, create_time) values ('gblinckohc@rediff.com', 'tsalmondhc', 'tVwChU2P', 'Yyx9TZ', '2022-03-11 03:24:40');
-insert into user (mail, username, password, salt, create_time) values ('sconkeyhd@ovh.net', 'msweetinghd', 'XkEcZk', 'znjAmFaq', '2021-11-12 12:43:55');
-insert into user (mail, username, password, salt, create_time) values ('smccaskillhe@1688.com', 'rdemarehe', 'GHwL7SKGQ', '65fOZu', '2022-07-01 17:21:25');
-insert into user (mail, username, password, salt, create_time) values ('tarundellhf@usnews.com', 'hhouldenhf', 'i55TXFWQYre', 'elG8VRomv', '2022-04-12 16:46:26');
-insert into user (mail, username, password, salt, create_time) values ('tjohananovhg@indiatimes.com', 'abayntonhg', 'Gzu8A4iWkog', 'SiJZTzwXk3hJ', '2022-11-05 16:06:31');
-insert into user (mail, username, password, salt, create_time) values ('jpotteridgehh@opera.com', 'spestellhh', '0H8SiiGL9n', 'IifmS38jW2', '2022-07-09 07:08:52');
-insert into user (mail, username, password, salt, create_time) values ('rgaitleyhi@ox.ac.uk', 'lmaileyhi', 'nLzqAP3', 'jZI3HQRgd', '2022-01-26 18:36:39');
-insert into user (mail, username, password, salt, create_time) values ('cbelitzhj@is.gd', 'edominiakhj', 'sUzUtsesX', 'yxcg86', '2021-11-06 16:02:24');
-insert into user (mail, username, password, salt, create_time) values ('jescalahk@devhub.com', 'adarkohk', 'qsV0PF', 'xiajFj9UXP', '2022-02-21 06:59:16');
-insert into user (mail, username, password, salt, create_time) values ('cgaywoodhl@google.co.jp', 'dbearwardhl', 'S3ATOKDSS', 'BIPq4FKN', '2022-04-14 04:21:22');
-insert into user (mail, username, password, salt, create_time) values ('shardwellhm@scribd.com', 'hpeckhamhm', 'hAjLxOW', 'e2gh7w7', '2022-03-07 18:06:00');
-insert into user (mail, username, password, salt, create_time) values ('ksitwellhn@theglobeandmail.com', 'pivshinhn', 'XnSgSdOp2Bw0', 'mhfNr34bV', '2021-12-09 01:09:06');
-insert into user (mail, username, password, salt, create_time) values ('bsandercockho@unc.edu', 'jpearceyho', 'F401nTes', 'B2tEdHGO', '2022-01-25 11:22:29');
-insert into user (mail, username, password, salt, create_time) values ('epittwoodhp@cloudflare.com', 'rroehp', 'LGDfAHQf', 'ILu8rPC', '2022-05-23 07:18:10');
-insert into user (mail, username, password, salt, create_time) values ('dvilehq@cam.ac.uk', 'qbarneshq', 'e77GFegt', 'WQTgXyhoeXg', '2021-12-29 09:55:15');
-insert into user (mail, username, password, salt, create_time) values ('dchandlarhr@over-blog.com', 'bdavidshr', 'N89xg6', 'mpZiD7', '2022-02-21 03:13:26');
-insert into user (mail, username, password, salt, create_time) values ('aweekshs@discuz.net', 'tclegghs', 'WyGQryYXeA6A', '4i4fn4OdCvs8', '2022-09-02 20:58:09');
-insert into user (mail, username, password, salt, create_time) values ('vriseamht@geocities.com', 'gbudnikht', 'BeecrnIwESf', 'oFRq4Zvt', '2022-09-05 02:38:25');
-insert into user (mail, username, password, salt, create_time) values ('dantalhu@go.com', 'mfranklinghu', '0aIXV2X0Y', 'JNNcKSrSqM', '2022-07-20 11:26:23');
-insert into user (mail, username, password, salt, create_time) values ('bjahnerhv@foxnews.com', 'mmcgeachyhv', 'x2fBES', 'MLsV7R', '2022-07-16 04:43:14');
-insert into user (mail, username, password, salt, create_time) values ('uskainhw@apple.com', 'vkenwortheyhw', 'G6hVaE6V', 'alwKMQYavh5', '2022-03-18 22:27:31');
-insert into user (mail, username, password, salt, create_time) values ('bpawlynhx@google.co.jp', 'jangelihx', 'xiKcv1', 'aZI0tL', '2022-08-14 05:21:30');
-insert into user (mail, username, password, salt, create_time) values ('dstinsonhy@feedburner.com', 'gackermannhy', 'G7r38Zvx', 'kbknsTlM', '2021-12-31 13:41:26');
-insert into user (mail, username, password, salt, create_time) values ('aoverpoolhz@com.com', 'lpurlehz', 'Likbb8GmPMH', 'PuXC8mpdPxc4', '2022-05-08 20:02:55');
-insert into user (mail, username, password, salt, create_time) values ('szutei0@earthlink.net', 'fsolowayi0', 'jCwzR3Kr', 'fULtX9H7oMW', '2022-06-22 13:41:00');
-insert into user (mail, username, password, salt, create_time) values ('kfenechi1@auda.org.au', 'rdalwisi1', 'Gtlf5v9W', '6h39eJVx1qM', '2022-04-24 05:19:15');
-insert into user (mail, username, password, salt, create_time) values ('kmigueli2@mediafire.com', 'celandi2', '7NWjz5Tk', 'FQIjq6ZP5', '2022-08-01 11:55:01');
-insert into user (mail, username, password, salt, create_time) values ('pwhitewoodi3@chicagotribune.com', 'ctwinbrowi3', '4O0IKMivZjN', 'oYnoUFkfY15', '2022-06-30 02:28:56');
-insert into user (mail, username, password, salt, create_time) values ('kmostyni4@buzzfeed.com', 'odumbletoni4', 'l2kZ5RtmvJE', 'YNvioyDU', '2022-03-29 05:26:27');
-insert into user (mail, username, password, salt, create_time) values ('uortigai5@elpais.com', 'hbaythorpi5', '105xl8Y8SrS', 'o2BgU8CLXB3N', '2022-06-26 22:35:00');
-insert into user (mail, username, password, salt, create_time) values ('jebbrelli6@hexun.com', 'nkieldi6', '7CZi4ZnEvao', 'zbeWbi', '2022-05-20 20:49:43');
-insert into user (mail, username, password, salt, create_time) values ('rthomanni7@auda.org.au', 'bworlandi7', 'Ztncpz', 'lhFAqeh4xR', '2022-08-23 15:25:19');
-insert into user (mail, username, password, salt, create_time) values ('dboulesi8@seesaa.net', 'bbendixi8', 'MSB1ZxwBEMU4', 'rwfrTRhhMLkB', '2022-07-28 11:50:45');
-insert into user (mail, username, password, salt, create_time) values ('dcupperi9@timesonline.co.uk', 'schrispini9', 'ouGVJHHTfdb', 'WkdvaYF4zxyM', '2022-06-10 03:39:43');
-insert into user (mail, username, password, salt, create_time) values ('ychildsia@infoseek.co.jp', 'ilinnia', 'x15HU8', '5jR5NRG', '2022-09-24 08:28:42');
-insert into user (mail, username, password, salt, create_time) values ('gpetrassoib@angelfire.com', 'abutterfillib', 'u6tDQ2eIii', '7hH6mX9ApA6', '2022-05-18 05:33:09');
-insert into user (mail, username, password, salt, create_time) values ('ddewickeic@amazon.com', 'ocadwalladeric', 'uIc4Fuf', 'tSm7wZ560T', '2022-07-18 04:26:58');
-insert into user (mail, username, password, salt, create_time) values ('ekhidrid@mozilla.org', 'hbedinnid', 'AooCM1o6', 'oC6nuyj3z', '2022-10-03 00:28:45');
-insert into user (mail, username, password, salt, create_time) values ('sgenneyie@exblog.jp', 'tdownieie', '7wyvcCbqcI', 'HBA8L0fkNY', '2022-08-01 07:19:46');
-insert into user (mail, username, password, salt, create_time) values ('gsaltmarsheif@nifty.com', 'ktolfreyif', 'TRb6BMEXt', 'c1qAiJ62zeF', '2022-09-17 05:04:50');
-insert into user (mail, username, password, salt, create_time) values ('nmaclucaisig@google.com.au', 'ckiteig', 'KpzO42YUvDU', 'GejTf0g052W', '2022-04-11 08:01:09');
-insert into user (mail, username, password, salt, create_time) values ('llitelih@alibaba.com', 'ctolcherih', '6lcVas', 'QcLsXlcO', '2022-02-25 10:04:24');
-insert into user (mail, username, password, salt, create_time) values ('cbingallii@mediafire.com', 'pwoodfineii', 'vQxhoO45Eq', 'mZvVaPR', '2022-04-27 20:25:37');
-insert into user (mail, username, password, salt, create_time) values ('slanceleyij@1und1.de', 'acrollmanij', 'jGcPYSqi', '9efX0VTlzl2', '2022-01-16 10:45:18');
-insert into user (mail, username, password, salt, create_time) values ('nshrimplinik@is.gd', 'hwiddowsonik', 'OMH59BHoRw', '9nmQmtF', '2022-05-13 11:34:26');
-insert into user (mail, username, password, salt, create_time) values ('eeidelmanil@amazon.de', 'tdurnellil', 'XDUF1GcAE', 'erQDEbF3O', '2021-12-12 21:45:43');
-insert into user (mail, username, password, salt, create_time) values ('btitmussim@netvibes.com', 'zmclurgim', 'wLMwP1U5J', 'Vvjo9jo58t', '2022-08-29 20:57:00');
-insert into user (mail, username, password, salt, create_time) values ('lbaldocciin@wp.com', 'pduchartin', '8WbhvQix', '5wuuFn', '2022-07-04 19:17:06');
-insert into user (mail, username, password, salt, create_time) values ('ocounihanio@oakley.com', 'kgermainio', '3MXNbL', 'y3r8irBplBjR', '2022-09-30 18:51:22');
-insert into user (mail, username, password, salt, create_time) values ('mducketip@umn.edu', 'wmulliganip', '0LCerK6tOz', 'jk6Pxf', '2022-03-20 15:08:46');
-insert into user (mail, username, password, salt, create_time) values ('shitteriq@economist.com', 'vlocockiq', 'dqpleXjGC', 'JbtKHuDrZ', '2022-07-18 14:41:08');
-insert into user (mail, username, password, salt, create_time) values ('cgarmonsir@usnews.com', 'iflochir', '00wT0uEGYFUb', 'qZ6oJZImOER0', '2022-03-30 07:41:04');
-insert into user (mail, username, password, salt, create_time) values ('ctowseis@bbb.org', 'esmoughtonis', 'gD9MRQ1d', '8bfowVmMH', '2022-04-13 03:33:36');
-insert into user (mail, username, password, salt, create_time) values ('dvanweedenburgit@constantcontact.com', 'jfeldererit', '87ksJfHgTm', '4IcAXOY', '2022-08-14 16:10:29');
-insert into user (mail, username, password, salt, create_time) values ('ttreleveniu@china.com.cn', 'raguirrezabaliu', 'w5XP4opaf', 'FBnWfOiDVlaB', '2021-11-11 11:16:59');
-insert into user (mail, username, password, salt, create_time) values ('sdelepineiv@instagram.com', 'bmckevanyiv', '8kbrFqkq', 'eIPlnWh', '2022-04-28 04:26:24');
-insert into user (mail, username, password, salt, create_time) values ('mdecayetteiw@go.com', 'fterrazzoiw', 'oWDzfuy7M4', 'IpK4M46', '2022-07-25 02:27:46');
-insert into user (mail, username, password, salt, create_time) values ('efidlerix@wisc.edu', 'mfelgateix', 'P4YSTb', 'A0k9u69DVTX', '2022-03-28 08:39:58');
-insert into user (mail, username, password, salt, create_time) values ('fulyattiy@tripod.com', 'murlingiy', '46hOhue', 'nOeiqlkfDl', '2022-02-20 18:12:47');
-insert into user (mail, username, password, salt, create_time) values ('lmcdugaliz@php.net', 'afyldesiz', 'Imx6Zuxz8', 'DHOXir', '2022-05-26 19:19:20');
-insert into user (mail, username, password, salt, create_time) values ('oballsdonj0@earthlink.net', 'lberzonj0', 'eEfDhNt', 'YElkhR9E', '2022-09-03 22:32:39');
-insert into user (mail, username, password, salt, create_time) values ('yheafordj1@go.com', 'mmenatj1', 'vJGu2Le8', 'Ej4nsOAiNXhO', '2022-08-03 09:05:13');
-insert into user (mail, username, password, salt, create_time) values ('dmcnaughtonj2@blinklist.com', 'bstoneyj2', 'airgspmN9wLJ', '0kG6RcYhz', '2022-02-05 22:45:06');
-insert into user (mail, username, password, salt, create_time) values ('mpittendreighj3@reddit.com', 'nmackrillj3', 'iF0r5AGofIR', '4BEX9v', '2021-12-04 14:37:44');
-insert into user (mail, username, password, salt, create_time) values ('dcurraghj4@t.co', 'cnaresj4', 'ljKifVr466', '9JAuwZwlVx8H', '2021-11-21 16:34:57');
-insert into user (mail, username, password, salt, create_time) values ('mferrerj5@google.ru', 'gcasarilij5', 'EhodXkkD', 'R9ovOIX', '2021-12-02 11:00:47');
-insert into user (mail, username, password, salt, create_time) values ('twyrillj6@indiatimes.com', 'ckinnierj6', 'SbXoGtd9a', 'xkOqmSidEw', '2022-05-28 14:57:13');
-insert into user (mail, username, password, salt, create_time) values ('rriddingj7@edublogs.org', 'kpaskej7', 'EP7aTJmiz9P8', 'iRWITo', '2022-08-04 00:46:03');
-insert into user (mail, username, password, salt, create_time) values ('klarensonj8@shareasale.com', 'akurdanij8', 'yrsbjbQ', 'EnZl4tV0Qfs', '2022-08-26 13:13:32');
-insert into user (mail, username, password, salt, create_time) values ('jgalletleyj9@friendfeed.com', 'yrichenj9', '97W844', 'zvZjjTDAVFU', '2021-12-17 13:05:59');
-insert into user (mail, username, password, salt, create_time) values ('jedghinnja@geocities.com', 'ddillestonja', 'WkXCJ9xkrN', 'Wq3MjmE2EK', '2022-03-17 09:57:26');
-insert into user (mail, username, password, salt, create_time) values ('rinnocentjb@w3.org', 'hricharzjb', 'bnllE1vto', 'Hk2I8GS', '2022-08-26 00:32:04');
-insert into user (mail, username, password, salt, create_time) values ('dcoggenjc@wufoo.com', 'wkilbanejc', 'Q2Nfpbotp', 'c5IqpHwE4c', '2022-06-25 07:10:51');
-insert into user (mail, username, password, salt, create_time) values ('kpickavantjd@bluehost.com', 'rcarnoghanjd', 'Opsdk0', '7rngVaMzM', '2022-06-29 19:24:53');
-insert into user (mail, username, password, salt, create_time) values ('bjahndelje@dot.gov', 'tpoweje', 'S0QwzGbV', 'Emdn00CTPe', '2022-07-24 15:40:33');
-insert into user (mail, username, password, salt, create_time) values ('shatonjf@aboutads.info', 'emurchjf', 'sYFL9wJEAf59', 'eN3uVh', '2021-11-22 08:55:06');
-insert into user (mail, username, password, salt, create_time) values ('kfontellesjg@techcrunch.com', 'cgreenhalghjg', 'u47mArh9hSjx', 'FfQXUMygV', '2022-09-26 06:41:17');
-insert into user (mail, username, password, salt, create_time) values ('oshallojh@nymag.com', 'cprewjh', 'WAbjNOMD', 'ho55vWpiE', '2022-02-17 16:25:32');
-insert into user (mail, username, password, salt, create_time) values ('ddunridgeji@umich.edu', 'smerrileesji', '0UitANY', 'wRhFGR4Yzr', '2022-02-17 21:05:43');
-insert into user (mail, username, password, salt, create_time) values ('bhawkswoodjj@dailymail.co.uk', 'cprettyjohnsjj', 'jGC3muNU', 'xpdpziLavG', '2022-01-08 16:55:05');
-insert into user (mail, username, password, salt, create_time) values ('fhornungjk@prlog.org', 'ehansiejk', 'DbAju1', 'L9Z41UsZg', '2022-08-08 07:04:49');
-insert into user (mail, username, password, salt, create_time) values ('npietersenjl@berkeley.edu', 'mmuldowneyjl', 'TUFgl3MmDib5', 'Nc6I8Zv2', '2022-07-25 14:59:24');
-insert into user (mail, username, password, salt, create_time) values ('alatchmorejm@vistaprint.com', 'jllorensjm', 'g2mxlAIpr', 't92h6E27k', '2022-06-03 03:06:24');
-insert into user (mail, username, password, salt, create_time) values ('msheachjn@odnoklassniki.ru', 'skesperjn', 'ojfAoj', 'Rr5Q5ftA', '2022-08-29 18:40:30');
-insert into user (mail, username, password, salt, create_time) values ('ssallissjo@freewebs.com', 'fdyballjo', 'ZlWQAJCwh5fd', 'fJXHABmXuj6K', '2022-08-04 18:07:40');
-insert into user (mail, username, password, salt, create_time) values ('dparlotjp@livejournal.com', 'rgopselljp', 'F0TQNidQZ', 'oRhAhvgtn2', '2022-02-11 18:55:19');
-insert into user (mail, username, password, salt, create_time) values ('ccarncrossjq@umich.edu', 'bpickoverjq', '9ucDgQ7NPLco', 'Rf5wae', '2022-07-17 19:11:51');
-insert into user (mail, username, password, salt, create_time) values ('fbaggallyjr@flavors.me', 'mpryelljr', 'PmnpSNlTX', 'vbchR4', '2022-03-01 02:26:58');
-insert into user (mail, username, password, salt, create_time) values ('bcraskejs@ovh.net', 'fpattullojs', 'eaStdFgvy1G3', 'ebNkP9Jg3paQ', '2021-11-26 11:54:08');
-insert into user (mail, username, password, salt, create_time) values ('lmelrossjt@cargocollective.com', 'kwormleightonjt', '6x0vynJ5mc5', 'EPeBjVbQdG0f', '2022-05-25 15:45:35');
-insert into user (mail, username, password, salt, create_time) values ('mnewensju@psu.edu', 'sgwytherju', 'QDyHPCQSqf', 'FSGV7VUWo', '2022-08-08 23:12:46');
-insert into user (mail, username, password, salt, create_time) values ('jprentyjv@purevolume.com', 'tboarleysonjv', 'nIGRkHh5', 'u22vc9H4tn7', '2022-03-01 08:40:44');
-insert into user (mail, username, password, salt, create_time) values ('flehrjw@dailymail.co.uk', 'ablazijw', 'GV5C53dYHmS', 'BZvMG8d4RC', '2022-09-24 18:56:13');
-insert into user (mail, username, password, salt, create_time) values ('bspalljx@nba.com', 'ssyderjx', 'eP47vLQNYKn9', 'JGNC7yP8eF', '2022-09-11 11:08:08');
-insert into user (mail, username, password, salt, create_time) values ('tbernardellijy@fotki.com', 'acustjy', 'Uh1wIRukzE', '3QZYaxQasz', '2022-10-28 03:11:03');
-insert into user (mail, username, password, salt, create_time) values ('cjudkinjz@homestead.com', 'dwellwoodjz', 'SLJT109zxP', 'fNE3Idkx9rE', '2022-01-28 18:30:13');
-insert into user (mail, username, password, salt, create_time) values ('tboaysk0@rambler.ru', 'tpainswickk0', 'w63GHuIU', 'Zsf9KLCle3Tb', '2022-03-31 16:06:41');
-insert into user (mail, username, password, salt, create_time) values ('mborrowsk1@dot.gov', 'bfreebornk1', 's2TRWjAok', 'MEkgtGwFF', '2022-06-06 07:44:35');
-insert into user (mail, username, password, salt, create_time) values ('sgoldak2@ed.gov', 'ezaniolettik2', 'RzzGw0QF0OY', 'X9aosU', '2022-04-05 00:31:18');
-insert into user (mail, username, password, salt, create_time) values ('trutherfoordk3@fc2.com', 'gcorradettik3', 'CO6dkMKT', '10D376JqKU', '2022-01-30 15:51:09');
-insert into user (mail, username, password, salt, create_time) values ('bkilliamk4@cafepress.com', 'ljohananoffk4', 'QvlZ4i', 'f3EwJwRsPlL', '2022-06-06 00:22:30');
-insert into user (mail, username, password, salt, create_time) values ('qborkettk5@seesaa.net', 'ahowlingsk5', 'gSOsRx', 'nBNTWJPlB', '2022-08-30 11:14:24');
-insert into user (mail, username, password, salt, create_time) values ('ebaudinellik6@bloglovin.com', 'dschistlk6', 'w9ZTouiL', 'VL4t2f28', '2022-10-14 21:31:31');
-insert into user (mail, username, password, salt, create_time) values ('propertk7@twitter.com', 'olaurischk7', 'QbEXlW8MdXC', 'Z7vbrdRKrx', '2022-03-08 02:33:12');
-insert into user (mail, username, password, salt, create_time) values ('stilerk8@about.me', 'edeeveyk8', 'htrM7kU8YG', '0Ve4m2x', '2022-05-06 15:42:44');
-insert into user (mail, username, password, salt, create_time) values ('bsapsfordk9@youku.com', 'hhorderk9', 'AtyrhWCk', 'cjgnurfBu', '2022-09-28 00:53:27');
-insert into user (mail, username, password, salt, create_time) values ('aoliverka@lulu.com', 'rparrettka', 'kedKsm9RjMu', 'AKNEpwC', '2021-12-22 06:45:56');
-insert into user (mail, username, password, salt, create_time) values ('tbuckinghamkb@indiatimes.com', 'fdustkb', 'u719mE9lnps', '2JoodWCyXk', '2022-03-29 21:33:28');
-insert into user (mail, username, password, salt, create_time) values ('ywedgbrowkc@storify.com', 'saslettkc', 'F5rIdQ8nfVy', 'VMgsIsfqQ', '2021-12-08 12:45:05');
-insert into user (mail, username, password, salt, create_time) values ('rphairkd@a8.net', 'kstakerkd', 'NxfmEfq0oW', '7nobGG', '2022-02-24 07:40:43');
-insert into user (mail, username, password, salt, create_time) values ('sdomesdayke@time.com', 'hporrittke', 'fBxDBGQfA', 'bPSj6FYzA', '2022-08-07 15:07:08');
-insert into user (mail, username, password, salt, create_time) values ('fivermeekf@angelfire.com', 'mhaycroftkf', '3bydZ063N', '7oJ6v9kHv1', '2022-10-02 14:26:43');
-insert into user (mail, username, password, salt, create_time) values ('giacapuccikg@privacy.gov.au', 'rannakinkg', '5znfKcfG1E6', '3EXVuKtFZ', '2021-12-04 00:01:26');
-insert into user (mail, username, password, salt, create_time) values ('sbutterwickkh@adobe.com', 'dwhytockkh', 'z7YQvln10bn', 'zBBoDXD0Fz', '2022-08-11 03:56:17');
-insert into user (mail, username, password, salt, create_time) values ('ldoerlingki@google.co.uk', 'yblackeyki', 'AE4cUbIjAsU5', '4J2rvMo3X', '2022-07-31 19:30:06');
-insert into user (mail, username, password, salt, create_time) values ('vayerkj@fda.gov', 'kremerkj', 'yv5vA2MKy', '0HBTwTZ0XJT', '2021-12-04 19:38:38');
-insert into user (mail, username, password, salt, create_time) values ('gheretykk@wunderground.com', 'amemmorykk', 'FWdg3p', 'AUWHSd', '2022-02-26 05:35:59');
-insert into user (mail, username, password, salt, create_time) values ('bnorcopkl@example.com', 'menriqueskl', '3zWCSaD', 'A6jDTubWVLn', '2021-11-26 15:47:27');
-insert into user (mail, username, password, salt, create_time) values ('cmurrhardtkm@usatoday.com', 'egreensteadkm', 'z5S7yL0AvJkZ', 'QTfg4nLMdNWe', '2022-05-06 14:51:36');
-insert into user (mail, username, password, salt, create_time) values ('ctaffiekn@lycos.com', 'tdacostakn', 'EgAmh7IMz', 'zkx8agieKR', '2022-08-12 07:30:27');
-insert into user (mail, username, password, salt, create_time) values ('mprettymanko@vistaprint.com', 'rcanlinko', '2WyaRInXaf', 'hvMmoF', '2022-11-01 15:37:41');
-insert into user (mail, username, password, salt, create_time) values ('abeardskp@deliciousdays.com', 'fcamamillkp', 'jizPgS5', '0RxgAC', '2022-08-04 05:31:14');
-insert into user (mail, username, password, salt, create_time) values ('hbeswetherickkq@jigsy.com', 'bserjentkq', 'Z221qrtOdW', 'YutVUi', '2022-07-26 23:33:49');
-insert into user (mail, username, password, salt, create_time) values ('taronoffkr@posterous.com', 'mchalcroftkr', 'za79vcaL', 'YB8x0i0RECb', '2022-07-02 12:15:46');
-insert into user (mail, username, password, salt, create_time) values ('tackensonks@fc2.com', 'wgrzegorekks', 'vHffEHEqptK', 'zYPLxyzGQsKJ', '2022-04-21 13:26:21');
-insert into user (mail, username, password, salt, create_time) values ('fhappertkt@surveymonkey.com', 'stillotkt', 'ENgYgqXc', 'GV76RPgiAh', '2021-11-28 23:55:58');
-insert into user (mail, username, password, salt, create_time) values ('dstollsteimerku@php.net', 'pitterku', 'E8xqLERyV', '92jD4yW', '2021-12-03 00:44:49');
-insert into user (mail, username, password, salt, create_time) values ('bcostanzakv@clickbank.net', 'scarolankv', 'Yo8UC3D9w', 'hqTCfn', '2022-10-09 13:12:42');
-insert into user (mail, username, password, salt, create_time) values ('sengelkw@digg.com', 'dkilfordkw', 'wjGB7y', 'pyuMo4tNDYnc', '2022-03-03 15:33:55');
-insert into user (mail, username, password, salt, create_time) values ('mjenninskx@jugem.jp', 'jmatzkekx', 'FFRiEJ0M', 'qi2sZ2ou7g3b', '2022-06-10 11:39:31');
-insert into user (mail, username, password, salt, create_time) values ('gstannahky@odnoklassniki.ru', 'cminardsky', 'V7jDTh', '8NCK1Z99', '2022-10-24 19:49:43');
-insert into user (mail, username, password, salt, create_time) values ('nolenchikovkz@163.com', 'coverillkz', 'ohWPiNQ', 'J0cpdOYXN', '2022-08-09 15:20:09');
-insert into user (mail, username, password, salt, create_time) values ('hmarrowsl0@cpanel.net', 'gtavernorl0', 'mGVLqCS4HrH', 's8UuHF1pM', '2021-12-29 07:16:52');
-insert into user (mail, username, password, salt, create_time) values ('khellmerl1@domainmarket.com', 'hwoosnaml1', 'FqV6JqqzeU', 'lEie3LVeqcI2', '2022-01-25 11:05:29');
-insert into user (mail, username, password, salt, create_time) values ('tsarjeantl2@twitpic.com', 'chubbockl2', 'lDZhmeZC', 'XS9MMhlmKlsh', '2022-08-13 14:36:27');
-insert into user (mail, username, password, salt, create_time) values ('sflindalll3@drupal.org', 'bellinl3', 'nVRD3tQjoN', 'LAIiMvs', '2021-12-08 03:33:47');
-insert into user (mail, username, password, salt, create_time) values ('fcristoforl4@abc.net.au', 'gmcrobbiel4', 'W18pPb7cUQ76', 'poqUct2bJ', '2021-12-22 18:26:43');
-insert into user (mail, username, password, salt, create_time) values ('ftipensl5@123-reg.co.uk', 'jnorthcottl5', 'xw1lekntVBup', 'DHyrOUYWZ', '2022-06-21 19:59:00');
-insert into user (mail, username, password, salt, create_time) values ('kfurtadol6@bizjournals.com', 'srapseyl6', 'K8OFjwPo', 'fdccx9s', '2022-06-25 21:43:32');
-insert into user (mail, username, password, salt, create_time) values ('imcclauriel7@zdnet.com', 'gwingerl7', 'MVVGgCnog', 'O3ZYChGdM7xC', '2022-09-07 02:02:24');
-insert into user (mail, username, password, salt, create_time) values ('bconfortl8@rediff.com', 'oeastmondl8', 'kvNHwdKBqjr', '0caHY61u', '2022-04-10 19:55:48');
-insert into user (mail, username, password, salt, create_time) values ('cbuntainl9@google.de', 'kinglesantl9', 'GinaIG2ESt', '0MbLZEphSx', '2021-12-07 10:38:25');
-insert into user (mail, username, password, salt, create_time) values ('jvalentala@ftc.gov', 'clackintonla', 'CUs3p5JnlA', 'TZAd8HG', '2022-03-02 22:37:58');
-insert into user (mail, username, password, salt, create_time) values ('aclaysonlb@scientificamerican.com', 'ysawoodlb', 'tSC1VfbVWfUp', '0gx6vva7MLG1', '2022-05-11 22:37:25');
-insert into user (mail, username, password, salt, create_time) values ('sdeyelc@un.org', 'pwestberglc', '6jJr3WkHDOwO', 'zOpxehG', '2022-04-10 19:23:37');
-insert into user (mail, username, password, salt, create_time) values ('rredhollsld@gmpg.org', 'mbonnetteld', 'J9uzlo', 'eDS3bBp8C', '2021-12-19 13:39:26');
-insert into user (mail, username, password, salt, create_time) values ('jhurlle@hhs.gov', 'ecobleyle', '2mxiP2ETq', 'RrG08HZn', '2021-11-06 01:09:36');
-insert into user (mail, username, password, salt, create_time) values ('lhaffendenlf@amazon.de', 'smocklerlf', 'YSVqfRm4qzB', 'ob27rrz3EM', '2022-02-23 08:08:58');
-insert into user (mail, username, password, salt, create_time) values ('jcandeylg@devhub.com', 'rpellerlg', 'AAqwBh8WU', 'pdedmWmtHti', '2022-04-05 11:36:53');
-insert into user (mail, username, password, salt, create_time) values ('sgreginelh@fda.gov', 'mkidmanlh', 'B7YN7qa', 'MqWmq87', '2022-09-11 05:03:14');
-insert into user (mail, username, password, salt, create_time) values ('amcgeaneyli@alexa.com', 'ezealli', '7kNFQzqFtI', 'm9X6UkAK', '2021-11-13 22:29:15');
-insert into user (mail, username, password, salt, create_time) values ('lsemradlj@issuu.com', 'tschalllj', 'FJU81Fg', '12H0IQpHm', '2022-02-26 09:32:19');
-insert into user (mail, username, password, salt, create_time) values ('rkeechlk@posterous.com', 'hdowdenlk', 'G5lbaZb', 'KYQSfT', '2022-08-31 17:19:46');
-insert into user (mail, username, password, salt, create_time) values ('tkielyll@independent.co.uk', 'cfarbrotherll', 'KTjwOih', 'Dyl6tR', '2022-06-24 23:27:29');
-insert into user (mail, username, password, salt, create_time) values ('gwightmanlm@washingtonpost.com', 'femminslm', '0ewR8q', 'MoARWAP', '2022-05-07 19:55:08');
-insert into user (mail, username, password, salt, create_time) values ('lphilottln@sakura.ne.jp', 'broebottomln', 'XMhYGt', '5XxjbRi', '2022-08-20 21:17:40');
-insert into user (mail, username, password, salt, create_time) values ('hephsonlo@jiathis.com', 'astricklandlo', 'iL0OLDnp0R', 'BzXUBKJpIAj', '2022-07-15 11:31:50');
-insert into user (mail, username, password, salt, create_time) values ('agellylp@booking.com', 'mjohansenlp', '4xRr4238', 'jmlzLBpUzUXN', '2022-09-29 07:01:39');
-insert into user (mail, username, password, salt, create_time) values ('scogarlq@digg.com', 'handrzejczaklq', 'DF3IICwZaz', 'pb73NCYsXNl', '2022-10-22 12:26:31');
-insert into user (mail, username, password, salt, create_time) values ('kriseleylr@soup.io', 'cabellalr', 'Av0Br6WgacK', 'cKKWGn304DP', '2022-08-23 17:02:00');
-insert into user (mail, username, password, salt, create_time) values ('lwittsls@utexas.edu', 'hkeseyls', 'qVxr5taa5', 'o6eQOQwIxgM', '2022-08-11 21:49:54');
-insert into user (mail, username, password, salt, create_time) values ('pwheelerlt@last.fm', 'ssimmslt', 'OqWLk19', 'dqrHYm', '2022-07-22 04:37:30');
-insert into user (mail, username, password, salt, create_time) values ('rabbatelu@walmart.com', 'tgarmslu', 'IE3J226BU5aS', '3FoC6576J6AM', '2022-04-16 15:11:35');
-insert into user (mail, username, password, salt, create_time) values ('hansteelv@squarespace.com', 'zfadianlv', 'INrlbDlpODLB', 'san8AwfpD', '2022-02-21 19:37:58');
-insert into user (mail, username, password, salt, create_time) values ('coliverlw@mashable.com', 'eorislw', 'qFJtHB', 'vDz6oCLv1q65', '2022-04-05 07:00:59');
-insert into user (mail, username, password, salt, create_time) values ('hstreatleylx@jiathis.com', 'hmonellilx', 'Kot3dc0i', 'TT033ZMI0u', '2022-03-31 08:16:49');
-insert into user (mail, username, password, salt, create_time) values ('wmclarnonly@vistaprint.com', 'madrianily', 'JAHmc93iW', 'aJL6EzmJ', '2022-07-29 20:14:19');
-insert into user (mail, username, password, salt, create_time) values ('amacveanlz@prweb.com', 'lyewdelllz', 'Nj0zxDGtN', 'GG5kIHZBhqYP', '2022-07-02 19:17:46');
-insert into user (mail, username, password, salt, create_time) values ('mcleaverm0@businessinsider.com', 'wrecordm0', 'j8h3nh', 'OSbJHVMcx', '2022-08-06 08:24:42');
-insert into user (mail, username, password, salt, create_time) values ('rdykasm1@networksolutions.com', 'tlesperm1', 'cpPTJBhrQiP', 'VZ3Dl42dyqO', '2022-06-18 11:44:34');
-insert into user (mail, username, password, salt, create_time) values ('mbolducm2@bandcamp.com', 'krubim2', 'aj2BUY92K', '8K7EsoLC', '2022-10-03 14:14:20');
-insert into user (mail, username, password, salt, create_time) values ('dorwinm3@admin.ch', 'mjoustm3', 'KU5GIvFeX3', 'CyJCbfX', '2022-05-08 09:29:19');
-insert into user (mail, username, password, salt, create_time) values ('sjennawaym4@bravesites.com', 'cferraram4', 'IXOdeuPvZIs', 'ujfhBSh6GS', '2022-09-28 23:15:19');
-insert into user (mail, username, password, salt, create_time) values ('gbaressm5@walmart.com', 'astuehmeyerm5', 'S3WcHAFzk', 'Sr24qEVBcz8m', '2022-09-10 08:51:33');
-insert into user (mail, username, password, salt, create_time) values ('lwhiskerdm6@ucla.edu', 'vlyfem6', '3oBVoA0', 'lS7NoNYI', '2022-06-12 15:27:45');
-insert into user (mail, username, password, salt, create_time) values ('wpatchettm7@washingtonpost.com', 'bpuddiferm7', 'aWJM4YiLIo', 'mFZ2Km7H', '2022-05-13 21:12:09');
-insert into user (mail, username, password, salt, create_time) values ('bgalvinm8@php.net', 'ilonsdalem8', 'fWJGyIBs', 'uT3a2ay', '2021-12-18 08:06:28');
-insert into user (mail, username, password, salt, create_time) values ('sjohlm9@github.com', 'thawkesm9', 'LuGcltVB', 'XE2yUoZyVb', '2021-12-26 06:09:52');
-insert into user (mail, username, password, salt, create_time) values ('coloshkinma@marketwatch.com', 'bhumfreyma', '3Gdq2xH', 'r8qguB8v4I1', '2022-04-06 09:41:20');
-insert into user (mail, username, password, salt, create_time) values ('ialbasinymb@umn.edu', 'meggersmb', 'UKza3yAD3F', 'r6ktJTNzA', '2022-01-18 07:21:48');
-insert into user (mail, username, password, salt, create_time) values ('sformiglimc@123-reg.co.uk', 'ahallyburtonmc', 'LpgYZlo', 'YFhCEs11DA', '2022-01-25 22:48:53');
-insert into user (mail, username, password, salt, create_time) values ('lsiaskowskimd@webmd.com', 'gcunningtonmd', 'oVQ8N86L4', 'nXAl9V', '2022-05-06 11:54:16');
-insert into user (mail, username, password, salt, create_time) values ('twetherillme@ezinearticles.com', 'skraftme', '4RZVYLq8QmI', '0ppq3pRfQ', '2022-01-01 02:07:26');
-insert into user (mail, username, password, salt, create_time) values ('cjablonskimf@istockphoto.com', 'bringmf', 'zxeDeOt44c', '9qV9YHvr', '2022-07-05 17:35:34');
-insert into user (mail, username, password, salt, create_time) values ('fkellermg@pen.io', 'kbrookhousemg', 'yVOdAMP', '2x50XjoK', '2022-05-26 21:26:43');
-insert into user (mail, username, password, salt, create_time) values ('hmatijasevicmh@about.me', 'kstuckemh', 'S5HZK1', 'pUaq62', '2021-11-16 13:28:07');
-insert into user (mail, username, password, salt, create_time) values ('jkirvinmi@sakura.ne.jp', 'fmeadermi', 'OM8271', 'cRpc6f1cNhkU', '2022-10-16 20:15:47');
-insert into user (mail, username, password, salt, create_time) values ('cbufferymj@cmu.edu', 'fflintoffmj', 'dEJZh6W6', 'VSdD1YxIQZL', '2022-10-24 07:46:32');
-insert into user (mail, username, password, salt, create_time) values ('ncoltherdmk@amazonaws.com', 'tbutterleymk', 'CYB7aDYK', 'NF4cjzVxmRk5', '2022-04-03 02:04:14');
-insert into user (mail, username, password, salt, create_time) values ('lcurrmml@prnewswire.com', 'cdumbrillml', 'pKirS8Js9Fq', 'KQNJ0hbm', '2022-08-13 04:06:41');
-insert into user (mail, username, password, salt, create_time) values ('akilshallmm@boston.com', 'ymcgarelmm', 'pUlY6uGD', 'qoX2coK', '2022-05-21 09:40:35');
-insert into user (mail, username, password, salt, create_time) values ('mrosebymn@flickr.com', 'pduffmn', 'u7L1jjYQ', 'htQhJE9B', '2022-02-11 15:44:52');
-insert into user (mail, username, password, salt, create_time) values ('bdittsmo@cnet.com', 'pbranthwaitemo', 'ABOW3cpvuPJJ', '1uvM1tVIP7', '2022-10-25 10:55:50');
-insert into user (mail, username, password, salt, create_time) values ('gsnalhammp@wp.com', 'dfermermp', '9GczkV', 'ZcnOMI', '2022-08-14 07:24:12');
-insert into user (mail, username, password, salt, create_time) values ('lleckymq@google.ca', 'scovetmq', 'A3s93JBG6sy', '5mqEUZEZp', '2021-12-07 23:19:08');
-insert into user (mail, username, password, salt, create_time) values ('kmcbaymr@google.nl', 'asollememr', 'agt5bq4ANQrO', 'VBMweW', '2021-12-29 18:27:46');
-insert into user (mail, username, password, salt, create_time) values ('bkobms@europa.eu', 'agregms', 'V4JxoWWVfg5b', 'kICKY39', '2022-09-16 15:57:43');
-insert into user (mail, username, password, salt, create_time) values ('frogansmt@blogspot.com', 'bmackaigmt', 'QcAmlC', 'IKCXNt1NZYj', '2022-01-24 00:57:17');
-insert into user (mail, username, password, salt, create_time) values ('earnottmu@flavors.me', 'ascowcroftmu', 'o1Q7YJ', 'Vblb2T8t9QV', '2022-02-21 02:55:31');
-insert into user (mail, username, password, salt, create_time) values ('edebenedittimv@51.la', 'tkupecmv', 'UG2xmvKS', 'KLfXTEu', '2022-07-18 18:44:01');
-insert into user (mail, username, password, salt, create_time) values ('jkillmw@msn.com', 'astallebrassmw', 'hNn2K9tJg5FJ', 'd7A9VGZ', '2022-04-23 01:55:20');
-insert into user (mail, username, password, salt, create_time) values ('sstermanmx@ovh.net', 'bdelislemx', 'rR0M3xEVTC', 'uUM305x3jhaB', '2022-03-18 07:11:50');
-insert into user (mail, username, password, salt, create_time) values ('crubinsohnmy@edublogs.org', 'cgaisfordmy', 'c6PBct0D', 'LdQBWOR1xYQ', '2022-08-21 17:14:42');
-insert into user (mail, username, password, salt, create_time) values ('cpeerlessmz@biblegateway.com', 'ndrillingcourtmz', 'Xf0w8XIJRyN', 'bcbJnZ', '2022-02-11 15:17:20');
-insert into user (mail, username, password, salt, create_time) values ('dcammishn0@bloglovin.com', 'dvelldenn0', 'pHJlnBGTyO', 'dKtuu9', '2021-12-15 02:29:26');
-insert into user (mail, username, password, salt, create_time) values ('bsternn1@kickstarter.com', 'mkrzysztofn1', '0jyJu7', 'SvN2NXLZ', '2022-06-27 22:27:34');
-insert into user (mail, username, password, salt, create_time) values ('vharvardn2@dailymotion.com', 'shaymann2', 'kWilzlM', '3SGF2F3', '2022-09-14 13:14:20');
-insert into user (mail, username, password, salt, create_time) values ('hhutchcraftn3@who.int', 'nfrin3', '4omL24U', 'MJhGvh', '2022-02-01 10:42:20');
-insert into user (mail, username, password, salt, create_time) values ('aparchmentn4@bravesites.com', 'abramhilln4', 'smDF6eP5MAHW', 'FiZVHnRBL', '2022-01-23 09:27:01');
-insert into user (mail, username, password, salt, create_time) values ('sleacyn5@liveinternet.ru', 'bdinisn5', '7Hs0bg4Ua', 'zbX06Rm9MK73', '2021-12-28 13:31:33');
-insert into user (mail, username, password, salt, create_time) values ('kleggettn6@craigslist.org', 'apalistern6', 'qWs4hHmBphw', 'T3qkapRMP', '2022-09-15 15:47:09');
-insert into user (mail, username, password, salt, create_time) values ('kmatantsevn7@cargocollective.com', 'dgeffen7', '0pXvVbGiG', 'vvWzVz', '2022-04-02 04:59:51');
-insert into user (mail, username, password, salt, create_time) values ('awyardn8@oaic.gov.au', 'kbrycen8', 'K7o8wul', 'stYorTkkqRL', '2022-09-06 21:58:21');
-insert into user (mail, username, password, salt, create_time) values ('rdomenicin9@fda.gov', 'lbarenskyn9', 'NTOrQ4', 'vV80TM6', '2022-02-15 01:42:11');
-insert into user (mail, username, password, salt, create_time) values ('lmcgunleyna@gov.uk', 'bbalentynena', 'rcgIyO', 'kyPFqcwI6Z', '2022-09-28 05:13:47');
-insert into user (mail, username, password, salt, create_time) values ('jmccannnb@miibeian.gov.cn', 'tcoalenb', 'QGY5H0Io', '3Y1L1SxqVCg', '2022-05-02 15:01:36');
-insert into user (mail, username, password, salt, create_time) values ('bjupenc@smugmug.com', 'jgealync', 'XIZ45Xybv9g2', 'iPOzv9hXvuFU', '2022-07-17 10:59:27');
-insert into user (mail, username, password, salt, create_time) values ('ccancellernd@paginegialle.it', 'hfalveynd', 'selmtMD2', 'QezYC2VvG0Er', '2022-03-11 06:12:56');
-insert into user (mail, username, password, salt, create_time) values ('jfattorinine@addthis.com', 'hbamfordne', 'EBvioTvMi1p', 'tGjcmLAln', '2022-09-27 14:07:10');
-insert into user (mail, username, password, salt, create_time) values ('vscarlinnf@sciencedirect.com', 'tbaythropnf', 'CLuseuGPJjNJ', 'ik3WKl', '2021-12-28 04:35:11');
-insert into user (mail, username, password, salt, create_time) values ('aphillippong@wisc.edu', 'nlongegang', '8h8kwHpit', 'vbwsR0', '2022-07-03 18:20:12');
-insert into user (mail, username, password, salt, create_time) values ('brapkinsnh@nasa.gov', 'bgrabbamnh', 'F9dm7mu', 'RlLKFB9', '2022-09-29 16:06:40');
-insert into user (mail, username, password, salt, create_time) values ('rjeynessni@macromedia.com', 'gmcpheeni', 'D6VDOuD', 'PV87A1UBvf2H', '2022-09-22 06:19:46');
-insert into user (mail, username, password, salt, create_time) values ('nwhittletonnj@hatena.ne.jp', 'mfontenotnj', 'XiROZR', 'XecTbWba9Px', '2022-06-14 10:46:42');
-insert into user (mail, username, password, salt, create_time) values ('kguiderank@1688.com', 'cdinglenk', 'y3GguPsz', 'eyTaJH', '2022-04-14 21:28:54');
-insert into user (mail, username, password, salt, create_time) values ('ghursthousenl@sina.com.cn', 'rblownl', 'qpLs0jW7brF', 'UlFOsH1reyii', '2022-09-03 23:39:49');
-insert into user (mail, username, password, salt, create_time) values ('mbowrynm@canalblog.com', 'ukummerlowenm', '5SAX6rxYkJ3W', 'ACY8T3hBD0Aw', '2022-06-13 15:36:34');
-insert into user (mail, username, password, salt, create_time) values ('memneynn@sun.com', 'tlonnonnn', 'rgqTo6A', '04Fv4JexFkM0', '2022-01-01 01:56:20');
-insert into user (mail, username, password, salt, create_time) values ('ppattenno@theglobeandmail.com', 'pgoatmanno', 'hcBJmr79F', 'jU3VIX7P', '2022-10-13 21:56:43');
-insert into user (mail, username, password, salt, create_time) values ('flighternessnp@howstuffworks.com', 'oscoullernp', 'pAZABR', 'deVq8jLceGPH', '2022-06-14 01:07:25');
-insert into user (mail, username, password, salt, create_time) values ('joverburynq@ucoz.ru', 'kpendellnq', 'DslaYfN', 'QvXdWrVq72Jw', '2022-04-03 08:44:14');
-insert into user (mail, username, password, salt, create_time) values ('awolledgenr@bizjournals.com', 'dderrnr', 'WPnA7JUXd', 'a3SZy18FK', '2022-06-24 08:20:34');
-insert into user (mail, username, password, salt, create_time) values ('fironns@twitpic.com', 'ubiglinns', 'OAaom7rLKw', '9O5XeMVRt', '2022-07-09 18:30:58');
-insert into user (mail, username, password, salt, create_time) values ('aburchessnt@google.com.br', 'etitmussnt', '97bCxg', 'd0loYYY', '2022-10-22 01:06:42');
-insert into user (mail, username, password, salt, create_time) values ('fhawkennu@amazon.co.uk', 'kgoodspeednu', 'DZKjnEv', 'TvqUZ10H', '2022-08-21 20:59:01');
-insert into user (mail, username, password, salt, create_time) values ('rhillyattnv@dailymotion.com', 'lspollennv', 'jt14eQ', 'il8Ja7Xh9k5J', '2022-08-21 14:27:40');
-insert into user (mail, username, password, salt, create_time) values ('gsmithendnw@cmu.edu', 'alandernw', 'RgkKHH', '3Pqnei4', '2022-04-09 22:56:06');
-insert into user (mail, username, password, salt, create_time) values ('dmourniannx@blinklist.com', 'gfraczkiewicznx', 'V1fNeGnc13z', 'RZYT7uooL', '2022-07-15 03:02:12');
-insert into user (mail, username, password, salt, create_time) values ('vgrzegorczykny@dion.ne.jp', 'gtabertny', 'krYXOnE', 'UhdttotnL', '2022-05-17 06:20:55');
-insert into user (mail, username, password, salt, create_time) values ('rpreshawnz@cloudflare.com', 'jkinnarnz', 'UrFsAzB7', 'rM9aNDg6gYlD', '2022-01-15 19:45:44');
-insert into user (mail, username, password, salt, create_time) values ('efreako0@bbb.org', 'tfosseyo0', 'CFaismlsQ', 'epn1olCk', '2022-02-24 06:53:32');
-insert into user (mail, username, password, salt, create_time) values ('rpragnallo1@cloudflare.com', 'sliddelo1', '9R6gp2HLKWg5', 'LyoO4iu', '2022-05-07 11:39:30');
-insert into user (mail, username, password, salt, create_time) values ('tfeighryo2@examiner.com', 'sdeightono2', 'LYIc0BFy3', 'Zx7ngkvnnu', '2022-03-09 17:25:25');
-insert into user (mail, username, password, salt, create_time) values ('gmollitto3@ameblo.jp', 'twanklingo3', 'Wu3U5ORlCZby', 'aAXOpCSdQ6Ja', '2022-06-08 09:27:36');
-insert into user (mail, username, password, salt, create_time) values ('chargraveo4@jiathis.com', 'odenningtono4', 'u1SN0H', 'Q8MVdw', '2022-07-02 11:33:22');
-insert into user (mail, username, password, salt, create_time) values ('spedrazzio5@kickstarter.com', 'mmeugenso5', 'VXt1DEqN', 'rQNBtVxg', '2021-11-11 15:03:49');
-insert into user (mail, username, password, salt, create_time) values ('bpurkisso6@unblog.fr', 'mrummero6', 'xAb3aeG', 'Cz5PVedGA0z', '2022-08-28 23:05:45');
-insert into user (mail, username, password, salt, create_time) values ('mduncano7@geocities.jp', 'skittelo7', 'D4ekWP', 'Ihzhed', '2022-08-08 00:57:28');
-insert into user (mail, username, password, salt, create_time) values ('gschimanno8@mashable.com', 'acasino8', 'bPPkQnKpD', 'bYrfNDIBBS', '2021-12-06 12:44:18');
-insert into user (mail, username, password, salt, create_time) values ('agisborneo9@godaddy.com', 'llangmuiro9', 'GKjqoj', 'xNggimGgeFnI', '2022-08-04 05:55:02');
-insert into user (mail, username, password, salt, create_time) values ('hbradnickoa@scribd.com', 'rfiggeroa', 'msyT2dSvfc8', '01uGVjDbwr', '2022-02-24 02:46:08');
-insert into user (mail, username, password, salt, create_time) values ('ecuellob@ihg.com', 'jdreyeob', 'svtSCxxWr1m', 'BkjqdKCyRf', '2022-08-10 22:09:23');
-insert into user (mail, username, password, salt, create_time) values ('jabbattoc@mozilla.org', 'reckoc', 'BGlWk8nNmg', 'wWYlWjiqFwuc', '2022-03-01 10:14:12');
-insert into user (mail, username, password, salt, create_time) values ('gkeningleyod@godaddy.com', 'rarborod', 'K66kNZAkr9', 'e2LBQQH', '2021-12-10 18:24:39');
-insert into user (mail, username, password, salt, create_time) values ('agravettoe@newsvine.com', 'pcarineoe', 'IH1YUzshyZ', 'Pk4eKRCIl', '2022-06-19 22:58:36');
-insert into user (mail, username, password, salt, create_time) values ('bivanchovof@cornell.edu', 'nbendinof', '8Xn2InbV', 'cj7HRjjg', '2022-03-13 09:18:37');
-insert into user (mail, username, password, salt, create_time) values ('dschopsog@wiley.com', 'clengletog', 'BypDG7YiN2sR', 'PJZbBw', '2022-02-15 22:36:41');
-insert into user (mail, username, password, salt, create_time) values ('gpoxsonoh@sohu.com', 'mcallanoh', 'n96oByISPLpw', '5Dvh1XYqs', '2022-09-21 22:19:14');
-insert into user (mail, username, password, salt, create_time) values ('bcohalanoi@g.co', 'gcappoi', 'lPdzygW6R2A', 'B0XT7SLDHZuM', '2022-10-12 02:51:29');
-insert into user (mail, username, password, salt, create_time) values ('rgurysoj@dion.ne.jp', 'dmosdelloj', 'AmCIiYT', 'Dor4JyTT8h', '2022-04-20 20:16:38');
-insert into user (mail, username, password, salt, create_time) values ('fphippok@nymag.com', 'efrowok', '3qqZJ3j', 'HOPQyetdRx', '2022-03-22 08:09:25');
-insert into user (mail, username, password, salt, create_time) values ('rlevineol@ustream.tv', 'mpawelecol', 'FST5AeC5P', 'ndGLtFf', '2022-07-02 20:57:35');
-insert into user (mail, username, password, salt, create_time) values ('elawesom@google.fr', 'bcurrorom', 'kNZCsXa97', '0gc9mnDO', '2022-01-13 22:57:23');
-insert into user (mail, username, password, salt, create_time) values ('jbaulcombeon@163.com', 'pbriggdaleon', 'NKPEjooXU6AZ', 'jbMd4u', '2022-08-31 20:27:36');
-insert into user (mail, username, password, salt, create_time) values ('kbonannooo@umich.edu', 'hgatecliffoo', 'ki7f3B', 'XHJdH4', '2022-10-30 16:22:14');
-insert into user (mail, username, password, salt, create_time) values ('dsibthorpop@storify.com', 'mlukashop', 'm3ONd01XWu', 'ONLycn3fb1', '2022-08-29 02:23:41');
-insert into user (mail, username, password, salt, create_time) values ('hpembertonoq@craigslist.org', 'skillockoq', 'dFTNPztTj', '48TgkXAlTZX', '2021-12-08 23:11:00');
-insert into user (mail, username, password, salt, create_time) values ('sphythonor@globo.com', 'kantoniouor', '3LlbPclwsOg', 'H4JIm3iu', '2021-11-27 00:47:49');
-insert into user (mail, username, password, salt, create_time) values ('acoxos@cargocollective.com', 'cdeathridgeos', 'MjejSud6BW', 'XQPxqi', '2022-05-28 05:40:37');
-insert into user (mail, username, password, salt, create_time) values ('nsymonesot@tuttocitta.it', 'cprovenot', 'cExOpuYYHF', 'TGpYTj', '2021-12-09 17:48:38');
-insert into user (mail, username, password, salt, create_time) values ('lblankmanou@arizona.edu', 'earnoudou', 'O3daukHd7O', 'UNXS1kz22', '2022-09-25 11:29:46');
-insert into user (mail, username, password, salt, create_time) values ('pgaiterov@google.fr', 'hsmytheov', 'G9WY4uH1Pf7', '2YUz15DJRYo', '2022-02-26 16:47:09');
-insert into user (mail, username, password, salt, create_time) values ('krudeyeardow@a8.net', 'hashborneow', 'FlPCkyD2SXW', 'FN7TdRGg4DA', '2022-09-08 17:33:47');
-insert into user (mail, username, password, salt, create_time) values ('dacoryox@weibo.com', 'vbirchnerox', 'z2IGGuQH', 'sYjNWair5P', '2022-07-01 04:09:07');
-insert into user (mail, username, password, salt, create_time) values ('ebenedicteoy@amazon.co.uk', 'icasserlyoy', '6JoOcyjtL3V', 'YQJsr8YBMsOF', '2021-11-21 00:02:40');
-insert into user (mail, username, password, salt, create_time) values ('esaladineoz@is.gd', 'avankeevoz', 'EiUNUpYO6VUB', 'zPZsuRBWbl', '2022-08-19 02:48:18');
-insert into user (mail, username, password, salt, create_time) values ('cdeekesp0@businessinsider.com', 'afullerp0', 'uykvi9CkJ', 'w1GthkK', '2022-02-18 19:12:26');
-insert into user (mail, username, password, salt, create_time) values ('dderhamp1@bluehost.com', 'egaythwaitep1', 'LRMSxR', 'aewydWmr6ZFO', '2022-04-01 03:43:47');
-insert into user (mail, username, password, salt, create_time) values ('bdutsonp2@go.com', 'tgresp2', 'SFIx9MHHKnm9', 'pOhsTIzfm', '2022-03-18 12:36:14');
-insert into user (mail, username, password, salt, create_time) values ('sshorthillp3@toplist.cz', 'mriccop3', 'GhKEYA', 'v3eyj1Eg', '2022-01-14 01:14:30');
-insert into user (mail, username, password, salt, create_time) values ('akopacekp4@imdb.com', 'edruelp4', 'RO9sL0', 'Ba8vNzoyU', '2022-10-20 06:42:58');
-insert into user (mail, username, password, salt, create_time) values ('hrableyp5@sohu.com', 'cdudleyp5', 'PV2xOY', 'g7bm3dofI', '2022-05-28 16:59:19');
-insert into user (mail, username, password, salt, create_time) values ('dhrishanokp6@theglobeandmail.com', 'bgowdridgep6', 'oS7lc47', 'ow5boR', '2022-01-29 00:28:20');
-insert into user (mail, username, password, salt, create_time) values ('mdavidovicip7@mit.edu', 'idarreep7', 'syeC7hUQz', 'JoozJdmmJ', '2021-11-17 20:07:59');
-insert into user (mail, username, password, salt, create_time) values ('bgiacomuccip8@t-online.de', 'jbeekmanp8', 'RCrTnPdLx9Pd', 'IKYCrN', '2022-08-04 22:54:57');
-insert into user (mail, username, password, salt, create_time) values ('ishannahanp9@amazon.de', 'olanferp9', 'xvhiGO98j', 'Llnzbg', '2021-12-19 00:13:10');
-insert into user (mail, username, password, salt, create_time) values ('vlebrumpa@oracle.com', 'bcarberrypa', 'l5qUy1RC', 'jFxdceY', '2022-09-19 16:11:56');
-insert into user (mail, username, password, salt, create_time) values ('ayushkovpb@netscape.com', 'fswiggerpb', 'emc1XHTFHB', 'XH6TNeddC', '2022-01-06 11:17:27');
-insert into user (mail, username, password, salt, create_time) values ('cvillaretpc@google.com', 'csharplesspc', 'rThgVU4pfVgR', 'SflIVfut', '2022-05-08 22:29:04');
-insert into user (mail, username, password, salt, create_time) values ('mderrickpd@dot.gov', 'aduckinfieldpd', '1dFV00yt0rms', 'DNgAdshTtH', '2022-09-06 11:54:33');
-insert into user (mail, username, password, salt, create_time) values ('jchestertonpe@fda.gov', 'emacafeepe', '8dMnIdf5', 'y5eriAW4', '2022-08-18 06:40:16');
-insert into user (mail, username, password, salt, create_time) values ('lmeinpf@state.gov', 'kungarettipf', 'ndwk75u6Va', 'w4qPjYtHFrs', '2022-09-07 22:20:11');
-insert into user (mail, username, password, salt, create_time) values ('ctyronepg@scribd.com', 'cdigiacomettinopg', 'JAICsFAZw', 'DNp9Wji', '2022-04-30 23:54:46');
-insert into user (mail, username, password, salt, create_time) values ('sjolinph@google.com.br', 'kpadgintonph', '681wfOd', 'e62YgmHt', '2021-12-25 13:44:55');
-insert into user (mail, username, password, salt, create_time) values ('mjedrykapi@gizmodo.com', 'mkinmanpi', 't2KGL8Zx', 'DEsNzpnZv', '2022-05-14 00:26:11');
-insert into user (mail, username, password, salt, create_time) values ('rrubinowitchpj@phoca.cz', 'iblasdalepj', 'RbMrX9cM', 'vCHowb', '2021-11-25 09:45:23');
-insert into user (mail, username, password, salt, create_time) values ('mleaveypk@creativecommons.org', 'hlevispk', 'lt3a35S34k', 'SI4zn9Ivc166', '2021-12-19 19:10:11');
-insert into user (mail, username, password, salt, create_time) values ('bkisbeypl@qq.com', 'ebyrespl', 'BSegeBfpRSX', 'KgQESYS3U', '2022-06-25 09:58:43');
-insert into user (mail, username, password, salt, create_time) values ('kridleypm@cmu.edu', 'soddeypm', 'elYHdt', 'dHiupIwkHMdV', '2022-02-19 06:54:50');
-insert into user (mail, username, password, salt, create_time) values ('jlavispn@ifeng.com', 'bbleythinpn', 'd2WTWj', 'IGut5b97J', '2022-03-30 20:19:05');
-insert into user (mail, username, password, salt, create_time) values ('edonisthorpepo@intel.com', 'hstooderspo', '9tlOQG9', 'TniPEMwUERa', '2022-07-28 17:01:38');
-insert into user (mail, username, password, salt, create_time) values ('erootespp@redcross.org', 'etristrampp', '2r9VzYpfl1', 'iAJvWpM2m8', '2022-06-03 19:39:45');
-insert into user (mail, username, password, salt, create_time) values ('ddorypq@yelp.com', 'sparadycepq', 'gAzLSCGT', '7fmmtWfp', '2022-02-27 16:18:40');
-insert into user (mail, username, password, salt, create_time) values ('pbimspr@umn.edu', 'tdousepr', 'gtJABvFD9k', '7M2zpWINXV', '2021-11-16 03:14:20');
-insert into user (mail, username, password, salt, create_time) values ('jlingardps@unicef.org', 'lbargps', 'QGeJ5BdiRH41', 'YpU40rDt6Bm', '2022-09-30 10:27:22');
-insert into user (mail, username, password, salt, create_time) values ('soxerpt@nih.gov', 'nerikssonpt', 'o9cjjpE0Scz', 'fuLQRUaIvZB', '2022-06-27 00:08:55');
-insert into user (mail, username, password, salt, create_time) values ('vhacquardpu@unc.edu', 'ecollatonpu', 'XOIPQ3vibp', 'SH5sNQList', '2022-01-17 07:34:03');
-insert into user (mail, username, password, salt, create_time) values ('amawmanpv@hatena.ne.jp', 'aphilippartpv', 'BSs1R0ecjA8M', '2jGUMUxvcq', '2022-05-04 05:46:49');
-insert into user (mail, username, password, salt, create_time) values ('slatekpw@netlog.com', 'gelsmorpw', 'wLQY1xRuU', 'qE690Uej', '2022-03-23 18:55:04');
-insert into user (mail, username, password, salt, create_time) values ('chebditchpx@unicef.org', 'nmcneelypx', 'DYG7BONXjA', '89Jco1qFYzp', '2022-07-22 20:16:44');
-insert into user (mail, username, password, salt, create_time) values ('nmounsiepy@tuttocitta.it', 'dolehanepy', '1yYhrx8C3Q', 'mv6yaQbk', '2022-07-05 19:06:55');
-insert into user (mail, username, password, salt, create_time) values ('kscolapz@sbwire.com', 'cjankowskipz', 'gFY04HYFjKAe', '7nmmVg6uu', '2022-04-07 07:17:34');
-insert into user (mail, username, password, salt, create_time) values ('hlimeburnerq0@sciencedirect.com', 'spreshousq0', 'BXDekaa0CCn', '3Zf6C73IeI97', '2022-07-19 01:14:50');
-insert into user (mail, username, password, salt, create_time) values ('kfavelq1@theatlantic.com', 'tmourtonq1', '8V0pPUQes', 'OBb3h9', '2022-04-07 09:25:24');
-insert into user (mail, username, password, salt, create_time) values ('lsobczakq2@photobucket.com', 'mbuddockq2', '4RQAU8c52', 'iVaImjmO', '2022-01-11 19:19:11');
-insert into user (mail, username, password, salt, create_time) values ('cbaslerq3@163.com', 'mcawtheraq3', 'KxgBT7mHiT', 'PG2QzchWm', '2022-07-03 00:34:44');
-insert into user (mail, username, password, salt, create_time) values ('cmendenhallq4@answers.com', 'iglennq4', 'WBno7Antmu0', 'Y39lS9', '2022-08-29 02:39:20');
-insert into user (mail, username, password, salt, create_time) values ('eorteauq5@zdnet.com', 'nbotwrightq5', 'XPEUQ9', 'HScrUl8', '2022-05-26 06:29:03');
-insert into user (mail, username, password, salt, create_time) values ('lbirkq6@cbslocal.com', 'ctullethq6', 'XBWHtp1n', 'Zvs8iM', '2022-03-14 19:03:26');
-insert into user (mail, username, password, salt, create_time) values ('ssentanceq7@shop-pro.jp', 'bochiltreeq7', '7fV4KxpL', '616azqqr', '2022-04-17 04:37:51');
-insert into user (mail, username, password, salt, create_time) values ('fjoeq8@foxnews.com', 'maitkinq8', 'FPfUH69L', 'nxFCGqje3S', '2022-09-11 09:03:33');
-insert into user (mail, username, password, salt, create_time) values ('ztingleq9@webmd.com', 'rchiechioq9', 'z7ZwHgdHzX8', '3h3Lt3ALeAIo', '2022-08-28 02:09:08');
-insert into user (mail, username, password, salt, create_time) values ('calnerqa@hibu.com', 'nrowberryqa', 'hen9qf5SIG', 'LUI5m9Pl1lY8', '2022-10-12 11:13:44');
-insert into user (mail, username, password, salt, create_time) values ('bbearneqb@aboutads.info', 'mantoszczykqb', 'Rr4WLP0', 'c1V34P', '2021-12-26 10:46:16');
-insert into user (mail, username, password, salt, create_time) values ('rminkinqc@mysql.com', 'ppirsonqc', 'yS7QHodQCy', 'BmqnPw2pme', '2022-06-20 02:17:48');
-insert into user (mail, username, password, salt, create_time) values ('ffoulserqd@nymag.com', 'mlovegroveqd', 'KxhdPvJRcAz', 'nnLxNVsr2N', '2022-03-20 20:28:32');
-insert into user (mail, username, password, salt, create_time) values ('dsheweryqe@com.com', 'mpetticrewqe', 'xq6UxxGltI', 'yQcd90Thpwau', '2022-08-26 21:21:37');
-insert into user (mail, username, password, salt, create_time) values ('risgarqf@xrea.com', 'mdunnionqf', 'cVCOcn5mlCl8', 'Pz98feO', '2022-08-16 21:29:38');
-insert into user (mail, username, password, salt, create_time) values ('rshottonqg@google.co.uk', 'jallkinsqg', 'Vdd9qhLzm8sT', 'swcahIp', '2022-01-31 01:16:41');
-insert into user (mail, username, password, salt, create_time) values ('lfidgeonqh@skyrock.com', 'ahareqh', 'ywWZ3yZWjYf', 'VDU9Ym1', '2021-11-14 23:05:56');
-insert into user (mail, username, password, salt, create_time) values ('ljostanqi@cafepress.com', 'vtamlettqi', '66CcLBhZPJt', '7L4C4oF', '2022-07-10 19:17:34');
-insert into user (mail, username, password, salt, create_time) values ('ncarmichaelqj@istockphoto.com', 'agriffittsqj', 'kHLHMogGdNUb', 'WMwanM', '2022-06-23 02:30:29');
-insert into user (mail, username, password, salt, create_time) values ('ddadyqk@topsy.com', 'wchmarnyqk', 'jIv7Ltoiam67', 'qQBZczJ9B15', '2022-09-11 09:04:00');
-insert into user (mail, username, password, salt, create_time) values ('ebyforthql@mysql.com', 'dmulhallql', 'YQcFbDjc', '82prYA55w', '2022-08-17 05:17:35');
-insert into user (mail, username, password, salt, create_time) values ('rbeachamqm@privacy.gov.au', 'tgilberthorpeqm', 'q5jHRwKCEeQg', 'zx3pT1vO2', '2021-11-06 21:09:47');
-insert into user (mail, username, password, salt, create_time) values ('ojaffraqn@mac.com', 'glaunderqn', 'pFwSpVN5n8', '3xKhZQ0fC', '2022-05-01 06:08:51');
-insert into user (mail, username, password, salt, create_time) values ('cmitchensonqo@paginegialle.it', 'smcilwrickqo', 'tEAWGBuYX', 'e0QOf03Q', '2022-08-04 05:08:30');
-insert into user (mail, username, password, salt, create_time) values ('kmuffqp@instagram.com', 'sparcellsqp', 'ZLmJizkbH7Nd', 'lQxBziDZCMOA', '2022-03-18 20:02:21');
-insert into user (mail, username, password, salt, create_time) values ('tfultonqq@cnet.com', 'npaneqq', '7J7omAjlWEZK', 'RjAgBHtbQ', '2022-01-24 20:07:57');
-insert into user (mail, username, password, salt, create_time) values ('rommundsenqr@tiny.cc', 'inorganqr', 'TT7rGTGGtC', 'dc9AO9Ks9agf', '2022-08-27 22:48:36');
-insert into user (mail, username, password, salt, create_time) values ('sborgnolqs@ox.ac.uk', 'slarsqs', 'dyo9p7nTQ', 'M6RHS6KJ0k9', '2022-06-29 08:16:59');
-insert into user (mail, username, password, salt, create_time) values ('rphilsonqt@ucsd.edu', 'pwillattqt', 'zDxF8cb0', 'GkDT0Qh3aol', '2021-12-26 10:42:23');
-insert into user (mail, username, password, salt, create_time) values ('jmerryqu@wiley.com', 'rtregonaqu', 'hxsBGFDCsS3', '1pV944P8EG', '2022-10-11 17:16:20');
-insert into user (mail, username, password, salt, create_time) values ('bfogdenqv@angelfire.com', 'sblackshawqv', 'n3pyZVKa', 'K6ewXj', '2021-12-14 15:18:12');
-insert into user (mail, username, password, salt, create_time) values ('dreyneqw@yahoo.co.jp', 'kspelsburyqw', 'wLnYwgTqe4YW', 'z4R3pip', '2022-02-22 07:59:28');
-insert into user (mail, username, password, salt, create_time) values ('cdensonqx@deviantart.com', 'rderrettqx', 'jo3DX9v9I', '7eICaF', '2022-01-05 22:24:30');
-insert into user (mail, username, password, salt, create_time) values ('nansillqy@sina.com.cn', 'crollandqy', 'uJVm5JsqeCZ', 'WYZ4RPAk2', '2022-03-19 17:15:53');
-insert into user (mail, username, password, salt, create_time) values ('hjouandetqz@tinyurl.com', 'gotteyqz', 'SndOyuShSivO', 'Tz1Qhei', '2022-02-27 17:02:16');
-insert into user (mail, username, password, salt, create_time) values ('mfishwickr0@miitbeian.gov.cn', 'gglackenr0', 'X8ZimsQ6rXcw', 'lwJnT07cPG', '2022-06-02 12:31:11');
-insert into user (mail, username, password, salt, create_time) values ('jnormadellr1@examiner.com', 'ghedgemanr1', 'T61ZH1N5mtL', '9Yyi4G5x', '2022-08-02 16:55:51');
-insert into user (mail, username, password, salt, create_time) values ('jwarlandr2@yellowbook.com', 'cfaker2', 'qwFt2gNq2n6', 'cMIkVpd0N', '2022-01-28 15:50:51');
-insert into user (mail, username, password, salt, create_time) values ('dpetracekr3@wordpress.com', 'jkearnr3', '2sWJWQFs5I', 'o7CUeMruO', '2022-03-18 18:58:45');
-insert into user (mail, username, password, salt, create_time) values ('dmundwellr4@a8.net', 'dtamlettr4', 'RqiyvDQe', '0uKDLd', '2022-01-10 10:23:50');
-insert into user (mail, username, password, salt, create_time) values ('mboomr5@ucsd.edu', 'ccausbyr5', 'ZLJ824avaYl', 'k84OzxKW5K', '2022-08-05 03:28:46');
-insert into user (mail, username, password, salt, create_time) values ('itraser6@sbwire.com', 'gkingabyr6', 'x9z5aQs', 'AgE83IoZ', '2022-05-21 07:11:36');
-insert into user (mail, username, password, salt, create_time) values ('jaubinr7@ted.com', 'rharroldr7', 'Df4A8rjHk', 'wlLFMK2', '2022-08-17 21:31:42');
-insert into user (mail, username, password, salt, create_time) values ('fyounger8@dropbox.com', 'dbissr8', 'JlgSR7hjU', 'sK5zFC', '2022-04-06 09:12:52');
-insert into user (mail, username, password, salt, create_time) values ('nsmallsmanr9@slideshare.net', 'ybenardeaur9', 'IrE6Uy5KQ7D', '5hSrz0R6O', '2022-08-19 03:06:04');
-insert into user (mail, username, password, salt, create_time) values ('kcalleyra@hp.com', 'bklossra', '0qkK716', 'a7NqUN8', '2022-08-16 23:30:07');
-insert into user (mail, username, password, salt, create_time) values ('tmasterrb@amazonaws.com', 'aromerilrb', 'ln01oCyeaRW', 'crleQmQ', '2022-02-08 23:14:49');
-insert into user (mail, username, password, salt, create_time) values ('jphilippaultrc@salon.com', 'lmccambridgerc', 'EXAxWTySL2p', '0RbBP4o4', '2022-02-02 21:44:10');
-insert into user (mail, username, password, salt, create_time) values ('pbuckleerd@slate.com', 'scornilsrd', 'Xi4wcGqxvH', '1atUQZy67Qr', '2022-04-13 09:09:28');
-insert into user (mail, username, password, salt, create_time) values ('cfincherre@jalbum.net', 'iskadere', 'KiKn3yNVfnzY', 'Axk9DHoq', '2022-05-24 04:36:52');
-insert into user (mail, username, password, salt, create_time) values ('jhynsonrf@godaddy.com', 'eswatheridgerf', 'Iwkg5bDL9oIN', 'rhjPm17', '2022-05-06 18:55:49');
-insert into user (mail, username, password, salt, create_time) values ('rfallowfieldrg@domainmarket.com', 'stockellrg', 'aSnd6jgzVmx', 'EZP4M8h7U6EE', '2022-02-07 12:09:19');
-insert into user (mail, username, password, salt, create_time) values ('ysinnierh@gmpg.org', 'afourmanrh', 'x8zcRp', '08g0PSLOU2R', '2022-04-16 19:44:49');
-insert into user (mail, username, password, salt, create_time) values ('loffellri@youtube.com', 'lgoodrickeri', 'bwxfRNowiuV', 'ABEsZI', '2022-03-22 02:52:45');
-insert into user (mail, username, password, salt, create_time) values ('mskrzynskirj@goo.gl', 'fkitcherrj', 'RvRRnqhlY7u', 'LpEpDM9S', '2022-11-03 11:20:41');
-insert into user (mail, username, password, salt, create_time) values ('kwindaybankrk@ehow.com', 'aclymork', 'BpG1NtwrP', 'Sbk7rIPpTPN', '2022-05-10 10:32:08');
-insert into user (mail, username, password, salt, create_time) values ('atenderrl@tamu.edu', 'ctullochrl', 'mnxi3wRz', 'rJaMX7v10KXy', '2022-11-05 01:49:45');
-insert into user (mail, username, password, salt, create_time) values ('spieruccirm@adobe.com', 'jquinneyrm', 'rKWItdm', 'uhXB4xs', '2022-04-18 21:46:53');
-insert into user (mail, username, password, salt, create_time) values ('fpalombirn@java.com', 'wkollaschrn', 'szycpv', 'AurPuoMZ', '2022-01-20 10:42:05');
-insert into user (mail, username, password, salt, create_time) values ('eslidesro@multiply.com', 'khabbeshawro', '8xdnRtKj', 'rYaJj83qTn', '2021-11-09 22:33:36');
-insert into user (mail, username, password, salt, create_time) values ('aseftonrp@usa.gov', 'czanicchirp', 'nChPNbXhig', '07WZav5f6q5', '2022-04-22 04:18:34');
-insert into user (mail, username, password, salt, create_time) values ('msmicklerq@dailymotion.com', 'rnyssensrq', '5UUdtYJYr', 'Lq9vr6B', '2022-07-19 23:11:17');
-insert into user (mail, username, password, salt, create_time) values ('ecasselyrr@dailymotion.com', 'rblunderfieldrr', 'Ager6iW', 'cOOUeXzYBFVl', '2022-02-09 00:26:21');
--- set all data password=123
-update user set password = 'ezaMtmis9Zsa0Oh2Iz9LVSoz1YAW1BRqkxWMJdQF51g=', salt = 'KMM4bCeFoclT5bwN4fhfhSlczLC8sREjFo1xyE6Y7DI=';
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- Add down migration script here
+ALTER TABLE `group` DROP COLUMN `dissolve_time`;
\ No newline at end of file
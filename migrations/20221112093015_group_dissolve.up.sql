@@ -0,0 +1,3 @@
+-- Add up migration script here
+ALTER TABLE `group`
+    ADD COLUMN `dissolve_time` datetime DEFAULT NULL COMMENT '解散时间, 为空表示群未解散';
\ No newline at end of file
@@ -0,0 +1,5 @@
+-- Add down migration script here
+ALTER TABLE `user`
+    DROP COLUMN `display_name`,
+    DROP COLUMN `avatar`,
+    DROP COLUMN `bio`;
\ No newline at end of file
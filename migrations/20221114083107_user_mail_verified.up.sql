@@ -0,0 +1,4 @@
+-- Add up migration script here
+-- 修改邮箱后需要重新验证
+ALTER TABLE `user`
+    ADD COLUMN `mail_verified` tinyint(1) NOT NULL DEFAULT 0 COMMENT '邮箱是否已验证';
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- Add up migration script here
+ALTER TABLE `user`
+    ADD COLUMN `last_seen` datetime DEFAULT NULL COMMENT '最后在线时间';
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- Add down migration script here
+drop table `read_cursor`;
\ No newline at end of file
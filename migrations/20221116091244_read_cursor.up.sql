@@ -0,0 +1,11 @@
+-- Add up migration script here
+-- 每个用户在每个会话中已读到的消息
+CREATE TABLE `read_cursor` (
+  `uid` bigint unsigned NOT NULL COMMENT '用户id',
+  `receiver_type` tinyint NOT NULL COMMENT '会话类型 0 用户 1 群',
+  `receiver_id` bigint unsigned NOT NULL COMMENT '私聊为对方用户id, 群聊为群id',
+  `last_read_mid` bigint unsigned NOT NULL DEFAULT 0 COMMENT '最后已读的消息id',
+  `update_time` datetime NOT NULL COMMENT '更新时间',
+  PRIMARY KEY (`uid`, `receiver_type`, `receiver_id`),
+  KEY `conversation_idx` (`receiver_type`, `receiver_id`, `last_read_mid`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;
\ No newline at end of file
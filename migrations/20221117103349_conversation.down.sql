@@ -0,0 +1,3 @@
+-- Add down migration script here
+drop table `conversation`;
+ALTER TABLE `group` DROP COLUMN `last_mid`;
\ No newline at end of file
@@ -0,0 +1,28 @@
+-- Add up migration script here
+-- 私聊会话, 每个参与者各有一行, 由发送消息时维护
+CREATE TABLE `conversation` (
+  `uid` bigint unsigned NOT NULL COMMENT '用户id',
+  `peer_uid` bigint unsigned NOT NULL COMMENT '对方用户id',
+  `last_mid` bigint unsigned NOT NULL COMMENT '最后一条消息id',
+  `update_time` datetime NOT NULL COMMENT '最后活跃时间',
+  PRIMARY KEY (`uid`, `peer_uid`),
+  KEY `uid_update_time_idx` (`uid`, `update_time`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;
+
+-- 群聊会话的最后一条消息记录在群上, 避免每条消息更新所有成员
+ALTER TABLE `group`
+    ADD COLUMN `last_mid` bigint unsigned DEFAULT NULL COMMENT '最后一条消息id';
+
+-- 根据已有的消息生成会话
+INSERT INTO `conversation` (`uid`, `peer_uid`, `last_mid`, `update_time`)
+SELECT t.uid, t.peer_uid, max(t.mid), coalesce(max(t.create_time), now()) FROM (
+    SELECT sender_uid AS uid, receiver_id AS peer_uid, mid, create_time FROM `message` WHERE receiver_type = 0
+    UNION ALL
+    SELECT receiver_id AS uid, sender_uid AS peer_uid, mid, create_time FROM `message` WHERE receiver_type = 0
+) t
+WHERE t.uid IS NOT NULL AND t.peer_uid IS NOT NULL
+GROUP BY t.uid, t.peer_uid;
+
+UPDATE `group` g SET g.last_mid = (
+    SELECT max(m.mid) FROM `message` m WHERE m.receiver_type = 1 AND m.receiver_id = g.gid
+);
\ No newline at end of file
@@ -0,0 +1,5 @@
+-- Add down migration script here
+drop table `message_edit`;
+ALTER TABLE `message`
+    DROP COLUMN `edit_time`,
+    DROP COLUMN `recall_time`;
\ No newline at end of file
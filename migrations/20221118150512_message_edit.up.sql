@@ -0,0 +1,14 @@
+-- Add up migration script here
+ALTER TABLE `message`
+    ADD COLUMN `edit_time` datetime DEFAULT NULL COMMENT '最后编辑时间',
+    ADD COLUMN `recall_time` datetime DEFAULT NULL COMMENT '撤回时间, 撤回后内容被清空';
+
+-- 消息被编辑前的内容
+CREATE TABLE `message_edit` (
+  `id` bigint unsigned NOT NULL AUTO_INCREMENT COMMENT '主键',
+  `mid` bigint unsigned NOT NULL COMMENT '消息id',
+  `content` text NOT NULL COMMENT '编辑前的内容',
+  `edit_time` datetime NOT NULL COMMENT '编辑时间',
+  PRIMARY KEY (`id`),
+  KEY `mid_idx` (`mid`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;
\ No newline at end of file
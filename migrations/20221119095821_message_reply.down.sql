@@ -0,0 +1,4 @@
+-- Add down migration script here
+ALTER TABLE `message`
+    DROP KEY `reply_to_mid_idx`,
+    DROP COLUMN `reply_to_mid`;
\ No newline at end of file
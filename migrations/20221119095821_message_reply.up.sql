@@ -0,0 +1,4 @@
+-- Add up migration script here
+ALTER TABLE `message`
+    ADD COLUMN `reply_to_mid` bigint unsigned DEFAULT NULL COMMENT '回复的消息id',
+    ADD KEY `reply_to_mid_idx` (`reply_to_mid`);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- Add down migration script here
+drop table `reaction`;
\ No newline at end of file
@@ -0,0 +1,8 @@
+-- Add up migration script here
+CREATE TABLE `reaction` (
+  `mid` bigint unsigned NOT NULL COMMENT '消息id',
+  `uid` bigint unsigned NOT NULL COMMENT '用户id',
+  `emoji` varchar(16) CHARACTER SET utf8mb4 COLLATE utf8mb4_bin NOT NULL COMMENT '表情',
+  `create_time` datetime NOT NULL COMMENT '创建时间',
+  PRIMARY KEY (`mid`, `uid`, `emoji`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;
\ No newline at end of file
@@ -1,2 +1,2 @@
 -- Add down migration script here
-truncate user;
\ No newline at end of file
+drop table `mention`;
\ No newline at end of file
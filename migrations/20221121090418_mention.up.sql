@@ -0,0 +1,8 @@
+-- Add up migration script here
+CREATE TABLE `mention` (
+  `mid` bigint unsigned NOT NULL COMMENT '消息id',
+  `uid` bigint unsigned NOT NULL COMMENT '被@的用户id, 0表示@所有人',
+  `gid` bigint unsigned NOT NULL COMMENT '群id',
+  PRIMARY KEY (`mid`, `uid`),
+  KEY `idx_gid_uid` (`gid`, `uid`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;
@@ -0,0 +1,4 @@
+-- Add down migration script here
+ALTER TABLE `message`
+    DROP COLUMN `kind`,
+    DROP COLUMN `payload`;
\ No newline at end of file
@@ -0,0 +1,4 @@
+-- Add up migration script here
+ALTER TABLE `message`
+    ADD COLUMN `kind` tinyint NOT NULL DEFAULT 0 COMMENT '消息类型 0 文本 1 图片 2 文件 3 语音 4 位置 5 名片 6 系统',
+    ADD COLUMN `payload` json DEFAULT NULL COMMENT '非文本消息的结构化内容';
\ No newline at end of file
@@ -0,0 +1,4 @@
+-- Add down migration script here
+drop table `file_share`;
+drop table `file`;
+drop table `file_blob`;
\ No newline at end of file
@@ -0,0 +1,24 @@
+-- Add up migration script here
+CREATE TABLE `file_blob` (
+  `sha256` char(64) NOT NULL COMMENT '文件内容的sha256, 也是存储的key',
+  `size` bigint unsigned NOT NULL COMMENT '文件大小',
+  `content_type` varchar(255) NOT NULL COMMENT '根据内容识别的文件类型',
+  `create_time` datetime NOT NULL COMMENT '创建时间',
+  PRIMARY KEY (`sha256`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;
+
+CREATE TABLE `file` (
+  `fid` bigint unsigned NOT NULL AUTO_INCREMENT COMMENT '文件id',
+  `sha256` char(64) NOT NULL COMMENT '文件内容的sha256',
+  `name` varchar(255) NOT NULL COMMENT '文件名',
+  `uploader` bigint unsigned NOT NULL COMMENT '上传者id',
+  `create_time` datetime NOT NULL COMMENT '上传时间',
+  PRIMARY KEY (`fid`),
+  KEY `sha256_idx` (`sha256`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;
+
+CREATE TABLE `file_share` (
+  `fid` bigint unsigned NOT NULL COMMENT '文件id',
+  `mid` bigint unsigned NOT NULL COMMENT '分享文件的消息id',
+  PRIMARY KEY (`fid`, `mid`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;
\ No newline at end of file
@@ -0,0 +1,6 @@
+-- Add down migration script here
+drop table `thumbnail`;
+ALTER TABLE `file_blob`
+    DROP COLUMN `width`,
+    DROP COLUMN `height`,
+    DROP COLUMN `blurhash`;
\ No newline at end of file
@@ -0,0 +1,14 @@
+-- Add up migration script here
+ALTER TABLE `file_blob`
+    ADD COLUMN `width` int unsigned DEFAULT NULL COMMENT '图片宽度',
+    ADD COLUMN `height` int unsigned DEFAULT NULL COMMENT '图片高度',
+    ADD COLUMN `blurhash` varchar(64) DEFAULT NULL COMMENT '图片加载前显示的占位';
+
+CREATE TABLE `thumbnail` (
+  `sha256` char(64) NOT NULL COMMENT '原图的sha256',
+  `max_side` int unsigned NOT NULL COMMENT '缩略图的最大边长',
+  `thumbnail_sha256` char(64) NOT NULL COMMENT '缩略图的sha256',
+  `width` int unsigned NOT NULL COMMENT '缩略图宽度',
+  `height` int unsigned NOT NULL COMMENT '缩略图高度',
+  PRIMARY KEY (`sha256`, `max_side`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;
\ No newline at end of file
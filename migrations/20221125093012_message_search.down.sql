@@ -0,0 +1,2 @@
+-- Add down migration script here
+ALTER TABLE `message` DROP INDEX `content_ft`;
\ No newline at end of file
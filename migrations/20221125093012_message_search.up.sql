@@ -0,0 +1,2 @@
+-- Add up migration script here
+ALTER TABLE `message` ADD FULLTEXT INDEX `content_ft` (`content`) WITH PARSER ngram;
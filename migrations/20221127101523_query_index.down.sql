@@ -0,0 +1,7 @@
+-- Add down migration script here
+ALTER TABLE `friend` DROP KEY `friend_id_idx`;
+ALTER TABLE `group` DROP KEY `owner_idx`;
+ALTER TABLE `group_user` DROP KEY `gid_status_idx`;
+ALTER TABLE `message`
+    DROP KEY `sender_idx`,
+    DROP KEY `receiver_idx`;
\ No newline at end of file
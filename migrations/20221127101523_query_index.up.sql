@@ -0,0 +1,7 @@
+-- Add up migration script here
+ALTER TABLE `message`
+    ADD KEY `receiver_idx` (`receiver_type`, `receiver_id`, `mid`),
+    ADD KEY `sender_idx` (`sender_uid`, `mid`);
+ALTER TABLE `group_user` ADD KEY `gid_status_idx` (`gid`, `status`);
+ALTER TABLE `group` ADD KEY `owner_idx` (`owner`);
+ALTER TABLE `friend` ADD KEY `friend_id_idx` (`friend_id`);
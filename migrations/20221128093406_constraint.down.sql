@@ -0,0 +1,48 @@
+-- Add down migration script here
+ALTER TABLE `conversation` DROP FOREIGN KEY `conversation_uid_fk`;
+ALTER TABLE `read_cursor`
+    DROP FOREIGN KEY `read_cursor_uid_fk`,
+    DROP FOREIGN KEY `read_cursor_receiver_type_fk`;
+ALTER TABLE `thumbnail` DROP FOREIGN KEY `thumbnail_sha256_fk`;
+ALTER TABLE `file_share`
+    DROP FOREIGN KEY `file_share_fid_fk`,
+    DROP FOREIGN KEY `file_share_mid_fk`;
+ALTER TABLE `file_share` DROP KEY `file_share_mid_fk`;
+ALTER TABLE `file` DROP FOREIGN KEY `file_sha256_fk`;
+ALTER TABLE `mention`
+    DROP FOREIGN KEY `mention_mid_fk`,
+    DROP FOREIGN KEY `mention_gid_fk`;
+ALTER TABLE `reaction`
+    DROP FOREIGN KEY `reaction_mid_fk`,
+    DROP FOREIGN KEY `reaction_uid_fk`;
+ALTER TABLE `reaction` DROP KEY `reaction_uid_fk`;
+ALTER TABLE `message_edit` DROP FOREIGN KEY `message_edit_mid_fk`;
+ALTER TABLE `friend`
+    DROP FOREIGN KEY `friend_uid_fk`,
+    DROP FOREIGN KEY `friend_friend_id_fk`,
+    DROP FOREIGN KEY `friend_status_fk`;
+ALTER TABLE `friend` DROP KEY `friend_status_fk`;
+ALTER TABLE `group_user`
+    DROP FOREIGN KEY `group_user_gid_fk`,
+    DROP FOREIGN KEY `group_user_uid_fk`,
+    DROP FOREIGN KEY `group_user_status_fk`;
+ALTER TABLE `group_user`
+    DROP KEY `group_user_status_fk`,
+    MODIFY `gid` bigint unsigned DEFAULT NULL COMMENT '群id',
+    MODIFY `uid` bigint unsigned DEFAULT NULL COMMENT '用户id',
+    MODIFY `status` tinyint DEFAULT NULL COMMENT '0 群主 1 管理员 2 普通成员';
+ALTER TABLE `message`
+    DROP FOREIGN KEY `message_receiver_type_fk`,
+    DROP FOREIGN KEY `message_kind_fk`,
+    DROP FOREIGN KEY `message_reply_to_mid_fk`;
+ALTER TABLE `message`
+    DROP KEY `message_kind_fk`,
+    MODIFY `content` text COMMENT '消息内容',
+    MODIFY `sender_uid` bigint unsigned DEFAULT NULL COMMENT '发送者id',
+    MODIFY `receiver_type` tinyint DEFAULT NULL COMMENT '接收对象类型 0 群 1 用户 ',
+    MODIFY `receiver_id` bigint unsigned DEFAULT NULL COMMENT '接受对象id',
+    MODIFY `create_time` datetime DEFAULT NULL COMMENT '创建时间';
+drop table `message_kind`;
+drop table `group_status`;
+drop table `friend_status`;
+drop table `receiver_type`;
\ No newline at end of file
@@ -0,0 +1,110 @@
+-- Add up migration script here
+-- 枚举字段的取值, name 与代码中枚举的成员名一致
+CREATE TABLE `receiver_type` (
+  `id` tinyint NOT NULL COMMENT '取值',
+  `name` varchar(16) NOT NULL COMMENT '名称',
+  PRIMARY KEY (`id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;
+INSERT INTO `receiver_type` (`id`, `name`) VALUES (0, 'User'), (1, 'Group');
+
+CREATE TABLE `friend_status` (
+  `id` tinyint NOT NULL COMMENT '取值',
+  `name` varchar(16) NOT NULL COMMENT '名称',
+  PRIMARY KEY (`id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;
+INSERT INTO `friend_status` (`id`, `name`) VALUES (0, 'Pending'), (1, 'Agree'), (2, 'Refused');
+
+CREATE TABLE `group_status` (
+  `id` tinyint NOT NULL COMMENT '取值',
+  `name` varchar(16) NOT NULL COMMENT '名称',
+  PRIMARY KEY (`id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;
+INSERT INTO `group_status` (`id`, `name`) VALUES (0, 'Pending'), (1, 'Agree'), (2, 'Refused');
+
+CREATE TABLE `message_kind` (
+  `id` tinyint NOT NULL COMMENT '取值',
+  `name` varchar(16) NOT NULL COMMENT '名称',
+  PRIMARY KEY (`id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci;
+INSERT INTO `message_kind` (`id`, `name`) VALUES (0, 'Text'), (1, 'Image'), (2, 'File'), (3, 'Voice'), (4, 'Location'), (5, 'Card'), (6, 'System');
+
+-- 清理不满足约束的数据, 没有接收对象的消息无法投递, 没有发送者的消息视为已注销用户发送
+DELETE FROM `message` WHERE `receiver_type` IS NULL OR `receiver_id` IS NULL
+    OR `receiver_type` NOT IN (SELECT `id` FROM `receiver_type`)
+    OR `kind` NOT IN (SELECT `id` FROM `message_kind`);
+UPDATE `message` SET `sender_uid` = 0 WHERE `sender_uid` IS NULL;
+UPDATE `message` SET `content` = '' WHERE `content` IS NULL;
+UPDATE `message` SET `create_time` = now() WHERE `create_time` IS NULL;
+UPDATE `message` SET `reply_to_mid` = NULL
+    WHERE `reply_to_mid` NOT IN (SELECT `mid` FROM (SELECT `mid` FROM `message`) t);
+DELETE FROM `message_edit` WHERE `mid` NOT IN (SELECT `mid` FROM `message`);
+DELETE FROM `reaction` WHERE `mid` NOT IN (SELECT `mid` FROM `message`)
+    OR `uid` NOT IN (SELECT `uid` FROM `user`);
+DELETE FROM `mention` WHERE `mid` NOT IN (SELECT `mid` FROM `message`)
+    OR `gid` NOT IN (SELECT `gid` FROM `group`);
+DELETE FROM `file` WHERE `sha256` NOT IN (SELECT `sha256` FROM `file_blob`);
+DELETE FROM `file_share` WHERE `fid` NOT IN (SELECT `fid` FROM `file`)
+    OR `mid` NOT IN (SELECT `mid` FROM `message`);
+DELETE FROM `thumbnail` WHERE `sha256` NOT IN (SELECT `sha256` FROM `file_blob`);
+DELETE FROM `friend` WHERE `uid` NOT IN (SELECT `uid` FROM `user`)
+    OR `friend_id` NOT IN (SELECT `uid` FROM `user`)
+    OR `status` NOT IN (SELECT `id` FROM `friend_status`);
+DELETE FROM `group_user` WHERE `gid` IS NULL OR `uid` IS NULL OR `status` IS NULL
+    OR `gid` NOT IN (SELECT `gid` FROM `group`)
+    OR `uid` NOT IN (SELECT `uid` FROM `user`)
+    OR `status` NOT IN (SELECT `id` FROM `group_status`);
+DELETE FROM `read_cursor` WHERE `uid` NOT IN (SELECT `uid` FROM `user`)
+    OR `receiver_type` NOT IN (SELECT `id` FROM `receiver_type`);
+DELETE FROM `conversation` WHERE `uid` NOT IN (SELECT `uid` FROM `user`);
+
+-- sender_uid 和 mention.uid 可能为0, group.owner 在用户注销后仍保留在已解散的群上, 所以没有外键
+ALTER TABLE `message`
+    MODIFY `content` text NOT NULL COMMENT '消息内容',
+    MODIFY `sender_uid` bigint unsigned NOT NULL COMMENT '发送者id, 0 表示已注销的用户',
+    MODIFY `receiver_type` tinyint NOT NULL COMMENT '接收对象类型 0 用户 1 群',
+    MODIFY `receiver_id` bigint unsigned NOT NULL COMMENT '接收对象id',
+    MODIFY `create_time` datetime NOT NULL COMMENT '创建时间',
+    ADD CONSTRAINT `message_receiver_type_fk` FOREIGN KEY (`receiver_type`) REFERENCES `receiver_type` (`id`),
+    ADD CONSTRAINT `message_kind_fk` FOREIGN KEY (`kind`) REFERENCES `message_kind` (`id`),
+    ADD CONSTRAINT `message_reply_to_mid_fk` FOREIGN KEY (`reply_to_mid`) REFERENCES `message` (`mid`) ON DELETE SET NULL;
+
+ALTER TABLE `group_user`
+    MODIFY `gid` bigint unsigned NOT NULL COMMENT '群id',
+    MODIFY `uid` bigint unsigned NOT NULL COMMENT '用户id',
+    MODIFY `status` tinyint NOT NULL COMMENT '0 等待处理中, 1 同意, 2 拒绝',
+    ADD CONSTRAINT `group_user_gid_fk` FOREIGN KEY (`gid`) REFERENCES `group` (`gid`),
+    ADD CONSTRAINT `group_user_uid_fk` FOREIGN KEY (`uid`) REFERENCES `user` (`uid`) ON DELETE CASCADE,
+    ADD CONSTRAINT `group_user_status_fk` FOREIGN KEY (`status`) REFERENCES `group_status` (`id`);
+
+ALTER TABLE `friend`
+    ADD CONSTRAINT `friend_uid_fk` FOREIGN KEY (`uid`) REFERENCES `user` (`uid`) ON DELETE CASCADE,
+    ADD CONSTRAINT `friend_friend_id_fk` FOREIGN KEY (`friend_id`) REFERENCES `user` (`uid`) ON DELETE CASCADE,
+    ADD CONSTRAINT `friend_status_fk` FOREIGN KEY (`status`) REFERENCES `friend_status` (`id`);
+
+ALTER TABLE `message_edit`
+    ADD CONSTRAINT `message_edit_mid_fk` FOREIGN KEY (`mid`) REFERENCES `message` (`mid`) ON DELETE CASCADE;
+
+ALTER TABLE `reaction`
+    ADD CONSTRAINT `reaction_mid_fk` FOREIGN KEY (`mid`) REFERENCES `message` (`mid`) ON DELETE CASCADE,
+    ADD CONSTRAINT `reaction_uid_fk` FOREIGN KEY (`uid`) REFERENCES `user` (`uid`) ON DELETE CASCADE;
+
+ALTER TABLE `mention`
+    ADD CONSTRAINT `mention_mid_fk` FOREIGN KEY (`mid`) REFERENCES `message` (`mid`) ON DELETE CASCADE,
+    ADD CONSTRAINT `mention_gid_fk` FOREIGN KEY (`gid`) REFERENCES `group` (`gid`);
+
+ALTER TABLE `file`
+    ADD CONSTRAINT `file_sha256_fk` FOREIGN KEY (`sha256`) REFERENCES `file_blob` (`sha256`);
+
+ALTER TABLE `file_share`
+    ADD CONSTRAINT `file_share_fid_fk` FOREIGN KEY (`fid`) REFERENCES `file` (`fid`) ON DELETE CASCADE,
+    ADD CONSTRAINT `file_share_mid_fk` FOREIGN KEY (`mid`) REFERENCES `message` (`mid`) ON DELETE CASCADE;
+
+ALTER TABLE `thumbnail`
+    ADD CONSTRAINT `thumbnail_sha256_fk` FOREIGN KEY (`sha256`) REFERENCES `file_blob` (`sha256`);
+
+ALTER TABLE `read_cursor`
+    ADD CONSTRAINT `read_cursor_uid_fk` FOREIGN KEY (`uid`) REFERENCES `user` (`uid`) ON DELETE CASCADE,
+    ADD CONSTRAINT `read_cursor_receiver_type_fk` FOREIGN KEY (`receiver_type`) REFERENCES `receiver_type` (`id`);
+
+ALTER TABLE `conversation`
+    ADD CONSTRAINT `conversation_uid_fk` FOREIGN KEY (`uid`) REFERENCES `user` (`uid`) ON DELETE CASCADE;
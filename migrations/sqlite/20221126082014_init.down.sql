@@ -0,0 +1,15 @@
+-- Add down migration script here
+drop table `thumbnail`;
+drop table `file_share`;
+drop table `file`;
+drop table `file_blob`;
+drop table `mention`;
+drop table `reaction`;
+drop table `conversation`;
+drop table `read_cursor`;
+drop table `message_edit`;
+drop table `message`;
+drop table `group_user`;
+drop table `group`;
+drop table `friend`;
+drop table `user`;
\ No newline at end of file
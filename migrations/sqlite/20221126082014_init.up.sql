@@ -0,0 +1,142 @@
+-- Add up migration script here
+-- the same schema as the mysql migrations, the ids are signed because sqlite has no unsigned integer
+CREATE TABLE `user` (
+  `uid` integer PRIMARY KEY AUTOINCREMENT,
+  `username` text NOT NULL COLLATE NOCASE UNIQUE,
+  `mail` text NOT NULL COLLATE NOCASE UNIQUE,
+  `password` text NOT NULL,
+  `salt` text NOT NULL,
+  `create_time` datetime NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `display_name` text DEFAULT NULL,
+  `avatar` text DEFAULT NULL,
+  `bio` text DEFAULT NULL,
+  -- 修改邮箱后需要重新验证
+  `mail_verified` boolean NOT NULL DEFAULT 0,
+  `last_seen` datetime DEFAULT NULL
+);
+
+CREATE TABLE `friend` (
+  `fid` integer PRIMARY KEY AUTOINCREMENT,
+  `uid` integer NOT NULL,
+  `friend_id` integer NOT NULL,
+  -- 0 等待处理中, 1 同意, 2 拒绝
+  `status` integer NOT NULL,
+  UNIQUE (`uid`, `friend_id`)
+);
+
+CREATE TABLE `group` (
+  `gid` integer PRIMARY KEY AUTOINCREMENT,
+  `name` text NOT NULL,
+  `create_time` datetime NOT NULL,
+  `owner` integer NOT NULL,
+  -- 为空表示群未解散
+  `dissolve_time` datetime DEFAULT NULL,
+  `last_mid` integer DEFAULT NULL
+);
+
+CREATE TABLE `group_user` (
+  `guid` integer PRIMARY KEY AUTOINCREMENT,
+  `gid` integer NOT NULL,
+  `uid` integer NOT NULL,
+  -- 0 等待处理中, 1 同意, 2 拒绝
+  `status` integer NOT NULL,
+  UNIQUE (`uid`, `gid`)
+);
+
+CREATE TABLE `message` (
+  `mid` integer PRIMARY KEY AUTOINCREMENT,
+  `content` text NOT NULL DEFAULT '',
+  `sender_uid` integer NOT NULL,
+  -- 0 用户 1 群
+  `receiver_type` integer NOT NULL,
+  `receiver_id` integer NOT NULL,
+  `create_time` datetime NOT NULL,
+  `edit_time` datetime DEFAULT NULL,
+  -- 撤回后内容被清空
+  `recall_time` datetime DEFAULT NULL,
+  `reply_to_mid` integer DEFAULT NULL,
+  -- 0 文本 1 图片 2 文件 3 语音 4 位置 5 名片 6 系统
+  `kind` integer NOT NULL DEFAULT 0,
+  -- 非文本消息的结构化内容, json
+  `payload` text DEFAULT NULL
+);
+CREATE INDEX `message_receiver_idx` ON `message` (`receiver_type`, `receiver_id`, `mid`);
+CREATE INDEX `message_sender_idx` ON `message` (`sender_uid`);
+CREATE INDEX `message_reply_to_mid_idx` ON `message` (`reply_to_mid`);
+
+CREATE TABLE `message_edit` (
+  `id` integer PRIMARY KEY AUTOINCREMENT,
+  `mid` integer NOT NULL,
+  `content` text NOT NULL,
+  `edit_time` datetime NOT NULL
+);
+CREATE INDEX `message_edit_mid_idx` ON `message_edit` (`mid`);
+
+CREATE TABLE `read_cursor` (
+  `uid` integer NOT NULL,
+  `receiver_type` integer NOT NULL,
+  `receiver_id` integer NOT NULL,
+  `last_read_mid` integer NOT NULL DEFAULT 0,
+  `update_time` datetime NOT NULL,
+  PRIMARY KEY (`uid`, `receiver_type`, `receiver_id`)
+);
+CREATE INDEX `read_cursor_conversation_idx` ON `read_cursor` (`receiver_type`, `receiver_id`, `last_read_mid`);
+
+CREATE TABLE `conversation` (
+  `uid` integer NOT NULL,
+  `peer_uid` integer NOT NULL,
+  `last_mid` integer NOT NULL,
+  `update_time` datetime NOT NULL,
+  PRIMARY KEY (`uid`, `peer_uid`)
+);
+
+CREATE TABLE `reaction` (
+  `mid` integer NOT NULL,
+  `uid` integer NOT NULL,
+  `emoji` text NOT NULL,
+  `create_time` datetime NOT NULL,
+  PRIMARY KEY (`mid`, `uid`, `emoji`)
+);
+
+CREATE TABLE `mention` (
+  `mid` integer NOT NULL,
+  -- 0 表示@所有人
+  `uid` integer NOT NULL,
+  `gid` integer NOT NULL,
+  PRIMARY KEY (`mid`, `uid`)
+);
+CREATE INDEX `mention_gid_uid_idx` ON `mention` (`gid`, `uid`);
+
+CREATE TABLE `file_blob` (
+  `sha256` text PRIMARY KEY NOT NULL,
+  `size` integer NOT NULL,
+  `content_type` text NOT NULL,
+  `create_time` datetime NOT NULL,
+  `width` integer DEFAULT NULL,
+  `height` integer DEFAULT NULL,
+  `blurhash` text DEFAULT NULL
+);
+
+CREATE TABLE `file` (
+  `fid` integer PRIMARY KEY AUTOINCREMENT,
+  `sha256` text NOT NULL,
+  `name` text NOT NULL,
+  `uploader` integer NOT NULL,
+  `create_time` datetime NOT NULL
+);
+CREATE INDEX `file_sha256_idx` ON `file` (`sha256`);
+
+CREATE TABLE `file_share` (
+  `fid` integer NOT NULL,
+  `mid` integer NOT NULL,
+  PRIMARY KEY (`fid`, `mid`)
+);
+
+CREATE TABLE `thumbnail` (
+  `sha256` text NOT NULL,
+  `max_side` integer NOT NULL,
+  `thumbnail_sha256` text NOT NULL,
+  `width` integer NOT NULL,
+  `height` integer NOT NULL,
+  PRIMARY KEY (`sha256`, `max_side`)
+);
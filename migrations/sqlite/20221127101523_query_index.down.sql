@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP INDEX `friend_friend_id_idx`;
+DROP INDEX `group_owner_idx`;
+DROP INDEX `group_user_gid_status_idx`;
\ No newline at end of file
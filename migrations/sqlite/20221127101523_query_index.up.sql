@@ -0,0 +1,4 @@
+-- Add up migration script here
+CREATE INDEX `group_user_gid_status_idx` ON `group_user` (`gid`, `status`);
+CREATE INDEX `group_owner_idx` ON `group` (`owner`);
+CREATE INDEX `friend_friend_id_idx` ON `friend` (`friend_id`);
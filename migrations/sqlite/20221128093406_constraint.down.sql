@@ -0,0 +1,146 @@
+-- Add down migration script here
+CREATE TABLE `conversation_old` (
+  `uid` integer NOT NULL,
+  `peer_uid` integer NOT NULL,
+  `last_mid` integer NOT NULL,
+  `update_time` datetime NOT NULL,
+  PRIMARY KEY (`uid`, `peer_uid`)
+);
+INSERT INTO `conversation_old` SELECT * FROM `conversation`;
+DROP TABLE `conversation`;
+ALTER TABLE `conversation_old` RENAME TO `conversation`;
+
+CREATE TABLE `read_cursor_old` (
+  `uid` integer NOT NULL,
+  `receiver_type` integer NOT NULL,
+  `receiver_id` integer NOT NULL,
+  `last_read_mid` integer NOT NULL DEFAULT 0,
+  `update_time` datetime NOT NULL,
+  PRIMARY KEY (`uid`, `receiver_type`, `receiver_id`)
+);
+INSERT INTO `read_cursor_old` SELECT * FROM `read_cursor`;
+DROP TABLE `read_cursor`;
+ALTER TABLE `read_cursor_old` RENAME TO `read_cursor`;
+CREATE INDEX `read_cursor_conversation_idx` ON `read_cursor` (`receiver_type`, `receiver_id`, `last_read_mid`);
+
+CREATE TABLE `thumbnail_old` (
+  `sha256` text NOT NULL,
+  `max_side` integer NOT NULL,
+  `thumbnail_sha256` text NOT NULL,
+  `width` integer NOT NULL,
+  `height` integer NOT NULL,
+  PRIMARY KEY (`sha256`, `max_side`)
+);
+INSERT INTO `thumbnail_old` SELECT * FROM `thumbnail`;
+DROP TABLE `thumbnail`;
+ALTER TABLE `thumbnail_old` RENAME TO `thumbnail`;
+
+CREATE TABLE `file_share_old` (
+  `fid` integer NOT NULL,
+  `mid` integer NOT NULL,
+  PRIMARY KEY (`fid`, `mid`)
+);
+INSERT INTO `file_share_old` SELECT * FROM `file_share`;
+DROP TABLE `file_share`;
+ALTER TABLE `file_share_old` RENAME TO `file_share`;
+
+CREATE TABLE `mention_old` (
+  `mid` integer NOT NULL,
+  -- 0 表示@所有人
+  `uid` integer NOT NULL,
+  `gid` integer NOT NULL,
+  PRIMARY KEY (`mid`, `uid`)
+);
+INSERT INTO `mention_old` SELECT * FROM `mention`;
+DROP TABLE `mention`;
+ALTER TABLE `mention_old` RENAME TO `mention`;
+CREATE INDEX `mention_gid_uid_idx` ON `mention` (`gid`, `uid`);
+
+CREATE TABLE `reaction_old` (
+  `mid` integer NOT NULL,
+  `uid` integer NOT NULL,
+  `emoji` text NOT NULL,
+  `create_time` datetime NOT NULL,
+  PRIMARY KEY (`mid`, `uid`, `emoji`)
+);
+INSERT INTO `reaction_old` SELECT * FROM `reaction`;
+DROP TABLE `reaction`;
+ALTER TABLE `reaction_old` RENAME TO `reaction`;
+
+CREATE TABLE `message_edit_old` (
+  `id` integer PRIMARY KEY AUTOINCREMENT,
+  `mid` integer NOT NULL,
+  `content` text NOT NULL,
+  `edit_time` datetime NOT NULL
+);
+INSERT INTO `message_edit_old` SELECT * FROM `message_edit`;
+DROP TABLE `message_edit`;
+ALTER TABLE `message_edit_old` RENAME TO `message_edit`;
+CREATE INDEX `message_edit_mid_idx` ON `message_edit` (`mid`);
+
+CREATE TABLE `group_user_old` (
+  `guid` integer PRIMARY KEY AUTOINCREMENT,
+  `gid` integer NOT NULL,
+  `uid` integer NOT NULL,
+  -- 0 等待处理中, 1 同意, 2 拒绝
+  `status` integer NOT NULL,
+  UNIQUE (`uid`, `gid`)
+);
+INSERT INTO `group_user_old` SELECT * FROM `group_user`;
+DROP TABLE `group_user`;
+ALTER TABLE `group_user_old` RENAME TO `group_user`;
+CREATE INDEX `group_user_gid_status_idx` ON `group_user` (`gid`, `status`);
+
+CREATE TABLE `friend_old` (
+  `fid` integer PRIMARY KEY AUTOINCREMENT,
+  `uid` integer NOT NULL,
+  `friend_id` integer NOT NULL,
+  -- 0 等待处理中, 1 同意, 2 拒绝
+  `status` integer NOT NULL,
+  UNIQUE (`uid`, `friend_id`)
+);
+INSERT INTO `friend_old` SELECT * FROM `friend`;
+DROP TABLE `friend`;
+ALTER TABLE `friend_old` RENAME TO `friend`;
+CREATE INDEX `friend_friend_id_idx` ON `friend` (`friend_id`);
+
+CREATE TABLE `file_old` (
+  `fid` integer PRIMARY KEY AUTOINCREMENT,
+  `sha256` text NOT NULL,
+  `name` text NOT NULL,
+  `uploader` integer NOT NULL,
+  `create_time` datetime NOT NULL
+);
+INSERT INTO `file_old` SELECT * FROM `file`;
+DROP TABLE `file`;
+ALTER TABLE `file_old` RENAME TO `file`;
+CREATE INDEX `file_sha256_idx` ON `file` (`sha256`);
+
+CREATE TABLE `message_old` (
+  `mid` integer PRIMARY KEY AUTOINCREMENT,
+  `content` text NOT NULL DEFAULT '',
+  `sender_uid` integer NOT NULL,
+  -- 0 用户 1 群
+  `receiver_type` integer NOT NULL,
+  `receiver_id` integer NOT NULL,
+  `create_time` datetime NOT NULL,
+  `edit_time` datetime DEFAULT NULL,
+  -- 撤回后内容被清空
+  `recall_time` datetime DEFAULT NULL,
+  `reply_to_mid` integer DEFAULT NULL,
+  -- 0 文本 1 图片 2 文件 3 语音 4 位置 5 名片 6 系统
+  `kind` integer NOT NULL DEFAULT 0,
+  -- 非文本消息的结构化内容, json
+  `payload` text DEFAULT NULL
+);
+INSERT INTO `message_old` SELECT * FROM `message`;
+DROP TABLE `message`;
+ALTER TABLE `message_old` RENAME TO `message`;
+CREATE INDEX `message_receiver_idx` ON `message` (`receiver_type`, `receiver_id`, `mid`);
+CREATE INDEX `message_sender_idx` ON `message` (`sender_uid`);
+CREATE INDEX `message_reply_to_mid_idx` ON `message` (`reply_to_mid`);
+
+DROP TABLE `message_kind`;
+DROP TABLE `group_status`;
+DROP TABLE `friend_status`;
+DROP TABLE `receiver_type`;
\ No newline at end of file
@@ -0,0 +1,185 @@
+-- Add up migration script here
+-- 枚举字段的取值, name 与代码中枚举的成员名一致
+CREATE TABLE `receiver_type` (
+  `id` integer PRIMARY KEY NOT NULL,
+  `name` text NOT NULL
+);
+INSERT INTO `receiver_type` (`id`, `name`) VALUES (0, 'User'), (1, 'Group');
+
+CREATE TABLE `friend_status` (
+  `id` integer PRIMARY KEY NOT NULL,
+  `name` text NOT NULL
+);
+INSERT INTO `friend_status` (`id`, `name`) VALUES (0, 'Pending'), (1, 'Agree'), (2, 'Refused');
+
+CREATE TABLE `group_status` (
+  `id` integer PRIMARY KEY NOT NULL,
+  `name` text NOT NULL
+);
+INSERT INTO `group_status` (`id`, `name`) VALUES (0, 'Pending'), (1, 'Agree'), (2, 'Refused');
+
+CREATE TABLE `message_kind` (
+  `id` integer PRIMARY KEY NOT NULL,
+  `name` text NOT NULL
+);
+INSERT INTO `message_kind` (`id`, `name`) VALUES (0, 'Text'), (1, 'Image'), (2, 'File'), (3, 'Voice'), (4, 'Location'), (5, 'Card'), (6, 'System');
+
+-- sqlite can't add a foreign key to a existing table, so the tables are rebuilt.
+-- the referenced tables are rebuilt first, a dropped table must not be referenced by others
+CREATE TABLE `message_new` (
+  `mid` integer PRIMARY KEY AUTOINCREMENT,
+  `content` text NOT NULL DEFAULT '',
+  -- 0 表示已注销的用户
+  `sender_uid` integer NOT NULL,
+  -- 0 用户 1 群
+  `receiver_type` integer NOT NULL REFERENCES `receiver_type` (`id`),
+  `receiver_id` integer NOT NULL,
+  `create_time` datetime NOT NULL,
+  `edit_time` datetime DEFAULT NULL,
+  -- 撤回后内容被清空
+  `recall_time` datetime DEFAULT NULL,
+  `reply_to_mid` integer DEFAULT NULL REFERENCES `message_new` (`mid`) ON DELETE SET NULL,
+  -- 0 文本 1 图片 2 文件 3 语音 4 位置 5 名片 6 系统
+  `kind` integer NOT NULL DEFAULT 0 REFERENCES `message_kind` (`id`),
+  -- 非文本消息的结构化内容, json
+  `payload` text DEFAULT NULL
+);
+INSERT INTO `message_new`
+SELECT `mid`, `content`, `sender_uid`, `receiver_type`, `receiver_id`, `create_time`, `edit_time`,
+    `recall_time`, NULL, `kind`, `payload`
+FROM `message`
+WHERE `receiver_type` IN (SELECT `id` FROM `receiver_type`) AND `kind` IN (SELECT `id` FROM `message_kind`);
+UPDATE `message_new` SET `reply_to_mid` = (
+    SELECT m.`reply_to_mid` FROM `message` m
+    WHERE m.`mid` = `message_new`.`mid` AND m.`reply_to_mid` IN (SELECT `mid` FROM `message_new`)
+);
+DROP TABLE `message`;
+ALTER TABLE `message_new` RENAME TO `message`;
+CREATE INDEX `message_receiver_idx` ON `message` (`receiver_type`, `receiver_id`, `mid`);
+CREATE INDEX `message_sender_idx` ON `message` (`sender_uid`);
+CREATE INDEX `message_reply_to_mid_idx` ON `message` (`reply_to_mid`);
+
+CREATE TABLE `file_new` (
+  `fid` integer PRIMARY KEY AUTOINCREMENT,
+  `sha256` text NOT NULL REFERENCES `file_blob` (`sha256`),
+  `name` text NOT NULL,
+  `uploader` integer NOT NULL,
+  `create_time` datetime NOT NULL
+);
+INSERT INTO `file_new` SELECT * FROM `file` WHERE `sha256` IN (SELECT `sha256` FROM `file_blob`);
+DROP TABLE `file`;
+ALTER TABLE `file_new` RENAME TO `file`;
+CREATE INDEX `file_sha256_idx` ON `file` (`sha256`);
+
+CREATE TABLE `friend_new` (
+  `fid` integer PRIMARY KEY AUTOINCREMENT,
+  `uid` integer NOT NULL REFERENCES `user` (`uid`) ON DELETE CASCADE,
+  `friend_id` integer NOT NULL REFERENCES `user` (`uid`) ON DELETE CASCADE,
+  -- 0 等待处理中, 1 同意, 2 拒绝
+  `status` integer NOT NULL REFERENCES `friend_status` (`id`),
+  UNIQUE (`uid`, `friend_id`)
+);
+INSERT INTO `friend_new` SELECT * FROM `friend`
+WHERE `uid` IN (SELECT `uid` FROM `user`) AND `friend_id` IN (SELECT `uid` FROM `user`)
+    AND `status` IN (SELECT `id` FROM `friend_status`);
+DROP TABLE `friend`;
+ALTER TABLE `friend_new` RENAME TO `friend`;
+CREATE INDEX `friend_friend_id_idx` ON `friend` (`friend_id`);
+
+CREATE TABLE `group_user_new` (
+  `guid` integer PRIMARY KEY AUTOINCREMENT,
+  `gid` integer NOT NULL REFERENCES `group` (`gid`),
+  `uid` integer NOT NULL REFERENCES `user` (`uid`) ON DELETE CASCADE,
+  -- 0 等待处理中, 1 同意, 2 拒绝
+  `status` integer NOT NULL REFERENCES `group_status` (`id`),
+  UNIQUE (`uid`, `gid`)
+);
+INSERT INTO `group_user_new` SELECT * FROM `group_user`
+WHERE `gid` IN (SELECT `gid` FROM `group`) AND `uid` IN (SELECT `uid` FROM `user`)
+    AND `status` IN (SELECT `id` FROM `group_status`);
+DROP TABLE `group_user`;
+ALTER TABLE `group_user_new` RENAME TO `group_user`;
+CREATE INDEX `group_user_gid_status_idx` ON `group_user` (`gid`, `status`);
+
+CREATE TABLE `message_edit_new` (
+  `id` integer PRIMARY KEY AUTOINCREMENT,
+  `mid` integer NOT NULL REFERENCES `message` (`mid`) ON DELETE CASCADE,
+  `content` text NOT NULL,
+  `edit_time` datetime NOT NULL
+);
+INSERT INTO `message_edit_new` SELECT * FROM `message_edit` WHERE `mid` IN (SELECT `mid` FROM `message`);
+DROP TABLE `message_edit`;
+ALTER TABLE `message_edit_new` RENAME TO `message_edit`;
+CREATE INDEX `message_edit_mid_idx` ON `message_edit` (`mid`);
+
+CREATE TABLE `reaction_new` (
+  `mid` integer NOT NULL REFERENCES `message` (`mid`) ON DELETE CASCADE,
+  `uid` integer NOT NULL REFERENCES `user` (`uid`) ON DELETE CASCADE,
+  `emoji` text NOT NULL,
+  `create_time` datetime NOT NULL,
+  PRIMARY KEY (`mid`, `uid`, `emoji`)
+);
+INSERT INTO `reaction_new` SELECT * FROM `reaction`
+WHERE `mid` IN (SELECT `mid` FROM `message`) AND `uid` IN (SELECT `uid` FROM `user`);
+DROP TABLE `reaction`;
+ALTER TABLE `reaction_new` RENAME TO `reaction`;
+
+CREATE TABLE `mention_new` (
+  `mid` integer NOT NULL REFERENCES `message` (`mid`) ON DELETE CASCADE,
+  -- 0 表示@所有人
+  `uid` integer NOT NULL,
+  `gid` integer NOT NULL REFERENCES `group` (`gid`),
+  PRIMARY KEY (`mid`, `uid`)
+);
+INSERT INTO `mention_new` SELECT * FROM `mention`
+WHERE `mid` IN (SELECT `mid` FROM `message`) AND `gid` IN (SELECT `gid` FROM `group`);
+DROP TABLE `mention`;
+ALTER TABLE `mention_new` RENAME TO `mention`;
+CREATE INDEX `mention_gid_uid_idx` ON `mention` (`gid`, `uid`);
+
+CREATE TABLE `file_share_new` (
+  `fid` integer NOT NULL REFERENCES `file` (`fid`) ON DELETE CASCADE,
+  `mid` integer NOT NULL REFERENCES `message` (`mid`) ON DELETE CASCADE,
+  PRIMARY KEY (`fid`, `mid`)
+);
+INSERT INTO `file_share_new` SELECT * FROM `file_share`
+WHERE `fid` IN (SELECT `fid` FROM `file`) AND `mid` IN (SELECT `mid` FROM `message`);
+DROP TABLE `file_share`;
+ALTER TABLE `file_share_new` RENAME TO `file_share`;
+
+CREATE TABLE `thumbnail_new` (
+  `sha256` text NOT NULL REFERENCES `file_blob` (`sha256`),
+  `max_side` integer NOT NULL,
+  `thumbnail_sha256` text NOT NULL,
+  `width` integer NOT NULL,
+  `height` integer NOT NULL,
+  PRIMARY KEY (`sha256`, `max_side`)
+);
+INSERT INTO `thumbnail_new` SELECT * FROM `thumbnail` WHERE `sha256` IN (SELECT `sha256` FROM `file_blob`);
+DROP TABLE `thumbnail`;
+ALTER TABLE `thumbnail_new` RENAME TO `thumbnail`;
+
+CREATE TABLE `read_cursor_new` (
+  `uid` integer NOT NULL REFERENCES `user` (`uid`) ON DELETE CASCADE,
+  `receiver_type` integer NOT NULL REFERENCES `receiver_type` (`id`),
+  `receiver_id` integer NOT NULL,
+  `last_read_mid` integer NOT NULL DEFAULT 0,
+  `update_time` datetime NOT NULL,
+  PRIMARY KEY (`uid`, `receiver_type`, `receiver_id`)
+);
+INSERT INTO `read_cursor_new` SELECT * FROM `read_cursor`
+WHERE `uid` IN (SELECT `uid` FROM `user`) AND `receiver_type` IN (SELECT `id` FROM `receiver_type`);
+DROP TABLE `read_cursor`;
+ALTER TABLE `read_cursor_new` RENAME TO `read_cursor`;
+CREATE INDEX `read_cursor_conversation_idx` ON `read_cursor` (`receiver_type`, `receiver_id`, `last_read_mid`);
+
+CREATE TABLE `conversation_new` (
+  `uid` integer NOT NULL REFERENCES `user` (`uid`) ON DELETE CASCADE,
+  `peer_uid` integer NOT NULL,
+  `last_mid` integer NOT NULL,
+  `update_time` datetime NOT NULL,
+  PRIMARY KEY (`uid`, `peer_uid`)
+);
+INSERT INTO `conversation_new` SELECT * FROM `conversation` WHERE `uid` IN (SELECT `uid` FROM `user`);
+DROP TABLE `conversation`;
+ALTER TABLE `conversation_new` RENAME TO `conversation`;
@@ -1,5 +1,3 @@
-use std::option;
-
 use axum::extract::{FromRequest, RequestParts};
 use axum::headers::{authorization::Bearer, Authorization};
 use axum::{async_trait, TypedHeader};
@@ -9,7 +7,9 @@ use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation}
 use serde::{Deserialize, Serialize};
 use tracing::debug;
 
-const SECRET: &'static str = "secret";
+use crate::ApiContext;
+
+const SECRET: &str = "secret";
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct AuthUser {
@@ -20,9 +20,10 @@ pub struct AuthUser {
 }
 
 impl AuthUser {
-    pub fn new(uid: u64, username: String, mail: String) -> Self {
+    /// the token expires after `lifetime`
+    pub fn new(uid: u64, username: String, mail: String, lifetime: Duration) -> Self {
         // get now sec
-        let exp = (Local::now() + Duration::days(3)).timestamp() as usize;
+        let exp = (Local::now() + lifetime).timestamp() as usize;
         AuthUser {
             exp,
             uid,
@@ -39,6 +40,16 @@ impl AuthUser {
         .unwrap()
     }
 
+    /// the tokens of a deleted user are rejected, the uid is never reused.
+    /// it's checked in the storage, so it still works after a restart
+    async fn is_revoked(&self, ctx: &ApiContext) -> Result<bool, Error> {
+        match ctx.storage.users.get_user(self.uid).await {
+            Ok(_) => Ok(false),
+            Err(Error::Sqlx(sqlx::Error::RowNotFound)) => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
+
     fn decode(token: &str) -> Result<Self, Error> {
         debug!(token = token, "parse token");
         decode::<AuthUser>(
@@ -62,12 +73,25 @@ where
     type Rejection = Error;
 
     async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let auth_user = AuthUser::from_token(req).await?;
+        if let Some(ctx) = req.extensions().get::<ApiContext>() {
+            if auth_user.is_revoked(ctx).await? {
+                debug!(uid = auth_user.uid, "the token has been revoked");
+                return Err(Error::Unauthorized);
+            }
+        }
+        Ok(auth_user)
+    }
+}
+
+impl AuthUser {
+    async fn from_token<B: Send>(req: &mut RequestParts<B>) -> Result<Self, Error> {
         let authorization = TypedHeader::<Authorization<Bearer>>::from_request(req)
             .await
             .map_err(|_| Error::Unauthorized);
-        if authorization.is_ok() {
+        if let Ok(authorization) = authorization {
             debug!("get token from header");
-            return AuthUser::decode(authorization.unwrap().token());
+            return AuthUser::decode(authorization.token());
         }
 
         // header have not token, try get token from uri
@@ -78,7 +102,7 @@ where
                 return AuthUser::decode(access_token);
             }
         }
-        return Err(Error::Unauthorized);
+        Err(Error::Unauthorized)
     }
 }
 
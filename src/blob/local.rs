@@ -0,0 +1,83 @@
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use bytes::Bytes;
+use eChat::err::Result;
+use tokio::fs;
+
+use super::{check_key, BlobStore};
+
+/// keep the blobs in the local file system, `<root>/<the first 2 chars of the key>/<key>`
+pub struct LocalStore {
+    root: PathBuf,
+}
+
+impl LocalStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        LocalStore { root: root.into() }
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf> {
+        check_key(key)?;
+        Ok(self.root.join(&key[..2]).join(key))
+    }
+}
+
+#[async_trait]
+impl BlobStore for LocalStore {
+    async fn put(&self, key: &str, bytes: Bytes, _content_type: &str) -> Result<()> {
+        let path = self.path(key)?;
+        let dir = path.parent().expect("the blob path always has a parent");
+        fs::create_dir_all(dir)
+            .await
+            .with_context(|| format!("create dir {:?}", dir))?;
+        // write to a temp file first, so that a half written blob is never read
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, &bytes)
+            .await
+            .with_context(|| format!("write blob {:?}", tmp))?;
+        fs::rename(&tmp, &path)
+            .await
+            .with_context(|| format!("rename blob {:?}", tmp))?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Bytes>> {
+        match fs::read(self.path(key)?).await {
+            Ok(bytes) => Ok(Some(bytes.into())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(anyhow::Error::new(e).context("read blob").into()),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        match fs::remove_file(self.path(key)?).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => {
+                Err(anyhow::Error::new(e).context("delete blob").into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn local_store_should_work() -> Result<()> {
+        let root = std::env::temp_dir().join(format!("echat-blob-{}", std::process::id()));
+        let store = LocalStore::new(&root);
+        let key = "ab01";
+        assert_eq!(store.get(key).await?, None);
+        store.put(key, Bytes::from_static(b"hello"), "text/plain").await?;
+        assert_eq!(store.get(key).await?, Some(Bytes::from_static(b"hello")));
+        store.delete(key).await?;
+        assert_eq!(store.get(key).await?, None);
+        assert!(store.get("../etc").await.is_err());
+        let _ = std::fs::remove_dir_all(root);
+        Ok(())
+    }
+}
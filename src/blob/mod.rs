@@ -0,0 +1,39 @@
+mod local;
+mod s3;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use eChat::err::Result;
+
+use crate::config::{BlobConfig, BlobKind};
+
+pub use local::LocalStore;
+pub use s3::S3Store;
+
+/// where the bytes of the uploaded files are kept, the key is the sha256 of the bytes
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    async fn put(&self, key: &str, bytes: Bytes, content_type: &str) -> Result<()>;
+
+    /// `None` if there is no blob of the key
+    async fn get(&self, key: &str) -> Result<Option<Bytes>>;
+
+    async fn delete(&self, key: &str) -> Result<()>;
+}
+
+pub fn from_config(config: &BlobConfig) -> Result<Arc<dyn BlobStore>> {
+    match config.store {
+        BlobKind::S3 => Ok(Arc::new(S3Store::new(&config.s3)?)),
+        BlobKind::Local => Ok(Arc::new(LocalStore::new(&config.dir))),
+    }
+}
+
+/// the key is used as a file name or a part of the url, only the hex digest is allowed
+fn check_key(key: &str) -> Result<()> {
+    if key.len() < 2 || !key.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(anyhow::anyhow!("invalid blob key: {}", key).into());
+    }
+    Ok(())
+}
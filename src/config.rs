@@ -0,0 +1,485 @@
+//! the settings of the server. they are loaded in layers, a later layer overrides the earlier:
+//! the defaults, the toml file, the env variables (`.env` included) and the command line flags
+
+use std::fs;
+use std::net::SocketAddr;
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use axum::http::HeaderValue;
+use clap::builder::BoolishValueParser;
+use clap::{Parser, ValueEnum};
+use reqwest::Url;
+use serde::Deserialize;
+use tower_http::cors::{AllowOrigin, Any, CorsLayer};
+use tracing::warn;
+
+use crate::modles::message::MessagePolicy;
+
+/// read if `--config` is not given, it's fine that the file doesn't exist
+const DEFAULT_CONFIG_FILE: &str = "echat.toml";
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub server: ServerConfig,
+    pub database: DatabaseConfig,
+    pub auth: AuthConfig,
+    pub message: MessageConfig,
+    pub upload: UploadConfig,
+    pub blob: BlobConfig,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub bind: SocketAddr,
+    /// the origins allowed by cors, `*` allows all of them, none is allowed if it's empty
+    pub cors_origins: Vec<String>,
+    /// how long to wait for the connections to close on shutdown, the rest are dropped
+    pub shutdown_timeout_secs: u64,
+    /// the hint sent to the websocket clients on shutdown
+    pub reconnect_after_secs: u64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseConfig {
+    /// `sqlite:` urls are backed by sqlite, others by mysql
+    pub url: String,
+    /// the default depends on the database
+    pub max_connections: Option<u32>,
+    /// apply the pending migrations
+    pub migrate: bool,
+    /// load the fake users if there is no user yet, it's ignored by the release build
+    pub fixtures: bool,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuthConfig {
+    pub token_lifetime_days: i64,
+    /// the iterations of pbkdf2 when hashing the passwords.
+    /// the iterations are not saved with the password, changing it breaks the existing passwords
+    pub pbkdf2_iterations: NonZeroU32,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MessageConfig {
+    /// the frames waiting to be sent to a websocket
+    pub queue_size: usize,
+    /// what to do when the queue of a slow client is full
+    pub overflow_policy: OverflowPolicy,
+    /// the seconds within which the sender can edit or recall a message
+    pub edit_window_secs: i64,
+    /// what to do with the messages of a deleted user
+    pub deleted_policy: MessagePolicy,
+}
+
+/// the frames are never waited to be queued, a slow client can't block the others
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum OverflowPolicy {
+    /// close the connection, the client loads what it missed after reconnecting
+    Disconnect,
+    /// drop the frame and send `Event::Resync` after the queued ones. only the chat message
+    /// is still saved and can be read from the history, the other events are lost
+    Offline,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UploadConfig {
+    pub max_bytes: usize,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BlobConfig {
+    pub store: BlobKind,
+    /// the dir of the local store
+    pub dir: String,
+    pub s3: S3Config,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum BlobKind {
+    Local,
+    S3,
+}
+
+/// a S3 compatible service, such as minio
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct S3Config {
+    pub endpoint: String,
+    pub bucket: String,
+    pub region: String,
+    pub access_key: String,
+    pub secret_key: String,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        ServerConfig {
+            bind: SocketAddr::from(([0, 0, 0, 0], 8080)),
+            cors_origins: vec!["*".to_string()],
+            shutdown_timeout_secs: 30,
+            reconnect_after_secs: 5,
+        }
+    }
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        DatabaseConfig {
+            url: String::new(),
+            max_connections: None,
+            migrate: true,
+            fixtures: false,
+        }
+    }
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        AuthConfig {
+            token_lifetime_days: 3,
+            pbkdf2_iterations: NonZeroU32::new(100_000).unwrap(),
+        }
+    }
+}
+
+impl Default for MessageConfig {
+    fn default() -> Self {
+        MessageConfig {
+            queue_size: 100,
+            overflow_policy: OverflowPolicy::Disconnect,
+            edit_window_secs: 120,
+            deleted_policy: MessagePolicy::Anonymize,
+        }
+    }
+}
+
+impl Default for UploadConfig {
+    fn default() -> Self {
+        UploadConfig {
+            max_bytes: 20 * 1024 * 1024,
+        }
+    }
+}
+
+impl Default for BlobConfig {
+    fn default() -> Self {
+        BlobConfig {
+            store: BlobKind::Local,
+            dir: "./data/blobs".to_string(),
+            s3: S3Config::default(),
+        }
+    }
+}
+
+impl Default for S3Config {
+    fn default() -> Self {
+        S3Config {
+            endpoint: String::new(),
+            bucket: String::new(),
+            region: "us-east-1".to_string(),
+            access_key: String::new(),
+            secret_key: String::new(),
+        }
+    }
+}
+
+/// every flag can be given by the env variable as well, the flag wins
+#[derive(Parser, Debug, Default)]
+#[command(version, about = "the eChat server")]
+struct Args {
+    /// the toml file of the config [default: echat.toml if it exists]
+    #[arg(short, long, env = "ECHAT_CONFIG")]
+    config: Option<PathBuf>,
+    #[arg(long, env = "BIND_ADDR")]
+    bind: Option<SocketAddr>,
+    /// separated by commas
+    #[arg(long, env = "CORS_ORIGINS", value_delimiter = ',')]
+    cors_origins: Option<Vec<String>>,
+    #[arg(long, env = "SHUTDOWN_TIMEOUT_SECS")]
+    shutdown_timeout_secs: Option<u64>,
+    #[arg(long, env = "RECONNECT_AFTER_SECS")]
+    reconnect_after_secs: Option<u64>,
+    #[arg(long, env = "DATABASE_URL")]
+    database_url: Option<String>,
+    #[arg(long, env = "DATABASE_MAX_CONNECTIONS")]
+    database_max_connections: Option<u32>,
+    #[arg(long, env = "DATABASE_MIGRATE", value_parser = BoolishValueParser::new())]
+    database_migrate: Option<bool>,
+    #[arg(long, env = "DATABASE_FIXTURES", value_parser = BoolishValueParser::new())]
+    database_fixtures: Option<bool>,
+    #[arg(long, env = "TOKEN_LIFETIME_DAYS")]
+    token_lifetime_days: Option<i64>,
+    #[arg(long, env = "PBKDF2_ITERATIONS")]
+    pbkdf2_iterations: Option<NonZeroU32>,
+    #[arg(long, env = "MESSAGE_QUEUE_SIZE")]
+    message_queue_size: Option<usize>,
+    #[arg(long, env = "MESSAGE_OVERFLOW_POLICY")]
+    message_overflow_policy: Option<OverflowPolicy>,
+    #[arg(long, env = "MESSAGE_EDIT_WINDOW_SECS")]
+    message_edit_window_secs: Option<i64>,
+    /// `anonymize` or `remove`
+    #[arg(long, env = "DELETED_MESSAGE_POLICY", value_parser = parse_policy)]
+    deleted_message_policy: Option<MessagePolicy>,
+    #[arg(long, env = "UPLOAD_MAX_BYTES")]
+    upload_max_bytes: Option<usize>,
+    #[arg(long, env = "BLOB_STORE")]
+    blob_store: Option<BlobKind>,
+    #[arg(long, env = "BLOB_DIR")]
+    blob_dir: Option<String>,
+    #[arg(long, env = "S3_ENDPOINT")]
+    s3_endpoint: Option<String>,
+    #[arg(long, env = "S3_BUCKET")]
+    s3_bucket: Option<String>,
+    #[arg(long, env = "S3_REGION")]
+    s3_region: Option<String>,
+    #[arg(long, env = "S3_ACCESS_KEY")]
+    s3_access_key: Option<String>,
+    #[arg(long, env = "S3_SECRET_KEY", hide_env_values = true)]
+    s3_secret_key: Option<String>,
+}
+
+fn parse_policy(policy: &str) -> Result<MessagePolicy, String> {
+    match policy {
+        "anonymize" => Ok(MessagePolicy::Anonymize),
+        "remove" => Ok(MessagePolicy::Remove),
+        _ => Err("expect `anonymize` or `remove`".to_string()),
+    }
+}
+
+/// replace the value if it's given
+fn set<T>(target: &mut T, value: Option<T>) {
+    if let Some(value) = value {
+        *target = value;
+    }
+}
+
+impl Args {
+    fn apply(self, config: &mut Config) {
+        set(&mut config.server.bind, self.bind);
+        set(&mut config.server.cors_origins, self.cors_origins);
+        set(&mut config.server.shutdown_timeout_secs, self.shutdown_timeout_secs);
+        set(&mut config.server.reconnect_after_secs, self.reconnect_after_secs);
+        set(&mut config.database.url, self.database_url);
+        set(
+            &mut config.database.max_connections,
+            self.database_max_connections.map(Some),
+        );
+        set(&mut config.database.migrate, self.database_migrate);
+        set(&mut config.database.fixtures, self.database_fixtures);
+        set(&mut config.auth.token_lifetime_days, self.token_lifetime_days);
+        set(&mut config.auth.pbkdf2_iterations, self.pbkdf2_iterations);
+        set(&mut config.message.queue_size, self.message_queue_size);
+        set(&mut config.message.overflow_policy, self.message_overflow_policy);
+        set(&mut config.message.edit_window_secs, self.message_edit_window_secs);
+        set(&mut config.message.deleted_policy, self.deleted_message_policy);
+        set(&mut config.upload.max_bytes, self.upload_max_bytes);
+        set(&mut config.blob.store, self.blob_store);
+        set(&mut config.blob.dir, self.blob_dir);
+        set(&mut config.blob.s3.endpoint, self.s3_endpoint);
+        set(&mut config.blob.s3.bucket, self.s3_bucket);
+        set(&mut config.blob.s3.region, self.s3_region);
+        set(&mut config.blob.s3.access_key, self.s3_access_key);
+        set(&mut config.blob.s3.secret_key, self.s3_secret_key);
+    }
+}
+
+impl Config {
+    /// read the flags of the process, `.env` should have been loaded
+    pub fn load() -> anyhow::Result<Config> {
+        Config::from_args(Args::parse())
+    }
+
+    fn from_args(args: Args) -> anyhow::Result<Config> {
+        let mut config = match &args.config {
+            Some(path) => Config::from_file(path)?,
+            None if Path::new(DEFAULT_CONFIG_FILE).exists() => {
+                Config::from_file(Path::new(DEFAULT_CONFIG_FILE))?
+            }
+            None => Config::default(),
+        };
+        args.apply(&mut config);
+        if config.database.fixtures && !cfg!(debug_assertions) {
+            warn!("the fixtures are only loaded by the debug build");
+            config.database.fixtures = false;
+        }
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn from_file(path: &Path) -> anyhow::Result<Config> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed to read the config {}", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("invalid config {}", path.display()))
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.database.url.is_empty() {
+            bail!("please set `database.url` or DATABASE_URL");
+        }
+        if self.database.max_connections == Some(0) {
+            bail!("`database.max_connections` must be positive");
+        }
+        if self.auth.token_lifetime_days <= 0 {
+            bail!("`auth.token_lifetime_days` must be positive");
+        }
+        if self.message.queue_size == 0 {
+            bail!("`message.queue_size` must be positive");
+        }
+        if self.message.edit_window_secs < 0 {
+            bail!("`message.edit_window_secs` can't be negative");
+        }
+        if self.upload.max_bytes == 0 {
+            bail!("`upload.max_bytes` must be positive");
+        }
+        let origins = &self.server.cors_origins;
+        if origins.len() > 1 && origins.iter().any(|origin| origin == "*") {
+            bail!("`server.cors_origins` can't mix `*` with other origins");
+        }
+        for origin in origins.iter().filter(|origin| *origin != "*") {
+            Url::parse(origin)
+                .ok()
+                .filter(|url| url.has_host())
+                .with_context(|| format!("invalid cors origin: {}", origin))?;
+        }
+        if self.blob.store == BlobKind::S3 {
+            let s3 = &self.blob.s3;
+            for (key, value) in [
+                ("endpoint", &s3.endpoint),
+                ("bucket", &s3.bucket),
+                ("access_key", &s3.access_key),
+                ("secret_key", &s3.secret_key),
+            ] {
+                if value.is_empty() {
+                    bail!("please set `blob.s3.{}` for the s3 store", key);
+                }
+            }
+            Url::parse(&s3.endpoint).context("invalid `blob.s3.endpoint`")?;
+        }
+        Ok(())
+    }
+}
+
+impl ServerConfig {
+    pub fn cors(&self) -> CorsLayer {
+        if self.cors_origins.iter().any(|origin| origin == "*") {
+            return CorsLayer::permissive();
+        }
+        // validated by `Config::validate`
+        let origins = self
+            .cors_origins
+            .iter()
+            .map(|origin| HeaderValue::from_str(origin.trim_end_matches('/')).unwrap());
+        CorsLayer::new()
+            .allow_origin(AllowOrigin::list(origins))
+            .allow_methods(Any)
+            .allow_headers(Any)
+            // the token is returned in the header of the login
+            .expose_headers(Any)
+    }
+
+    pub fn shutdown_timeout(&self) -> Duration {
+        Duration::from_secs(self.shutdown_timeout_secs)
+    }
+}
+
+impl AuthConfig {
+    pub fn token_lifetime(&self) -> chrono::Duration {
+        chrono::Duration::days(self.token_lifetime_days)
+    }
+}
+
+impl MessageConfig {
+    pub fn edit_window(&self) -> chrono::Duration {
+        chrono::Duration::seconds(self.edit_window_secs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn flag_should_override_file() {
+        let file = std::env::temp_dir().join(format!("echat-config-{}.toml", std::process::id()));
+        fs::write(
+            &file,
+            r#"
+            [server]
+            bind = "127.0.0.1:9000"
+            cors_origins = ["https://example.com"]
+
+            [database]
+            url = "sqlite::memory:"
+            max_connections = 2
+
+            [message]
+            deleted_policy = "remove"
+            "#,
+        )
+        .unwrap();
+        let args = Args {
+            config: Some(file.clone()),
+            bind: Some("127.0.0.1:9001".parse().unwrap()),
+            ..Args::default()
+        };
+        let config = Config::from_args(args).unwrap();
+        fs::remove_file(file).unwrap();
+
+        assert_eq!(config.server.bind.port(), 9001);
+        assert_eq!(config.server.cors_origins, ["https://example.com"]);
+        assert_eq!(config.database.max_connections, Some(2));
+        assert_eq!(config.message.deleted_policy, MessagePolicy::Remove);
+        // the defaults
+        assert_eq!(config.message.queue_size, 100);
+        assert!(config.database.migrate);
+    }
+
+    #[test]
+    fn invalid_config_should_be_rejected() {
+        let config = |f: fn(&mut Config)| {
+            let mut config = Config::default();
+            config.database.url = "sqlite::memory:".to_string();
+            f(&mut config);
+            config.validate()
+        };
+        assert!(config(|_| ()).is_ok());
+        assert!(config(|c| c.database.url.clear()).is_err());
+        assert!(config(|c| c.message.queue_size = 0).is_err());
+        assert!(config(|c| c.server.cors_origins = vec!["example".to_string()]).is_err());
+        assert!(config(|c| c.blob.store = BlobKind::S3).is_err());
+        assert!(toml::from_str::<Config>("[server]\nport = 80").is_err());
+    }
+
+    #[test]
+    fn flags_should_be_parsed() {
+        let args = Args::try_parse_from([
+            "eChat",
+            "--database-migrate",
+            "no",
+            "--deleted-message-policy",
+            "remove",
+            "--cors-origins",
+            "http://a.com,http://b.com",
+            "--message-overflow-policy",
+            "offline",
+        ])
+        .unwrap();
+        assert_eq!(args.database_migrate, Some(false));
+        assert_eq!(args.deleted_message_policy, Some(MessagePolicy::Remove));
+        assert_eq!(args.cors_origins.unwrap().len(), 2);
+        assert_eq!(args.message_overflow_policy, Some(OverflowPolicy::Offline));
+    }
+}
@@ -0,0 +1,385 @@
+//! the outbound queue of a websocket connection. the frames are queued without waiting,
+//! so a slow client can't block the users sending to it
+
+use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::mpsc::{channel, Receiver, Sender};
+use tokio::sync::watch;
+use tracing::{debug, warn};
+
+use crate::config::OverflowPolicy;
+use crate::modles::frame::{Event, Frame};
+
+/// the sender end of the queue
+#[derive(Clone)]
+pub struct Connection {
+    uid: u64,
+    sender: Sender<Frame>,
+    /// set by `close`, the websocket is closed without flushing the queue
+    closed: Arc<watch::Sender<bool>>,
+    /// a frame is dropped by `OverflowPolicy::Offline`, the client is told to resync
+    overflowed: Arc<AtomicBool>,
+}
+
+/// the receiver end of the queue, drained by the websocket
+pub struct Outbox {
+    receiver: Receiver<Frame>,
+    closed: watch::Receiver<bool>,
+    overflowed: Arc<AtomicBool>,
+}
+
+impl Connection {
+    pub fn new(uid: u64, queue_size: usize) -> (Connection, Outbox) {
+        let (sender, receiver) = channel(queue_size);
+        let (closed, closed_receiver) = watch::channel(false);
+        let overflowed = Arc::new(AtomicBool::new(false));
+        let connection = Connection {
+            uid,
+            sender,
+            closed: Arc::new(closed),
+            overflowed: overflowed.clone(),
+        };
+        let outbox = Outbox {
+            receiver,
+            closed: closed_receiver,
+            overflowed,
+        };
+        (connection, outbox)
+    }
+
+    /// queue the frame without waiting, return false if it's not queued
+    pub fn send(&self, frame: Frame, policy: OverflowPolicy) -> bool {
+        match self.sender.try_send(frame) {
+            Ok(()) => true,
+            // the websocket is closing
+            Err(TrySendError::Closed(_)) => false,
+            Err(TrySendError::Full(_)) => {
+                match policy {
+                    OverflowPolicy::Disconnect => {
+                        warn!(uid = self.uid, "the queue is full, disconnect the client");
+                        self.close();
+                    }
+                    OverflowPolicy::Offline => {
+                        debug!(uid = self.uid, "the queue is full, drop the frame");
+                        self.overflowed.store(true, Ordering::Relaxed);
+                    }
+                }
+                false
+            }
+        }
+    }
+
+    /// wait until the frame is queued, only for the last frame before the connection closes
+    pub async fn send_last(&self, frame: Frame) {
+        let _ = self.sender.send(frame).await;
+    }
+
+    pub fn close(&self) {
+        self.closed.send_replace(true);
+    }
+
+    /// resolves once `close` is called
+    pub async fn closed(&self) {
+        let mut closed = self.closed.subscribe();
+        while !*closed.borrow_and_update() {
+            if closed.changed().await.is_err() {
+                return;
+            }
+        }
+    }
+
+    pub fn queued(&self) -> usize {
+        self.sender.max_capacity() - self.sender.capacity()
+    }
+
+    /// whether they are the same connection, a user may connect again before the old one is gone
+    pub fn same(&self, other: &Connection) -> bool {
+        self.sender.same_channel(&other.sender)
+    }
+}
+
+/// the connections of the online users, sharded so the senders don't wait for each other
+#[derive(Clone, Default)]
+pub struct ConnectionRegistry {
+    connections: Arc<DashMap<u64, Connection>>,
+    /// the open connections, including the ones replaced by a new connection of the same user
+    count: Arc<AtomicUsize>,
+    /// the connections opened since the start
+    total: Arc<AtomicU64>,
+}
+
+/// the result of sending a frame to the online users
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Sent {
+    pub queued: usize,
+    /// the queue is full or the connection is closing
+    pub dropped: usize,
+}
+
+/// unregister the connection when it's dropped, it dereferences to the connection
+pub struct Registration {
+    registry: ConnectionRegistry,
+    connection: Option<Connection>,
+}
+
+impl ConnectionRegistry {
+    pub fn new() -> Self {
+        ConnectionRegistry::default()
+    }
+
+    /// the new connection of a user replaces the old one, which doesn't receive anything after it
+    pub fn register(&self, connection: Connection) -> Registration {
+        self.connections.insert(connection.uid, connection.clone());
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.total.fetch_add(1, Ordering::Relaxed);
+        Registration {
+            registry: self.clone(),
+            connection: Some(connection),
+        }
+    }
+
+    pub fn is_online(&self, uid: u64) -> bool {
+        self.connections.contains_key(&uid)
+    }
+
+    /// close the connection of the user, return false if he is offline
+    pub fn close(&self, uid: u64) -> bool {
+        match self.connections.remove(&uid) {
+            Some((_, connection)) => {
+                connection.close();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// queue the frame to the online users in `uids`, the offline users are not counted
+    pub fn send(&self, uids: &[u64], frame: &Frame, policy: OverflowPolicy) -> Sent {
+        let mut sent = Sent::default();
+        for connection in uids.iter().filter_map(|uid| self.connections.get(uid)) {
+            if connection.send(frame.clone(), policy) {
+                sent.queued += 1;
+            } else {
+                sent.dropped += 1;
+            }
+        }
+        sent
+    }
+
+    /// the frames waiting in the queues of the registered connections
+    pub fn queued(&self) -> usize {
+        self.connections
+            .iter()
+            .map(|connection| connection.queued())
+            .sum()
+    }
+
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total.load(Ordering::Relaxed)
+    }
+
+    fn unregister(&self, connection: &Connection) {
+        // it may have been replaced by a new connection of the user
+        self.connections
+            .remove_if(&connection.uid, |_, c| c.same(connection));
+        self.count.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl Registration {
+    /// unregister it now, the returned connection can still send the last frames
+    pub fn unregister(mut self) -> Connection {
+        let connection = self.connection.take().unwrap();
+        self.registry.unregister(&connection);
+        connection
+    }
+}
+
+impl Deref for Registration {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        // it's only taken by `unregister`, which consumes the registration
+        self.connection.as_ref().unwrap()
+    }
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            self.registry.unregister(&connection);
+        }
+    }
+}
+
+impl Outbox {
+    /// the next frame, `None` if the connection is closed or all the sender ends are dropped
+    pub async fn recv(&mut self) -> Option<Frame> {
+        loop {
+            if *self.closed.borrow_and_update() {
+                return None;
+            }
+            if self.overflowed.swap(false, Ordering::Relaxed) {
+                // it's told after the queued frames, so the client reloads the latest state
+                match self.receiver.try_recv() {
+                    Ok(frame) => {
+                        self.overflowed.store(true, Ordering::Relaxed);
+                        return Some(frame);
+                    }
+                    Err(_) => return Some(Event::Resync.into()),
+                }
+            }
+            tokio::select! {
+                biased;
+                changed = self.closed.changed() => {
+                    // the connection is dropped, drain the queue
+                    if changed.is_err() {
+                        return self.receiver.recv().await;
+                    }
+                }
+                frame = self.receiver.recv() => return frame,
+            }
+        }
+    }
+
+    /// whether it's closed by `Connection::close`, the queued frames are not sent in this case
+    pub fn is_closed(&self) -> bool {
+        *self.closed.borrow()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::modles::message::Msg;
+
+    #[tokio::test]
+    async fn overflow_should_follow_policy() {
+        let (connection, mut outbox) = Connection::new(1, 1);
+        assert!(connection.send(Msg::new("1").into(), OverflowPolicy::Offline));
+        assert!(!connection.send(Msg::new("2").into(), OverflowPolicy::Offline));
+        assert!(!*connection.closed.borrow());
+        // the client is told to resync after the queued frames
+        assert!(matches!(outbox.recv().await, Some(Frame::Msg(msg)) if msg.content == "1"));
+        assert!(matches!(outbox.recv().await, Some(Frame::Event(Event::Resync))));
+        assert!(connection.send(Msg::new("2").into(), OverflowPolicy::Offline));
+
+        assert!(!connection.send(Msg::new("3").into(), OverflowPolicy::Disconnect));
+        tokio::time::timeout(Duration::from_secs(1), connection.closed())
+            .await
+            .expect("the connection is not closed");
+        assert!(outbox.is_closed());
+        assert!(outbox.recv().await.is_none());
+    }
+
+    #[test]
+    fn registration_should_clean_up() {
+        let registry = ConnectionRegistry::new();
+        let (old, _old_outbox) = Connection::new(1, 10);
+        let old = registry.register(old);
+        let (new, mut new_outbox) = Connection::new(1, 10);
+        let new = registry.register(new);
+        assert_eq!(registry.count(), 2);
+
+        // the old one doesn't remove the new one
+        drop(old);
+        assert!(registry.is_online(1));
+        let sent = registry.send(&[1, 2], &Msg::new("hi").into(), OverflowPolicy::Offline);
+        assert_eq!(sent, Sent { queued: 1, dropped: 0 });
+        assert_eq!(registry.queued(), 1);
+        assert!(new_outbox.receiver.try_recv().is_ok());
+
+        let new = new.unregister();
+        assert!(!registry.is_online(1));
+        assert_eq!(registry.count(), 0);
+        assert_eq!(registry.total(), 2);
+        drop(new);
+    }
+
+    /// the send throughput with many concurrent senders, compared with a global lock:
+    /// `cargo test --release send_throughput -- --ignored --nocapture`
+    #[tokio::test(flavor = "multi_thread")]
+    #[ignore]
+    async fn send_throughput() {
+        use std::collections::HashMap;
+        use std::time::Instant;
+
+        use tokio::sync::Mutex;
+
+        const USERS: u64 = 1000;
+        const SENDERS: u64 = 64;
+        const SENDS: u64 = 10_000;
+        // every send goes to a group of 10 users
+        let group = |sender: u64, i: u64| -> Vec<u64> {
+            (0..10).map(|n| (sender * 7919 + i * 10 + n) % USERS).collect()
+        };
+
+        let registry = ConnectionRegistry::new();
+        let locked = Arc::new(Mutex::new(HashMap::new()));
+        let mut registrations = Vec::new();
+        let mut outboxes = Vec::new();
+        for uid in 0..USERS {
+            let (connection, outbox) = Connection::new(uid, 16);
+            locked.lock().await.insert(uid, connection.clone());
+            registrations.push(registry.register(connection));
+            outboxes.push(outbox);
+        }
+        // drain the queues so that the frames are not dropped
+        for mut outbox in outboxes {
+            tokio::spawn(async move { while outbox.recv().await.is_some() {} });
+        }
+        let frame: Frame = Msg::new("hello").into();
+
+        let start = Instant::now();
+        let tasks: Vec<_> = (0..SENDERS)
+            .map(|sender| {
+                let locked = locked.clone();
+                let frame = frame.clone();
+                tokio::spawn(async move {
+                    for i in 0..SENDS {
+                        let uids = group(sender, i);
+                        let connections: Vec<_> = {
+                            let map = locked.lock().await;
+                            uids.iter().filter_map(|uid| map.get(uid).cloned()).collect()
+                        };
+                        for connection in connections {
+                            connection.send(frame.clone(), OverflowPolicy::Offline);
+                        }
+                    }
+                })
+            })
+            .collect();
+        futures::future::join_all(tasks).await;
+        let global = start.elapsed();
+
+        let start = Instant::now();
+        let tasks: Vec<_> = (0..SENDERS)
+            .map(|sender| {
+                let registry = registry.clone();
+                let frame = frame.clone();
+                tokio::spawn(async move {
+                    for i in 0..SENDS {
+                        registry.send(&group(sender, i), &frame, OverflowPolicy::Offline);
+                    }
+                })
+            })
+            .collect();
+        futures::future::join_all(tasks).await;
+        let sharded = start.elapsed();
+
+        let sends = (SENDERS * SENDS) as f64;
+        println!("global lock: {:.0} sends/s", sends / global.as_secs_f64());
+        println!("registry:    {:.0} sends/s", sends / sharded.as_secs_f64());
+        drop(registrations);
+    }
+}
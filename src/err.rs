@@ -9,7 +9,6 @@ use std::collections::HashMap;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
-
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("authentication required")]
@@ -71,9 +70,11 @@ impl Error {
             Self::Forbidden => StatusCode::FORBIDDEN,
             Self::NotFound => StatusCode::NOT_FOUND,
             Self::UnprocessableEntity { .. } => StatusCode::UNPROCESSABLE_ENTITY,
-            Self::Sqlx(_) | Self::Anyhow(_) | Self::Duplicated(_) | Self::Axum(_) | Self::Serde(_) => {
-                StatusCode::INTERNAL_SERVER_ERROR
-            }
+            Self::Sqlx(_)
+            | Self::Anyhow(_)
+            | Self::Duplicated(_)
+            | Self::Axum(_)
+            | Self::Serde(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 }
@@ -168,15 +169,20 @@ where
     fn on_duplicated(self, msg: String) -> Result<T> {
         self.map_err(|e| -> Error {
             match e.into() {
-                Error::Sqlx(sqlx::Error::Database(dbe)) => {
-                    match dbe.try_downcast_ref::<MySqlDatabaseError>() {
-                        // 1062 is the error code of mysql duplicate entry
-                        Some(mysql_dbe) if mysql_dbe.number() == 1062 => Error::Duplicated(msg),
-                        _ => Error::Sqlx(sqlx::Error::Database(dbe)),
-                    }
+                Error::Sqlx(sqlx::Error::Database(dbe)) if is_duplicated(&*dbe) => {
+                    Error::Duplicated(msg)
                 }
                 e => e,
             }
         })
     }
 }
+
+fn is_duplicated(dbe: &dyn DatabaseError) -> bool {
+    if let Some(mysql_dbe) = dbe.try_downcast_ref::<MySqlDatabaseError>() {
+        // 1062 is the error code of mysql duplicate entry
+        return mysql_dbe.number() == 1062;
+    }
+    // 2067 and 1555 are the extended codes of sqlite unique and primary key constraints
+    matches!(dbe.code().as_deref(), Some("2067") | Some("1555"))
+}
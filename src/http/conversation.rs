@@ -0,0 +1,97 @@
+use axum::extract::Path;
+use axum::routing::{get, post};
+use axum::{Extension, Json, Router};
+use eChat::err::{Error, Result};
+
+use crate::auth::AuthUser;
+use crate::http::send_to_users;
+use crate::modles::conversation::*;
+use crate::modles::frame::Event;
+use crate::modles::message::ReceiverType;
+use crate::persistent::{ConversationManage, GroupManage, MessageManage};
+use crate::ApiContext;
+
+pub fn router(ctx: &ApiContext) -> Router {
+    Router::new()
+        .route("/api/conversations", get(get_conversations))
+        .route("/api/conversations/read", post(read_message))
+        .route("/api/messages/:mid/reads", get(get_message_reads))
+        .layer(Extension(ctx.storage.conversations.clone()))
+        .layer(Extension(ctx.storage.groups.clone()))
+        .layer(Extension(ctx.storage.messages.clone()))
+}
+
+async fn get_conversations(
+    auth_user: AuthUser,
+    Extension(conversation_manage): Extension<ConversationManage>,
+) -> Result<Json<Vec<Conversation>>> {
+    let conversations = conversation_manage.get_conversations(auth_user.uid).await?;
+    Ok(Json(conversations))
+}
+
+async fn read_message(
+    auth_user: AuthUser,
+    Json(read): Json<ReadMessage>,
+    Extension(ctx): Extension<ApiContext>,
+) -> Result<()> {
+    advance_read(&ctx, auth_user.uid, read).await
+}
+
+/// move the read cursor of the user, and tell the peer if it's a direct chat.
+/// it's shared by the rest api and the websocket
+pub async fn advance_read(ctx: &ApiContext, uid: u64, read: ReadMessage) -> Result<()> {
+    let message = ctx.storage.messages
+        .get_message(read.mid)
+        .await?
+        .ok_or(Error::NotFound)?;
+    if !message.in_conversation(uid, read.receiver_type, read.receiver_id) {
+        return Err(Error::unprocessable_entity([("mid", "消息不属于该会话")]));
+    }
+    if read.receiver_type == ReceiverType::Group {
+        let members = ctx.storage.groups
+            .get_members(read.receiver_id)
+            .await?;
+        if !members.contains(&uid) {
+            return Err(Error::Forbidden);
+        }
+    }
+
+    let moved = ctx.storage.conversations
+        .read(uid, &read)
+        .await?;
+    // the read count of group messages is queried by the members themselves
+    if moved && read.receiver_type == ReceiverType::User {
+        let event = Event::Read {
+            uid,
+            receiver_type: ReceiverType::User,
+            receiver_id: read.receiver_id,
+            mid: read.mid,
+        };
+        send_to_users(ctx, &[read.receiver_id], event.into()).await;
+    }
+    Ok(())
+}
+
+async fn get_message_reads(
+    auth_user: AuthUser,
+    Path(mid): Path<u64>,
+    Extension(message_manage): Extension<MessageManage>,
+    Extension(group_manage): Extension<GroupManage>,
+    Extension(conversation_manage): Extension<ConversationManage>,
+) -> Result<Json<MessageReads>> {
+    let message = message_manage
+        .get_message(mid)
+        .await?
+        .ok_or(Error::NotFound)?;
+    if message.receiver_type != ReceiverType::Group {
+        return Err(Error::unprocessable_entity([("mid", "只有群消息才有已读人数")]));
+    }
+    let members = group_manage.get_members(message.receiver_id).await?;
+    if !members.contains(&auth_user.uid) {
+        return Err(Error::Forbidden);
+    }
+    let read_count = conversation_manage
+        .get_read_count(message.receiver_id, mid, message.sender_uid)
+        .await?;
+    Ok(Json(MessageReads { mid, read_count }))
+}
@@ -0,0 +1,172 @@
+use axum::extract::{Multipart, Path};
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE, X_CONTENT_TYPE_OPTIONS};
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::{Extension, Json, Router};
+use bytes::BytesMut;
+use chrono::Local;
+use eChat::err::{Error, Result};
+use eChat::utils::sha256_hex;
+
+use crate::auth::AuthUser;
+use crate::media;
+use crate::modles::file::*;
+use crate::persistent::FileManage;
+use crate::ApiContext;
+
+/// the type of the content which can't be recognized
+const UNKNOWN_CONTENT_TYPE: &str = "application/octet-stream";
+
+pub fn router(ctx: &ApiContext) -> Router {
+    Router::new()
+        .route("/api/files", post(upload))
+        .route("/api/files/:fid", get(download))
+        .route("/api/files/:fid/thumbnails/:max_side", get(download_thumbnail))
+        .layer(Extension(ctx.storage.files.clone()))
+}
+
+/// upload the file in the multipart field `file`, the same content is stored only once
+async fn upload(
+    auth_user: AuthUser,
+    Extension(file_manage): Extension<FileManage>,
+    Extension(ctx): Extension<ApiContext>,
+    mut multipart: Multipart,
+) -> Result<Json<FileView>> {
+    let max_size = ctx.config.upload.max_bytes;
+    while let Some(mut field) = multipart.next_field().await.map_err(upload_error)? {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let name = file_name(field.file_name());
+        // read chunk by chunk, so that a too large file is rejected early
+        let mut content = BytesMut::new();
+        while let Some(chunk) = field.chunk().await.map_err(upload_error)? {
+            if content.len() + chunk.len() > max_size {
+                return Err(Error::unprocessable_entity([(
+                    "file",
+                    format!("文件不能超过{}字节", max_size),
+                )]));
+            }
+            content.extend_from_slice(&chunk);
+        }
+        if content.is_empty() {
+            return Err(Error::unprocessable_entity([("file", "文件不能为空")]));
+        }
+        let mut content = content.freeze();
+
+        let content_type = infer::get(&content).map_or(UNKNOWN_CONTENT_TYPE, |t| t.mime_type());
+        // the location must not be leaked to the others, it's removed before saving
+        if let Some(stripped) = media::strip_exif_location(&content) {
+            content = stripped.into();
+        }
+        let sha256 = sha256_hex(&content);
+        let is_image = content_type.starts_with("image/");
+        if !file_manage.blob_exists(&sha256).await? {
+            ctx.blob_store
+                .put(&sha256, content.clone(), content_type)
+                .await?;
+            if is_image {
+                media::spawn_process(ctx.clone(), file_manage.clone(), sha256.clone(), content.clone());
+            }
+        } else if is_image && file_manage.get_image(&sha256).await?.is_none() {
+            // the last processing failed or was interrupted, saving the result again is harmless
+            // if it's still running
+            media::spawn_process(ctx.clone(), file_manage.clone(), sha256.clone(), content.clone());
+        }
+        let mut file = File {
+            fid: 0,
+            sha256,
+            name,
+            uploader: auth_user.uid,
+            size: content.len() as u64,
+            content_type: content_type.to_string(),
+            create_time: Local::now().naive_local(),
+        };
+        file.fid = file_manage.create_file(&file).await?;
+        return Ok(Json(file.into()));
+    }
+    Err(Error::unprocessable_entity([("file", "请选择要上传的文件")]))
+}
+
+/// only the uploader and the users who can see the file in a conversation can download it
+async fn download(
+    auth_user: AuthUser,
+    Path(fid): Path<u64>,
+    Extension(file_manage): Extension<FileManage>,
+    Extension(ctx): Extension<ApiContext>,
+) -> Result<impl IntoResponse> {
+    let file = file_manage.get_file(fid).await?.ok_or(Error::NotFound)?;
+    if !file_manage.can_access(auth_user.uid, &file).await? {
+        return Err(Error::Forbidden);
+    }
+    let content = ctx
+        .blob_store
+        .get(&file.sha256)
+        .await?
+        .ok_or(Error::NotFound)?;
+    // the media can be shown in the page, other files are downloaded
+    let disposition = if ["image/", "audio/", "video/"]
+        .iter()
+        .any(|prefix| file.content_type.starts_with(prefix))
+    {
+        "inline"
+    } else {
+        "attachment"
+    };
+    Ok((
+        [
+            (CONTENT_TYPE, file.content_type.clone()),
+            (
+                CONTENT_DISPOSITION,
+                format!("{}; filename*=UTF-8''{}", disposition, percent_encode(&file.name)),
+            ),
+            (X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+        ],
+        content,
+    ))
+}
+
+/// the thumbnail of a uploaded image, the access is the same as the image
+async fn download_thumbnail(
+    auth_user: AuthUser,
+    Path((fid, max_side)): Path<(u64, u32)>,
+    Extension(file_manage): Extension<FileManage>,
+    Extension(ctx): Extension<ApiContext>,
+) -> Result<impl IntoResponse> {
+    let file = file_manage.get_file(fid).await?.ok_or(Error::NotFound)?;
+    if !file_manage.can_access(auth_user.uid, &file).await? {
+        return Err(Error::Forbidden);
+    }
+    let sha256 = file_manage
+        .get_thumbnail(&file.sha256, max_side)
+        .await?
+        .ok_or(Error::NotFound)?;
+    let content = ctx.blob_store.get(&sha256).await?.ok_or(Error::NotFound)?;
+    Ok(([(CONTENT_TYPE, "image/jpeg")], content))
+}
+
+fn upload_error(e: axum::extract::multipart::MultipartError) -> Error {
+    Error::unprocessable_entity([("file", format!("上传失败: {}", e))])
+}
+
+/// keep the last part of the path and cut it to `MAX_FILE_NAME_LEN` chars
+fn file_name(name: Option<&str>) -> String {
+    let name = name
+        .and_then(|name| name.rsplit(['/', '\\']).next())
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .unwrap_or("file");
+    name.chars().take(MAX_FILE_NAME_LEN).collect()
+}
+
+/// encode the file name for `filename*` of `Content-Disposition`
+fn percent_encode(name: &str) -> String {
+    name.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
@@ -1,10 +1,11 @@
-use axum::routing::{post};
+use axum::routing::{get, post};
 use axum::Router;
 use axum::{Extension, Json};
 use eChat::err::Result;
 
 use crate::auth::AuthUser;
 use crate::modles::friend::*;
+use crate::modles::presence::{PresenceStatus, PresenceView};
 use crate::modles::user::ViewUser;
 use crate::persistent::FriendManage;
 use crate::ApiContext;
@@ -14,7 +15,8 @@ pub fn router(ctx: &ApiContext) -> Router {
         .route("/api/friends", post(add_friend).get(get_friend))
         .route("/api/friends/agree", post(agree_friend))
         .route("/api/friends/refuse", post(refuse_friend))
-        .layer(Extension(FriendManage::new(ctx.db.clone())))
+        .route("/api/friends/presence", get(get_friends_presence))
+        .layer(Extension(ctx.storage.friends.clone()))
 }
 
 async fn add_friend(
@@ -56,4 +58,22 @@ async fn refuse_friend(
     Ok(Json(()))
 }
 
-
+/// the presence of all the accepted friends
+async fn get_friends_presence(
+    auth_user: AuthUser,
+    Extension(friend_manage): Extension<FriendManage>,
+    Extension(ctx): Extension<ApiContext>,
+) -> Result<Json<Vec<PresenceView>>> {
+    let friends = friend_manage.get_friends_last_seen(auth_user.uid).await?;
+    let presence = friends
+        .into_iter()
+        .map(|friend| {
+            ctx.presence.get(friend.uid).unwrap_or(PresenceView {
+                uid: friend.uid,
+                status: PresenceStatus::Offline,
+                last_seen: friend.last_seen,
+            })
+        })
+        .collect();
+    Ok(Json(presence))
+}
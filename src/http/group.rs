@@ -1,16 +1,19 @@
 use axum::{routing::post, Extension, Json, Router};
 
 use crate::auth::AuthUser;
+use crate::http::send_to_users;
+use crate::modles::frame::Event;
 use crate::modles::group::*;
 use crate::{persistent::GroupManage, ApiContext};
 use eChat::err::Result;
 
 pub fn router(ctx: &ApiContext) -> Router {
-    let group_manage = GroupManage::new(ctx.db.clone());
+    let group_manage = ctx.storage.groups.clone();
     Router::new()
         .route("/api/groups", post(create_group))
         .route("/api/groups/join", post(join_group))
         .route("/api/groups/agree", post(agree))
+        .route("/api/groups/dissolve", post(dissolve))
         .layer(Extension(group_manage))
 }
 
@@ -24,6 +27,7 @@ pub async fn create_group(
         owner: auth_user.uid,
         name: create_group.name.clone(),
         create_time: chrono::Local::now().naive_local(),
+        dissolve_time: None,
     };
     group_manage.create_group(group).await?;
     Ok(())
@@ -48,3 +52,16 @@ pub async fn agree(
         .await?;
     Ok(())
 }
+
+pub async fn dissolve(
+    auth_user: AuthUser,
+    Json(dissolve_group): Json<DissolveGroup>,
+    Extension(group_manage): Extension<GroupManage>,
+    Extension(ctx): Extension<ApiContext>,
+) -> Result<()> {
+    let gid = dissolve_group.gid;
+    let members = group_manage.dissolve(auth_user.uid, gid).await?;
+    // notify the online members, the offline members will find it when they query the group
+    send_to_users(&ctx, &members, Event::GroupDissolved { gid }.into()).await;
+    Ok(())
+}
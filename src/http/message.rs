@@ -1,26 +1,55 @@
 use crate::auth::AuthUser;
-use crate::modles::message::Msg;
-use crate::persistent::MessageManage;
+use crate::connection::{Connection, Outbox, Registration, Sent};
+use crate::http::conversation::advance_read;
+use crate::http::reaction::react;
+use crate::modles::file::{parse_file_url, File};
+use crate::modles::frame::{ClientEvent, ClientFrame, Event, Frame};
+use crate::modles::message::Message as ChatMessage;
+use crate::modles::message::{
+    highlight, EditMessage, MessageEdit, MessageHistory, MessageHit, MessageView,
+    Msg, ReceiverType, SearchMessage, MENTION_ALL,
+};
+use crate::modles::payload::{MessageKind, Payload};
+use crate::modles::presence::PresenceStatus;
+use crate::persistent::{FileManage, FriendManage, GroupManage, MessageManage, ReactionManage};
+use crate::typing::{self, TypingState};
 use crate::ApiContext;
-use axum::extract::ws::{Message, WebSocket};
-use axum::extract::WebSocketUpgrade;
+use axum::extract::ws::{close_code, CloseFrame, Message, WebSocket};
+use axum::extract::{Path, Query, WebSocketUpgrade};
 use axum::response::IntoResponse;
-use axum::routing::get;
-use axum::{Extension, Router};
+use axum::routing::{get, post};
+use axum::{Extension, Json, Router};
+use chrono::Local;
 use eChat::err::{Error, Result};
 use futures::stream::{SplitSink, SplitStream};
 use futures::{SinkExt, StreamExt};
 use tokio::join;
-use tokio::sync::mpsc::{channel, Receiver};
-use tracing::debug;
-
-const DEFAULT_MESSAGE_QUEUE_SIZE: usize = 100;
+use tracing::{debug, warn};
 
 pub fn router(ctx: &ApiContext) -> Router {
-    let message_manage = MessageManage::new(ctx.db.clone());
+    let message_manage = ctx.storage.messages.clone();
     Router::new()
         .route("/ws", get(ws_handler))
+        .route("/api/messages", get(get_history))
+        .route("/api/messages/search", get(search_messages))
+        .route("/api/messages/:mid/edit", post(edit_message))
+        .route("/api/messages/:mid/recall", post(recall_message))
+        .route("/api/messages/:mid/edits", get(get_edits))
+        .route("/api/messages/:mid/thread", get(get_thread))
         .layer(Extension(message_manage))
+        .layer(Extension(ctx.storage.groups.clone()))
+        .layer(Extension(ctx.storage.friends.clone()))
+        .layer(Extension(ctx.storage.reactions.clone()))
+        .layer(Extension(ctx.storage.files.clone()))
+}
+
+/// send the frame to every online user in `uids`, the offline users are ignored.
+/// it never waits for a slow client, see `OverflowPolicy`
+pub async fn send_to_users(ctx: &ApiContext, uids: &[u64], frame: Frame) -> Sent {
+    let sent = ctx.connections
+        .send(uids, &frame, ctx.config.message.overflow_policy);
+    ctx.metrics.frame(sent);
+    sent
 }
 
 async fn ws_handler(
@@ -28,9 +57,14 @@ async fn ws_handler(
     ws: WebSocketUpgrade,
     auth_user: AuthUser,
     Extension(message_manage): Extension<MessageManage>,
+    Extension(group_manage): Extension<GroupManage>,
+    Extension(friend_manage): Extension<FriendManage>,
+    Extension(file_manage): Extension<FileManage>,
     Extension(ctx): Extension<ApiContext>,
 ) -> impl IntoResponse {
-    ws.on_upgrade(|ws| handle_socket(ws, ctx, auth_user, message_manage))
+    ws.on_upgrade(|ws| {
+        handle_socket(ws, ctx, auth_user, message_manage, group_manage, friend_manage, file_manage)
+    })
 }
 
 async fn handle_socket(
@@ -38,77 +72,640 @@ async fn handle_socket(
     ctx: ApiContext,
     auth_user: AuthUser,
     message_manage: MessageManage,
+    group_manage: GroupManage,
+    friend_manage: FriendManage,
+    file_manage: FileManage,
 ) {
     debug!("receiver a connect");
+    // the shutdown waits for the session until it's dropped
+    let _session = ctx.shutdown.session();
     // websocket sender and receiver
     let (sender, receiver) = socket.split();
     //sender.send(Message::Text("test".into())).await.unwrap();
-    // a channel which community with other user
-    let (tx, rx) = channel::<Msg>(DEFAULT_MESSAGE_QUEUE_SIZE);
-    // save current user sender end for other user to send message
-    ctx.active_users.lock().await.insert(auth_user.uid, tx);
+    let uid = auth_user.uid;
+    // the queue which other users send to
+    let (connection, outbox) = Connection::new(uid, ctx.config.message.queue_size);
+    // it's dropped after the registration, so the user is offline unless he connects again
+    let _offline = ctx.presence.offline_guard(&ctx, uid);
+    // replace the old connection of the user if there is one,
+    // it's unregistered when the registration is dropped even if the task panics
+    let registration = ctx.connections.register(connection);
+    if let Err(e) = ctx.presence.set_status(&ctx, uid, PresenceStatus::Online).await {
+        warn!(error = ?e, "update presence failed");
+    }
 
     // TODO: make a nicer name
-    let receiver_task = receiver_message(receiver, &ctx, message_manage, auth_user);
-    let sender_task = sender_message(sender, rx);
+    let receiver_task = async {
+        let result = receiver_message(
+            receiver,
+            &ctx,
+            message_manage,
+            group_manage,
+            friend_manage,
+            file_manage,
+            auth_user,
+            &registration,
+        )
+        .await;
+        // the client is gone, drop the sender end so that the sender task can finish
+        let connection = registration.unregister();
+        if ctx.shutdown.is_triggered() {
+            let going_away = Event::GoingAway {
+                reconnect_after_secs: ctx.config.server.reconnect_after_secs,
+            };
+            connection.send_last(going_away.into()).await;
+        }
+        drop(connection);
+        result
+    };
+    let sender_task = sender_message(sender, outbox, &ctx);
     let (r1, r2) = join!(receiver_task, sender_task);
-    r1.unwrap();
-    r2.unwrap();
+    debug!(receiver = ?r1, sender = ?r2, "websocket closed");
 }
 
 async fn sender_message(
     mut sender: SplitSink<WebSocket, Message>,
-    mut receiver: Receiver<Msg>,
+    mut outbox: Outbox,
+    ctx: &ApiContext,
 ) -> Result<()> {
-    while let Some(msg) = receiver.recv().await {
+    while let Some(msg) = outbox.recv().await {
         sender
             .send(Message::Text(serde_json::to_string(&msg)?))
             .await?;
     }
+    if outbox.is_closed() {
+        // the queued frames are dropped, the client loads them after reconnecting
+        let close = CloseFrame {
+            code: close_code::POLICY,
+            reason: "连接已被服务器关闭".into(),
+        };
+        sender.send(Message::Close(Some(close))).await?;
+        return Ok(());
+    }
+    if ctx.shutdown.is_triggered() {
+        // the queue is flushed, the going away frame is the last one
+        let close = CloseFrame {
+            code: close_code::AWAY,
+            reason: "服务器重启中".into(),
+        };
+        sender.send(Message::Close(Some(close))).await?;
+        return Ok(());
+    }
     Err(Error::unprocessable_entity([("msg", "websocket closed")]))
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn receiver_message(
     mut receiver: SplitStream<WebSocket>,
     ctx: &ApiContext,
     message_manage: MessageManage,
+    group_manage: GroupManage,
+    friend_manage: FriendManage,
+    file_manage: FileManage,
     auth_user: AuthUser,
+    connection: &Registration,
 ) -> Result<()> {
+    let typing = TypingState::new();
     // TODO: handler time out situation, use tokio::time::timeout
-    while let Some(message) = receiver.next().await {
+    loop {
+        // stop reading on shutdown, the message being handled is finished first
+        let message = tokio::select! {
+            biased;
+            _ = ctx.shutdown.triggered() => return Ok(()),
+            _ = connection.closed() => return Ok(()),
+            message = receiver.next() => message,
+        };
+        let Some(message) = message else { break };
         let message = message?;
         debug!("receiver a message {:?}", message);
         match message {
-            Message::Text(message) => {
-                let map = &ctx.active_users;
-                if let Ok(msg) = serde_json::from_str::<Msg>(&message) {
+            Message::Text(message) => match ClientFrame::parse(&message) {
+                Ok(ClientFrame::Message(mut msg)) => {
+                    let recipients = match recipients(
+                        &group_manage,
+                        auth_user.uid,
+                        msg.receiver_type,
+                        msg.receiver_id,
+                    )
+                    .await?
+                    {
+                        Ok(recipients) => recipients,
+                        Err(reason) => {
+                            send_to_users(ctx, &[auth_user.uid], Msg::new(reason).into()).await;
+                            continue;
+                        }
+                    };
+                    if let Err(reason) = attach_file(&file_manage, auth_user.uid, &mut msg).await? {
+                        send_to_users(ctx, &[auth_user.uid], Msg::new(reason).into()).await;
+                        continue;
+                    }
+                    if let Err(reason) = validate_payload(&friend_manage, auth_user.uid, &msg).await? {
+                        send_to_users(ctx, &[auth_user.uid], Msg::new(reason).into()).await;
+                        continue;
+                    }
+                    if let Some(reply_to_mid) = msg.reply_to_mid {
+                        let replied = message_manage.get_message(reply_to_mid).await?;
+                        // the sender is in the conversation, so he can see the replied message
+                        let valid = replied.is_some_and(|replied| {
+                            replied.in_conversation(auth_user.uid, msg.receiver_type, msg.receiver_id)
+                        });
+                        if !valid {
+                            send_to_users(ctx, &[auth_user.uid], Msg::new("回复的消息不存在").into()).await;
+                            continue;
+                        }
+                    }
+                    let mentioned =
+                        match mentioned(&group_manage, auth_user.uid, &msg, &recipients).await? {
+                            Ok(mentioned) => mentioned,
+                            Err(reason) => {
+                                send_to_users(ctx, &[auth_user.uid], Msg::new(reason).into()).await;
+                                continue;
+                            }
+                        };
                     // first save message
-                    message_manage.create_message(msg.to_message(auth_user.uid)).await?;
+                    let mut message = msg.to_message(auth_user.uid);
+                    message.mid = message_manage.create_message(message.clone()).await?;
+                    let mut mentions = msg.mentions.clone();
+                    if msg.mention_all {
+                        mentions.push(MENTION_ALL);
+                    }
+                    message_manage
+                        .create_mentions(message.mid, msg.receiver_id, &mentions)
+                        .await?;
+                    // the users in the conversation can download the uploaded file
+                    if let Some(fid) = msg.payload.url().and_then(parse_file_url) {
+                        file_manage.share(fid, message.mid).await?;
+                    }
                     debug!(
                         "receiver a message from {}({}): {}",
                         auth_user.uid, auth_user.username, msg.content
                     );
-                    // TODO: consider the receiver type is group
-                    if let Some(sender) = map.lock().await.get(&msg.receiver_id) {
-                        debug!("send message {:?} to {}", msg, msg.receiver_id);
-                        sender.send(msg).await.expect("send message error");
-                    }
-                }else {
-                    // this unwrap is safe, because there are a sender of the user in the dash
-                    let map = map.lock().await;
-                    let sender = map.get(&auth_user.uid).unwrap(); 
-                    sender.send(Msg::new("message format error")).await.expect("send message error");
+                    debug!("send message {:?} to {:?}", message, recipients);
+                    let mention = Event::Mention {
+                        mid: message.mid,
+                        gid: message.receiver_id,
+                        sender_uid: auth_user.uid,
+                        all: msg.mention_all,
+                    };
+                    let sent = send_to_users(ctx, &recipients, message.into()).await;
+                    ctx.metrics.message(msg.receiver_type, sent);
+                    // the mention is always notified, the client shouldn't mute it
+                    send_to_users(ctx, &mentioned, mention.into()).await;
                 }
+                Ok(ClientFrame::Event(event)) => {
+                    handle_client_event(ctx, &group_manage, &friend_manage, &typing, &auth_user, event)
+                        .await?;
+                }
+                Err(_) => {
+                    send_to_users(ctx, &[auth_user.uid], Msg::new("message format error").into()).await;
+                }
+            },
+            Message::Ping(_) | Message::Pong(_) => {
+                ctx.presence.heartbeat(auth_user.uid);
             }
             Message::Close(_) => {
-                let map = &ctx.active_users;
-                map.lock().await.remove(&auth_user.uid);
+                // the stream ends after it, the connection is unregistered then
             }
-            _ => {
-                // TODO: handle other message
-                todo!()
+            Message::Binary(_) => {
+                // only the text frames are supported
+                send_to_users(ctx, &[auth_user.uid], Msg::new("message format error").into()).await;
             }
         }
     }
     Err(Error::unprocessable_entity([("msg", "websocket closed")]))
 }
+
+/// the users who should receive the message sent by `sender_uid`, the sender is excluded.
+/// return `Ok(Err(reason))` if the sender can't send to the receiver
+pub async fn recipients(
+    group_manage: &GroupManage,
+    sender_uid: u64,
+    receiver_type: ReceiverType,
+    receiver_id: u64,
+) -> Result<std::result::Result<Vec<u64>, &'static str>> {
+    match receiver_type {
+        ReceiverType::User => Ok(Ok(vec![receiver_id])),
+        ReceiverType::Group => {
+            // a dissolved group can't receive message any more
+            match group_manage.get_group(receiver_id).await? {
+                Some(group) if !group.is_dissolved() => (),
+                _ => return Ok(Err("群不存在或已解散")),
+            }
+            let mut members = group_manage.get_members(receiver_id).await?;
+            if !members.contains(&sender_uid) {
+                return Ok(Err("你不是该群的成员"));
+            }
+            members.retain(|&uid| uid != sender_uid);
+            Ok(Ok(members))
+        }
+    }
+}
+
+/// check the uploaded file in the payload, only the file which the sender can access
+/// can be shared. the size, the placeholder and the thumbnails of a processed image are filled
+async fn attach_file(
+    file_manage: &FileManage,
+    sender_uid: u64,
+    msg: &mut Msg,
+) -> Result<std::result::Result<(), &'static str>> {
+    let fid = match msg.payload.url().and_then(parse_file_url) {
+        Some(fid) => fid,
+        None => return Ok(Ok(())),
+    };
+    let file = match file_manage.get_file(fid).await? {
+        Some(file) if file_manage.can_access(sender_uid, &file).await? => file,
+        _ => return Ok(Err("文件不存在")),
+    };
+    fill_image(file_manage, &file, &mut msg.payload).await?;
+    Ok(Ok(()))
+}
+
+/// fill the size, the placeholder and the thumbnails of the image if it has been processed
+async fn fill_image(file_manage: &FileManage, file: &File, payload: &mut Payload) -> Result<()> {
+    if let Payload::Image {
+        width,
+        height,
+        blurhash,
+        thumbnails,
+        ..
+    } = payload
+    {
+        // the image may be still processing, the client shows the original image then
+        if let Some((meta, thumbnail_blobs)) = file_manage.get_image(&file.sha256).await? {
+            *width = meta.width;
+            *height = meta.height;
+            *blurhash = Some(meta.blurhash);
+            *thumbnails = thumbnail_blobs
+                .iter()
+                .map(|thumbnail| thumbnail.to_thumbnail(file.fid))
+                .collect();
+        }
+    }
+    Ok(())
+}
+
+/// the image may be processed after the message is sent, fill it when the message is read
+async fn fill_processed_images(file_manage: &FileManage, messages: &mut [ChatMessage]) -> Result<()> {
+    for message in messages.iter_mut() {
+        let payload = match &mut message.payload {
+            Some(sqlx::types::Json(payload @ Payload::Image { blurhash: None, .. })) => payload,
+            _ => continue,
+        };
+        let file = match payload.url().and_then(parse_file_url) {
+            Some(fid) => file_manage.get_file(fid).await?,
+            None => None,
+        };
+        if let Some(file) = file {
+            fill_image(file_manage, &file, payload).await?;
+        }
+    }
+    Ok(())
+}
+
+/// check the content of the message sent by the client
+async fn validate_payload(
+    friend_manage: &FriendManage,
+    sender_uid: u64,
+    msg: &Msg,
+) -> Result<std::result::Result<(), &'static str>> {
+    match &msg.payload {
+        Payload::Text if msg.content.is_empty() => Ok(Err("消息内容不能为空")),
+        // only the card of a friend can be shared
+        Payload::Card { uid } if !friend_manage.is_friend(sender_uid, *uid).await? => {
+            Ok(Err("只能分享好友的名片"))
+        }
+        payload => Ok(payload.validate()),
+    }
+}
+
+/// check the mentions of the message, the mentioned users must be the members of the group,
+/// and only the admin can mention all. `members` is the recipients of the message.
+/// return the users who should be notified
+async fn mentioned(
+    group_manage: &GroupManage,
+    sender_uid: u64,
+    msg: &Msg,
+    members: &[u64],
+) -> Result<std::result::Result<Vec<u64>, &'static str>> {
+    if msg.mentions.is_empty() && !msg.mention_all {
+        return Ok(Ok(vec![]));
+    }
+    if msg.receiver_type != ReceiverType::Group {
+        return Ok(Err("只能在群聊中@成员"));
+    }
+    if msg.mention_all {
+        // only the owner is the admin of the group for now
+        let is_admin = group_manage
+            .get_group(msg.receiver_id)
+            .await?
+            .is_some_and(|group| group.owner == sender_uid);
+        if !is_admin {
+            return Ok(Err("只有管理员可以@所有人"));
+        }
+        return Ok(Ok(members.to_vec()));
+    }
+    if msg
+        .mentions
+        .iter()
+        .any(|uid| *uid != sender_uid && !members.contains(uid))
+    {
+        return Ok(Err("被@的用户不是该群的成员"));
+    }
+    let mut mentioned: Vec<u64> = msg
+        .mentions
+        .iter()
+        .copied()
+        .filter(|&uid| uid != sender_uid)
+        .collect();
+    mentioned.sort_unstable();
+    mentioned.dedup();
+    Ok(Ok(mentioned))
+}
+
+async fn handle_client_event(
+    ctx: &ApiContext,
+    group_manage: &GroupManage,
+    friend_manage: &FriendManage,
+    typing: &TypingState,
+    auth_user: &AuthUser,
+    event: ClientEvent,
+) -> Result<()> {
+    match event {
+        ClientEvent::Heartbeat => ctx.presence.heartbeat(auth_user.uid),
+        // a connected user can't be offline, it's decided by the connection
+        ClientEvent::Presence {
+            status: PresenceStatus::Offline,
+        } => {
+            send_to_users(ctx, &[auth_user.uid], Msg::new("不能设置为离线状态").into()).await;
+        }
+        ClientEvent::Presence { status } => {
+            ctx.presence.set_status(ctx, auth_user.uid, status).await?;
+        }
+        ClientEvent::TypingStart {
+            receiver_type,
+            receiver_id,
+        } => {
+            let conversation = (receiver_type, receiver_id);
+            let generation = match typing.start(conversation) {
+                Some(generation) => generation,
+                None => return Ok(()),
+            };
+            let recipients =
+                typing_recipients(group_manage, friend_manage, auth_user.uid, conversation).await?;
+            typing::relay(ctx, auth_user.uid, conversation, true, &recipients).await;
+            typing.expire_later(ctx, auth_user.uid, conversation, generation, recipients);
+        }
+        ClientEvent::TypingStop {
+            receiver_type,
+            receiver_id,
+        } => {
+            let conversation = (receiver_type, receiver_id);
+            if typing.stop(conversation) {
+                let recipients =
+                    typing_recipients(group_manage, friend_manage, auth_user.uid, conversation)
+                        .await?;
+                typing::relay(ctx, auth_user.uid, conversation, false, &recipients).await;
+            }
+        }
+        ClientEvent::ReactionAdd { mid, emoji } => {
+            if let Err(e) = react(ctx, auth_user.uid, mid, emoji, true).await {
+                debug!(error = ?e, "add reaction failed");
+                send_to_users(ctx, &[auth_user.uid], Msg::new("添加表情失败").into()).await;
+            }
+        }
+        ClientEvent::ReactionRemove { mid, emoji } => {
+            if let Err(e) = react(ctx, auth_user.uid, mid, emoji, false).await {
+                debug!(error = ?e, "remove reaction failed");
+                send_to_users(ctx, &[auth_user.uid], Msg::new("删除表情失败").into()).await;
+            }
+        }
+        ClientEvent::Read(read) => {
+            // a wrong read is only told to the user, it should not close the websocket
+            if let Err(e) = advance_read(ctx, auth_user.uid, read).await {
+                debug!(error = ?e, "advance read cursor failed");
+                send_to_users(ctx, &[auth_user.uid], Msg::new("已读消息错误").into()).await;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// same as `recipients`, but a user who is not a friend of the sender doesn't
+/// receive the typing, so that the refused user can't bother him
+async fn typing_recipients(
+    group_manage: &GroupManage,
+    friend_manage: &FriendManage,
+    uid: u64,
+    (receiver_type, receiver_id): (ReceiverType, u64),
+) -> Result<Vec<u64>> {
+    if receiver_type == ReceiverType::User && !friend_manage.is_friend(uid, receiver_id).await? {
+        return Ok(vec![]);
+    }
+    Ok(recipients(group_manage, uid, receiver_type, receiver_id)
+        .await?
+        .unwrap_or_default())
+}
+
+/// the user can see the message if he is in the conversation now
+pub async fn can_see(
+    group_manage: &GroupManage,
+    uid: u64,
+    message: &ChatMessage,
+) -> Result<bool> {
+    match message.receiver_type {
+        ReceiverType::User => Ok(message.sender_uid == uid || message.receiver_id == uid),
+        ReceiverType::Group => Ok(group_manage
+            .get_members(message.receiver_id)
+            .await?
+            .contains(&uid)),
+    }
+}
+
+async fn get_history(
+    auth_user: AuthUser,
+    Query(history): Query<MessageHistory>,
+    Extension(message_manage): Extension<MessageManage>,
+    Extension(group_manage): Extension<GroupManage>,
+    Extension(reaction_manage): Extension<ReactionManage>,
+    Extension(file_manage): Extension<FileManage>,
+) -> Result<Json<Vec<MessageView>>> {
+    if history.receiver_type == ReceiverType::Group
+        && !group_manage
+            .get_members(history.receiver_id)
+            .await?
+            .contains(&auth_user.uid)
+    {
+        return Err(Error::Forbidden);
+    }
+    let messages = message_manage.get_history(auth_user.uid, &history).await?;
+    let views = to_views(&message_manage, &reaction_manage, &file_manage, auth_user.uid, messages).await?;
+    Ok(Json(views))
+}
+
+/// search the messages in the conversations of the user, with the matched terms highlighted
+async fn search_messages(
+    auth_user: AuthUser,
+    Query(search): Query<SearchMessage>,
+    Extension(message_manage): Extension<MessageManage>,
+    Extension(file_manage): Extension<FileManage>,
+) -> Result<Json<Vec<MessageHit>>> {
+    let terms = search.terms();
+    if terms.is_empty() {
+        return Err(Error::unprocessable_entity([("q", "搜索内容不能为空")]));
+    }
+    let mut messages = message_manage
+        .search_messages(auth_user.uid, &search)
+        .await?;
+    fill_processed_images(&file_manage, &mut messages).await?;
+    let hits = messages
+        .into_iter()
+        .map(|message| MessageHit {
+            snippet: highlight(&message.content, &terms),
+            message,
+        })
+        .collect();
+    Ok(Json(hits))
+}
+
+/// embed the preview of the replied message and the reactions into every message
+async fn to_views(
+    message_manage: &MessageManage,
+    reaction_manage: &ReactionManage,
+    file_manage: &FileManage,
+    uid: u64,
+    mut messages: Vec<ChatMessage>,
+) -> Result<Vec<MessageView>> {
+    fill_processed_images(file_manage, &mut messages).await?;
+    let mut previews = message_manage.get_reply_previews(&messages).await?;
+    let mids: Vec<u64> = messages.iter().map(|message| message.mid).collect();
+    let mut reactions = reaction_manage.get_counts(&mids, uid).await?;
+    let views = messages
+        .into_iter()
+        .map(|message| MessageView {
+            reply_to: message.reply_to_mid.and_then(|mid| previews.remove(&mid)),
+            reactions: reactions.remove(&message.mid).unwrap_or_default(),
+            message,
+        })
+        .collect();
+    Ok(views)
+}
+
+/// the message which the user can edit or recall now
+async fn editable_message(
+    ctx: &ApiContext,
+    message_manage: &MessageManage,
+    uid: u64,
+    mid: u64,
+) -> Result<ChatMessage> {
+    let message = message_manage
+        .get_message(mid)
+        .await?
+        .ok_or(Error::NotFound)?;
+    if message.sender_uid != uid {
+        return Err(Error::Forbidden);
+    }
+    if message.recall_time.is_some() {
+        return Err(Error::unprocessable_entity([("msg", "消息已撤回")]));
+    }
+    if Local::now().naive_local() - message.create_time > ctx.config.message.edit_window() {
+        return Err(Error::unprocessable_entity([("msg", "已超过可以修改的时间")]));
+    }
+    Ok(message)
+}
+
+async fn edit_message(
+    auth_user: AuthUser,
+    Path(mid): Path<u64>,
+    Json(edit): Json<EditMessage>,
+    Extension(message_manage): Extension<MessageManage>,
+    Extension(group_manage): Extension<GroupManage>,
+    Extension(ctx): Extension<ApiContext>,
+) -> Result<()> {
+    if edit.content.is_empty() {
+        return Err(Error::unprocessable_entity([("content", "消息内容不能为空")]));
+    }
+    let message = editable_message(&ctx, &message_manage, auth_user.uid, mid).await?;
+    if message.kind != MessageKind::Text {
+        return Err(Error::unprocessable_entity([("msg", "只能修改文本消息")]));
+    }
+    let edit_time = Local::now().naive_local();
+    message_manage
+        .edit_message(mid, &edit.content, edit_time)
+        .await?;
+
+    let recipients = recipients(&group_manage, auth_user.uid, message.receiver_type, message.receiver_id)
+        .await?
+        .unwrap_or_default();
+    let event = Event::MessageEdited {
+        mid,
+        receiver_type: message.receiver_type,
+        receiver_id: message.receiver_id,
+        content: edit.content,
+        edit_time,
+    };
+    send_to_users(&ctx, &recipients, event.into()).await;
+    Ok(())
+}
+
+async fn recall_message(
+    auth_user: AuthUser,
+    Path(mid): Path<u64>,
+    Extension(message_manage): Extension<MessageManage>,
+    Extension(group_manage): Extension<GroupManage>,
+    Extension(ctx): Extension<ApiContext>,
+) -> Result<()> {
+    let message = editable_message(&ctx, &message_manage, auth_user.uid, mid).await?;
+    let recall_time = Local::now().naive_local();
+    message_manage.recall_message(mid, recall_time).await?;
+
+    let recipients = recipients(&group_manage, auth_user.uid, message.receiver_type, message.receiver_id)
+        .await?
+        .unwrap_or_default();
+    let event = Event::MessageRecalled {
+        mid,
+        receiver_type: message.receiver_type,
+        receiver_id: message.receiver_id,
+        recall_time,
+    };
+    send_to_users(&ctx, &recipients, event.into()).await;
+    Ok(())
+}
+
+async fn get_edits(
+    auth_user: AuthUser,
+    Path(mid): Path<u64>,
+    Extension(message_manage): Extension<MessageManage>,
+    Extension(group_manage): Extension<GroupManage>,
+) -> Result<Json<Vec<MessageEdit>>> {
+    let message = message_manage
+        .get_message(mid)
+        .await?
+        .ok_or(Error::NotFound)?;
+    if !can_see(&group_manage, auth_user.uid, &message).await? {
+        return Err(Error::Forbidden);
+    }
+    let edits = message_manage.get_edits(mid).await?;
+    Ok(Json(edits))
+}
+
+/// all the replies to the root message, the root message itself is the first one
+async fn get_thread(
+    auth_user: AuthUser,
+    Path(mid): Path<u64>,
+    Extension(message_manage): Extension<MessageManage>,
+    Extension(group_manage): Extension<GroupManage>,
+    Extension(reaction_manage): Extension<ReactionManage>,
+    Extension(file_manage): Extension<FileManage>,
+) -> Result<Json<Vec<MessageView>>> {
+    let root = message_manage
+        .get_message(mid)
+        .await?
+        .ok_or(Error::NotFound)?;
+    if !can_see(&group_manage, auth_user.uid, &root).await? {
+        return Err(Error::Forbidden);
+    }
+    let mut messages = vec![root];
+    messages.extend(message_manage.get_thread(mid).await?);
+    let views = to_views(&message_manage, &reaction_manage, &file_manage, auth_user.uid, messages).await?;
+    Ok(Json(views))
+}
@@ -0,0 +1,62 @@
+use std::time::Instant;
+
+use axum::extract::MatchedPath;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::Request;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Extension, Router};
+
+use crate::ApiContext;
+
+pub fn router(_ctx: &ApiContext) -> Router {
+    Router::new().route("/metrics", get(metrics))
+}
+
+async fn metrics(Extension(ctx): Extension<ApiContext>) -> impl IntoResponse {
+    let metrics = &ctx.metrics;
+    // the gauges are sampled now instead of being updated everywhere
+    metrics
+        .websocket_connections
+        .set(ctx.connections.count() as i64);
+    metrics.queued_frames.set(ctx.connections.queued() as i64);
+    let size = ctx.storage.pool.size() as i64;
+    let idle = ctx.storage.pool.idle() as i64;
+    metrics.db_connections.with_label_values(&["idle"]).set(idle);
+    metrics
+        .db_connections
+        .with_label_values(&["busy"])
+        .set(size - idle);
+    (
+        [(CONTENT_TYPE, "text/plain; version=0.0.4")],
+        metrics.encode(),
+    )
+}
+
+/// count the requests and time them by the matched route, it's added by `route_layer`
+/// so that the unmatched paths don't make a label each
+pub async fn track<B>(request: Request<B>, next: Next<B>) -> Response {
+    let start = Instant::now();
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_default();
+    let ctx = request.extensions().get::<ApiContext>().cloned();
+
+    let response = next.run(request).await;
+    if let Some(ctx) = ctx {
+        let status = response.status().as_u16().to_string();
+        ctx.metrics
+            .http_requests
+            .with_label_values(&[&method, &route, &status])
+            .inc();
+        ctx.metrics
+            .http_duration
+            .with_label_values(&[&method, &route])
+            .observe(start.elapsed().as_secs_f64());
+    }
+    response
+}
@@ -3,11 +3,19 @@ mod friend;
 mod msg;
 mod group;
 mod message;
+mod conversation;
+mod reaction;
+mod file;
+mod metrics;
+#[cfg(test)]
+mod test;
 
-use axum::Router;
+use axum::{middleware, Router};
 
 use crate::ApiContext;
 
+pub use message::send_to_users;
+
 
 pub fn api_router(ctx: &ApiContext) -> Router {
     // This is the order that the modules were authored in.
@@ -15,4 +23,9 @@ pub fn api_router(ctx: &ApiContext) -> Router {
         .merge(friend::router(ctx))
         .merge(group::router(ctx))
         .merge(message::router(ctx))
+        .merge(conversation::router(ctx))
+        .merge(reaction::router(ctx))
+        .merge(file::router(ctx))
+        .merge(metrics::router(ctx))
+        .route_layer(middleware::from_fn(metrics::track))
 }
\ No newline at end of file
@@ -0,0 +1,85 @@
+use axum::extract::Path;
+use axum::routing::post;
+use axum::{Extension, Json, Router};
+use eChat::err::{Error, Result};
+
+use crate::auth::AuthUser;
+use crate::http::message::{can_see, recipients};
+use crate::http::send_to_users;
+use crate::modles::frame::Event;
+use crate::modles::message::ReceiverType;
+use crate::modles::reaction::*;
+use crate::ApiContext;
+
+pub fn router(_ctx: &ApiContext) -> Router {
+    Router::new().route(
+        "/api/messages/:mid/reactions",
+        post(add_reaction).delete(remove_reaction),
+    )
+}
+
+async fn add_reaction(
+    auth_user: AuthUser,
+    Path(mid): Path<u64>,
+    Json(reaction): Json<React>,
+    Extension(ctx): Extension<ApiContext>,
+) -> Result<()> {
+    react(&ctx, auth_user.uid, mid, reaction.emoji, true).await
+}
+
+async fn remove_reaction(
+    auth_user: AuthUser,
+    Path(mid): Path<u64>,
+    Json(reaction): Json<React>,
+    Extension(ctx): Extension<ApiContext>,
+) -> Result<()> {
+    react(&ctx, auth_user.uid, mid, reaction.emoji, false).await
+}
+
+/// add or remove the reaction of the user, and tell the others in the conversation.
+/// it's shared by the rest api and the websocket
+pub async fn react(ctx: &ApiContext, uid: u64, mid: u64, emoji: String, add: bool) -> Result<()> {
+    if !is_emoji(&emoji) {
+        return Err(Error::unprocessable_entity([("emoji", "表情格式错误")]));
+    }
+    let group_manage = ctx.storage.groups.clone();
+    let message = ctx.storage.messages
+        .get_message(mid)
+        .await?
+        .ok_or(Error::NotFound)?;
+    if !can_see(&group_manage, uid, &message).await? {
+        return Err(Error::Forbidden);
+    }
+    if message.recall_time.is_some() {
+        return Err(Error::unprocessable_entity([("msg", "消息已撤回")]));
+    }
+
+    let reaction_manage = ctx.storage.reactions.clone();
+    let changed = if add {
+        reaction_manage.add(mid, uid, &emoji).await?
+    } else {
+        reaction_manage.remove(mid, uid, &emoji).await?
+    };
+    if !changed {
+        return Ok(());
+    }
+
+    // the conversation seen by the user, a direct chat is identified by the peer
+    let receiver_id = match message.receiver_type {
+        ReceiverType::User if message.receiver_id == uid => message.sender_uid,
+        _ => message.receiver_id,
+    };
+    let recipients = recipients(&group_manage, uid, message.receiver_type, receiver_id)
+        .await?
+        .unwrap_or_default();
+    let event = Event::Reaction {
+        mid,
+        receiver_type: message.receiver_type,
+        receiver_id: message.receiver_id,
+        uid,
+        emoji,
+        added: add,
+    };
+    send_to_users(ctx, &recipients, event.into()).await;
+    Ok(())
+}
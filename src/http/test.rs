@@ -0,0 +1,758 @@
+//! drive the routes end-to-end against the in-memory stores,
+//! a server is started on a random port for every test
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::http::StatusCode;
+use axum::Extension;
+use futures::{SinkExt, StreamExt};
+use image::ImageEncoder;
+use reqwest::Method;
+use serde_json::{json, Value};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::blob::LocalStore;
+use crate::config::Config;
+use crate::connection::ConnectionRegistry;
+use crate::http::api_router;
+use crate::metrics::Metrics;
+use crate::modles::file::File;
+use crate::persistent::memory;
+use crate::presence::PresenceManage;
+use crate::shutdown::Shutdown;
+use crate::ApiContext;
+
+pub struct TestApp {
+    pub ctx: ApiContext,
+    addr: SocketAddr,
+    client: reqwest::Client,
+}
+
+/// a registered user who has logged in
+pub struct TestUser {
+    pub uid: u64,
+    pub token: String,
+}
+
+pub struct TestSocket {
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+}
+
+impl TestApp {
+    pub async fn spawn() -> TestApp {
+        let blob_dir = std::env::temp_dir().join(format!("echat-test-{}", std::process::id()));
+        let ctx = ApiContext {
+            config: Arc::new(Config::default()),
+            storage: memory::storage(),
+            connections: ConnectionRegistry::new(),
+            presence: PresenceManage::new(),
+            blob_store: Arc::new(LocalStore::new(blob_dir)),
+            shutdown: Shutdown::new(),
+            metrics: Metrics::new(),
+        };
+        let app = api_router(&ctx).layer(Extension(ctx.clone()));
+        let server =
+            axum::Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(app.into_make_service());
+        let addr = server.local_addr();
+        tokio::spawn(server);
+        TestApp {
+            ctx,
+            addr,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// send a json request, the body of the response is a json value,
+    /// or a string if it isn't json
+    pub async fn request(
+        &self,
+        method: Method,
+        uri: &str,
+        token: Option<&str>,
+        body: Option<Value>,
+    ) -> (StatusCode, Value) {
+        let mut request = self
+            .client
+            .request(method, format!("http://{}{}", self.addr, uri));
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        if let Some(body) = body {
+            request = request
+                .header("content-type", "application/json")
+                .body(body.to_string());
+        }
+        let response = request.send().await.unwrap();
+        let status = StatusCode::from_u16(response.status().as_u16()).unwrap();
+        let text = response.text().await.unwrap();
+        let body = serde_json::from_str(&text).unwrap_or(Value::String(text));
+        (status, body)
+    }
+
+    pub async fn get(&self, uri: &str, user: &TestUser) -> (StatusCode, Value) {
+        self.request(Method::GET, uri, Some(&user.token), None)
+            .await
+    }
+
+    pub async fn post(&self, uri: &str, user: &TestUser, body: Value) -> (StatusCode, Value) {
+        self.request(Method::POST, uri, Some(&user.token), Some(body))
+            .await
+    }
+
+    /// upload the file as `multipart/form-data`, return the status and the `FileView`
+    pub async fn upload(&self, user: &TestUser, name: &str, content: &[u8]) -> (StatusCode, Value) {
+        let boundary = "echat-test-boundary";
+        let mut body = format!(
+            "--{}\r\ncontent-disposition: form-data; name=\"file\"; filename=\"{}\"\r\n\r\n",
+            boundary, name
+        )
+        .into_bytes();
+        body.extend_from_slice(content);
+        body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+        let response = self
+            .client
+            .post(format!("http://{}/api/files", self.addr))
+            .bearer_auth(&user.token)
+            .header(
+                "content-type",
+                format!("multipart/form-data; boundary={}", boundary),
+            )
+            .body(body)
+            .send()
+            .await
+            .unwrap();
+        let status = StatusCode::from_u16(response.status().as_u16()).unwrap();
+        let text = response.text().await.unwrap();
+        (status, serde_json::from_str(&text).unwrap_or(Value::Null))
+    }
+
+    /// the raw content of the response
+    pub async fn download(&self, uri: &str, user: &TestUser) -> (StatusCode, Vec<u8>) {
+        let response = self
+            .client
+            .get(format!("http://{}{}", self.addr, uri))
+            .bearer_auth(&user.token)
+            .send()
+            .await
+            .unwrap();
+        let status = StatusCode::from_u16(response.status().as_u16()).unwrap();
+        (status, response.bytes().await.unwrap().to_vec())
+    }
+
+    /// register the user, the password is the same as the username
+    pub async fn register(&self, username: &str) -> TestUser {
+        let user = json!({
+            "username": username,
+            "mail": format!("{}@test.com", username),
+            "password": username,
+        });
+        let (status, _) = self
+            .request(Method::POST, "/api/users", None, Some(user))
+            .await;
+        assert_eq!(status, StatusCode::OK);
+        self.login(username, username).await.unwrap()
+    }
+
+    /// `None` if the login is rejected
+    pub async fn login(&self, username: &str, password: &str) -> Option<TestUser> {
+        let login = json!({ "username": username, "password": password });
+        let response = self
+            .client
+            .post(format!("http://{}/api/users/login", self.addr))
+            .header("content-type", "application/json")
+            .body(login.to_string())
+            .send()
+            .await
+            .unwrap();
+        let token = response
+            .headers()
+            .get("authorization")?
+            .to_str()
+            .unwrap()
+            .trim_start_matches("Bearer ")
+            .to_string();
+        let mut user = TestUser { uid: 0, token };
+        let (_, view) = self.get("/api/users", &user).await;
+        user.uid = view["uid"].as_u64().unwrap();
+        Some(user)
+    }
+
+    /// send a text message through the socket of the sender, return it once it's saved
+    pub async fn send_text(
+        &self,
+        socket: &mut TestSocket,
+        sender: &TestUser,
+        receiver: &TestUser,
+        content: &str,
+    ) -> Value {
+        socket
+            .send(json!({ "receiver_type": "User", "receiver_id": receiver.uid, "content": content }))
+            .await;
+        let uri = format!("/api/messages?receiver_type=User&receiver_id={}", receiver.uid);
+        for _ in 0..100 {
+            let (_, history) = self.get(&uri, sender).await;
+            let sent = history
+                .as_array()
+                .and_then(|history| history.iter().find(|m| m["content"] == content));
+            if let Some(message) = sent {
+                return message.clone();
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("the message {:?} is not saved", content);
+    }
+
+    /// connect the websocket as the user, wait until he is online
+    pub async fn connect(&self, user: &TestUser) -> TestSocket {
+        let url = format!("ws://{}/ws?access_token={}", self.addr, user.token);
+        let (stream, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+        for _ in 0..100 {
+            if self.ctx.connections.is_online(user.uid) {
+                return TestSocket { stream };
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("user {} is not online", user.uid);
+    }
+}
+
+impl TestSocket {
+    pub async fn send(&mut self, frame: Value) {
+        self.stream
+            .send(Message::Text(frame.to_string()))
+            .await
+            .unwrap();
+    }
+
+    /// the next frame which matches `filter`, the others are skipped
+    pub async fn recv(&mut self, filter: impl Fn(&Value) -> bool) -> Value {
+        let recv = async {
+            while let Some(message) = self.stream.next().await {
+                if let Message::Text(text) = message.unwrap() {
+                    let frame: Value = serde_json::from_str(&text).unwrap();
+                    if filter(&frame) {
+                        return frame;
+                    }
+                }
+            }
+            panic!("the websocket is closed");
+        };
+        tokio::time::timeout(Duration::from_secs(5), recv)
+            .await
+            .expect("no frame is received in time")
+    }
+
+    /// wait for the close frame, the text frames before it are skipped
+    pub async fn closed(&mut self) -> Option<CloseFrame<'static>> {
+        let recv = async {
+            while let Some(message) = self.stream.next().await {
+                if let Message::Close(frame) = message.unwrap() {
+                    return frame;
+                }
+            }
+            panic!("the websocket is dropped without a close frame");
+        };
+        tokio::time::timeout(Duration::from_secs(5), recv)
+            .await
+            .expect("the websocket is not closed in time")
+    }
+}
+
+#[tokio::test]
+async fn login_should_work() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    assert!(app.login("alice", "wrong").await.is_none());
+
+    let (status, user) = app.get("/api/users", &alice).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(user["uid"], alice.uid);
+    assert_eq!(user["username"], "alice");
+
+    let (status, _) = app.request(Method::GET, "/api/users", None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn public_profile_should_hide_mail() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    let bob = app.register("bob").await;
+
+    let (status, users) = app.get("/api/users/search?q=ali", &bob).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(users[0]["uid"], alice.uid);
+    assert_eq!(users[0]["username"], "alice");
+    assert!(users[0].get("mail").is_none());
+
+    let (status, user) = app.get(&format!("/api/users/{}", alice.uid), &bob).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(user["username"], "alice");
+    assert!(user.get("mail").is_none());
+    // the current user still sees the mail
+    let (_, user) = app.get("/api/users", &alice).await;
+    assert_eq!(user["mail"], "alice@test.com");
+}
+
+#[tokio::test]
+async fn deleted_user_should_be_rejected() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    let mut socket = app.connect(&alice).await;
+    let (status, _) = app
+        .request(
+            Method::DELETE,
+            "/api/users",
+            Some(&alice.token),
+            Some(json!({ "password": "alice" })),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    socket.closed().await;
+
+    // the token hasn't expired, but the user doesn't exist any more
+    let (status, _) = app.get("/api/users", &alice).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let url = format!("ws://{}/ws?access_token={}", app.addr, alice.token);
+    assert!(tokio_tungstenite::connect_async(url).await.is_err());
+}
+
+#[tokio::test]
+async fn friend_should_work() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    let bob = app.register("bob").await;
+
+    let (status, _) = app
+        .post("/api/friends", &alice, json!({ "friend_id": bob.uid }))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, friends) = app.get("/api/friends", &alice).await;
+    assert_eq!(friends, json!([]));
+
+    app.post(
+        "/api/friends/agree",
+        &bob,
+        json!({ "friend_id": alice.uid }),
+    )
+    .await;
+    let (_, friends) = app.get("/api/friends", &alice).await;
+    assert_eq!(friends[0]["username"], "bob");
+}
+
+#[tokio::test]
+async fn group_should_work() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    let bob = app.register("bob").await;
+
+    app.post("/api/groups", &alice, json!({ "name": "test" }))
+        .await;
+    let gid = app
+        .ctx
+        .storage
+        .groups
+        .get_groups_of_user(alice.uid)
+        .await
+        .unwrap()[0]
+        .gid;
+    app.post("/api/groups/join", &bob, json!({ "gid": gid }))
+        .await;
+    let (status, _) = app
+        .post(
+            "/api/groups/agree",
+            &bob,
+            json!({ "gid": gid, "uid": bob.uid }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    app.post(
+        "/api/groups/agree",
+        &alice,
+        json!({ "gid": gid, "uid": bob.uid }),
+    )
+    .await;
+
+    let mut socket = app.connect(&bob).await;
+    let (status, _) = app
+        .post("/api/groups/dissolve", &alice, json!({ "gid": gid }))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    let event = socket.recv(|frame| frame["event"] == "group_dissolved").await;
+    assert_eq!(event["gid"], gid);
+}
+
+#[tokio::test]
+async fn send_message_should_work() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    let bob = app.register("bob").await;
+    let mut alice_socket = app.connect(&alice).await;
+    let mut bob_socket = app.connect(&bob).await;
+
+    alice_socket
+        .send(json!({ "receiver_type": "User", "receiver_id": bob.uid, "content": "hello" }))
+        .await;
+    let message = bob_socket.recv(|frame| frame.get("mid").is_some()).await;
+    assert_eq!(message["sender_uid"], alice.uid);
+    assert_eq!(message["content"], "hello");
+
+    let uri = format!("/api/messages?receiver_type=User&receiver_id={}", alice.uid);
+    let (status, history) = app.get(&uri, &bob).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(history[0]["mid"], message["mid"]);
+
+    // an empty message is rejected, only the sender is told
+    alice_socket
+        .send(json!({ "receiver_type": "User", "receiver_id": bob.uid, "content": "" }))
+        .await;
+    alice_socket
+        .recv(|frame| frame["content"] == "消息内容不能为空")
+        .await;
+}
+
+#[tokio::test]
+async fn shutdown_should_close_sockets() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    let mut socket = app.connect(&alice).await;
+    assert_eq!(app.ctx.shutdown.sessions(), 1);
+
+    app.ctx.shutdown.trigger();
+    let frame = socket.recv(|frame| frame["event"] == "going_away").await;
+    assert_eq!(frame["reconnect_after_secs"], 5);
+    let close = socket.closed().await.unwrap();
+    assert_eq!(close.code, CloseCode::Away);
+
+    tokio::time::timeout(Duration::from_secs(5), app.ctx.shutdown.drained())
+        .await
+        .expect("the session is not closed");
+    assert!(!app.ctx.connections.is_online(alice.uid));
+    assert_eq!(app.ctx.connections.count(), 0);
+}
+
+#[tokio::test]
+async fn metrics_should_work() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    assert!(app.login("alice", "wrong").await.is_none());
+    app.get("/api/users", &alice).await;
+
+    let (status, text) = app.request(Method::GET, "/metrics", None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let text = text.as_str().unwrap();
+    assert!(text.contains("echat_login_failures_total 1"));
+    assert!(text.contains(
+        "echat_http_requests_total{method=\"GET\",route=\"/api/users\",status=\"200\"}"
+    ));
+    assert!(text.contains("echat_db_connections{state=\"idle\"}"));
+    assert!(text.contains("echat_queued_frames 0"));
+    assert!(text.contains("echat_frames_dropped_total 0"));
+}
+
+#[tokio::test]
+async fn conversations_should_work() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    let bob = app.register("bob").await;
+    let mut alice_socket = app.connect(&alice).await;
+    app.send_text(&mut alice_socket, &alice, &bob, "one").await;
+    let message = app.send_text(&mut alice_socket, &alice, &bob, "two").await;
+
+    let (status, conversations) = app.get("/api/conversations", &bob).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(conversations[0]["receiver_id"], alice.uid);
+    assert_eq!(conversations[0]["name"], "alice");
+    assert_eq!(conversations[0]["last_message"]["content"], "two");
+    assert_eq!(conversations[0]["unread"], 2);
+
+    let read = json!({ "receiver_type": "User", "receiver_id": alice.uid, "mid": message["mid"] });
+    let (status, _) = app.post("/api/conversations/read", &bob, read.clone()).await;
+    assert_eq!(status, StatusCode::OK);
+    let frame = alice_socket.recv(|frame| frame["event"] == "read").await;
+    assert_eq!(frame["uid"], bob.uid);
+    assert_eq!(frame["mid"], message["mid"]);
+    let (_, conversations) = app.get("/api/conversations", &bob).await;
+    assert_eq!(conversations[0]["unread"], 0);
+    assert_eq!(conversations[0]["last_read_mid"], message["mid"]);
+
+    // the cursor doesn't move back
+    assert!(!app
+        .ctx
+        .storage
+        .conversations
+        .read(bob.uid, &serde_json::from_value(read).unwrap())
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn reactions_should_work() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    let bob = app.register("bob").await;
+    let carol = app.register("carol").await;
+    let mut alice_socket = app.connect(&alice).await;
+    let message = app.send_text(&mut alice_socket, &alice, &bob, "hello").await;
+    let uri = format!("/api/messages/{}/reactions", message["mid"]);
+
+    for emoji in ["lol", "<b>", " "] {
+        let (status, _) = app.post(&uri, &bob, json!({ "emoji": emoji })).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    let (status, _) = app.post(&uri, &bob, json!({ "emoji": "👍" })).await;
+    assert_eq!(status, StatusCode::OK);
+    let frame = alice_socket.recv(|frame| frame["event"] == "reaction").await;
+    assert_eq!(frame["uid"], bob.uid);
+    assert_eq!(frame["added"], true);
+    let (status, _) = app.post(&uri, &carol, json!({ "emoji": "👍" })).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let history = format!("/api/messages?receiver_type=User&receiver_id={}", alice.uid);
+    let (_, messages) = app.get(&history, &bob).await;
+    assert_eq!(
+        messages[0]["reactions"],
+        json!([{ "emoji": "👍", "count": 1, "reacted": true }])
+    );
+
+    let (status, _) = app
+        .request(Method::DELETE, &uri, Some(&bob.token), Some(json!({ "emoji": "👍" })))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, messages) = app.get(&history, &bob).await;
+    assert_eq!(messages[0]["reactions"], json!([]));
+}
+
+#[tokio::test]
+async fn edit_and_recall_should_work() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    let bob = app.register("bob").await;
+    let mut alice_socket = app.connect(&alice).await;
+    let mut bob_socket = app.connect(&bob).await;
+    let message = app.send_text(&mut alice_socket, &alice, &bob, "hello").await;
+    let mid = message["mid"].as_u64().unwrap();
+
+    let edit = json!({ "content": "hi" });
+    let (status, _) = app
+        .post(&format!("/api/messages/{}/edit", mid), &bob, edit.clone())
+        .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = app
+        .post(&format!("/api/messages/{}/edit", mid), &alice, edit)
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    let frame = bob_socket
+        .recv(|frame| frame["event"] == "message_edited")
+        .await;
+    assert_eq!(frame["content"], "hi");
+    let (_, edits) = app
+        .get(&format!("/api/messages/{}/edits", mid), &bob)
+        .await;
+    assert_eq!(edits[0]["content"], "hello");
+
+    let (status, _) = app
+        .post(&format!("/api/messages/{}/recall", mid), &alice, json!({}))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    bob_socket
+        .recv(|frame| frame["event"] == "message_recalled" && frame["mid"] == mid)
+        .await;
+    let history = format!("/api/messages?receiver_type=User&receiver_id={}", alice.uid);
+    let (_, messages) = app.get(&history, &bob).await;
+    assert_eq!(messages[0]["content"], "");
+    assert!(messages[0]["recall_time"].is_string());
+    let (_, edits) = app
+        .get(&format!("/api/messages/{}/edits", mid), &bob)
+        .await;
+    assert_eq!(edits, json!([]));
+}
+
+#[tokio::test]
+async fn files_should_work() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    let bob = app.register("bob").await;
+    let (status, file) = app.upload(&alice, "note.txt", b"hello").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(file["name"], "note.txt");
+    assert_eq!(file["size"], 5);
+    let url = file["url"].as_str().unwrap();
+
+    let (status, content) = app.download(url, &alice).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content, b"hello");
+    let (status, _) = app.download(url, &bob).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // bob can download it once it's shared with him
+    let mut alice_socket = app.connect(&alice).await;
+    let mut bob_socket = app.connect(&bob).await;
+    alice_socket
+        .send(json!({
+            "receiver_type": "User",
+            "receiver_id": bob.uid,
+            "payload": { "kind": "file", "url": url, "name": "note.txt", "size": 5 },
+        }))
+        .await;
+    bob_socket.recv(|frame| frame.get("mid").is_some()).await;
+    let (status, content) = app.download(url, &bob).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content, b"hello");
+}
+
+#[tokio::test]
+async fn image_should_be_processed_later() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    let bob = app.register("bob").await;
+    let image = image::RgbImage::from_pixel(800, 400, image::Rgb([200, 100, 50]));
+    let mut content = Vec::new();
+    image::codecs::png::PngEncoder::new(&mut content)
+        .write_image(image.as_raw(), 800, 400, image::ColorType::Rgb8)
+        .unwrap();
+    // the blob was saved, but the processing of it was interrupted
+    let sha256 = eChat::utils::sha256_hex(&content);
+    let mut file = File {
+        fid: 0,
+        sha256: sha256.clone(),
+        name: "a.png".to_string(),
+        uploader: alice.uid,
+        size: content.len() as u64,
+        content_type: "image/png".to_string(),
+        create_time: chrono::Local::now().naive_local(),
+    };
+    file.fid = app.ctx.storage.files.create_file(&file).await.unwrap();
+
+    let mut alice_socket = app.connect(&alice).await;
+    let mut bob_socket = app.connect(&bob).await;
+    alice_socket
+        .send(json!({
+            "receiver_type": "User",
+            "receiver_id": bob.uid,
+            "payload": { "kind": "image", "url": file.url(), "width": 80, "height": 40 },
+        }))
+        .await;
+    let frame = bob_socket.recv(|frame| frame.get("mid").is_some()).await;
+    assert!(frame["payload"].get("blurhash").is_none());
+
+    // uploading the same bytes processes it again
+    let (status, _) = app.upload(&alice, "a.png", &content).await;
+    assert_eq!(status, StatusCode::OK);
+    for _ in 0..500 {
+        if app.ctx.storage.files.get_image(&sha256).await.unwrap().is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let uri = format!("/api/messages?receiver_type=User&receiver_id={}", alice.uid);
+    let (_, history) = app.get(&uri, &bob).await;
+    let payload = &history[0]["payload"];
+    assert_eq!(payload["width"], 800);
+    assert_eq!(payload["height"], 400);
+    assert!(payload["blurhash"].is_string());
+    assert_eq!(payload["thumbnails"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn typing_should_work() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    let bob = app.register("bob").await;
+    app.post("/api/friends", &alice, json!({ "friend_id": bob.uid }))
+        .await;
+    app.post(
+        "/api/friends/agree",
+        &bob,
+        json!({ "friend_id": alice.uid }),
+    )
+    .await;
+    let mut alice_socket = app.connect(&alice).await;
+    let mut bob_socket = app.connect(&bob).await;
+
+    let typing = json!({ "receiver_type": "User", "receiver_id": bob.uid });
+    let mut start = typing.clone();
+    start["event"] = json!("typing_start");
+    alice_socket.send(start).await;
+    let frame = bob_socket.recv(|frame| frame["event"] == "typing").await;
+    assert_eq!(frame["uid"], alice.uid);
+    assert_eq!(frame["typing"], true);
+
+    let mut stop = typing;
+    stop["event"] = json!("typing_stop");
+    alice_socket.send(stop).await;
+    let frame = bob_socket.recv(|frame| frame["event"] == "typing").await;
+    assert_eq!(frame["typing"], false);
+
+    // the typing frames are not saved as messages
+    let uri = format!("/api/messages?receiver_type=User&receiver_id={}", bob.uid);
+    let (_, history) = app.get(&uri, &alice).await;
+    assert_eq!(history, json!([]));
+}
+
+#[tokio::test]
+async fn read_through_websocket_should_work() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    let bob = app.register("bob").await;
+    let mut alice_socket = app.connect(&alice).await;
+    let mut bob_socket = app.connect(&bob).await;
+    let message = app.send_text(&mut alice_socket, &alice, &bob, "hello").await;
+
+    bob_socket
+        .send(json!({
+            "event": "read",
+            "receiver_type": "User",
+            "receiver_id": alice.uid,
+            "mid": message["mid"],
+        }))
+        .await;
+    let frame = alice_socket.recv(|frame| frame["event"] == "read").await;
+    assert_eq!(frame["uid"], bob.uid);
+    assert_eq!(frame["mid"], message["mid"]);
+
+    let (_, conversations) = app.get("/api/conversations", &bob).await;
+    assert_eq!(conversations[0]["unread"], 0);
+    // the read frame is not sent to alice as a message
+    let uri = format!("/api/messages?receiver_type=User&receiver_id={}", bob.uid);
+    let (_, history) = app.get(&uri, &alice).await;
+    assert_eq!(history.as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn binary_frame_should_be_rejected() {
+    let app = TestApp::spawn().await;
+    let alice = app.register("alice").await;
+    let bob = app.register("bob").await;
+    app.post("/api/friends", &alice, json!({ "friend_id": bob.uid }))
+        .await;
+    app.post(
+        "/api/friends/agree",
+        &bob,
+        json!({ "friend_id": alice.uid }),
+    )
+    .await;
+    let mut alice_socket = app.connect(&alice).await;
+    let mut bob_socket = app.connect(&bob).await;
+
+    bob_socket.stream.send(Message::Binary(vec![1, 2, 3])).await.unwrap();
+    let frame = bob_socket
+        .recv(|frame| frame["content"] == "message format error")
+        .await;
+    assert!(frame.get("mid").is_none());
+    // the session is still alive
+    app.send_text(&mut bob_socket, &bob, &alice, "hello").await;
+
+    // the friends are told once the user is gone
+    drop(bob_socket);
+    let frame = alice_socket
+        .recv(|frame| frame["event"] == "presence" && frame["status"] == "offline")
+        .await;
+    assert_eq!(frame["uid"], bob.uid);
+}
@@ -1,3 +1,6 @@
+use axum::extract::{Path, Query};
+use axum::http::header::CONTENT_DISPOSITION;
+use axum::response::IntoResponse;
 use axum::routing::{get, post};
 use axum::Router;
 use axum::{
@@ -9,34 +12,52 @@ use eChat::err::Error;
 use eChat::utils;
 
 use crate::auth::AuthUser;
+use crate::http::send_to_users;
+use crate::modles::frame::Event;
 use crate::modles::user::*;
-use crate::persistent::UserManage;
+use crate::persistent::{FriendManage, GroupManage, MessageManage, UserManage};
 use crate::ApiContext;
 
 pub fn router(ctx: &ApiContext) -> Router {
     Router::new()
         .route("/api/users/login", post(login))
-        .route("/api/users", get(get_current_user).post(create_user))
-        .layer(Extension(UserManage::new(ctx.db.clone())))
+        .route(
+            "/api/users",
+            get(get_current_user)
+                .post(create_user)
+                .patch(update_current_user)
+                .delete(delete_current_user),
+        )
+        .route("/api/users/export", get(export_current_user))
+        .route("/api/users/search", get(search_users))
+        .route("/api/users/:uid", get(get_user_profile))
+        .layer(Extension(ctx.storage.users.clone()))
+        .layer(Extension(ctx.storage.friends.clone()))
+        .layer(Extension(ctx.storage.groups.clone()))
+        .layer(Extension(ctx.storage.messages.clone()))
 }
 
 #[debug_handler]
 async fn login(
     Json(login_user): Json<LoginUser>,
     Extension(user_manage): Extension<UserManage>,
+    Extension(ctx): Extension<ApiContext>,
 ) -> Result<TypedHeader<Authorization<Bearer>>, Error> {
     let user = user_manage
         .get_user_by_username(&login_user.username)
         .await?;
     if user.is_none() {
+        ctx.metrics.login_failures.inc();
         return Err(Error::unprocessable_entity([("msg", "用户不存在")]));
     }
     let user = user.unwrap();
-    if !utils::verify(&login_user.password, &user.password, &user.salt) {
+    let auth = &ctx.config.auth;
+    if !utils::verify(&login_user.password, &user.password, &user.salt, auth.pbkdf2_iterations) {
+        ctx.metrics.login_failures.inc();
         return Err(Error::unprocessable_entity([("msg", "用户名或者密码错误")]));
     }
 
-    let auth_user = AuthUser::new(user.uid, user.username, user.mail);
+    let auth_user = AuthUser::new(user.uid, user.username, user.mail, auth.token_lifetime());
     let token = AuthUser::encode(&auth_user);
 
     Ok(TypedHeader(Authorization::bearer(&token).unwrap()))
@@ -53,8 +74,130 @@ async fn get_current_user(
 async fn create_user(
     Json(user): Json<CreateUser>,
     Extension(user_manage): Extension<UserManage>,
+    Extension(ctx): Extension<ApiContext>,
 ) -> Result<String, Error> {
     tracing::info!(user = ?user, "create a user");
-    user_manage.create_user(user.into()).await?;
+    let user = user.into_user(ctx.config.auth.pbkdf2_iterations);
+    user_manage.create_user(user).await?;
     Ok("注册成功".to_string())
 }
+
+/// only the safe fields can be changed here, a new mail need to be verified again
+async fn update_current_user(
+    auth_user: AuthUser,
+    Json(profile): Json<UpdateProfile>,
+    Extension(user_manage): Extension<UserManage>,
+) -> Result<Json<ViewUser>, Error> {
+    validate_profile(&profile)?;
+    user_manage
+        .update_user(profile.into_update_user(auth_user.uid))
+        .await?;
+    let user = user_manage.get_user(auth_user.uid).await?;
+    Ok(Json(user.into()))
+}
+
+fn validate_profile(profile: &UpdateProfile) -> Result<(), Error> {
+    let mut errors = Vec::new();
+    if let Some(username) = &profile.username {
+        if username.is_empty() || username.chars().count() > 20 {
+            errors.push(("username", "用户名长度需要在1到20之间"));
+        }
+    }
+    if let Some(mail) = &profile.mail {
+        if !mail.contains('@') || mail.chars().count() > 50 {
+            errors.push(("mail", "邮箱格式错误"));
+        }
+    }
+    if let Some(display_name) = &profile.display_name {
+        if display_name.chars().count() > 32 {
+            errors.push(("display_name", "昵称不能超过32个字符"));
+        }
+    }
+    if let Some(avatar) = &profile.avatar {
+        if avatar.chars().count() > 255 {
+            errors.push(("avatar", "头像地址不能超过255个字符"));
+        }
+    }
+    if let Some(bio) = &profile.bio {
+        if bio.chars().count() > 255 {
+            errors.push(("bio", "个人简介不能超过255个字符"));
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::unprocessable_entity(errors))
+    }
+}
+
+async fn search_users(
+    _auth_user: AuthUser,
+    Query(search): Query<SearchUser>,
+    Extension(user_manage): Extension<UserManage>,
+) -> Result<Json<Vec<PublicUser>>, Error> {
+    if search.q.is_empty() {
+        return Err(Error::unprocessable_entity([("q", "搜索内容不能为空")]));
+    }
+    let users = user_manage.search_users(&search).await?;
+    Ok(Json(users))
+}
+
+/// the public profile of any user
+async fn get_user_profile(
+    _auth_user: AuthUser,
+    Path(uid): Path<u64>,
+    Extension(user_manage): Extension<UserManage>,
+) -> Result<Json<PublicUser>, Error> {
+    let user = user_manage.get_user(uid).await.map_err(|e| match e {
+        Error::Sqlx(sqlx::Error::RowNotFound) => Error::NotFound,
+        e => e,
+    })?;
+    Ok(Json(user.into()))
+}
+
+/// download everything about the current user as a json file
+async fn export_current_user(
+    auth_user: AuthUser,
+    Extension(user_manage): Extension<UserManage>,
+    Extension(friend_manage): Extension<FriendManage>,
+    Extension(group_manage): Extension<GroupManage>,
+    Extension(message_manage): Extension<MessageManage>,
+) -> Result<impl IntoResponse, Error> {
+    let uid = auth_user.uid;
+    let export = UserExport {
+        profile: user_manage.get_user(uid).await?.into(),
+        friends: friend_manage.get_friends(uid).await?,
+        groups: group_manage.get_groups_of_user(uid).await?,
+        messages: message_manage.get_messages_of_user(uid).await?,
+    };
+    Ok((
+        [(CONTENT_DISPOSITION, "attachment; filename=\"echat-export.json\"")],
+        Json(export),
+    ))
+}
+
+/// delete the account of current user, the password is required
+async fn delete_current_user(
+    auth_user: AuthUser,
+    Json(delete_user): Json<DeleteUser>,
+    Extension(user_manage): Extension<UserManage>,
+    Extension(ctx): Extension<ApiContext>,
+) -> Result<String, Error> {
+    let user = user_manage.get_user(auth_user.uid).await?;
+    let n_iter = ctx.config.auth.pbkdf2_iterations;
+    if !utils::verify(&delete_user.password, &user.password, &user.salt, n_iter) {
+        return Err(Error::unprocessable_entity([("msg", "密码错误")]));
+    }
+
+    let dissolved = user_manage
+        .delete_user(user.uid, ctx.config.message.deleted_policy)
+        .await?;
+
+    // the tokens of the user are rejected since the user is deleted, close the websocket as well
+    ctx.connections.close(user.uid);
+
+    for (gid, members) in dissolved {
+        send_to_users(&ctx, &members, Event::GroupDissolved { gid }.into()).await;
+    }
+    Ok("账号已注销".to_string())
+}
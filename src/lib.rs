@@ -1,4 +1,5 @@
 #![allow(dead_code)]
+#![allow(non_snake_case)]
 
 use std::collections::HashMap;
 
@@ -16,7 +17,6 @@ pub enum Error {
     NoSubscriber,
 }
 
-const QUEUE_SIZE: usize = 100;
 type Subscribers<T> = HashMap<String, broadcast::Sender<T>>;
 
 pub enum Event<T> {
@@ -46,8 +46,9 @@ impl<T> MessageBus<T>
 where
     T: Clone + Send + Sync + 'static,
 {
-    pub fn new() -> Self {
-        let (bus, message_manage) = MessageBusManage::new();
+    /// `queue_size` is the capacity of the event queue and of every subscription
+    pub fn new(queue_size: usize) -> Self {
+        let (bus, message_manage) = MessageBusManage::new(queue_size);
         message_manage.run();
         bus       
     }
@@ -78,19 +79,21 @@ where
 {
     subscribers: Subscribers<T>,
     receiver: mpsc::Receiver<Event<T>>,
+    queue_size: usize,
 }
 
 impl<T> MessageBusManage<T>
 where
     T: Clone + Send + Sync + 'static,
 {
-    pub fn new() -> (MessageBus<T>, MessageBusManage<T>) {
-        let (sender, receiver) = mpsc::channel(QUEUE_SIZE);
+    pub fn new(queue_size: usize) -> (MessageBus<T>, MessageBusManage<T>) {
+        let (sender, receiver) = mpsc::channel(queue_size);
         (
             MessageBus { sender },
             MessageBusManage {
                 subscribers: HashMap::new(),
                 receiver,
+                queue_size,
             },
         )
     }
@@ -111,8 +114,9 @@ where
                         let _ = sender.send(result);
                     }
                     Event::NewSubscriber { key, sender } => {
+                        let queue_size = self.queue_size;
                         let tx = self.subscribers.entry(key).or_insert_with(|| {
-                            let (tx, _) = broadcast::channel(QUEUE_SIZE);
+                            let (tx, _) = broadcast::channel(queue_size);
                             tx
                         });
                         // ignore the situation which caller no longer care the result
@@ -134,7 +138,7 @@ mod test {
 
     #[tokio::test]
     async fn publish_subscribe_should_work(){
-        let bus: MessageBus<String> = MessageBus::new();
+        let bus: MessageBus<String> = MessageBus::new(100);
 
         let mut receiver = bus.subscribe("alice".to_string()).await.unwrap();
         let join_handle = tokio::spawn(async move {
@@ -152,7 +156,7 @@ mod test {
 
     #[tokio::test]
     async fn multiple_subscribe_should_work(){
-        let bus: MessageBus<String> = MessageBus::new();
+        let bus: MessageBus<String> = MessageBus::new(100);
 
         let mut receiver = bus.subscribe("alice".to_string()).await.unwrap();
         let subscriber1 = tokio::spawn(async move {
@@ -1,50 +1,108 @@
+#![allow(non_snake_case)]
 mod auth;
+mod blob;
+mod config;
+mod connection;
 mod http;
+mod media;
+mod metrics;
 mod modles;
 mod persistent;
+mod presence;
+mod shutdown;
+mod typing;
+// the bin shares these with the lib, part of them is only used by the lib
+#[allow(dead_code)]
 mod err;
+#[allow(dead_code)]
 mod utils;
 
-use std::collections::HashMap;
 use std::sync::Arc;
 
 use axum::Extension;
-use dashmap::DashMap;
+use blob::BlobStore;
+use config::Config;
+use connection::ConnectionRegistry;
 use eChat::err::Error;
 use http::api_router;
-use modles::message::Msg;
-use persistent::get_pool;
-use sqlx::MySqlPool;
-use tokio::sync::Mutex;
-use tokio::sync::mpsc::Sender;
-use tower_http::cors::{CorsLayer};
+use metrics::Metrics;
+use persistent::Storage;
+use presence::PresenceManage;
+use shutdown::Shutdown;
 use tower_http::trace::TraceLayer;
 
 #[derive(Clone)]
 pub struct ApiContext {
-    pub db: Arc<MySqlPool>,
-    pub active_users: Arc<Mutex<HashMap<u64, Sender<Msg>>>>,
+    pub config: Arc<Config>,
+    pub storage: Storage,
+    pub connections: ConnectionRegistry,
+    pub presence: PresenceManage,
+    pub blob_store: Arc<dyn BlobStore>,
+    pub shutdown: Shutdown,
+    pub metrics: Metrics,
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Error> {
-    dotenvy::dotenv().unwrap(); // load .env file
-                                // Initialize the logger
+    // load .env file, it's optional
+    if let Err(e) = dotenvy::dotenv() {
+        if !e.not_found() {
+            return Err(anyhow::Error::new(e).context("invalid .env").into());
+        }
+    }
+    // Initialize the logger
     tracing_subscriber::fmt::init();
     //tracing_subscriber::fmt().with_max_level(tracing::Level::TRACE).init();
-    let pool = get_pool().await?;
+    let config = Config::load()?;
+    let metrics = Metrics::new();
     let ctx = ApiContext {
-        db: Arc::new(pool),
-        active_users: Arc::new(Mutex::new(HashMap::default()))
+        storage: Storage::connect(&config.database)
+            .await?
+            .metered(&metrics.db_query_duration),
+        connections: ConnectionRegistry::new(),
+        presence: PresenceManage::new(),
+        blob_store: blob::from_config(&config.blob)?,
+        shutdown: Shutdown::new(),
+        metrics,
+        config: Arc::new(config),
     };
     let app = api_router(&ctx)
-        .layer(Extension(ctx))
-        .layer(CorsLayer::permissive())
+        .layer(Extension(ctx.clone()))
+        .layer(ctx.config.server.cors())
         .layer(TraceLayer::new_for_http());
 
-    axum::Server::bind(&"0.0.0.0:8080".parse().unwrap())
-        .serve(app.into_make_service())
-        .await
-        .expect("error running HTTP server");
+    tracing::info!(addr = %ctx.config.server.bind, "listening");
+    let shutdown = ctx.shutdown.clone();
+    // stop accepting new connections once the shutdown is triggered
+    let mut server = tokio::spawn(
+        axum::Server::bind(&ctx.config.server.bind)
+            .serve(app.into_make_service())
+            .with_graceful_shutdown(async move { shutdown.triggered().await }),
+    );
+    tokio::select! {
+        result = &mut server => {
+            result
+                .expect("the server task panicked")
+                .expect("error running HTTP server");
+            return Ok(());
+        }
+        _ = shutdown::signal() => (),
+    }
+
+    tracing::info!(sessions = ctx.shutdown.sessions(), "shutting down");
+    ctx.shutdown.trigger();
+    let deadline = ctx.config.server.shutdown_timeout();
+    let closed = tokio::time::timeout(deadline, async {
+        let _ = server.await;
+        ctx.shutdown.drained().await;
+    })
+    .await;
+    if closed.is_err() {
+        tracing::warn!(sessions = ctx.shutdown.sessions(), "shutdown timed out");
+    }
+    // the sessions not closed in time are dropped, save their last seen time
+    if let Err(e) = ctx.presence.save_all(&ctx).await {
+        tracing::warn!(error = ?e, "save presence failed");
+    }
     Ok(())
 }
@@ -0,0 +1,266 @@
+use anyhow::{anyhow, Context};
+use bytes::Bytes;
+use eChat::err::Result;
+use eChat::utils::sha256_hex;
+use image::codecs::jpeg::JpegEncoder;
+use image::GenericImageView;
+use tracing::{debug, warn};
+
+use crate::modles::file::{ImageMeta, ThumbnailBlob, THUMBNAIL_SIZES};
+use crate::persistent::FileManage;
+use crate::ApiContext;
+
+/// the quality of the jpeg thumbnails
+const THUMBNAIL_QUALITY: u8 = 80;
+/// the components of the blurhash, more components make a longer hash
+const BLURHASH_COMPONENTS: (u32, u32) = (4, 3);
+/// the image is scaled down before computing the blurhash, it's slow on a large image
+const BLURHASH_SIDE: u32 = 32;
+/// the tag of the pointer to the GPS IFD in the IFD0
+const GPS_IFD_TAG: usize = 0x8825;
+
+/// the result of decoding a uploaded image
+pub struct ProcessedImage {
+    pub meta: ImageMeta,
+    /// (max side, jpeg content, width, height)
+    pub thumbnails: Vec<(u32, Vec<u8>, u32, u32)>,
+}
+
+/// generate the thumbnails and the blurhash of the uploaded image in the background,
+/// the result is saved with the blob of the image
+pub fn spawn_process(ctx: ApiContext, file_manage: FileManage, sha256: String, content: Bytes) {
+    tokio::spawn(async move {
+        if let Err(e) = process(&ctx, &file_manage, &sha256, content).await {
+            warn!(error = ?e, sha256, "process image failed");
+        }
+    });
+}
+
+async fn process(
+    ctx: &ApiContext,
+    file_manage: &FileManage,
+    sha256: &str,
+    content: Bytes,
+) -> Result<()> {
+    // decoding is cpu bound, don't block the runtime
+    let processed = tokio::task::spawn_blocking(move || analyze(&content))
+        .await
+        .context("analyze image")??;
+    let mut thumbnails = Vec::with_capacity(processed.thumbnails.len());
+    for (max_side, content, width, height) in processed.thumbnails {
+        let thumbnail_sha256 = sha256_hex(&content);
+        ctx.blob_store
+            .put(&thumbnail_sha256, content.into(), "image/jpeg")
+            .await?;
+        thumbnails.push(ThumbnailBlob {
+            max_side,
+            sha256: thumbnail_sha256,
+            width,
+            height,
+        });
+    }
+    file_manage
+        .save_image(sha256, &processed.meta, &thumbnails)
+        .await?;
+    debug!(sha256, meta = ?processed.meta, "image processed");
+    Ok(())
+}
+
+/// decode the image, a thumbnail is generated for every size in `THUMBNAIL_SIZES`
+/// which is smaller than the image
+pub fn analyze(content: &[u8]) -> anyhow::Result<ProcessedImage> {
+    let image = image::load_from_memory(content).context("decode image")?;
+    let (width, height) = image.dimensions();
+
+    let small = image.thumbnail(BLURHASH_SIDE, BLURHASH_SIDE).to_rgba8();
+    let blurhash = blurhash::encode(
+        BLURHASH_COMPONENTS.0,
+        BLURHASH_COMPONENTS.1,
+        small.width(),
+        small.height(),
+        small.as_raw(),
+    )
+    .map_err(|e| anyhow!("encode blurhash: {:?}", e))?;
+
+    let mut thumbnails = Vec::new();
+    for max_side in THUMBNAIL_SIZES {
+        if width.max(height) <= max_side {
+            continue;
+        }
+        let thumbnail = image.thumbnail(max_side, max_side).to_rgb8();
+        let mut content = Vec::new();
+        JpegEncoder::new_with_quality(&mut content, THUMBNAIL_QUALITY)
+            .encode_image(&thumbnail)
+            .context("encode thumbnail")?;
+        thumbnails.push((max_side, content, thumbnail.width(), thumbnail.height()));
+    }
+
+    Ok(ProcessedImage {
+        meta: ImageMeta {
+            width,
+            height,
+            blurhash,
+        },
+        thumbnails,
+    })
+}
+
+/// erase the GPS IFD in the EXIF of a jpeg, the other metadata such as the orientation is kept.
+/// return `None` if the content is not a jpeg or has no location
+pub fn strip_exif_location(content: &[u8]) -> Option<Vec<u8>> {
+    if !content.starts_with(&[0xFF, 0xD8]) {
+        return None;
+    }
+    let mut content = content.to_vec();
+    let mut stripped = false;
+    let mut pos = 2;
+    while pos + 4 <= content.len() && content[pos] == 0xFF {
+        let marker = content[pos + 1];
+        // the image data begins after SOS, there is no metadata any more
+        if marker == 0xDA {
+            break;
+        }
+        let len = u16::from_be_bytes([content[pos + 2], content[pos + 3]]) as usize;
+        let end = pos + 2 + len;
+        if len < 2 || end > content.len() {
+            break;
+        }
+        // APP1 with the `Exif\0\0` header, a TIFF structure follows
+        if marker == 0xE1 && content[pos + 4..end].starts_with(b"Exif\0\0") {
+            if let Some(mut tiff) = Tiff::new(&mut content[pos + 10..end]) {
+                stripped |= tiff.erase_gps();
+            }
+        }
+        pos = end;
+    }
+    stripped.then_some(content)
+}
+
+struct Tiff<'a> {
+    data: &'a mut [u8],
+    big_endian: bool,
+}
+
+impl<'a> Tiff<'a> {
+    fn new(data: &'a mut [u8]) -> Option<Self> {
+        let big_endian = match data.get(..2)? {
+            b"MM" => true,
+            b"II" => false,
+            _ => return None,
+        };
+        Some(Tiff { data, big_endian })
+    }
+
+    fn u16(&self, at: usize) -> Option<usize> {
+        let bytes = [*self.data.get(at)?, *self.data.get(at + 1)?];
+        let value = if self.big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        };
+        Some(value as usize)
+    }
+
+    fn u32(&self, at: usize) -> Option<usize> {
+        let bytes: [u8; 4] = self.data.get(at..at + 4)?.try_into().ok()?;
+        let value = if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        };
+        Some(value as usize)
+    }
+
+    fn zero(&mut self, from: usize, len: usize) -> Option<()> {
+        self.data.get_mut(from..from.checked_add(len)?)?.fill(0);
+        Some(())
+    }
+
+    /// zero the entries of the GPS IFD and the values they point to,
+    /// the IFD is left with no entry. return false if there is no GPS IFD
+    fn erase_gps(&mut self) -> bool {
+        self.try_erase_gps().unwrap_or(false)
+    }
+
+    fn try_erase_gps(&mut self) -> Option<bool> {
+        let ifd0 = self.u32(4)?;
+        let gps = (0..self.u16(ifd0)?)
+            .map(|i| ifd0 + 2 + i * 12)
+            .find(|&entry| self.u16(entry) == Some(GPS_IFD_TAG))
+            .and_then(|entry| self.u32(entry + 8))?;
+        let count = self.u16(gps)?;
+        for i in 0..count {
+            let entry = gps + 2 + i * 12;
+            let size = type_size(self.u16(entry + 2)?) * self.u32(entry + 4)?;
+            // the value is saved in the entry if it fits in 4 bytes
+            if size > 4 {
+                let offset = self.u32(entry + 8)?;
+                self.zero(offset, size)?;
+            }
+        }
+        // the count, the entries and the offset of the next IFD
+        self.zero(gps, 2 + count * 12 + 4)?;
+        Some(count > 0)
+    }
+}
+
+/// the bytes of a value of the TIFF field type
+fn type_size(field_type: usize) -> usize {
+    match field_type {
+        1 | 2 | 6 | 7 => 1,
+        3 | 8 => 2,
+        4 | 9 | 11 => 4,
+        5 | 10 | 12 => 8,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// a jpeg header with a little endian EXIF, the IFD0 has only the GPS pointer,
+    /// and the GPS IFD has a `GPSLatitude` of 3 rationals
+    fn jpeg_with_gps() -> Vec<u8> {
+        let mut tiff = b"II\x2a\x00\x08\x00\x00\x00".to_vec();
+        // IFD0 at 8: 1 entry, GPS pointer -> 26
+        tiff.extend([1, 0, 0x25, 0x88, 4, 0, 1, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0]);
+        // GPS IFD at 26: 1 entry, GPSLatitude, 3 rationals at 44
+        tiff.extend([1, 0, 2, 0, 5, 0, 3, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0]);
+        tiff.extend((1..=24).collect::<Vec<u8>>());
+
+        let mut app1 = b"Exif\0\0".to_vec();
+        app1.extend(tiff);
+        let len = (app1.len() + 2) as u16;
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE1];
+        jpeg.extend(len.to_be_bytes());
+        jpeg.extend(app1);
+        jpeg.extend([0xFF, 0xDA, 0x00, 0x02, 0xFF, 0xD9]);
+        jpeg
+    }
+
+    #[test]
+    fn strip_exif_location_should_work() {
+        let jpeg = jpeg_with_gps();
+        let stripped = strip_exif_location(&jpeg).unwrap();
+        assert_eq!(stripped.len(), jpeg.len());
+        // the header of the segment and the IFD0 are kept
+        assert_eq!(stripped[..38], jpeg[..38]);
+        // the GPS IFD and the latitude are erased
+        assert!(stripped[38..stripped.len() - 6].iter().all(|&b| b == 0));
+        assert_eq!(strip_exif_location(&stripped), None);
+        assert_eq!(strip_exif_location(b"\x89PNG"), None);
+    }
+
+    #[test]
+    fn analyze_should_work() {
+        let image = image::RgbImage::from_pixel(800, 400, image::Rgb([200, 100, 50]));
+        let mut content = Vec::new();
+        JpegEncoder::new(&mut content).encode_image(&image).unwrap();
+        let processed = analyze(&content).unwrap();
+        assert_eq!((processed.meta.width, processed.meta.height), (800, 400));
+        assert!(!processed.meta.blurhash.is_empty());
+        let sizes: Vec<_> = processed.thumbnails.iter().map(|t| (t.0, t.2, t.3)).collect();
+        assert_eq!(sizes, vec![(160, 160, 80), (640, 640, 320)]);
+    }
+}
@@ -0,0 +1,172 @@
+//! the metrics of the server, served by `/metrics` in the prometheus text format
+
+use prometheus::{
+    exponential_buckets, Encoder, HistogramOpts, HistogramVec, IntCounter, IntCounterVec,
+    IntGauge, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+
+use crate::connection::Sent;
+use crate::modles::message::ReceiverType;
+
+/// cloning it only clones the `Arc`s inside
+#[derive(Clone)]
+pub struct Metrics {
+    registry: Registry,
+    /// labeled by `method`, `route` and `status`, the route is the pattern such as `/api/messages/:mid`
+    pub http_requests: IntCounterVec,
+    /// labeled by `method` and `route`
+    pub http_duration: HistogramVec,
+    /// the open websocket connections, it's sampled when it's scraped
+    pub websocket_connections: IntGauge,
+    /// the frames waiting in the outbound queues of all the connections, sampled as well.
+    /// there is no offline queue, the frames which don't fit are counted by `frames_dropped`
+    pub queued_frames: IntGauge,
+    /// the frames of any kind not queued because the queue is full or the connection is closing,
+    /// the client resyncs or reconnects for them, see `OverflowPolicy`
+    pub frames_dropped: IntCounter,
+    /// the chat messages saved, labeled by `receiver_type`
+    pub messages_received: IntCounterVec,
+    /// the chat messages queued to the online recipients, labeled by `receiver_type`
+    pub messages_delivered: IntCounterVec,
+    /// the chat messages not queued because the queue is full, see `OverflowPolicy`
+    pub messages_dropped: IntCounterVec,
+    /// the connections of the pool, labeled by `state` which is `idle` or `busy`
+    pub db_connections: IntGaugeVec,
+    /// labeled by `store` and `query`, see `Storage::metered`
+    pub db_query_duration: HistogramVec,
+    pub login_failures: IntCounter,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new_custom(Some("echat".to_string()), None).unwrap();
+        // 1ms to about 16s
+        let buckets = exponential_buckets(0.001, 2.0, 15).unwrap();
+        let metrics = Metrics {
+            http_requests: IntCounterVec::new(
+                Opts::new("http_requests_total", "the handled http requests"),
+                &["method", "route", "status"],
+            )
+            .unwrap(),
+            http_duration: HistogramVec::new(
+                HistogramOpts::new("http_request_duration_seconds", "the latency of the http requests")
+                    .buckets(buckets.clone()),
+                &["method", "route"],
+            )
+            .unwrap(),
+            websocket_connections: IntGauge::new(
+                "websocket_connections",
+                "the open websocket connections",
+            )
+            .unwrap(),
+            queued_frames: IntGauge::new(
+                "queued_frames",
+                "the frames waiting in the outbound queues of the websockets",
+            )
+            .unwrap(),
+            frames_dropped: IntCounter::new(
+                "frames_dropped_total",
+                "the frames dropped by the full or closing outbound queues",
+            )
+            .unwrap(),
+            messages_received: IntCounterVec::new(
+                Opts::new("messages_received_total", "the chat messages received"),
+                &["receiver_type"],
+            )
+            .unwrap(),
+            messages_delivered: IntCounterVec::new(
+                Opts::new("messages_delivered_total", "the chat messages queued to the recipients"),
+                &["receiver_type"],
+            )
+            .unwrap(),
+            messages_dropped: IntCounterVec::new(
+                Opts::new("messages_dropped_total", "the chat messages dropped by the full queues"),
+                &["receiver_type"],
+            )
+            .unwrap(),
+            db_connections: IntGaugeVec::new(
+                Opts::new("db_connections", "the connections of the database pool"),
+                &["state"],
+            )
+            .unwrap(),
+            db_query_duration: HistogramVec::new(
+                HistogramOpts::new("db_query_duration_seconds", "the latency of the database queries")
+                    .buckets(buckets),
+                &["store", "query"],
+            )
+            .unwrap(),
+            login_failures: IntCounter::new("login_failures_total", "the rejected logins").unwrap(),
+            registry,
+        };
+        let collectors: Vec<Box<dyn prometheus::core::Collector>> = vec![
+            Box::new(metrics.http_requests.clone()),
+            Box::new(metrics.http_duration.clone()),
+            Box::new(metrics.websocket_connections.clone()),
+            Box::new(metrics.queued_frames.clone()),
+            Box::new(metrics.frames_dropped.clone()),
+            Box::new(metrics.messages_received.clone()),
+            Box::new(metrics.messages_delivered.clone()),
+            Box::new(metrics.messages_dropped.clone()),
+            Box::new(metrics.db_connections.clone()),
+            Box::new(metrics.db_query_duration.clone()),
+            Box::new(metrics.login_failures.clone()),
+        ];
+        for collector in collectors {
+            metrics.registry.register(collector).unwrap();
+        }
+        metrics
+    }
+
+    /// a chat message is saved and sent to the online recipients
+    pub fn message(&self, receiver_type: ReceiverType, sent: Sent) {
+        let label = [receiver_type_label(receiver_type)];
+        self.messages_received.with_label_values(&label).inc();
+        self.messages_delivered
+            .with_label_values(&label)
+            .inc_by(sent.queued as u64);
+        self.messages_dropped
+            .with_label_values(&label)
+            .inc_by(sent.dropped as u64);
+    }
+
+    /// a frame is sent to the online users
+    pub fn frame(&self, sent: Sent) {
+        self.frames_dropped.inc_by(sent.dropped as u64);
+    }
+
+    /// the text format
+    pub fn encode(&self) -> String {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .unwrap();
+        // the text format is always utf-8
+        String::from_utf8(buffer).unwrap()
+    }
+}
+
+fn receiver_type_label(receiver_type: ReceiverType) -> &'static str {
+    match receiver_type {
+        ReceiverType::User => "user",
+        ReceiverType::Group => "group",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_should_work() {
+        let metrics = Metrics::new();
+        metrics.message(ReceiverType::Group, Sent { queued: 2, dropped: 1 });
+        metrics.frame(Sent { queued: 0, dropped: 3 });
+        metrics.login_failures.inc();
+        let text = metrics.encode();
+        assert!(text.contains("echat_messages_received_total{receiver_type=\"group\"} 1"));
+        assert!(text.contains("echat_messages_delivered_total{receiver_type=\"group\"} 2"));
+        assert!(text.contains("echat_messages_dropped_total{receiver_type=\"group\"} 1"));
+        assert!(text.contains("echat_frames_dropped_total 3"));
+        assert!(text.contains("echat_login_failures_total 1"));
+    }
+}
@@ -0,0 +1,95 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::modles::message::{Message, ReceiverType};
+
+/// the max chars of the content in a preview
+const PREVIEW_LEN: usize = 50;
+/// the content of a preview for the recalled message
+pub const RECALLED_PREVIEW: &str = "[消息已撤回]";
+
+/// a direct chat is identified by the uid of the peer, a group chat by the gid
+#[derive(Serialize, Debug)]
+pub struct Conversation {
+    pub receiver_type: ReceiverType,
+    pub receiver_id: u64,
+    /// the display name of the peer or the name of the group
+    pub name: String,
+    pub avatar: Option<String>,
+    pub last_message: Option<MessagePreview>,
+    /// the time of the last message, or the create time of a group without message
+    pub last_active_time: NaiveDateTime,
+    pub last_read_mid: u64,
+    pub unread: i64,
+    /// the unread messages which mention the user, always 0 for a direct chat
+    pub mentions: i64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct MessagePreview {
+    pub mid: u64,
+    pub sender_uid: u64,
+    pub content: String,
+    pub create_time: NaiveDateTime,
+}
+
+impl MessagePreview {
+    /// the content is cut to `PREVIEW_LEN` chars
+    pub fn new(mid: u64, sender_uid: u64, content: &str, create_time: NaiveDateTime) -> Self {
+        let content = match content.char_indices().nth(PREVIEW_LEN) {
+            Some((end, _)) => format!("{}…", &content[..end]),
+            None => content.to_string(),
+        };
+        MessagePreview {
+            mid,
+            sender_uid,
+            content,
+            create_time,
+        }
+    }
+}
+
+impl From<&Message> for MessagePreview {
+    fn from(message: &Message) -> Self {
+        let content = match message.recall_time {
+            Some(_) => RECALLED_PREVIEW,
+            None => &message.content,
+        };
+        MessagePreview::new(
+            message.mid,
+            message.sender_uid,
+            content,
+            message.create_time,
+        )
+    }
+}
+
+/// the user has read the messages until `mid` in the conversation
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ReadMessage {
+    pub receiver_type: ReceiverType,
+    pub receiver_id: u64,
+    pub mid: u64,
+}
+
+/// how many members of the group have read the message, the sender is excluded
+#[derive(Serialize, Debug)]
+pub struct MessageReads {
+    pub mid: u64,
+    pub read_count: i64,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn preview_should_be_cut() {
+        let now = chrono::Local::now().naive_local();
+        let preview = MessagePreview::new(1, 1, "你好", now);
+        assert_eq!(preview.content, "你好");
+        let content = "好".repeat(PREVIEW_LEN + 1);
+        let preview = MessagePreview::new(1, 1, &content, now);
+        assert_eq!(preview.content, format!("{}…", "好".repeat(PREVIEW_LEN)));
+    }
+}
@@ -0,0 +1,103 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/// the url of a uploaded file is `FILE_URL_PREFIX` followed by the fid
+pub const FILE_URL_PREFIX: &str = "/api/files/";
+/// the max chars of a file name
+pub const MAX_FILE_NAME_LEN: usize = 255;
+
+/// the max side of the thumbnails generated for a uploaded image
+pub const THUMBNAIL_SIZES: [u32; 2] = [160, 640];
+
+/// a uploaded file, the files with the same content share one blob
+#[derive(Debug, Clone)]
+pub struct File {
+    pub fid: u64,
+    /// the hex sha256 of the content, it's also the key of the blob
+    pub sha256: String,
+    pub name: String,
+    pub uploader: u64,
+    pub size: u64,
+    /// sniffed from the content, the type claimed by the client is ignored
+    pub content_type: String,
+    pub create_time: NaiveDateTime,
+}
+
+impl File {
+    pub fn url(&self) -> String {
+        format!("{}{}", FILE_URL_PREFIX, self.fid)
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct FileView {
+    pub fid: u64,
+    pub url: String,
+    pub name: String,
+    pub size: u64,
+    pub content_type: String,
+}
+
+impl From<File> for FileView {
+    fn from(file: File) -> Self {
+        FileView {
+            url: file.url(),
+            fid: file.fid,
+            name: file.name,
+            size: file.size,
+            content_type: file.content_type,
+        }
+    }
+}
+
+/// the size and the placeholder of a uploaded image, filled in the background
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageMeta {
+    pub width: u32,
+    pub height: u32,
+    pub blurhash: String,
+}
+
+/// a thumbnail of a uploaded image, it's kept in the blob store too
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThumbnailBlob {
+    pub max_side: u32,
+    pub sha256: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ThumbnailBlob {
+    pub fn to_thumbnail(&self, fid: u64) -> Thumbnail {
+        Thumbnail {
+            url: format!("{}{}/thumbnails/{}", FILE_URL_PREFIX, fid, self.max_side),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+/// the thumbnail in the payload of a image message
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Thumbnail {
+    pub url: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// the fid of the url of a uploaded file, `None` for other urls
+pub fn parse_file_url(url: &str) -> Option<u64> {
+    url.strip_prefix(FILE_URL_PREFIX)?.parse().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_file_url_should_work() {
+        assert_eq!(parse_file_url("/api/files/12"), Some(12));
+        assert_eq!(parse_file_url("/api/files/x"), None);
+        assert_eq!(parse_file_url("https://example.com/api/files/12"), None);
+    }
+}
@@ -0,0 +1,178 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::modles::conversation::ReadMessage;
+use crate::modles::message::{Message, Msg, ReceiverType};
+use crate::modles::presence::{PresenceStatus, PresenceView};
+
+/// the frame pushed to the client through the websocket
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum Frame {
+    /// the notice from the server keeps the original format of `Msg`
+    Msg(Msg),
+    /// the saved chat message, a superset of `Msg` with the mid and the sender
+    Message(Message),
+    Event(Event),
+}
+
+/// the events other than chat message, distinguished by the `event` field
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    Presence(PresenceView),
+    /// `uid` is typing in the conversation, `typing` is false when he stops
+    Typing {
+        uid: u64,
+        receiver_type: ReceiverType,
+        receiver_id: u64,
+        typing: bool,
+    },
+    /// `uid` has read the messages until `mid`, only sent to the peer of a direct chat
+    Read {
+        uid: u64,
+        receiver_type: ReceiverType,
+        receiver_id: u64,
+        mid: u64,
+    },
+    MessageEdited {
+        mid: u64,
+        receiver_type: ReceiverType,
+        receiver_id: u64,
+        content: String,
+        edit_time: NaiveDateTime,
+    },
+    MessageRecalled {
+        mid: u64,
+        receiver_type: ReceiverType,
+        receiver_id: u64,
+        recall_time: NaiveDateTime,
+    },
+    /// `uid` adds or removes a reaction to the message
+    Reaction {
+        mid: u64,
+        receiver_type: ReceiverType,
+        receiver_id: u64,
+        uid: u64,
+        emoji: String,
+        added: bool,
+    },
+    /// the user is mentioned in the group message `mid`, `all` is true for `@all`
+    Mention {
+        mid: u64,
+        gid: u64,
+        sender_uid: u64,
+        all: bool,
+    },
+    /// the group is dissolved by the owner, or because the owner deleted the account
+    GroupDissolved { gid: u64 },
+    /// some frames were dropped because the client is too slow, only the chat messages are
+    /// saved. the client should reload the conversations and the history
+    Resync,
+    /// the server is shutting down, it's the last frame before the socket is closed.
+    /// the client should connect again after `reconnect_after_secs`
+    GoingAway { reconnect_after_secs: u64 },
+}
+
+impl From<Msg> for Frame {
+    fn from(msg: Msg) -> Self {
+        Frame::Msg(msg)
+    }
+}
+
+impl From<Message> for Frame {
+    fn from(message: Message) -> Self {
+        Frame::Message(message)
+    }
+}
+
+impl From<Event> for Frame {
+    fn from(event: Event) -> Self {
+        Frame::Event(event)
+    }
+}
+
+/// the frame sent by the client which is not a chat message
+#[derive(Deserialize, Debug)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ClientEvent {
+    Heartbeat,
+    Presence { status: PresenceStatus },
+    TypingStart { receiver_type: ReceiverType, receiver_id: u64 },
+    TypingStop { receiver_type: ReceiverType, receiver_id: u64 },
+    Read(ReadMessage),
+    ReactionAdd { mid: u64, emoji: String },
+    ReactionRemove { mid: u64, emoji: String },
+}
+
+/// the text frame sent by the client
+#[derive(Debug)]
+pub enum ClientFrame {
+    Message(Msg),
+    Event(ClientEvent),
+}
+
+impl ClientFrame {
+    /// the frame with an `event` field is a client event, otherwise it's a chat message.
+    /// `Msg` ignores the unknown fields, so a event like typing is a valid `Msg` as well
+    pub fn parse(text: &str) -> serde_json::Result<Self> {
+        let frame: serde_json::Value = serde_json::from_str(text)?;
+        if frame.get("event").is_some() {
+            serde_json::from_value(frame).map(ClientFrame::Event)
+        } else {
+            serde_json::from_value(frame).map(ClientFrame::Message)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn event_should_be_tagged() {
+        let frame: Frame = Event::Presence(PresenceView {
+            uid: 1,
+            status: PresenceStatus::Away,
+            last_seen: None,
+        })
+        .into();
+        let json = serde_json::to_value(&frame).unwrap();
+        assert_eq!(json["event"], "presence");
+        assert_eq!(json["status"], "away");
+    }
+
+    #[test]
+    fn client_event_should_work() {
+        let event: ClientEvent = serde_json::from_str(r#"{"event":"heartbeat"}"#).unwrap();
+        assert!(matches!(event, ClientEvent::Heartbeat));
+        let event: ClientEvent =
+            serde_json::from_str(r#"{"event":"presence", "status":"away"}"#).unwrap();
+        assert!(matches!(
+            event,
+            ClientEvent::Presence {
+                status: PresenceStatus::Away
+            }
+        ));
+        let event: ClientEvent = serde_json::from_str(
+            r#"{"event":"read", "receiver_type":"Group", "receiver_id":1, "mid":10}"#,
+        )
+        .unwrap();
+        assert!(matches!(event, ClientEvent::Read(ReadMessage { mid: 10, .. })));
+    }
+
+    #[test]
+    fn client_frame_should_dispatch_on_event() {
+        let frame = ClientFrame::parse(
+            r#"{"event":"typing_start", "receiver_type":"User", "receiver_id":1}"#,
+        )
+        .unwrap();
+        assert!(matches!(frame, ClientFrame::Event(ClientEvent::TypingStart { .. })));
+        let frame =
+            ClientFrame::parse(r#"{"receiver_type":"User", "receiver_id":1, "content":"hi"}"#)
+                .unwrap();
+        assert!(matches!(frame, ClientFrame::Message(_)));
+        let frame = ClientFrame::parse(r#"{"event":"unknown", "receiver_type":"User", "receiver_id":1}"#);
+        assert!(frame.is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Serialize, Deserialize};
 
+#[allow(dead_code)]
 #[derive(Serialize, Debug)]
 pub struct Friend {
     fid: u64,
@@ -8,7 +9,7 @@ pub struct Friend {
     status: FriendStatus,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, sqlx::Type)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, sqlx::Type)]
 #[repr(i8)]
 pub enum FriendStatus {
     Pending = 0,
@@ -1,21 +1,29 @@
 use chrono::NaiveDateTime;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Group {
     pub gid: u64,
     pub owner: u64,
     pub name: String,
-    pub create_time: NaiveDateTime
+    pub create_time: NaiveDateTime,
+    /// the time when the group was dissolved, `None` means the group is still active
+    pub dissolve_time: Option<NaiveDateTime>,
+}
+
+impl Group {
+    pub fn is_dissolved(&self) -> bool {
+        self.dissolve_time.is_some()
+    }
 }
 #[derive(Deserialize)]
 pub struct CreateGroup {
     pub name: String,
 }
 
-#[derive(Debug, sqlx::Type)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, sqlx::Type)]
+#[repr(i8)]
 pub enum GroupStatus {
     Pending = 0,
     Agree = 1,
@@ -31,4 +39,9 @@ pub struct JoinGroup{
 pub struct AgreeGroup {
     pub gid: u64,
     pub uid: u64
+}
+
+#[derive(Deserialize)]
+pub struct DissolveGroup {
+    pub gid: u64
 }
\ No newline at end of file
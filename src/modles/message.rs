@@ -1,49 +1,297 @@
 use chrono::{Local, NaiveDateTime};
 use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
 
-#[derive(Clone, Debug, PartialEq, Serialize, sqlx::Type)]
+use crate::modles::conversation::MessagePreview;
+use crate::modles::payload::{MessageKind, Payload};
+use crate::modles::reaction::ReactionCount;
+
+#[derive(Clone, Debug, PartialEq, Serialize, sqlx::Type, sqlx::FromRow)]
 pub struct Message {
     pub mid: u64,
+    /// the text, or the fallback of the payload which can be searched
     pub content: String,
     pub sender_uid: u64,
     pub receiver_id: u64,
     pub create_time: NaiveDateTime,
     pub receiver_type: ReceiverType,
+    pub edit_time: Option<NaiveDateTime>,
+    /// a recalled message is a tombstone, its content is empty
+    pub recall_time: Option<NaiveDateTime>,
+    /// the message which is replied, it's in the same conversation
+    pub reply_to_mid: Option<u64>,
+    pub kind: MessageKind,
+    /// `None` for a text message
+    pub payload: Option<Json<Payload>>,
+}
+
+impl Message {
+    /// whether the message belongs to the conversation seen by `uid`,
+    /// a direct chat is identified by the peer, a group chat by the gid
+    pub fn in_conversation(&self, uid: u64, receiver_type: ReceiverType, receiver_id: u64) -> bool {
+        self.receiver_type == receiver_type
+            && match receiver_type {
+                ReceiverType::User => {
+                    (self.sender_uid == receiver_id && self.receiver_id == uid)
+                        || (self.sender_uid == uid && self.receiver_id == receiver_id)
+                }
+                ReceiverType::Group => self.receiver_id == receiver_id,
+            }
+    }
+}
+
+/// the message in the history, with the preview of the message it replies to
+/// and the reactions
+#[derive(Serialize, Debug)]
+pub struct MessageView {
+    #[serde(flatten)]
+    pub message: Message,
+    pub reply_to: Option<MessagePreview>,
+    pub reactions: Vec<ReactionCount>,
 }
 
 #[derive(Deserialize, Debug, Serialize, Clone)]
 pub struct Msg {
     pub receiver_type: ReceiverType,
     pub receiver_id: u64,
+    #[serde(default)]
     pub content: String,
+    /// the content of the message which is not a text
+    #[serde(default, skip_serializing_if = "is_text")]
+    pub payload: Payload,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_to_mid: Option<u64>,
+    /// the uids mentioned by `@user`, only for the group message
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mentions: Vec<u64>,
+    /// `@all`, only the admin of the group can mention all the members
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub mention_all: bool,
+}
+
+fn is_false(b: &bool) -> bool {
+    !*b
 }
+
+fn is_text(payload: &Payload) -> bool {
+    *payload == Payload::Text
+}
+
 impl Msg {
     pub fn new(content: &str) -> Self {
         Msg {
             receiver_type: ReceiverType::User,
             receiver_id: 0,
             content: content.into(),
+            payload: Payload::Text,
+            reply_to_mid: None,
+            mentions: vec![],
+            mention_all: false,
         }
     }
     pub fn to_message(&self, sender_uid: u64) -> Message {
+        let (content, payload) = match self.payload {
+            Payload::Text => (self.content.clone(), None),
+            ref payload => (payload.fallback(), Some(Json(payload.clone()))),
+        };
         Message {
             mid: 0,
-            content: self.content.clone(),
+            content,
             receiver_id: self.receiver_id,
-            receiver_type: self.receiver_type.clone(),
+            receiver_type: self.receiver_type,
             sender_uid,
             create_time: Local::now().naive_local(),
+            edit_time: None,
+            recall_time: None,
+            reply_to_mid: self.reply_to_mid,
+            kind: self.payload.kind(),
+            payload,
         }
     }
 }
 
-#[derive(Deserialize, Serialize, Clone, PartialEq, Debug, sqlx::Type)]
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash, Debug, sqlx::Type)]
 #[repr(i8)]
 pub enum ReceiverType {
     User = 0,
     Group = 1,
 }
 
+/// the uid saved in the `mention` table for `@all`
+pub const MENTION_ALL: u64 = 0;
+
+/// the content before the message was edited
+#[derive(Serialize, Debug)]
+pub struct MessageEdit {
+    pub mid: u64,
+    pub content: String,
+    pub edit_time: NaiveDateTime,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct EditMessage {
+    pub content: String,
+}
+
+const DEFAULT_HISTORY_SIZE: u32 = 20;
+const MAX_HISTORY_SIZE: u32 = 100;
+
+fn default_history_size() -> u32 {
+    DEFAULT_HISTORY_SIZE
+}
+
+/// query the messages of a conversation page by page, from the newest to the oldest
+#[derive(Deserialize, Debug)]
+pub struct MessageHistory {
+    pub receiver_type: ReceiverType,
+    /// the uid of the peer, or the gid
+    pub receiver_id: u64,
+    /// only the messages before this mid are returned, `None` means from the newest
+    pub before: Option<u64>,
+    #[serde(default = "default_history_size")]
+    pub size: u32,
+}
+
+impl MessageHistory {
+    pub fn limit(&self) -> u32 {
+        self.size.clamp(1, MAX_HISTORY_SIZE)
+    }
+}
+
+/// the max terms of a search, the rest are ignored
+const MAX_SEARCH_TERMS: usize = 5;
+/// the chars before the first matched term in a snippet
+const SNIPPET_BEFORE: usize = 20;
+/// the max chars of a snippet, the matched term at the end is kept whole
+const SNIPPET_LEN: usize = 80;
+
+/// search the messages in the conversations of the current user, the newest first
+#[derive(Deserialize, Debug)]
+pub struct SearchMessage {
+    /// the terms separated by spaces, a message must contain all of them
+    pub q: String,
+    /// only search in the conversation, `receiver_id` is the uid of the peer or the gid
+    pub receiver_type: Option<ReceiverType>,
+    pub receiver_id: Option<u64>,
+    pub sender_uid: Option<u64>,
+    /// only the messages created in [from, to)
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+    /// page number, start from 0
+    #[serde(default)]
+    pub page: u32,
+    #[serde(default = "default_history_size")]
+    pub size: u32,
+}
+
+impl SearchMessage {
+    pub fn terms(&self) -> Vec<&str> {
+        let mut terms: Vec<&str> = Vec::new();
+        for term in self.q.split_whitespace() {
+            if !terms.contains(&term) {
+                terms.push(term);
+            }
+        }
+        terms.truncate(MAX_SEARCH_TERMS);
+        terms
+    }
+
+    pub fn limit(&self) -> u32 {
+        self.size.clamp(1, MAX_HISTORY_SIZE)
+    }
+
+    pub fn offset(&self) -> u32 {
+        self.page.saturating_mul(self.limit())
+    }
+}
+
+/// a message found by the search
+#[derive(Serialize, Debug)]
+pub struct MessageHit {
+    #[serde(flatten)]
+    pub message: Message,
+    /// a part of the content around the first matched term, the html is escaped
+    /// and the matched terms are wrapped in `<em>`
+    pub snippet: String,
+}
+
+/// cut the content around the first matched term and highlight the terms, case insensitive
+pub fn highlight(content: &str, terms: &[&str]) -> String {
+    let lower = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let chars: Vec<char> = content.chars().collect();
+    let terms: Vec<Vec<char>> = terms
+        .iter()
+        .map(|term| term.chars().map(lower).collect())
+        .filter(|term: &Vec<char>| !term.is_empty())
+        .collect();
+    // the length of the term matched at `i`
+    let matched = |i: usize| {
+        terms
+            .iter()
+            .filter(|term| {
+                chars.len() - i >= term.len()
+                    && term.iter().zip(&chars[i..]).all(|(t, &c)| *t == lower(c))
+            })
+            .map(Vec::len)
+            .max()
+    };
+
+    let first = (0..chars.len()).find(|&i| matched(i).is_some()).unwrap_or(0);
+    let start = first.saturating_sub(SNIPPET_BEFORE);
+    let end = chars.len().min(start + SNIPPET_LEN);
+
+    let mut snippet = String::new();
+    if start > 0 {
+        snippet.push('…');
+    }
+    let mut i = start;
+    let mut last = start;
+    while i < end {
+        match matched(i) {
+            Some(len) => {
+                snippet.push_str("<em>");
+                push_escaped(&mut snippet, &chars[i..i + len]);
+                snippet.push_str("</em>");
+                i += len;
+            }
+            None => {
+                push_escaped(&mut snippet, &chars[i..i + 1]);
+                i += 1;
+            }
+        }
+        last = i;
+    }
+    if last < chars.len() {
+        snippet.push('…');
+    }
+    snippet
+}
+
+fn push_escaped(out: &mut String, chars: &[char]) {
+    for &c in chars {
+        match c {
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '&' => out.push_str("&amp;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// the `sender_uid` of the messages whose sender has deleted his account
+pub const DELETED_USER_ID: u64 = 0;
+
+/// what to do with the messages of a user when the user deletes his account
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessagePolicy {
+    /// keep the messages, but the sender is replaced with `DELETED_USER_ID`
+    Anonymize,
+    /// delete the messages
+    Remove,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -60,4 +308,34 @@ mod test {
         let msg: Msg = serde_json::from_str(msg).unwrap();
         println!("{:?}", msg);
     }
+
+    #[test]
+    fn mentions_should_be_optional() {
+        let msg = r#"{"receiver_type":"Group", "receiver_id":1, "content":"@all", "mention_all":true}"#;
+        let msg: Msg = serde_json::from_str(msg).unwrap();
+        assert!(msg.mentions.is_empty());
+        assert!(msg.mention_all);
+        let msg = serde_json::to_string(&Msg::new("hello")).unwrap();
+        assert!(!msg.contains("mention"));
+    }
+
+    #[test]
+    fn highlight_should_work() {
+        assert_eq!(highlight("Hello World", &["world"]), "Hello <em>World</em>");
+        assert_eq!(highlight("a<b> 你好", &["你好"]), "a&lt;b&gt; <em>你好</em>");
+        let content = format!("{}明天开会{}", "啊".repeat(30), "哦".repeat(100));
+        let snippet = highlight(&content, &["开会"]);
+        assert!(snippet.starts_with(&format!("…{}明天<em>开会</em>", "啊".repeat(18))));
+        assert!(snippet.ends_with('…'));
+    }
+
+    #[test]
+    fn in_conversation_should_work() {
+        let mut message = Msg::new("hello").to_message(1);
+        message.receiver_id = 2;
+        assert!(message.in_conversation(1, ReceiverType::User, 2));
+        assert!(message.in_conversation(2, ReceiverType::User, 1));
+        assert!(!message.in_conversation(3, ReceiverType::User, 1));
+        assert!(!message.in_conversation(1, ReceiverType::Group, 2));
+    }
 }
@@ -1,4 +1,10 @@
 pub mod user;
 pub mod friend;
 pub mod group;
-pub mod message;
\ No newline at end of file
+pub mod message;
+pub mod payload;
+pub mod presence;
+pub mod frame;
+pub mod conversation;
+pub mod reaction;
+pub mod file;
\ No newline at end of file
@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+
+use crate::modles::file::{Thumbnail, FILE_URL_PREFIX};
+
+/// the max chars of a url in the payload
+const MAX_URL_LEN: usize = 1024;
+/// the max chars of a file name or a location name
+const MAX_NAME_LEN: usize = 255;
+/// the max seconds of a voice message
+pub const MAX_VOICE_SECS: u32 = 60;
+
+/// the kind of the message, saved in `message.kind`
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[repr(i8)]
+pub enum MessageKind {
+    Text = 0,
+    Image = 1,
+    File = 2,
+    Voice = 3,
+    Location = 4,
+    Card = 5,
+    System = 6,
+}
+
+/// the structured content of the message, distinguished by the `kind` field.
+/// the text is saved in `message.content`, so a text message has no payload
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Payload {
+    #[default]
+    Text,
+    /// `blurhash` and `thumbnails` are filled by the server for the uploaded image
+    Image {
+        url: String,
+        width: u32,
+        height: u32,
+        /// the placeholder shown before the image is loaded
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        blurhash: Option<String>,
+        /// the smallest first
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        thumbnails: Vec<Thumbnail>,
+    },
+    File {
+        url: String,
+        name: String,
+        /// bytes
+        size: u64,
+    },
+    Voice {
+        url: String,
+        /// seconds
+        duration: u32,
+    },
+    Location {
+        latitude: f64,
+        longitude: f64,
+        name: String,
+    },
+    /// the contact card of a user
+    Card { uid: u64 },
+    /// the notice generated by the server, the client can't send it
+    System { text: String },
+}
+
+impl Payload {
+    pub fn kind(&self) -> MessageKind {
+        match self {
+            Payload::Text => MessageKind::Text,
+            Payload::Image { .. } => MessageKind::Image,
+            Payload::File { .. } => MessageKind::File,
+            Payload::Voice { .. } => MessageKind::Voice,
+            Payload::Location { .. } => MessageKind::Location,
+            Payload::Card { .. } => MessageKind::Card,
+            Payload::System { .. } => MessageKind::System,
+        }
+    }
+
+    /// the url of the image, file or voice
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            Payload::Image { url, .. } | Payload::File { url, .. } | Payload::Voice { url, .. } => {
+                Some(url)
+            }
+            _ => None,
+        }
+    }
+
+    /// the plain text saved in `message.content` for the message which is not a text,
+    /// so that it can be searched and shown in the preview
+    pub fn fallback(&self) -> String {
+        match self {
+            Payload::Text => String::new(),
+            Payload::Image { .. } => "[图片]".to_string(),
+            Payload::File { name, .. } => format!("[文件] {}", name),
+            Payload::Voice { .. } => "[语音]".to_string(),
+            Payload::Location { name, .. } => format!("[位置] {}", name),
+            Payload::Card { .. } => "[名片]".to_string(),
+            Payload::System { text } => text.clone(),
+        }
+    }
+
+    /// check the payload sent by the client, return the reason if it's invalid
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self {
+            Payload::Text | Payload::Card { .. } => Ok(()),
+            Payload::Image {
+                url, width, height, ..
+            } => {
+                validate_url(url)?;
+                if *width == 0 || *height == 0 {
+                    return Err("图片尺寸不正确");
+                }
+                Ok(())
+            }
+            Payload::File { url, name, size } => {
+                validate_url(url)?;
+                validate_name(name)?;
+                if *size == 0 {
+                    return Err("文件不能为空");
+                }
+                Ok(())
+            }
+            Payload::Voice { url, duration } => {
+                validate_url(url)?;
+                if *duration == 0 || *duration > MAX_VOICE_SECS {
+                    return Err("语音时长不正确");
+                }
+                Ok(())
+            }
+            Payload::Location {
+                latitude,
+                longitude,
+                name,
+            } => {
+                if !(-90.0..=90.0).contains(latitude) || !(-180.0..=180.0).contains(longitude) {
+                    return Err("位置坐标不正确");
+                }
+                validate_name(name)
+            }
+            Payload::System { .. } => Err("不能发送系统消息"),
+        }
+    }
+}
+
+/// only the http url or the url of the uploaded file is allowed
+fn validate_url(url: &str) -> Result<(), &'static str> {
+    let allowed = url.starts_with("https://")
+        || url.starts_with("http://")
+        || url.starts_with(FILE_URL_PREFIX);
+    if !allowed || url.chars().count() > MAX_URL_LEN {
+        return Err("链接格式不正确");
+    }
+    Ok(())
+}
+
+fn validate_name(name: &str) -> Result<(), &'static str> {
+    if name.trim().is_empty() || name.chars().count() > MAX_NAME_LEN {
+        return Err("名称不能为空且不能超过255个字符");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn payload_should_be_tagged() {
+        let payload = r#"{"kind":"voice","url":"https://example.com/a.amr","duration":3}"#;
+        let payload: Payload = serde_json::from_str(payload).unwrap();
+        assert_eq!(payload.kind(), MessageKind::Voice);
+        assert!(payload.validate().is_ok());
+
+        let payload = Payload::Location {
+            latitude: 91.0,
+            longitude: 0.0,
+            name: "北极".to_string(),
+        };
+        assert!(payload.validate().is_err());
+        assert!(Payload::System { text: "hi".into() }.validate().is_err());
+    }
+}
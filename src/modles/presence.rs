@@ -0,0 +1,24 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PresenceStatus {
+    Online,
+    Away,
+    Offline,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PresenceView {
+    pub uid: u64,
+    pub status: PresenceStatus,
+    /// `None` means the user has never connected
+    pub last_seen: Option<NaiveDateTime>,
+}
+
+#[derive(Debug)]
+pub struct FriendLastSeen {
+    pub uid: u64,
+    pub last_seen: Option<NaiveDateTime>,
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+/// the max chars of a emoji, some emoji are made of several chars
+pub const MAX_EMOJI_LEN: usize = 16;
+
+/// joins several emoji into one, such as a family
+const ZWJ: char = '\u{200D}';
+/// makes the digits, `#` and `*` a keycap emoji
+const KEYCAP: char = '\u{20E3}';
+
+/// whether it's a single emoji or a sequence of them, with the skin tones,
+/// the variation selectors, the ZWJ sequences, the keycaps and the flags
+pub fn is_emoji(emoji: &str) -> bool {
+    if emoji.is_empty() || emoji.chars().count() > MAX_EMOJI_LEN {
+        return false;
+    }
+    let keycap = emoji.contains(KEYCAP);
+    let mut pictographs = 0;
+    for c in emoji.chars() {
+        if is_pictograph(c) || (keycap && matches!(c, '0'..='9' | '#' | '*')) {
+            pictographs += 1;
+        } else if !is_emoji_component(c) {
+            return false;
+        }
+    }
+    pictographs > 0
+}
+
+/// the chars which are shown as a emoji by themselves
+fn is_pictograph(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x00A9 | 0x00AE | 0x203C | 0x2049 | 0x2122 | 0x2139
+            | 0x2194..=0x21AA
+            | 0x231A..=0x23FF
+            | 0x24C2
+            | 0x25AA..=0x25FE
+            | 0x2600..=0x27BF
+            | 0x2934 | 0x2935
+            | 0x2B05..=0x2B55
+            | 0x3030 | 0x303D | 0x3297 | 0x3299
+            // including the regional indicators of the flags and the skin tones
+            | 0x1F000..=0x1FAFF
+    )
+}
+
+/// the chars which only modify or join the emoji
+fn is_emoji_component(c: char) -> bool {
+    matches!(c, ZWJ | KEYCAP | '\u{FE0E}' | '\u{FE0F}' | '\u{E0020}'..='\u{E007F}')
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct React {
+    pub emoji: String,
+}
+
+/// how many users reacted to the message with the emoji
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ReactionCount {
+    pub emoji: String,
+    pub count: i64,
+    /// whether the current user is one of them
+    pub reacted: bool,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn emoji_should_be_checked() {
+        for emoji in ["👍", "👍🏽", "❤️", "👨‍👩‍👧", "🏳️‍🌈", "🇨🇳", "1️⃣", "#️⃣", "☕", "🏴󠁧󠁢󠁳󠁣󠁴󠁿"] {
+            assert!(is_emoji(emoji), "{}", emoji);
+        }
+        for emoji in ["", "lol", " ", "<b>", "1", "\u{200D}", "\u{FE0F}", "👍 ", "好"] {
+            assert!(!is_emoji(emoji), "{:?}", emoji);
+        }
+    }
+}
@@ -1,9 +1,13 @@
+use std::num::NonZeroU32;
+
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
+use crate::modles::group::Group;
+use crate::modles::message::Message;
 use crate::utils;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct User {
     pub uid: u64,
     pub username: String,
@@ -11,16 +15,60 @@ pub struct User {
     pub password: String,
     pub salt: String,
     pub create_time: NaiveDateTime,
+    pub display_name: Option<String>,
+    pub avatar: Option<String>,
+    pub bio: Option<String>,
+    /// reset to false whenever the mail is changed
+    pub mail_verified: bool,
+    pub last_seen: Option<NaiveDateTime>,
 }
 
+/// the fields which the user is allowed to change by himself,
+/// `None` means keep the old value
 #[derive(Debug)]
 pub struct UpdateUser {
     pub uid: u64,
     pub username: Option<String>,
     pub mail: Option<String>,
-    pub password: Option<String>,
-    pub salt: Option<String>,
-    pub create_time: Option<NaiveDateTime>,
+    pub display_name: Option<String>,
+    pub avatar: Option<String>,
+    pub bio: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateProfile {
+    pub username: Option<String>,
+    pub mail: Option<String>,
+    pub display_name: Option<String>,
+    pub avatar: Option<String>,
+    pub bio: Option<String>,
+}
+
+impl UpdateProfile {
+    pub fn into_update_user(self, uid: u64) -> UpdateUser {
+        UpdateUser {
+            uid,
+            username: self.username,
+            mail: self.mail,
+            display_name: self.display_name,
+            avatar: self.avatar,
+            bio: self.bio,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct DeleteUser {
+    pub password: String,
+}
+
+/// everything about a user, downloaded before the account is deleted
+#[derive(Serialize)]
+pub struct UserExport {
+    pub profile: ViewUser,
+    pub friends: Vec<ViewUser>,
+    pub groups: Vec<Group>,
+    pub messages: Vec<Message>,
 }
 
 #[derive(Deserialize)]
@@ -36,9 +84,10 @@ pub struct CreateUser {
     pub password: String,
 }
 
-impl Into<User> for CreateUser {
-    fn into(self) -> User {
-        let (password, salt) = utils::encyption(&self.password).unwrap();
+impl CreateUser {
+    /// the password is hashed with `n_iter` iterations
+    pub fn into_user(self, n_iter: NonZeroU32) -> User {
+        let (password, salt) = utils::encyption(&self.password, n_iter).unwrap();
         User {
             uid: 0,
             username: self.username,
@@ -46,6 +95,11 @@ impl Into<User> for CreateUser {
             mail: self.mail,
             salt,
             create_time: chrono::Local::now().naive_local(),
+            display_name: None,
+            avatar: None,
+            bio: None,
+            mail_verified: false,
+            last_seen: None,
         }
     }
 }
@@ -57,6 +111,9 @@ pub struct ViewUser {
     pub username: String,
     pub mail: String,
     pub create_time: NaiveDateTime,
+    pub display_name: Option<String>,
+    pub avatar: Option<String>,
+    pub bio: Option<String>,
 }
 
 impl From<User> for ViewUser {
@@ -66,6 +123,59 @@ impl From<User> for ViewUser {
             username: user.username,
             mail: user.mail,
             create_time: user.create_time,
+            display_name: user.display_name,
+            avatar: user.avatar,
+            bio: user.bio,
         }
     }
 }
+
+/// the profile which anyone can see, the mail is private
+#[derive(Serialize)]
+pub struct PublicUser {
+    pub uid: u64,
+    pub username: String,
+    pub display_name: Option<String>,
+    pub avatar: Option<String>,
+    pub bio: Option<String>,
+}
+
+impl From<User> for PublicUser {
+    fn from(user: User) -> Self {
+        PublicUser {
+            uid: user.uid,
+            username: user.username,
+            display_name: user.display_name,
+            avatar: user.avatar,
+            bio: user.bio,
+        }
+    }
+}
+
+const DEFAULT_PAGE_SIZE: u32 = 20;
+const MAX_PAGE_SIZE: u32 = 100;
+
+fn default_page_size() -> u32 {
+    DEFAULT_PAGE_SIZE
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SearchUser {
+    /// the prefix of username
+    pub q: String,
+    /// page number, start from 0
+    #[serde(default)]
+    pub page: u32,
+    #[serde(default = "default_page_size")]
+    pub size: u32,
+}
+
+impl SearchUser {
+    pub fn limit(&self) -> u32 {
+        self.size.clamp(1, MAX_PAGE_SIZE)
+    }
+
+    pub fn offset(&self) -> u32 {
+        self.page.saturating_mul(self.limit())
+    }
+}
@@ -0,0 +1,144 @@
+use async_trait::async_trait;
+use eChat::err::Result;
+
+use super::{Db, Tables};
+use crate::modles::conversation::*;
+use crate::modles::message::{Message, ReceiverType, MENTION_ALL};
+use crate::persistent::ConversationStore;
+
+#[derive(Clone)]
+pub struct MemoryConversationStore {
+    db: Db,
+}
+
+impl MemoryConversationStore {
+    pub(super) fn new(db: Db) -> Self {
+        MemoryConversationStore { db }
+    }
+}
+
+impl Tables {
+    fn last_read_mid(&self, uid: u64, receiver_type: ReceiverType, receiver_id: u64) -> u64 {
+        let key = (uid, receiver_type, receiver_id);
+        self.read_cursors.get(&key).copied().unwrap_or(0)
+    }
+
+    fn count_messages(&self, filter: impl Fn(&Message) -> bool) -> i64 {
+        self.messages.values().filter(|m| filter(m)).count() as i64
+    }
+}
+
+#[async_trait]
+impl ConversationStore for MemoryConversationStore {
+    async fn read(&self, uid: u64, read: &ReadMessage) -> Result<bool> {
+        let mut tables = self.db.lock();
+        let last_read_mid = tables
+            .read_cursors
+            .entry((uid, read.receiver_type, read.receiver_id))
+            .or_insert(0);
+        if read.mid <= *last_read_mid {
+            return Ok(false);
+        }
+        *last_read_mid = read.mid;
+        Ok(true)
+    }
+
+    async fn get_conversations(&self, uid: u64) -> Result<Vec<Conversation>> {
+        let tables = self.db.lock();
+        let direct = tables
+            .conversations
+            .iter()
+            .filter(|((u, _), _)| *u == uid)
+            .map(|((_, peer_uid), (last_mid, update_time))| {
+                let peer = tables.users.get(peer_uid);
+                let last_read_mid = tables.last_read_mid(uid, ReceiverType::User, *peer_uid);
+                Conversation {
+                    receiver_type: ReceiverType::User,
+                    receiver_id: *peer_uid,
+                    name: peer
+                        .map(|peer| peer.display_name.clone().unwrap_or_else(|| peer.username.clone()))
+                        .unwrap_or_else(|| "已注销用户".to_string()),
+                    avatar: peer.and_then(|peer| peer.avatar.clone()),
+                    last_message: tables.messages.get(last_mid).map(MessagePreview::from),
+                    last_active_time: *update_time,
+                    last_read_mid,
+                    unread: tables.count_messages(|m| {
+                        m.receiver_type == ReceiverType::User
+                            && m.receiver_id == uid
+                            && m.sender_uid == *peer_uid
+                            && m.mid > last_read_mid
+                    }),
+                    mentions: 0,
+                }
+            });
+
+        let group = tables
+            .groups
+            .values()
+            .filter(|group| !group.is_dissolved() && tables.is_member(group.gid, uid))
+            .map(|group| {
+                let gid = group.gid;
+                let last_message = tables
+                    .messages
+                    .values()
+                    .rev()
+                    .find(|m| m.receiver_type == ReceiverType::Group && m.receiver_id == gid)
+                    .map(MessagePreview::from);
+                let last_read_mid = tables.last_read_mid(uid, ReceiverType::Group, gid);
+                let mut mentioned: Vec<u64> = tables
+                    .mentions
+                    .iter()
+                    .filter(|(mid, u, g)| {
+                        *g == gid && (*u == uid || *u == MENTION_ALL) && *mid > last_read_mid
+                    })
+                    .filter(|(mid, _, _)| {
+                        tables
+                            .messages
+                            .get(mid)
+                            .is_some_and(|m| m.sender_uid != uid && m.recall_time.is_none())
+                    })
+                    .map(|(mid, _, _)| *mid)
+                    .collect();
+                // `@user` and `@all` in the same message are counted once
+                mentioned.sort_unstable();
+                mentioned.dedup();
+                Conversation {
+                    receiver_type: ReceiverType::Group,
+                    receiver_id: gid,
+                    name: group.name.clone(),
+                    avatar: None,
+                    last_active_time: last_message
+                        .as_ref()
+                        .map_or(group.create_time, |m| m.create_time),
+                    last_message,
+                    last_read_mid,
+                    unread: tables.count_messages(|m| {
+                        m.receiver_type == ReceiverType::Group
+                            && m.receiver_id == gid
+                            && m.sender_uid != uid
+                            && m.mid > last_read_mid
+                    }),
+                    mentions: mentioned.len() as i64,
+                }
+            });
+
+        let mut conversations: Vec<Conversation> = direct.chain(group).collect();
+        conversations.sort_by_key(|c| std::cmp::Reverse(c.last_active_time));
+        Ok(conversations)
+    }
+
+    async fn get_read_count(&self, gid: u64, mid: u64, sender_uid: u64) -> Result<i64> {
+        let tables = self.db.lock();
+        let count = tables
+            .read_cursors
+            .iter()
+            .filter(|((uid, receiver_type, receiver_id), last_read_mid)| {
+                *receiver_type == ReceiverType::Group
+                    && *receiver_id == gid
+                    && **last_read_mid >= mid
+                    && *uid != sender_uid
+            })
+            .count();
+        Ok(count as i64)
+    }
+}
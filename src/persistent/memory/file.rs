@@ -0,0 +1,124 @@
+use async_trait::async_trait;
+use eChat::err::Result;
+
+use super::{Blob, Db};
+use crate::modles::file::*;
+use crate::modles::message::ReceiverType;
+use crate::persistent::FileStore;
+
+#[derive(Clone)]
+pub struct MemoryFileStore {
+    db: Db,
+}
+
+impl MemoryFileStore {
+    pub(super) fn new(db: Db) -> Self {
+        MemoryFileStore { db }
+    }
+}
+
+#[async_trait]
+impl FileStore for MemoryFileStore {
+    async fn blob_exists(&self, sha256: &str) -> Result<bool> {
+        Ok(self.db.lock().blobs.contains_key(sha256))
+    }
+
+    async fn create_file(&self, file: &File) -> Result<u64> {
+        let mut tables = self.db.lock();
+        tables.blobs.entry(file.sha256.clone()).or_insert_with(|| Blob {
+            size: file.size,
+            content_type: file.content_type.clone(),
+            image: None,
+        });
+        let fid = tables.next_id("file");
+        tables.files.insert(fid, File { fid, ..file.clone() });
+        Ok(fid)
+    }
+
+    async fn get_file(&self, fid: u64) -> Result<Option<File>> {
+        let tables = self.db.lock();
+        let file = tables.files.get(&fid).and_then(|file| {
+            let blob = tables.blobs.get(&file.sha256)?;
+            Some(File {
+                size: blob.size,
+                content_type: blob.content_type.clone(),
+                ..file.clone()
+            })
+        });
+        Ok(file)
+    }
+
+    async fn save_image(
+        &self,
+        sha256: &str,
+        meta: &ImageMeta,
+        thumbnails: &[ThumbnailBlob],
+    ) -> Result<()> {
+        let mut tables = self.db.lock();
+        if let Some(blob) = tables.blobs.get_mut(sha256) {
+            blob.image = Some(meta.clone());
+        }
+        for thumbnail in thumbnails {
+            let exists = tables
+                .thumbnails
+                .iter()
+                .any(|(s, t)| s == sha256 && t.max_side == thumbnail.max_side);
+            if !exists {
+                tables.thumbnails.push((sha256.to_string(), thumbnail.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    async fn get_image(&self, sha256: &str) -> Result<Option<(ImageMeta, Vec<ThumbnailBlob>)>> {
+        let tables = self.db.lock();
+        let meta = match tables.blobs.get(sha256).and_then(|blob| blob.image.clone()) {
+            Some(meta) => meta,
+            None => return Ok(None),
+        };
+        let mut thumbnails: Vec<ThumbnailBlob> = tables
+            .thumbnails
+            .iter()
+            .filter(|(s, _)| s == sha256)
+            .map(|(_, thumbnail)| thumbnail.clone())
+            .collect();
+        thumbnails.sort_by_key(|thumbnail| thumbnail.max_side);
+        Ok(Some((meta, thumbnails)))
+    }
+
+    async fn get_thumbnail(&self, sha256: &str, max_side: u32) -> Result<Option<String>> {
+        let tables = self.db.lock();
+        let thumbnail = tables
+            .thumbnails
+            .iter()
+            .find(|(s, t)| s == sha256 && t.max_side == max_side)
+            .map(|(_, thumbnail)| thumbnail.sha256.clone());
+        Ok(thumbnail)
+    }
+
+    async fn share(&self, fid: u64, mid: u64) -> Result<()> {
+        let mut tables = self.db.lock();
+        if !tables.shares.contains(&(fid, mid)) {
+            tables.shares.push((fid, mid));
+        }
+        Ok(())
+    }
+
+    async fn can_access(&self, uid: u64, file: &File) -> Result<bool> {
+        if file.uploader == uid {
+            return Ok(true);
+        }
+        let tables = self.db.lock();
+        let shared = tables
+            .shares
+            .iter()
+            .filter(|(fid, _)| *fid == file.fid)
+            .filter_map(|(_, mid)| tables.messages.get(mid))
+            .filter(|m| m.recall_time.is_none())
+            .any(|m| match m.receiver_type {
+                ReceiverType::User => m.sender_uid == uid || m.receiver_id == uid,
+                ReceiverType::Group => tables.is_member(m.receiver_id, uid),
+            });
+        Ok(shared)
+    }
+}
@@ -0,0 +1,88 @@
+use async_trait::async_trait;
+use eChat::err::{Error, Result};
+
+use super::Db;
+use crate::modles::friend::*;
+use crate::modles::presence::FriendLastSeen;
+use crate::modles::user::{User, ViewUser};
+use crate::persistent::FriendStore;
+
+#[derive(Clone)]
+pub struct MemoryFriendStore {
+    db: Db,
+}
+
+impl MemoryFriendStore {
+    pub(super) fn new(db: Db) -> Self {
+        MemoryFriendStore { db }
+    }
+
+    /// status only be changed from Pending to Agree or Refused
+    fn change_status(&self, uid: u64, friend_id: u64, status: FriendStatus) -> Result<()> {
+        let mut tables = self.db.lock();
+        let row = tables
+            .friends
+            .iter_mut()
+            .find(|(u, f, s)| *u == uid && *f == friend_id && *s == FriendStatus::Pending);
+        if let Some(row) = row {
+            row.2 = status;
+        }
+        Ok(())
+    }
+
+    /// the accepted friends who have not been deleted
+    fn friends(&self, uid: u64) -> Vec<User> {
+        let tables = self.db.lock();
+        tables
+            .friends
+            .iter()
+            .filter(|(u, f, _)| *u == uid && tables.is_friend(uid, *f))
+            .filter_map(|(_, f, _)| tables.users.get(f).cloned())
+            .collect()
+    }
+}
+
+#[async_trait]
+impl FriendStore for MemoryFriendStore {
+    async fn create_friend(&self, uid: u64, friend_id: u64) -> Result<()> {
+        let mut tables = self.db.lock();
+        let requested = tables
+            .friends
+            .iter()
+            .any(|(u, f, _)| (*u, *f) == (uid, friend_id) || (*u, *f) == (friend_id, uid));
+        if requested {
+            return Err(Error::Duplicated("已经申请添加好友了".into()));
+        }
+        tables.friends.push((uid, friend_id, FriendStatus::Agree));
+        tables.friends.push((friend_id, uid, FriendStatus::Pending));
+        Ok(())
+    }
+
+    async fn agree_friend(&self, uid: u64, friend_id: u64) -> Result<()> {
+        self.change_status(uid, friend_id, FriendStatus::Agree)
+    }
+
+    async fn refuse_friend(&self, uid: u64, friend_id: u64) -> Result<()> {
+        self.change_status(uid, friend_id, FriendStatus::Refused)
+    }
+
+    async fn get_friends(&self, uid: u64) -> Result<Vec<ViewUser>> {
+        Ok(self.friends(uid).into_iter().map(ViewUser::from).collect())
+    }
+
+    async fn is_friend(&self, uid: u64, friend_id: u64) -> Result<bool> {
+        Ok(self.db.lock().is_friend(uid, friend_id))
+    }
+
+    async fn get_friends_last_seen(&self, uid: u64) -> Result<Vec<FriendLastSeen>> {
+        let friends = self
+            .friends(uid)
+            .into_iter()
+            .map(|user| FriendLastSeen {
+                uid: user.uid,
+                last_seen: user.last_seen,
+            })
+            .collect();
+        Ok(friends)
+    }
+}
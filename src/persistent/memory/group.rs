@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use eChat::err::{Error, Result};
+
+use super::Db;
+use crate::modles::group::*;
+use crate::persistent::GroupStore;
+
+#[derive(Clone)]
+pub struct MemoryGroupStore {
+    db: Db,
+}
+
+impl MemoryGroupStore {
+    pub(super) fn new(db: Db) -> Self {
+        MemoryGroupStore { db }
+    }
+
+    /// the active group owned by `user_id`
+    fn check_owner(&self, user_id: u64, gid: u64) -> bool {
+        let tables = self.db.lock();
+        tables.groups.get(&gid).is_some_and(|group| {
+            group.owner == user_id && !group.is_dissolved()
+        })
+    }
+}
+
+#[async_trait]
+impl GroupStore for MemoryGroupStore {
+    async fn create_group(&self, mut group: Group) -> Result<()> {
+        let mut tables = self.db.lock();
+        group.gid = tables.next_id("group");
+        tables.groups.insert(group.gid, group);
+        Ok(())
+    }
+
+    async fn get_group(&self, gid: u64) -> Result<Option<Group>> {
+        Ok(self.db.lock().groups.get(&gid).cloned())
+    }
+
+    async fn get_members(&self, gid: u64) -> Result<Vec<u64>> {
+        let tables = self.db.lock();
+        let mut members: Vec<u64> = tables
+            .groups
+            .get(&gid)
+            .map(|g| g.owner)
+            .into_iter()
+            .collect();
+        for (g, uid, status) in &tables.group_users {
+            if *g == gid && matches!(status, GroupStatus::Agree) && !members.contains(uid) {
+                members.push(*uid);
+            }
+        }
+        Ok(members)
+    }
+
+    async fn get_groups_of_user(&self, uid: u64) -> Result<Vec<Group>> {
+        let tables = self.db.lock();
+        let groups = tables
+            .groups
+            .values()
+            .filter(|group| !group.is_dissolved() && tables.is_member(group.gid, uid))
+            .cloned()
+            .collect();
+        Ok(groups)
+    }
+
+    async fn join(&self, uid: u64, gid: u64) -> Result<()> {
+        match self.get_group(gid).await? {
+            Some(group) if !group.is_dissolved() => (),
+            _ => return Err(Error::unprocessable_entity([("msg", "群不存在或已解散")])),
+        }
+        let mut tables = self.db.lock();
+        if tables
+            .group_users
+            .iter()
+            .any(|(g, u, _)| *g == gid && *u == uid)
+        {
+            return Err(Error::Duplicated("已经申请加入该群了".into()));
+        }
+        tables.group_users.push((gid, uid, GroupStatus::Pending));
+        Ok(())
+    }
+
+    async fn agree(&self, user_id: u64, uid: u64, gid: u64) -> Result<()> {
+        if !self.check_owner(user_id, gid) {
+            return Err(Error::unprocessable_entity([("msg", "没有权限同意")]));
+        }
+        let mut tables = self.db.lock();
+        for (g, u, status) in tables.group_users.iter_mut() {
+            if *g == gid && *u == uid {
+                *status = GroupStatus::Agree;
+            }
+        }
+        Ok(())
+    }
+
+    async fn dissolve(&self, user_id: u64, gid: u64) -> Result<Vec<u64>> {
+        if !self.check_owner(user_id, gid) {
+            return Err(Error::unprocessable_entity([("msg", "没有权限解散")]));
+        }
+        let mut tables = self.db.lock();
+        let members = tables
+            .group_users
+            .iter()
+            .filter(|(g, _, status)| *g == gid && matches!(status, GroupStatus::Agree))
+            .map(|(_, u, _)| *u)
+            .collect();
+        tables.group_users.retain(|(g, _, _)| *g != gid);
+        if let Some(group) = tables.groups.get_mut(&gid) {
+            group.dissolve_time = Some(chrono::Local::now().naive_local());
+        }
+        Ok(members)
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use eChat::err::Result;
+
+use super::Db;
+use crate::modles::conversation::MessagePreview;
+use crate::modles::message::*;
+use crate::persistent::MessageStore;
+
+#[derive(Clone)]
+pub struct MemoryMessageStore {
+    db: Db,
+}
+
+impl MemoryMessageStore {
+    pub(super) fn new(db: Db) -> Self {
+        MemoryMessageStore { db }
+    }
+
+    /// the messages which match `filter`, the oldest first
+    fn find_all(&self, filter: impl Fn(&Message) -> bool) -> Vec<Message> {
+        let tables = self.db.lock();
+        tables
+            .messages
+            .values()
+            .filter(|m| filter(m))
+            .cloned()
+            .collect()
+    }
+}
+
+#[async_trait]
+impl MessageStore for MemoryMessageStore {
+    async fn create_message(&self, mut message: Message) -> Result<u64> {
+        let mut tables = self.db.lock();
+        message.mid = tables.next_id("message");
+        if message.receiver_type == ReceiverType::User {
+            // both of the sender and the receiver have the conversation
+            let last = (message.mid, message.create_time);
+            tables
+                .conversations
+                .insert((message.sender_uid, message.receiver_id), last);
+            tables
+                .conversations
+                .insert((message.receiver_id, message.sender_uid), last);
+        }
+        tables.messages.insert(message.mid, message.clone());
+        Ok(message.mid)
+    }
+
+    async fn create_mentions(&self, mid: u64, gid: u64, uids: &[u64]) -> Result<()> {
+        let mut tables = self.db.lock();
+        for uid in uids {
+            if !tables.mentions.contains(&(mid, *uid, gid)) {
+                tables.mentions.push((mid, *uid, gid));
+            }
+        }
+        Ok(())
+    }
+
+    async fn get_message_by_receiver_id(
+        &self,
+        id: u64,
+        mtype: ReceiverType,
+    ) -> Result<Vec<Message>> {
+        Ok(self.find_all(|m| m.receiver_id == id && m.receiver_type == mtype))
+    }
+
+    async fn get_message(&self, mid: u64) -> Result<Option<Message>> {
+        Ok(self.db.lock().messages.get(&mid).cloned())
+    }
+
+    async fn get_history(&self, uid: u64, history: &MessageHistory) -> Result<Vec<Message>> {
+        let before = history.before.unwrap_or(u64::MAX);
+        let messages = self
+            .find_all(|m| {
+                m.mid < before && m.in_conversation(uid, history.receiver_type, history.receiver_id)
+            })
+            .into_iter()
+            .rev()
+            .take(history.limit() as usize)
+            .collect();
+        Ok(messages)
+    }
+
+    async fn search_messages(&self, uid: u64, search: &SearchMessage) -> Result<Vec<Message>> {
+        let terms: Vec<String> = search
+            .terms()
+            .iter()
+            .map(|term| term.to_lowercase())
+            .collect();
+        let tables = self.db.lock();
+        let visible = |m: &Message| match m.receiver_type {
+            ReceiverType::User => m.sender_uid == uid || m.receiver_id == uid,
+            ReceiverType::Group => tables.is_member(m.receiver_id, uid),
+        };
+        let matched = |m: &Message| {
+            let content = m.content.to_lowercase();
+            terms.iter().all(|term| content.contains(term.as_str()))
+        };
+        let in_conversation = |m: &Message| match (search.receiver_type, search.receiver_id) {
+            (Some(receiver_type), Some(receiver_id)) => {
+                m.receiver_type == receiver_type
+                    && match receiver_type {
+                        ReceiverType::User => {
+                            m.sender_uid == receiver_id || m.receiver_id == receiver_id
+                        }
+                        ReceiverType::Group => m.receiver_id == receiver_id,
+                    }
+            }
+            _ => true,
+        };
+        let messages = tables
+            .messages
+            .values()
+            .rev()
+            .filter(|m| m.recall_time.is_none() && visible(m) && matched(m) && in_conversation(m))
+            .filter(|m| {
+                search
+                    .sender_uid
+                    .is_none_or(|sender_uid| m.sender_uid == sender_uid)
+            })
+            .filter(|m| search.from.is_none_or(|from| m.create_time >= from))
+            .filter(|m| search.to.is_none_or(|to| m.create_time < to))
+            .skip(search.offset() as usize)
+            .take(search.limit() as usize)
+            .cloned()
+            .collect();
+        Ok(messages)
+    }
+
+    async fn edit_message(&self, mid: u64, content: &str, edit_time: NaiveDateTime) -> Result<()> {
+        let mut tables = self.db.lock();
+        let tables = &mut *tables;
+        let message = match tables.messages.get_mut(&mid) {
+            Some(message) if message.recall_time.is_none() => message,
+            _ => return Ok(()),
+        };
+        let edit = MessageEdit {
+            mid,
+            content: std::mem::replace(&mut message.content, content.to_string()),
+            edit_time,
+        };
+        message.edit_time = Some(edit_time);
+        tables.edits.push(edit);
+        Ok(())
+    }
+
+    async fn recall_message(&self, mid: u64, recall_time: NaiveDateTime) -> Result<()> {
+        let mut tables = self.db.lock();
+        match tables.messages.get_mut(&mid) {
+            Some(message) if message.recall_time.is_none() => {
+                message.content.clear();
+                message.payload = None;
+                message.recall_time = Some(recall_time);
+            }
+            _ => return Ok(()),
+        }
+        tables.edits.retain(|edit| edit.mid != mid);
+        Ok(())
+    }
+
+    async fn get_thread(&self, root_mid: u64) -> Result<Vec<Message>> {
+        Ok(self.find_all(|m| m.reply_to_mid == Some(root_mid)))
+    }
+
+    async fn get_reply_previews(
+        &self,
+        messages: &[Message],
+    ) -> Result<HashMap<u64, MessagePreview>> {
+        let tables = self.db.lock();
+        let previews = messages
+            .iter()
+            .filter_map(|m| m.reply_to_mid)
+            .filter_map(|mid| tables.messages.get(&mid))
+            .map(|replied| (replied.mid, MessagePreview::from(replied)))
+            .collect();
+        Ok(previews)
+    }
+
+    async fn get_edits(&self, mid: u64) -> Result<Vec<MessageEdit>> {
+        let tables = self.db.lock();
+        let edits = tables
+            .edits
+            .iter()
+            .filter(|edit| edit.mid == mid)
+            .map(|edit| MessageEdit {
+                mid: edit.mid,
+                content: edit.content.clone(),
+                edit_time: edit.edit_time,
+            })
+            .collect();
+        Ok(edits)
+    }
+
+    async fn get_messages_of_user(&self, uid: u64) -> Result<Vec<Message>> {
+        Ok(self.find_all(|m| {
+            m.sender_uid == uid || (m.receiver_type == ReceiverType::User && m.receiver_id == uid)
+        }))
+    }
+}
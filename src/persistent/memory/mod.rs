@@ -0,0 +1,128 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::NaiveDateTime;
+
+use super::{PoolUsage, Storage};
+use crate::modles::file::{File, ImageMeta, ThumbnailBlob};
+use crate::modles::friend::FriendStatus;
+use crate::modles::group::{Group, GroupStatus};
+use crate::modles::message::{Message, MessageEdit, ReceiverType};
+use crate::modles::user::User;
+
+mod conversation;
+mod file;
+mod friend;
+mod group;
+mod message;
+mod reaction;
+mod user;
+
+pub use conversation::MemoryConversationStore;
+pub use file::MemoryFileStore;
+pub use friend::MemoryFriendStore;
+pub use group::MemoryGroupStore;
+pub use message::MemoryMessageStore;
+pub use reaction::MemoryReactionStore;
+pub use user::MemoryUserStore;
+
+/// the rows of the tables which are kept in memory, every store of a `storage` shares them
+#[derive(Default)]
+struct Tables {
+    /// the last id of every table, the ids are never reused like `auto_increment`
+    ids: HashMap<&'static str, u64>,
+    users: BTreeMap<u64, User>,
+    /// (uid, friend_id, status), in the order they are created
+    friends: Vec<(u64, u64, FriendStatus)>,
+    groups: BTreeMap<u64, Group>,
+    /// (gid, uid, status), in the order they are created
+    group_users: Vec<(u64, u64, GroupStatus)>,
+    messages: BTreeMap<u64, Message>,
+    /// (mid, uid, gid)
+    mentions: Vec<(u64, u64, u64)>,
+    edits: Vec<MessageEdit>,
+    /// (uid, peer_uid) to (last_mid, update_time), maintained by `create_message`
+    conversations: HashMap<(u64, u64), (u64, NaiveDateTime)>,
+    /// (uid, receiver_type, receiver_id) to last_read_mid
+    read_cursors: HashMap<(u64, ReceiverType, u64), u64>,
+    /// (mid, uid, emoji), in the order they are created
+    reactions: Vec<(u64, u64, String)>,
+    blobs: HashMap<String, Blob>,
+    /// the size and the content type are read from the blob
+    files: BTreeMap<u64, File>,
+    /// (sha256, thumbnail)
+    thumbnails: Vec<(String, ThumbnailBlob)>,
+    /// (fid, mid)
+    shares: Vec<(u64, u64)>,
+}
+
+struct Blob {
+    size: u64,
+    content_type: String,
+    image: Option<ImageMeta>,
+}
+
+impl Tables {
+    fn next_id(&mut self, table: &'static str) -> u64 {
+        let id = self.ids.entry(table).or_insert(0);
+        *id += 1;
+        *id
+    }
+
+    /// the owner and the agreed members
+    fn is_member(&self, gid: u64, uid: u64) -> bool {
+        let is_owner = self
+            .groups
+            .get(&gid)
+            .is_some_and(|group| group.owner == uid);
+        is_owner
+            || self.group_users.iter().any(|(g, u, status)| {
+                *g == gid && *u == uid && matches!(status, GroupStatus::Agree)
+            })
+    }
+
+    fn is_friend(&self, uid: u64, friend_id: u64) -> bool {
+        let agreed = |uid: u64, friend_id: u64| {
+            self.friends.iter().any(|(u, f, status)| {
+                *u == uid && *f == friend_id && *status == FriendStatus::Agree
+            })
+        };
+        agreed(uid, friend_id) && agreed(friend_id, uid)
+    }
+}
+
+#[derive(Clone, Default)]
+struct Db(Arc<Mutex<Tables>>);
+
+impl Db {
+    /// the lock is never held across an await, so a std mutex is enough
+    fn lock(&self) -> MutexGuard<'_, Tables> {
+        self.0.lock().unwrap()
+    }
+}
+
+/// there is no connection to count
+impl PoolUsage for Db {
+    fn size(&self) -> u32 {
+        0
+    }
+
+    fn idle(&self) -> usize {
+        0
+    }
+}
+
+/// all the stores share the tables in memory, they are dropped with the storage
+pub fn storage() -> Storage {
+    let db = Db::default();
+    Storage {
+        users: Arc::new(MemoryUserStore::new(db.clone())),
+        friends: Arc::new(MemoryFriendStore::new(db.clone())),
+        groups: Arc::new(MemoryGroupStore::new(db.clone())),
+        messages: Arc::new(MemoryMessageStore::new(db.clone())),
+        conversations: Arc::new(MemoryConversationStore::new(db.clone())),
+        reactions: Arc::new(MemoryReactionStore::new(db.clone())),
+        files: Arc::new(MemoryFileStore::new(db.clone())),
+        pool: Arc::new(db),
+    }
+}
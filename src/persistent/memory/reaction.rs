@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use eChat::err::Result;
+
+use super::Db;
+use crate::modles::reaction::*;
+use crate::persistent::ReactionStore;
+
+#[derive(Clone)]
+pub struct MemoryReactionStore {
+    db: Db,
+}
+
+impl MemoryReactionStore {
+    pub(super) fn new(db: Db) -> Self {
+        MemoryReactionStore { db }
+    }
+}
+
+#[async_trait]
+impl ReactionStore for MemoryReactionStore {
+    async fn add(&self, mid: u64, uid: u64, emoji: &str) -> Result<bool> {
+        let mut tables = self.db.lock();
+        let reacted = tables
+            .reactions
+            .iter()
+            .any(|(m, u, e)| *m == mid && *u == uid && e == emoji);
+        if reacted {
+            return Ok(false);
+        }
+        tables.reactions.push((mid, uid, emoji.to_string()));
+        Ok(true)
+    }
+
+    async fn remove(&self, mid: u64, uid: u64, emoji: &str) -> Result<bool> {
+        let mut tables = self.db.lock();
+        let len = tables.reactions.len();
+        tables
+            .reactions
+            .retain(|(m, u, e)| !(*m == mid && *u == uid && e == emoji));
+        Ok(tables.reactions.len() < len)
+    }
+
+    async fn get_counts(&self, mids: &[u64], uid: u64) -> Result<HashMap<u64, Vec<ReactionCount>>> {
+        let tables = self.db.lock();
+        let mut counts: HashMap<u64, Vec<ReactionCount>> = HashMap::new();
+        // the reactions are in the order they are created, so the earliest emoji comes first
+        for (mid, u, emoji) in tables.reactions.iter().filter(|(m, _, _)| mids.contains(m)) {
+            let reactions = counts.entry(*mid).or_default();
+            let count = match reactions.iter_mut().find(|count| count.emoji == *emoji) {
+                Some(count) => count,
+                None => {
+                    reactions.push(ReactionCount {
+                        emoji: emoji.clone(),
+                        count: 0,
+                        reacted: false,
+                    });
+                    reactions.last_mut().unwrap()
+                }
+            };
+            count.count += 1;
+            count.reacted |= *u == uid;
+        }
+        Ok(counts)
+    }
+}
@@ -0,0 +1,183 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use eChat::err::{Error, Result};
+
+use super::Db;
+use crate::modles::group::GroupStatus;
+use crate::modles::message::{MessagePolicy, DELETED_USER_ID};
+use crate::modles::user::*;
+use crate::persistent::UserStore;
+
+#[derive(Clone)]
+pub struct MemoryUserStore {
+    db: Db,
+}
+
+impl MemoryUserStore {
+    pub(super) fn new(db: Db) -> Self {
+        MemoryUserStore { db }
+    }
+}
+
+#[async_trait]
+impl UserStore for MemoryUserStore {
+    async fn create_user(&self, mut user: User) -> Result<u64> {
+        let mut tables = self.db.lock();
+        // the username and the mail are case insensitive like the database
+        let exists = tables.users.values().any(|u| {
+            u.username.eq_ignore_ascii_case(&user.username)
+                || u.mail.eq_ignore_ascii_case(&user.mail)
+        });
+        if exists {
+            return Err(Error::Duplicated("用户名或者邮箱已经存在".into()));
+        }
+        user.uid = tables.next_id("user");
+        user.mail_verified = false;
+        user.last_seen = None;
+        tables.users.insert(user.uid, user.clone());
+        Ok(user.uid)
+    }
+
+    async fn update_user(&self, user: UpdateUser) -> Result<()> {
+        let mut tables = self.db.lock();
+        let exists = tables.users.values().any(|u| {
+            u.uid != user.uid
+                && (user
+                    .username
+                    .as_ref()
+                    .is_some_and(|name| u.username.eq_ignore_ascii_case(name))
+                    || user
+                        .mail
+                        .as_ref()
+                        .is_some_and(|mail| u.mail.eq_ignore_ascii_case(mail)))
+        });
+        if exists {
+            return Err(Error::Duplicated("用户名或者邮箱已经存在".into()));
+        }
+        if let Some(u) = tables.users.get_mut(&user.uid) {
+            if let Some(mail) = user.mail {
+                if mail != u.mail {
+                    u.mail_verified = false;
+                }
+                u.mail = mail;
+            }
+            u.username = user.username.unwrap_or_else(|| u.username.clone());
+            u.display_name = user.display_name.or_else(|| u.display_name.take());
+            u.avatar = user.avatar.or_else(|| u.avatar.take());
+            u.bio = user.bio.or_else(|| u.bio.take());
+        }
+        Ok(())
+    }
+
+    async fn delete_user(&self, uid: u64, policy: MessagePolicy) -> Result<Vec<(u64, Vec<u64>)>> {
+        let mut tables = self.db.lock();
+        let tables = &mut *tables;
+
+        match policy {
+            MessagePolicy::Anonymize => {
+                for message in tables.messages.values_mut() {
+                    if message.sender_uid == uid {
+                        message.sender_uid = DELETED_USER_ID;
+                    }
+                }
+            }
+            MessagePolicy::Remove => {
+                let messages = &tables.messages;
+                let removed = |mid: &u64| messages.get(mid).is_some_and(|m| m.sender_uid == uid);
+                tables.mentions.retain(|(mid, _, _)| !removed(mid));
+                tables.reactions.retain(|(mid, _, _)| !removed(mid));
+                tables.messages.retain(|_, m| m.sender_uid != uid);
+                // the edits are deleted by the foreign key in the database
+                let messages = &tables.messages;
+                tables.edits.retain(|edit| messages.contains_key(&edit.mid));
+            }
+        }
+
+        tables.friends.retain(|(u, f, _)| *u != uid && *f != uid);
+        // the conversations of the peers are kept, they still have the messages
+        tables.conversations.retain(|(u, _), _| *u != uid);
+        tables.read_cursors.retain(|(u, _, _), _| *u != uid);
+        tables.reactions.retain(|(_, u, _)| *u != uid);
+        tables.mentions.retain(|(_, u, _)| *u != uid);
+        tables.group_users.retain(|(_, u, _)| *u != uid);
+
+        let owned_groups: Vec<u64> = tables
+            .groups
+            .values()
+            .filter(|group| group.owner == uid && !group.is_dissolved())
+            .map(|group| group.gid)
+            .collect();
+        let mut dissolved = Vec::new();
+        for gid in owned_groups {
+            let new_owner = tables
+                .group_users
+                .iter()
+                .find(|(g, _, status)| *g == gid && matches!(status, GroupStatus::Agree))
+                .map(|(_, u, _)| *u);
+            match new_owner {
+                Some(new_owner) => {
+                    // the owner is not a member in group_users
+                    tables
+                        .group_users
+                        .retain(|(g, u, _)| !(*g == gid && *u == new_owner));
+                    tables.groups.get_mut(&gid).unwrap().owner = new_owner;
+                }
+                None => {
+                    // only pending members are left, they are notified as well
+                    let members = tables
+                        .group_users
+                        .iter()
+                        .filter(|(g, _, _)| *g == gid)
+                        .map(|(_, u, _)| *u)
+                        .collect();
+                    tables.group_users.retain(|(g, _, _)| *g != gid);
+                    tables.groups.get_mut(&gid).unwrap().dissolve_time =
+                        Some(chrono::Local::now().naive_local());
+                    dissolved.push((gid, members));
+                }
+            }
+        }
+
+        tables.users.remove(&uid);
+        Ok(dissolved)
+    }
+
+    async fn get_user(&self, id: u64) -> Result<User> {
+        let user = self.db.lock().users.get(&id).cloned();
+        user.ok_or_else(|| sqlx::Error::RowNotFound.into())
+    }
+
+    async fn get_user_by_username(&self, name: &str) -> Result<Option<User>> {
+        let tables = self.db.lock();
+        let user = tables
+            .users
+            .values()
+            .find(|u| u.username.eq_ignore_ascii_case(name));
+        Ok(user.cloned())
+    }
+
+    async fn update_last_seen(&self, uid: u64, last_seen: NaiveDateTime) -> Result<()> {
+        if let Some(user) = self.db.lock().users.get_mut(&uid) {
+            user.last_seen = Some(last_seen);
+        }
+        Ok(())
+    }
+
+    async fn search_users(&self, search: &SearchUser) -> Result<Vec<PublicUser>> {
+        let prefix = search.q.to_lowercase();
+        let tables = self.db.lock();
+        let mut users: Vec<&User> = tables
+            .users
+            .values()
+            .filter(|u| u.username.to_lowercase().starts_with(&prefix))
+            .collect();
+        users.sort_by(|a, b| a.username.cmp(&b.username));
+        let users = users
+            .into_iter()
+            .skip(search.offset() as usize)
+            .take(search.limit() as usize)
+            .map(|u| u.clone().into())
+            .collect();
+        Ok(users)
+    }
+}
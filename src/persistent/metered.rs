@@ -0,0 +1,298 @@
+//! the stores wrapped to time every query, the latencies are labeled by the store and the method
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use eChat::err::Result;
+use prometheus::HistogramVec;
+
+use super::{
+    ConversationStore, FileStore, FriendStore, GroupStore, MessageStore, ReactionStore, Storage,
+    UserStore,
+};
+use crate::modles::conversation::{Conversation, MessagePreview, ReadMessage};
+use crate::modles::file::{File, ImageMeta, ThumbnailBlob};
+use crate::modles::group::Group;
+use crate::modles::message::*;
+use crate::modles::presence::FriendLastSeen;
+use crate::modles::reaction::ReactionCount;
+use crate::modles::user::{PublicUser, SearchUser, UpdateUser, User, ViewUser};
+
+pub fn storage(storage: Storage, histogram: &HistogramVec) -> Storage {
+    Storage {
+        users: Arc::new(Metered::new(storage.users, "user", histogram)),
+        friends: Arc::new(Metered::new(storage.friends, "friend", histogram)),
+        groups: Arc::new(Metered::new(storage.groups, "group", histogram)),
+        messages: Arc::new(Metered::new(storage.messages, "message", histogram)),
+        conversations: Arc::new(Metered::new(storage.conversations, "conversation", histogram)),
+        reactions: Arc::new(Metered::new(storage.reactions, "reaction", histogram)),
+        files: Arc::new(Metered::new(storage.files, "file", histogram)),
+        pool: storage.pool,
+    }
+}
+
+struct Metered<S: ?Sized> {
+    inner: Arc<S>,
+    store: &'static str,
+    histogram: HistogramVec,
+}
+
+impl<S: ?Sized> Metered<S> {
+    fn new(inner: Arc<S>, store: &'static str, histogram: &HistogramVec) -> Self {
+        Metered {
+            inner,
+            store,
+            histogram: histogram.clone(),
+        }
+    }
+
+    async fn time<T>(&self, query: &str, f: impl Future<Output = Result<T>>) -> Result<T> {
+        // observed when it's dropped, the failed queries are timed as well
+        let _timer = self
+            .histogram
+            .with_label_values(&[self.store, query])
+            .start_timer();
+        f.await
+    }
+}
+
+#[async_trait]
+impl UserStore for Metered<dyn UserStore> {
+    async fn create_user(&self, user: User) -> Result<u64> {
+        self.time("create_user", self.inner.create_user(user)).await
+    }
+
+    async fn update_user(&self, user: UpdateUser) -> Result<()> {
+        self.time("update_user", self.inner.update_user(user)).await
+    }
+
+    async fn delete_user(&self, uid: u64, policy: MessagePolicy) -> Result<Vec<(u64, Vec<u64>)>> {
+        self.time("delete_user", self.inner.delete_user(uid, policy)).await
+    }
+
+    async fn get_user(&self, id: u64) -> Result<User> {
+        self.time("get_user", self.inner.get_user(id)).await
+    }
+
+    async fn get_user_by_username(&self, name: &str) -> Result<Option<User>> {
+        self.time("get_user_by_username", self.inner.get_user_by_username(name))
+            .await
+    }
+
+    async fn update_last_seen(&self, uid: u64, last_seen: NaiveDateTime) -> Result<()> {
+        self.time("update_last_seen", self.inner.update_last_seen(uid, last_seen))
+            .await
+    }
+
+    async fn search_users(&self, search: &SearchUser) -> Result<Vec<PublicUser>> {
+        self.time("search_users", self.inner.search_users(search)).await
+    }
+}
+
+#[async_trait]
+impl FriendStore for Metered<dyn FriendStore> {
+    async fn create_friend(&self, uid: u64, friend_id: u64) -> Result<()> {
+        self.time("create_friend", self.inner.create_friend(uid, friend_id))
+            .await
+    }
+
+    async fn agree_friend(&self, uid: u64, friend_id: u64) -> Result<()> {
+        self.time("agree_friend", self.inner.agree_friend(uid, friend_id))
+            .await
+    }
+
+    async fn refuse_friend(&self, uid: u64, friend_id: u64) -> Result<()> {
+        self.time("refuse_friend", self.inner.refuse_friend(uid, friend_id))
+            .await
+    }
+
+    async fn get_friends(&self, uid: u64) -> Result<Vec<ViewUser>> {
+        self.time("get_friends", self.inner.get_friends(uid)).await
+    }
+
+    async fn is_friend(&self, uid: u64, friend_id: u64) -> Result<bool> {
+        self.time("is_friend", self.inner.is_friend(uid, friend_id)).await
+    }
+
+    async fn get_friends_last_seen(&self, uid: u64) -> Result<Vec<FriendLastSeen>> {
+        self.time("get_friends_last_seen", self.inner.get_friends_last_seen(uid))
+            .await
+    }
+}
+
+#[async_trait]
+impl GroupStore for Metered<dyn GroupStore> {
+    async fn create_group(&self, group: Group) -> Result<()> {
+        self.time("create_group", self.inner.create_group(group)).await
+    }
+
+    async fn get_group(&self, gid: u64) -> Result<Option<Group>> {
+        self.time("get_group", self.inner.get_group(gid)).await
+    }
+
+    async fn get_members(&self, gid: u64) -> Result<Vec<u64>> {
+        self.time("get_members", self.inner.get_members(gid)).await
+    }
+
+    async fn get_groups_of_user(&self, uid: u64) -> Result<Vec<Group>> {
+        self.time("get_groups_of_user", self.inner.get_groups_of_user(uid))
+            .await
+    }
+
+    async fn join(&self, uid: u64, gid: u64) -> Result<()> {
+        self.time("join", self.inner.join(uid, gid)).await
+    }
+
+    async fn agree(&self, user_id: u64, uid: u64, gid: u64) -> Result<()> {
+        self.time("agree", self.inner.agree(user_id, uid, gid)).await
+    }
+
+    async fn dissolve(&self, user_id: u64, gid: u64) -> Result<Vec<u64>> {
+        self.time("dissolve", self.inner.dissolve(user_id, gid)).await
+    }
+}
+
+#[async_trait]
+impl MessageStore for Metered<dyn MessageStore> {
+    async fn create_message(&self, message: Message) -> Result<u64> {
+        self.time("create_message", self.inner.create_message(message)).await
+    }
+
+    async fn create_mentions(&self, mid: u64, gid: u64, uids: &[u64]) -> Result<()> {
+        self.time("create_mentions", self.inner.create_mentions(mid, gid, uids))
+            .await
+    }
+
+    async fn get_message_by_receiver_id(
+        &self,
+        id: u64,
+        mtype: ReceiverType,
+    ) -> Result<Vec<Message>> {
+        self.time(
+            "get_message_by_receiver_id",
+            self.inner.get_message_by_receiver_id(id, mtype),
+        )
+        .await
+    }
+
+    async fn get_message(&self, mid: u64) -> Result<Option<Message>> {
+        self.time("get_message", self.inner.get_message(mid)).await
+    }
+
+    async fn get_history(&self, uid: u64, history: &MessageHistory) -> Result<Vec<Message>> {
+        self.time("get_history", self.inner.get_history(uid, history)).await
+    }
+
+    async fn search_messages(&self, uid: u64, search: &SearchMessage) -> Result<Vec<Message>> {
+        self.time("search_messages", self.inner.search_messages(uid, search))
+            .await
+    }
+
+    async fn edit_message(&self, mid: u64, content: &str, edit_time: NaiveDateTime) -> Result<()> {
+        self.time("edit_message", self.inner.edit_message(mid, content, edit_time))
+            .await
+    }
+
+    async fn recall_message(&self, mid: u64, recall_time: NaiveDateTime) -> Result<()> {
+        self.time("recall_message", self.inner.recall_message(mid, recall_time))
+            .await
+    }
+
+    async fn get_thread(&self, root_mid: u64) -> Result<Vec<Message>> {
+        self.time("get_thread", self.inner.get_thread(root_mid)).await
+    }
+
+    async fn get_reply_previews(
+        &self,
+        messages: &[Message],
+    ) -> Result<HashMap<u64, MessagePreview>> {
+        self.time("get_reply_previews", self.inner.get_reply_previews(messages))
+            .await
+    }
+
+    async fn get_edits(&self, mid: u64) -> Result<Vec<MessageEdit>> {
+        self.time("get_edits", self.inner.get_edits(mid)).await
+    }
+
+    async fn get_messages_of_user(&self, uid: u64) -> Result<Vec<Message>> {
+        self.time("get_messages_of_user", self.inner.get_messages_of_user(uid))
+            .await
+    }
+}
+
+#[async_trait]
+impl ConversationStore for Metered<dyn ConversationStore> {
+    async fn read(&self, uid: u64, read: &ReadMessage) -> Result<bool> {
+        self.time("read", self.inner.read(uid, read)).await
+    }
+
+    async fn get_conversations(&self, uid: u64) -> Result<Vec<Conversation>> {
+        self.time("get_conversations", self.inner.get_conversations(uid))
+            .await
+    }
+
+    async fn get_read_count(&self, gid: u64, mid: u64, sender_uid: u64) -> Result<i64> {
+        self.time("get_read_count", self.inner.get_read_count(gid, mid, sender_uid))
+            .await
+    }
+}
+
+#[async_trait]
+impl ReactionStore for Metered<dyn ReactionStore> {
+    async fn add(&self, mid: u64, uid: u64, emoji: &str) -> Result<bool> {
+        self.time("add", self.inner.add(mid, uid, emoji)).await
+    }
+
+    async fn remove(&self, mid: u64, uid: u64, emoji: &str) -> Result<bool> {
+        self.time("remove", self.inner.remove(mid, uid, emoji)).await
+    }
+
+    async fn get_counts(&self, mids: &[u64], uid: u64) -> Result<HashMap<u64, Vec<ReactionCount>>> {
+        self.time("get_counts", self.inner.get_counts(mids, uid)).await
+    }
+}
+
+#[async_trait]
+impl FileStore for Metered<dyn FileStore> {
+    async fn blob_exists(&self, sha256: &str) -> Result<bool> {
+        self.time("blob_exists", self.inner.blob_exists(sha256)).await
+    }
+
+    async fn create_file(&self, file: &File) -> Result<u64> {
+        self.time("create_file", self.inner.create_file(file)).await
+    }
+
+    async fn get_file(&self, fid: u64) -> Result<Option<File>> {
+        self.time("get_file", self.inner.get_file(fid)).await
+    }
+
+    async fn save_image(
+        &self,
+        sha256: &str,
+        meta: &ImageMeta,
+        thumbnails: &[ThumbnailBlob],
+    ) -> Result<()> {
+        self.time("save_image", self.inner.save_image(sha256, meta, thumbnails))
+            .await
+    }
+
+    async fn get_image(&self, sha256: &str) -> Result<Option<(ImageMeta, Vec<ThumbnailBlob>)>> {
+        self.time("get_image", self.inner.get_image(sha256)).await
+    }
+
+    async fn get_thumbnail(&self, sha256: &str, max_side: u32) -> Result<Option<String>> {
+        self.time("get_thumbnail", self.inner.get_thumbnail(sha256, max_side))
+            .await
+    }
+
+    async fn share(&self, fid: u64, mid: u64) -> Result<()> {
+        self.time("share", self.inner.share(fid, mid)).await
+    }
+
+    async fn can_access(&self, uid: u64, file: &File) -> Result<bool> {
+        self.time("can_access", self.inner.can_access(uid, file)).await
+    }
+}
@@ -1,25 +1,272 @@
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
 use eChat::err::Result;
-use sqlx::{mysql::MySqlPoolOptions, MySql, Pool};
-mod friend;
-mod user;
-mod group;
-mod message;
-
-pub use friend::FriendManage;
-pub use user::UserManage;
-pub use group::GroupManage;
-pub use message::MessageManage;
-
-// get connect pool
-pub async fn get_pool() -> Result<Pool<MySql>> {
-    let url = get_url();
-    let pool = MySqlPoolOptions::new()
-        .max_connections(20)
-        .connect(&url)
-        .await?;
-    Ok(pool)
-}
-
-pub fn get_url() -> String {
-    dotenvy::var("DATABASE_URL").expect("please set the env variable of DATABASE_URL")
+use prometheus::HistogramVec;
+use sqlx::{Database, Pool};
+
+use crate::config::DatabaseConfig;
+use crate::modles::conversation::{MessagePreview, RECALLED_PREVIEW};
+
+#[cfg(test)]
+pub mod memory;
+mod metered;
+mod mysql;
+mod sqlite;
+mod store;
+
+pub use store::*;
+
+pub type UserManage = Arc<dyn UserStore>;
+pub type FriendManage = Arc<dyn FriendStore>;
+pub type GroupManage = Arc<dyn GroupStore>;
+pub type MessageManage = Arc<dyn MessageStore>;
+pub type ConversationManage = Arc<dyn ConversationStore>;
+pub type ReactionManage = Arc<dyn ReactionStore>;
+pub type FileManage = Arc<dyn FileStore>;
+
+/// all the stores of one database, cloning it only clones the `Arc`s
+#[derive(Clone)]
+pub struct Storage {
+    pub users: UserManage,
+    pub friends: FriendManage,
+    pub groups: GroupManage,
+    pub messages: MessageManage,
+    pub conversations: ConversationManage,
+    pub reactions: ReactionManage,
+    pub files: FileManage,
+    pub pool: Arc<dyn PoolUsage>,
+}
+
+/// the usage of the connection pool, for the metrics
+pub trait PoolUsage: Send + Sync {
+    /// the open connections, including the idle ones
+    fn size(&self) -> u32;
+
+    fn idle(&self) -> usize;
+}
+
+impl<DB: Database> PoolUsage for Pool<DB> {
+    fn size(&self) -> u32 {
+        Pool::size(self)
+    }
+
+    fn idle(&self) -> usize {
+        self.num_idle()
+    }
+}
+
+impl Storage {
+    /// `sqlite:` urls are backed by sqlite, others by mysql
+    pub async fn connect(config: &DatabaseConfig) -> Result<Storage> {
+        if config.url.starts_with("sqlite:") {
+            sqlite::connect(config).await
+        } else {
+            mysql::connect(config).await
+        }
+    }
+
+    /// time the queries of every store
+    pub fn metered(self, histogram: &HistogramVec) -> Storage {
+        metered::storage(self, histogram)
+    }
+}
+
+/// the fake users for development, their password is `123`
+const FIXTURES: &str = include_str!("../../fixtures/users.sql");
+
+fn escape_like(keyword: &str) -> String {
+    let mut escaped = String::with_capacity(keyword.len());
+    for c in keyword.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// the last message may have been removed, `None` in that case
+fn preview(
+    mid: Option<u64>,
+    sender_uid: Option<u64>,
+    content: Option<String>,
+    create_time: Option<NaiveDateTime>,
+    recall_time: Option<NaiveDateTime>,
+) -> Option<MessagePreview> {
+    let content = match recall_time {
+        Some(_) => RECALLED_PREVIEW.to_string(),
+        None => content.unwrap_or_default(),
+    };
+    Some(MessagePreview::new(
+        mid?,
+        sender_uid?,
+        &content,
+        create_time?,
+    ))
+}
+
+/// the database of `TEST_DATABASE_URL`, or a empty in-memory sqlite database
+#[cfg(test)]
+pub async fn test_storage() -> Result<Storage> {
+    match dotenvy::var("TEST_DATABASE_URL") {
+        Ok(url) => {
+            let config = DatabaseConfig {
+                url,
+                ..DatabaseConfig::default()
+            };
+            Storage::connect(&config).await
+        }
+        Err(_) => sqlite::memory().await,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::modles::conversation::ReadMessage;
+    use crate::modles::group::Group;
+    use crate::modles::message::{Message, ReceiverType};
+    use crate::modles::payload::MessageKind;
+    use crate::modles::user::User;
+
+    fn user(username: &str) -> User {
+        User {
+            uid: 0,
+            username: username.to_string(),
+            mail: format!("{}@test.com", username),
+            password: "test".to_string(),
+            salt: "test".to_string(),
+            create_time: chrono::Local::now().naive_local(),
+            display_name: None,
+            avatar: None,
+            bio: None,
+            mail_verified: false,
+            last_seen: None,
+        }
+    }
+
+    fn message(
+        sender_uid: u64,
+        receiver_type: ReceiverType,
+        receiver_id: u64,
+        content: &str,
+    ) -> Message {
+        Message {
+            mid: 0,
+            content: content.to_string(),
+            sender_uid,
+            receiver_id,
+            create_time: chrono::Local::now().naive_local(),
+            receiver_type,
+            edit_time: None,
+            recall_time: None,
+            reply_to_mid: None,
+            kind: MessageKind::Text,
+            payload: None,
+        }
+    }
+
+    #[test]
+    fn escape_like_should_work() {
+        assert_eq!(escape_like("abc"), "abc");
+        assert_eq!(escape_like("a_b%c\\"), "a\\_b\\%c\\\\");
+    }
+
+    #[tokio::test]
+    async fn create_user_should_work() -> Result<()> {
+        let storage = test_storage().await?;
+        let uid = storage.users.create_user(user("test1")).await?;
+        assert_eq!(storage.users.get_user(uid).await?.username, "test1");
+        assert!(storage.users.create_user(user("test1")).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_message_should_work() -> Result<()> {
+        let storage = test_storage().await?;
+        let alice = storage.users.create_user(user("alice")).await?;
+        let bob = storage.users.create_user(user("bob")).await?;
+        let mid = storage
+            .messages
+            .create_message(message(alice, ReceiverType::User, bob, "world"))
+            .await?;
+
+        let conversations = storage.conversations.get_conversations(bob).await?;
+        assert_eq!(conversations.len(), 1);
+        assert_eq!(conversations[0].receiver_id, alice);
+        assert_eq!(conversations[0].unread, 1);
+
+        let read = ReadMessage {
+            receiver_type: ReceiverType::User,
+            receiver_id: alice,
+            mid,
+        };
+        assert!(storage.conversations.read(bob, &read).await?);
+        // the cursor never moves backward
+        assert!(!storage.conversations.read(bob, &read).await?);
+        let conversations = storage.conversations.get_conversations(bob).await?;
+        assert_eq!(conversations[0].unread, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_message_should_work() -> Result<()> {
+        let storage = test_storage().await?;
+        let alice = storage.users.create_user(user("alice")).await?;
+        let bob = storage.users.create_user(user("bob")).await?;
+        let mid = storage
+            .messages
+            .create_message(message(alice, ReceiverType::User, bob, "hello"))
+            .await?;
+        let messages = storage
+            .messages
+            .get_message_by_receiver_id(bob, ReceiverType::User)
+            .await?;
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].mid, mid);
+        assert_eq!(messages[0].content, "hello");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn friend_should_work() -> Result<()> {
+        let storage = test_storage().await?;
+        let alice = storage.users.create_user(user("alice")).await?;
+        let bob = storage.users.create_user(user("bob")).await?;
+        storage.friends.create_friend(alice, bob).await?;
+        assert!(!storage.friends.is_friend(alice, bob).await?);
+        storage.friends.agree_friend(bob, alice).await?;
+        assert!(storage.friends.is_friend(alice, bob).await?);
+        assert_eq!(storage.friends.get_friends(alice).await?.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn group_should_work() -> Result<()> {
+        let storage = test_storage().await?;
+        let alice = storage.users.create_user(user("alice")).await?;
+        let bob = storage.users.create_user(user("bob")).await?;
+        storage
+            .groups
+            .create_group(Group {
+                gid: 0,
+                owner: alice,
+                name: "test".to_string(),
+                create_time: chrono::Local::now().naive_local(),
+                dissolve_time: None,
+            })
+            .await?;
+        let gid = storage.groups.get_groups_of_user(alice).await?[0].gid;
+        storage.groups.join(bob, gid).await?;
+        assert!(storage.groups.agree(bob, bob, gid).await.is_err());
+        storage.groups.agree(alice, bob, gid).await?;
+        let mut members = storage.groups.get_members(gid).await?;
+        members.sort();
+        assert_eq!(members, vec![alice, bob]);
+
+        assert_eq!(storage.groups.dissolve(alice, gid).await?, vec![bob]);
+        assert!(storage.groups.get_groups_of_user(bob).await?.is_empty());
+        Ok(())
+    }
 }
@@ -0,0 +1,235 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use eChat::err::Result;
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, Row};
+
+use crate::modles::conversation::*;
+use crate::modles::group::GroupStatus;
+use crate::modles::message::{ReceiverType, MENTION_ALL};
+use crate::persistent::{preview, ConversationStore};
+
+#[derive(Clone, Debug)]
+pub struct MySqlConversationStore {
+    db: Arc<Pool<MySql>>,
+}
+
+impl MySqlConversationStore {
+    pub fn new(db: Arc<Pool<MySql>>) -> Self {
+        MySqlConversationStore { db }
+    }
+
+    /// move the existing cursor forward, return true if it's moved
+    async fn move_cursor(&self, uid: u64, read: &ReadMessage) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            update
+                read_cursor
+            set
+                last_read_mid = ?, update_time = ?
+            where
+                uid = ? and receiver_type = ? and receiver_id = ? and last_read_mid < ?
+            "#,
+        )
+        .bind(read.mid)
+        .bind(chrono::Local::now().naive_local())
+        .bind(uid)
+        .bind(read.receiver_type)
+        .bind(read.receiver_id)
+        .bind(read.mid)
+        .execute(&*self.db)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// the last message of the conversation in the row, which is joined as `m`
+fn last_message(row: &MySqlRow) -> sqlx::Result<Option<MessagePreview>> {
+    Ok(preview(
+        row.try_get("mid")?,
+        row.try_get("sender_uid")?,
+        row.try_get("content")?,
+        row.try_get("create_time")?,
+        row.try_get("recall_time")?,
+    ))
+}
+
+#[async_trait]
+impl ConversationStore for MySqlConversationStore {
+    async fn read(&self, uid: u64, read: &ReadMessage) -> Result<bool> {
+        // `rows_affected` counts the matched rows with `CLIENT_FOUND_ROWS`, not the changed ones,
+        // so the cursor is only matched if it's moved forward
+        if self.move_cursor(uid, read).await? {
+            return Ok(true);
+        }
+        let result = sqlx::query(
+            r#"
+            insert ignore into
+                read_cursor (uid, receiver_type, receiver_id, last_read_mid, update_time)
+            values (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(uid)
+        .bind(read.receiver_type)
+        .bind(read.receiver_id)
+        .bind(read.mid)
+        .bind(chrono::Local::now().naive_local())
+        .execute(&*self.db)
+        .await?;
+        if result.rows_affected() > 0 {
+            return Ok(true);
+        }
+        // the cursor may be created by another connection of the user just now
+        self.move_cursor(uid, read).await
+    }
+
+    /// the direct chats come from the `conversation` table which is maintained by
+    /// `MessageStore::create_message`, the last message of a group is saved on the group
+    async fn get_conversations(&self, uid: u64) -> Result<Vec<Conversation>> {
+        // `coalesce` of a unsigned column and `0` is cast back to unsigned for decoding as u64
+        let direct = sqlx::query(
+            r#"
+            select
+                c.peer_uid,
+                c.update_time,
+                u.username,
+                u.display_name,
+                u.avatar,
+                m.mid,
+                m.sender_uid,
+                m.content,
+                m.create_time,
+                m.recall_time,
+                cast(coalesce(r.last_read_mid, 0) as unsigned) as last_read_mid,
+                (
+                    select count(*) from message m2
+                    where m2.receiver_type = ? and m2.receiver_id = c.uid and m2.sender_uid = c.peer_uid
+                        and m2.mid > coalesce(r.last_read_mid, 0)
+                ) as unread
+            from
+                conversation c
+            left join
+                user u on u.uid = c.peer_uid
+            left join
+                message m on m.mid = c.last_mid
+            left join
+                read_cursor r on r.uid = c.uid and r.receiver_type = ? and r.receiver_id = c.peer_uid
+            where
+                c.uid = ?
+            "#,
+        )
+        .bind(ReceiverType::User)
+        .bind(ReceiverType::User)
+        .bind(uid)
+        .try_map(|row: MySqlRow| {
+            let display_name: Option<String> = row.try_get("display_name")?;
+            let username: Option<String> = row.try_get("username")?;
+            Ok(Conversation {
+                receiver_type: ReceiverType::User,
+                receiver_id: row.try_get("peer_uid")?,
+                name: display_name
+                    .or(username)
+                    .unwrap_or_else(|| "已注销用户".to_string()),
+                avatar: row.try_get("avatar")?,
+                last_message: last_message(&row)?,
+                last_active_time: row.try_get("update_time")?,
+                last_read_mid: row.try_get("last_read_mid")?,
+                unread: row.try_get("unread")?,
+                mentions: 0,
+            })
+        })
+        .fetch_all(&*self.db)
+        .await?;
+
+        let group = sqlx::query(
+            r#"
+            select
+                g.gid,
+                g.name,
+                g.create_time as group_create_time,
+                m.mid,
+                m.sender_uid,
+                m.content,
+                m.create_time,
+                m.recall_time,
+                cast(coalesce(r.last_read_mid, 0) as unsigned) as last_read_mid,
+                (
+                    select count(*) from message m2
+                    where m2.receiver_type = ? and m2.receiver_id = g.gid and m2.sender_uid <> ?
+                        and m2.mid > coalesce(r.last_read_mid, 0)
+                ) as unread,
+                (
+                    select count(distinct mt.mid) from mention mt
+                    join message m3 on m3.mid = mt.mid
+                    where mt.gid = g.gid and mt.uid in (?, ?) and m3.sender_uid <> ?
+                        and m3.recall_time is null and mt.mid > coalesce(r.last_read_mid, 0)
+                ) as mentions
+            from
+                `group` g
+            left join
+                message m on m.mid = g.last_mid
+            left join
+                read_cursor r on r.uid = ? and r.receiver_type = ? and r.receiver_id = g.gid
+            where
+                g.dissolve_time is null
+                and (g.owner = ? or exists (
+                    select 1 from group_user gu where gu.gid = g.gid and gu.uid = ? and gu.status = ?
+                ))
+            "#,
+        )
+        .bind(ReceiverType::Group)
+        .bind(uid)
+        .bind(uid)
+        .bind(MENTION_ALL)
+        .bind(uid)
+        .bind(uid)
+        .bind(ReceiverType::Group)
+        .bind(uid)
+        .bind(uid)
+        .bind(GroupStatus::Agree)
+        .try_map(|row: MySqlRow| {
+            let last_message = last_message(&row)?;
+            Ok(Conversation {
+                receiver_type: ReceiverType::Group,
+                receiver_id: row.try_get("gid")?,
+                name: row.try_get("name")?,
+                avatar: None,
+                last_active_time: match &last_message {
+                    Some(message) => message.create_time,
+                    None => row.try_get("group_create_time")?,
+                },
+                last_message,
+                last_read_mid: row.try_get("last_read_mid")?,
+                unread: row.try_get("unread")?,
+                mentions: row.try_get("mentions")?,
+            })
+        })
+        .fetch_all(&*self.db)
+        .await?;
+
+        let mut conversations: Vec<Conversation> = direct.into_iter().chain(group).collect();
+        conversations.sort_by_key(|c| std::cmp::Reverse(c.last_active_time));
+        Ok(conversations)
+    }
+
+    async fn get_read_count(&self, gid: u64, mid: u64, sender_uid: u64) -> Result<i64> {
+        let count = sqlx::query_scalar(
+            r#"
+            select
+                count(*)
+            from
+                read_cursor
+            where
+                receiver_type = ? and receiver_id = ? and last_read_mid >= ? and uid <> ?
+            "#,
+        )
+        .bind(ReceiverType::Group)
+        .bind(gid)
+        .bind(mid)
+        .bind(sender_uid)
+        .fetch_one(&*self.db)
+        .await?;
+        Ok(count)
+    }
+}
\ No newline at end of file
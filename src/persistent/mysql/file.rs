@@ -0,0 +1,232 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use eChat::err::Result;
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, Row};
+
+use super::to_file;
+use crate::modles::file::*;
+use crate::modles::group::GroupStatus;
+use crate::modles::message::ReceiverType;
+use crate::persistent::FileStore;
+
+#[derive(Clone, Debug)]
+pub struct MySqlFileStore {
+    db: Arc<Pool<MySql>>,
+}
+
+impl MySqlFileStore {
+    pub fn new(db: Arc<Pool<MySql>>) -> Self {
+        MySqlFileStore { db }
+    }
+}
+
+#[async_trait]
+impl FileStore for MySqlFileStore {
+    async fn blob_exists(&self, sha256: &str) -> Result<bool> {
+        let count: i64 = sqlx::query_scalar("select count(*) from file_blob where sha256 = ?")
+            .bind(sha256)
+            .fetch_one(&*self.db)
+            .await?;
+        Ok(count > 0)
+    }
+
+    async fn create_file(&self, file: &File) -> Result<u64> {
+        let mut tx = self.db.begin().await?;
+        sqlx::query(
+            r#"
+            insert ignore into
+                file_blob (sha256, size, content_type, create_time)
+            values (?, ?, ?, ?)
+            "#,
+        )
+        .bind(&file.sha256)
+        .bind(file.size)
+        .bind(&file.content_type)
+        .bind(file.create_time)
+        .execute(&mut tx)
+        .await?;
+        let fid = sqlx::query(
+            "insert into file (sha256, name, uploader, create_time) values (?, ?, ?, ?)",
+        )
+        .bind(&file.sha256)
+        .bind(&file.name)
+        .bind(file.uploader)
+        .bind(file.create_time)
+        .execute(&mut tx)
+        .await?
+        .last_insert_id();
+        tx.commit().await?;
+        Ok(fid)
+    }
+
+    async fn get_file(&self, fid: u64) -> Result<Option<File>> {
+        let file = sqlx::query(
+            r#"
+            select
+                f.fid,
+                f.sha256,
+                f.name,
+                f.uploader,
+                b.size,
+                b.content_type,
+                f.create_time
+            from
+                file f
+            join
+                file_blob b on b.sha256 = f.sha256
+            where
+                f.fid = ?
+            "#,
+        )
+        .bind(fid)
+        .try_map(to_file)
+        .fetch_optional(&*self.db)
+        .await?;
+        Ok(file)
+    }
+
+    async fn save_image(
+        &self,
+        sha256: &str,
+        meta: &ImageMeta,
+        thumbnails: &[ThumbnailBlob],
+    ) -> Result<()> {
+        let mut tx = self.db.begin().await?;
+        sqlx::query("update file_blob set width = ?, height = ?, blurhash = ? where sha256 = ?")
+            .bind(meta.width)
+            .bind(meta.height)
+            .bind(&meta.blurhash)
+            .bind(sha256)
+            .execute(&mut tx)
+            .await?;
+        for thumbnail in thumbnails {
+            sqlx::query(
+                r#"
+                insert ignore into
+                    thumbnail (sha256, max_side, thumbnail_sha256, width, height)
+                values (?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(sha256)
+            .bind(thumbnail.max_side)
+            .bind(&thumbnail.sha256)
+            .bind(thumbnail.width)
+            .bind(thumbnail.height)
+            .execute(&mut tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn get_image(&self, sha256: &str) -> Result<Option<(ImageMeta, Vec<ThumbnailBlob>)>> {
+        let meta = sqlx::query("select width, height, blurhash from file_blob where sha256 = ?")
+            .bind(sha256)
+            .try_map(|row: MySqlRow| {
+                let width: Option<u32> = row.try_get("width")?;
+                let height: Option<u32> = row.try_get("height")?;
+                let blurhash: Option<String> = row.try_get("blurhash")?;
+                Ok((width, height, blurhash))
+            })
+            .fetch_optional(&*self.db)
+            .await?
+            .and_then(|(width, height, blurhash)| {
+                Some(ImageMeta {
+                    width: width?,
+                    height: height?,
+                    blurhash: blurhash?,
+                })
+            });
+        let meta = match meta {
+            Some(meta) => meta,
+            None => return Ok(None),
+        };
+        let thumbnails = sqlx::query(
+            r#"
+            select
+                max_side,
+                thumbnail_sha256,
+                width,
+                height
+            from
+                thumbnail
+            where
+                sha256 = ?
+            order by
+                max_side
+            "#,
+        )
+        .bind(sha256)
+        .try_map(|row: MySqlRow| {
+            Ok(ThumbnailBlob {
+                max_side: row.try_get("max_side")?,
+                sha256: row.try_get("thumbnail_sha256")?,
+                width: row.try_get("width")?,
+                height: row.try_get("height")?,
+            })
+        })
+        .fetch_all(&*self.db)
+        .await?;
+        Ok(Some((meta, thumbnails)))
+    }
+
+    async fn get_thumbnail(&self, sha256: &str, max_side: u32) -> Result<Option<String>> {
+        let thumbnail = sqlx::query_scalar(
+            "select thumbnail_sha256 from thumbnail where sha256 = ? and max_side = ?",
+        )
+        .bind(sha256)
+        .bind(max_side)
+        .fetch_optional(&*self.db)
+        .await?;
+        Ok(thumbnail)
+    }
+
+    async fn share(&self, fid: u64, mid: u64) -> Result<()> {
+        sqlx::query("insert ignore into file_share (fid, mid) values (?, ?)")
+            .bind(fid)
+            .bind(mid)
+            .execute(&*self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn can_access(&self, uid: u64, file: &File) -> Result<bool> {
+        if file.uploader == uid {
+            return Ok(true);
+        }
+        let count: i64 = sqlx::query_scalar(
+            r#"
+            select
+                count(*)
+            from
+                file_share fs
+            join
+                message m on m.mid = fs.mid
+            where
+                fs.fid = ?
+                and m.recall_time is null
+                and (
+                    (m.receiver_type = ? and (m.sender_uid = ? or m.receiver_id = ?))
+                    or (m.receiver_type = ? and exists (
+                        select 1 from `group` g where g.gid = m.receiver_id and (g.owner = ? or exists (
+                            select 1 from group_user gu where gu.gid = g.gid and gu.uid = ? and gu.status = ?
+                        ))
+                    ))
+                )
+            "#,
+        )
+        .bind(file.fid)
+        .bind(ReceiverType::User)
+        .bind(uid)
+        .bind(uid)
+        .bind(ReceiverType::Group)
+        .bind(uid)
+        .bind(uid)
+        .bind(GroupStatus::Agree)
+        .fetch_one(&*self.db)
+        .await?;
+        Ok(count > 0)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,165 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use eChat::err::{Result, ResultExt};
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, Row};
+use tracing::instrument;
+
+use super::to_view_user;
+use crate::modles::friend::*;
+use crate::modles::presence::FriendLastSeen;
+use crate::modles::user::ViewUser;
+use crate::persistent::FriendStore;
+
+#[derive(Clone, Debug)]
+pub struct MySqlFriendStore {
+    db: Arc<Pool<MySql>>,
+}
+
+impl MySqlFriendStore {
+    pub fn new(db: Arc<Pool<MySql>>) -> Self {
+        MySqlFriendStore { db }
+    }
+
+    /// status only be changed from Pending to Agree or Refused
+    #[instrument]
+    async fn change_status(&self, uid: u64, friend_id: u64, status: FriendStatus) -> Result<()> {
+        let mut tx = self.db.begin().await?;
+        sqlx::query(
+            r#"update
+                friend
+            set
+                status = ?
+            where
+                uid = ? and friend_id = ? and status = ?
+            "#,
+        )
+        .bind(status)
+        .bind(uid)
+        .bind(friend_id)
+        .bind(FriendStatus::Pending)
+        .execute(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl FriendStore for MySqlFriendStore {
+    #[instrument]
+    async fn create_friend(&self, uid: u64, friend_id: u64) -> Result<()> {
+        let mut tx = self.db.begin().await?; // sqlx how to rollback
+
+        sqlx::query("insert into friend (uid, friend_id, status) values (?, ?, ?)")
+            .bind(uid)
+            .bind(friend_id)
+            .bind(FriendStatus::Agree)
+            .execute(&mut tx)
+            .await
+            .on_duplicated("已经申请添加好友了".into())?;
+
+        sqlx::query("insert into friend (uid, friend_id, status) values (?, ?, ?)")
+            .bind(friend_id)
+            .bind(uid)
+            .bind(FriendStatus::Pending)
+            .execute(&mut tx)
+            .await
+            .on_duplicated("已经申请添加好友了".into())?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn agree_friend(&self, uid: u64, friend_id: u64) -> Result<()> {
+        self.change_status(uid, friend_id, FriendStatus::Agree)
+            .await
+    }
+
+    async fn refuse_friend(&self, uid: u64, friend_id: u64) -> Result<()> {
+        self.change_status(uid, friend_id, FriendStatus::Refused)
+            .await
+    }
+
+    #[instrument]
+    async fn get_friends(&self, uid: u64) -> Result<Vec<ViewUser>> {
+        let friends = sqlx::query(
+            r#"
+            with friends as
+            (
+            SELECT
+                f1.uid, f1.friend_id
+            FROM
+                friend f1 join friend f2 on f1.uid = f2.friend_id and f1.friend_id = f2.uid
+            where f1.status = ? and f2.status = ?
+            )
+
+            select
+                u.uid, u.mail, u.username, u.create_time, u.display_name, u.avatar, u.bio
+            from
+                friends  f
+            join
+                user u on u.uid = f.friend_id
+            where
+                f.uid = ?;
+            "#,
+        )
+        .bind(FriendStatus::Agree)
+        .bind(FriendStatus::Agree)
+        .bind(uid)
+        .try_map(to_view_user)
+        .fetch_all(&*self.db)
+        .await?;
+        Ok(friends)
+    }
+
+    async fn is_friend(&self, uid: u64, friend_id: u64) -> Result<bool> {
+        let count: i64 = sqlx::query_scalar(
+            r#"
+            select
+                count(*)
+            from
+                friend f1 join friend f2 on f1.uid = f2.friend_id and f1.friend_id = f2.uid
+            where
+                f1.uid = ? and f1.friend_id = ? and f1.status = ? and f2.status = ?
+            "#,
+        )
+        .bind(uid)
+        .bind(friend_id)
+        .bind(FriendStatus::Agree)
+        .bind(FriendStatus::Agree)
+        .fetch_one(&*self.db)
+        .await?;
+        Ok(count > 0)
+    }
+
+    async fn get_friends_last_seen(&self, uid: u64) -> Result<Vec<FriendLastSeen>> {
+        let friends = sqlx::query(
+            r#"
+            select
+                u.uid, u.last_seen
+            from
+                friend f1
+            join
+                friend f2 on f1.uid = f2.friend_id and f1.friend_id = f2.uid
+            join
+                user u on u.uid = f1.friend_id
+            where
+                f1.uid = ? and f1.status = ? and f2.status = ?
+            "#,
+        )
+        .bind(uid)
+        .bind(FriendStatus::Agree)
+        .bind(FriendStatus::Agree)
+        .try_map(|row: MySqlRow| {
+            Ok(FriendLastSeen {
+                uid: row.try_get("uid")?,
+                last_seen: row.try_get("last_seen")?,
+            })
+        })
+        .fetch_all(&*self.db)
+        .await?;
+        Ok(friends)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,397 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use eChat::err::Result;
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, QueryBuilder, Row};
+
+use super::MESSAGE_COLUMNS;
+use crate::modles::conversation::MessagePreview;
+use crate::modles::group::GroupStatus;
+use crate::modles::message::*;
+use crate::persistent::{escape_like, MessageStore};
+
+#[derive(Clone, Debug)]
+pub struct MySqlMessageStore {
+    db: Arc<Pool<MySql>>,
+}
+
+impl MySqlMessageStore {
+    pub fn new(db: Arc<Pool<MySql>>) -> Self {
+        MySqlMessageStore { db }
+    }
+}
+
+#[async_trait]
+impl MessageStore for MySqlMessageStore {
+    async fn create_message(&self, message: Message) -> Result<u64> {
+        let mut tx = self.db.begin().await?;
+        let mid = sqlx::query(
+            r#"
+            insert into
+                message(sender_uid,
+                    receiver_type,
+                    receiver_id,
+                    content,
+                    create_time,
+                    reply_to_mid,
+                    kind,
+                    payload)
+            values (?, ?, ?,?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(message.sender_uid)
+        .bind(message.receiver_type)
+        .bind(message.receiver_id)
+        .bind(&message.content)
+        .bind(message.create_time)
+        .bind(message.reply_to_mid)
+        .bind(message.kind)
+        .bind(&message.payload)
+        .execute(&mut tx)
+        .await?
+        .last_insert_id();
+
+        match message.receiver_type {
+            ReceiverType::User => {
+                // both of the sender and the receiver have the conversation
+                let participants = [
+                    (message.sender_uid, message.receiver_id),
+                    (message.receiver_id, message.sender_uid),
+                ];
+                for (uid, peer_uid) in participants {
+                    sqlx::query(
+                        r#"
+                        insert into
+                            conversation (uid, peer_uid, last_mid, update_time)
+                        values (?, ?, ?, ?)
+                        on duplicate key update
+                            last_mid = values(last_mid), update_time = values(update_time)
+                        "#,
+                    )
+                    .bind(uid)
+                    .bind(peer_uid)
+                    .bind(mid)
+                    .bind(message.create_time)
+                    .execute(&mut tx)
+                    .await?;
+                }
+            }
+            ReceiverType::Group => {
+                sqlx::query("update `group` set last_mid = ? where gid = ?")
+                    .bind(mid)
+                    .bind(message.receiver_id)
+                    .execute(&mut tx)
+                    .await?;
+            }
+        }
+
+        tx.commit().await?;
+        Ok(mid)
+    }
+
+    async fn create_mentions(&self, mid: u64, gid: u64, uids: &[u64]) -> Result<()> {
+        if uids.is_empty() {
+            return Ok(());
+        }
+        let mut query_builder: QueryBuilder<MySql> =
+            QueryBuilder::new("insert ignore into mention (mid, uid, gid) ");
+        query_builder.push_values(uids, |mut b, uid| {
+            b.push_bind(mid).push_bind(uid).push_bind(gid);
+        });
+        query_builder.build().execute(&*self.db).await?;
+        Ok(())
+    }
+
+    async fn get_message_by_receiver_id(
+        &self,
+        id: u64,
+        mtype: ReceiverType,
+    ) -> Result<Vec<Message>> {
+        let sql = format!(
+            "select {} from message where receiver_id = ? and receiver_type = ?",
+            MESSAGE_COLUMNS
+        );
+        let messages = sqlx::query_as(&sql)
+            .bind(id)
+            .bind(mtype)
+            .fetch_all(&*self.db)
+            .await?;
+        Ok(messages)
+    }
+
+    async fn get_message(&self, mid: u64) -> Result<Option<Message>> {
+        let sql = format!("select {} from message where mid = ?", MESSAGE_COLUMNS);
+        let message = sqlx::query_as(&sql)
+            .bind(mid)
+            .fetch_optional(&*self.db)
+            .await?;
+        Ok(message)
+    }
+
+    async fn get_history(&self, uid: u64, history: &MessageHistory) -> Result<Vec<Message>> {
+        let before = history.before.unwrap_or(u64::MAX);
+        let messages = match history.receiver_type {
+            ReceiverType::User => {
+                let sql = format!(
+                    r#"
+                    select {} from message
+                    where
+                        receiver_type = ?
+                        and ((sender_uid = ? and receiver_id = ?) or (sender_uid = ? and receiver_id = ?))
+                        and mid < ?
+                    order by
+                        mid desc
+                    limit ?
+                    "#,
+                    MESSAGE_COLUMNS
+                );
+                sqlx::query_as(&sql)
+                    .bind(ReceiverType::User)
+                    .bind(uid)
+                    .bind(history.receiver_id)
+                    .bind(history.receiver_id)
+                    .bind(uid)
+                    .bind(before)
+                    .bind(history.limit())
+                    .fetch_all(&*self.db)
+                    .await?
+            }
+            ReceiverType::Group => {
+                let sql = format!(
+                    r#"
+                    select {} from message
+                    where
+                        receiver_type = ? and receiver_id = ? and mid < ?
+                    order by
+                        mid desc
+                    limit ?
+                    "#,
+                    MESSAGE_COLUMNS
+                );
+                sqlx::query_as(&sql)
+                    .bind(ReceiverType::Group)
+                    .bind(history.receiver_id)
+                    .bind(before)
+                    .bind(history.limit())
+                    .fetch_all(&*self.db)
+                    .await?
+            }
+        };
+        Ok(messages)
+    }
+
+    /// the terms of at least 2 chars use the ngram full-text index, the shorter ones use `like`
+    async fn search_messages(&self, uid: u64, search: &SearchMessage) -> Result<Vec<Message>> {
+        let mut query = QueryBuilder::new(
+            r#"
+            select
+                mid, content, sender_uid, receiver_id, create_time, receiver_type,
+                edit_time, recall_time, reply_to_mid, kind, payload
+            from
+                message m
+            where
+                m.recall_time is null
+                and (
+                    (m.receiver_type = "#,
+        );
+        query
+            .push_bind(ReceiverType::User)
+            .push(" and (m.sender_uid = ")
+            .push_bind(uid)
+            .push(" or m.receiver_id = ")
+            .push_bind(uid)
+            .push(")) or (m.receiver_type = ")
+            .push_bind(ReceiverType::Group)
+            .push(
+                " and exists (select 1 from `group` g where g.gid = m.receiver_id and (g.owner = ",
+            )
+            .push_bind(uid)
+            .push(" or exists (select 1 from group_user gu where gu.gid = g.gid and gu.uid = ")
+            .push_bind(uid)
+            .push(" and gu.status = ")
+            .push_bind(GroupStatus::Agree)
+            .push(")))))");
+
+        let (long, short): (Vec<&str>, Vec<&str>) = search
+            .terms()
+            .into_iter()
+            .partition(|term| term.chars().count() >= 2);
+        if !long.is_empty() {
+            // every term is required and matched as a phrase, the operators inside are literal
+            let against = long
+                .iter()
+                .map(|term| format!("+\"{}\"", term.replace('"', " ")))
+                .collect::<Vec<_>>()
+                .join(" ");
+            query
+                .push(" and match(m.content) against (")
+                .push_bind(against)
+                .push(" in boolean mode)");
+        }
+        for term in short {
+            query
+                .push(" and m.content like ")
+                .push_bind(format!("%{}%", escape_like(term)));
+        }
+
+        if let (Some(receiver_type), Some(receiver_id)) = (search.receiver_type, search.receiver_id)
+        {
+            query
+                .push(" and m.receiver_type = ")
+                .push_bind(receiver_type);
+            match receiver_type {
+                ReceiverType::User => {
+                    query
+                        .push(" and (m.sender_uid = ")
+                        .push_bind(receiver_id)
+                        .push(" or m.receiver_id = ")
+                        .push_bind(receiver_id)
+                        .push(")");
+                }
+                ReceiverType::Group => {
+                    query.push(" and m.receiver_id = ").push_bind(receiver_id);
+                }
+            }
+        }
+        if let Some(sender_uid) = search.sender_uid {
+            query.push(" and m.sender_uid = ").push_bind(sender_uid);
+        }
+        if let Some(from) = search.from {
+            query.push(" and m.create_time >= ").push_bind(from);
+        }
+        if let Some(to) = search.to {
+            query.push(" and m.create_time < ").push_bind(to);
+        }
+        query
+            .push(" order by m.mid desc limit ")
+            .push_bind(search.limit())
+            .push(" offset ")
+            .push_bind(search.offset());
+
+        let messages = query
+            .build_query_as::<Message>()
+            .fetch_all(&*self.db)
+            .await?;
+        Ok(messages)
+    }
+
+    async fn edit_message(&self, mid: u64, content: &str, edit_time: NaiveDateTime) -> Result<()> {
+        let mut tx = self.db.begin().await?;
+        sqlx::query(
+            r#"
+            insert into
+                message_edit (mid, content, edit_time)
+            select
+                mid, content, ?
+            from
+                message
+            where
+                mid = ? and recall_time is null
+            "#,
+        )
+        .bind(edit_time)
+        .bind(mid)
+        .execute(&mut tx)
+        .await?;
+        sqlx::query(
+            "update message set content = ?, edit_time = ? where mid = ? and recall_time is null",
+        )
+        .bind(content)
+        .bind(edit_time)
+        .bind(mid)
+        .execute(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn recall_message(&self, mid: u64, recall_time: NaiveDateTime) -> Result<()> {
+        let mut tx = self.db.begin().await?;
+        sqlx::query(
+            "update message set content = '', payload = null, recall_time = ? where mid = ? and recall_time is null",
+        )
+        .bind(recall_time)
+        .bind(mid)
+        .execute(&mut tx)
+        .await?;
+        sqlx::query("delete from message_edit where mid = ?")
+            .bind(mid)
+            .execute(&mut tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn get_thread(&self, root_mid: u64) -> Result<Vec<Message>> {
+        let sql = format!(
+            "select {} from message where reply_to_mid = ? order by mid",
+            MESSAGE_COLUMNS
+        );
+        let messages = sqlx::query_as(&sql)
+            .bind(root_mid)
+            .fetch_all(&*self.db)
+            .await?;
+        Ok(messages)
+    }
+
+    async fn get_reply_previews(
+        &self,
+        messages: &[Message],
+    ) -> Result<HashMap<u64, MessagePreview>> {
+        let mids: Vec<u64> = messages.iter().filter_map(|m| m.reply_to_mid).collect();
+        if mids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let mut query = QueryBuilder::<MySql>::new(format!(
+            "select {} from message where mid in (",
+            MESSAGE_COLUMNS
+        ));
+        let mut separated = query.separated(", ");
+        for mid in mids {
+            separated.push_bind(mid);
+        }
+        separated.push_unseparated(")");
+        let previews = query
+            .build_query_as::<Message>()
+            .fetch_all(&*self.db)
+            .await?
+            .iter()
+            .map(|message| (message.mid, MessagePreview::from(message)))
+            .collect();
+        Ok(previews)
+    }
+
+    async fn get_edits(&self, mid: u64) -> Result<Vec<MessageEdit>> {
+        let edits = sqlx::query(
+            "select mid, content, edit_time from message_edit where mid = ? order by id",
+        )
+        .bind(mid)
+        .try_map(|row: MySqlRow| {
+            Ok(MessageEdit {
+                mid: row.try_get("mid")?,
+                content: row.try_get("content")?,
+                edit_time: row.try_get("edit_time")?,
+            })
+        })
+        .fetch_all(&*self.db)
+        .await?;
+        Ok(edits)
+    }
+
+    async fn get_messages_of_user(&self, uid: u64) -> Result<Vec<Message>> {
+        let sql = format!(
+            "select {} from message where sender_uid = ? or (receiver_type = ? and receiver_id = ?) order by mid",
+            MESSAGE_COLUMNS
+        );
+        let messages = sqlx::query_as(&sql)
+            .bind(uid)
+            .bind(ReceiverType::User)
+            .bind(uid)
+            .fetch_all(&*self.db)
+            .await?;
+        Ok(messages)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use eChat::err::Result;
+use sqlx::mysql::{MySqlPoolOptions, MySqlRow};
+use sqlx::{MySql, Pool, Row};
+use tracing::info;
+
+use super::{Storage, FIXTURES};
+use crate::config::DatabaseConfig;
+use crate::modles::file::File;
+use crate::modles::group::Group;
+use crate::modles::user::{PublicUser, User, ViewUser};
+
+mod conversation;
+mod file;
+mod friend;
+mod group;
+mod message;
+mod reaction;
+mod user;
+
+pub use conversation::MySqlConversationStore;
+pub use file::MySqlFileStore;
+pub use friend::MySqlFriendStore;
+pub use group::MySqlGroupStore;
+pub use message::MySqlMessageStore;
+pub use reaction::MySqlReactionStore;
+pub use user::MySqlUserStore;
+
+/// the columns of `message` read by `Message::from_row`
+const MESSAGE_COLUMNS: &str = "mid, content, sender_uid, receiver_id, create_time, receiver_type, \
+    edit_time, recall_time, reply_to_mid, kind, payload";
+
+/// the size of the pool if `max_connections` is not set
+const DEFAULT_MAX_CONNECTIONS: u32 = 20;
+
+pub async fn connect(config: &DatabaseConfig) -> Result<Storage> {
+    let pool = MySqlPoolOptions::new()
+        .max_connections(config.max_connections.unwrap_or(DEFAULT_MAX_CONNECTIONS))
+        .connect(&config.url)
+        .await?;
+    prepare(&pool, config).await?;
+    Ok(storage(Arc::new(pool)))
+}
+
+async fn prepare(pool: &Pool<MySql>, config: &DatabaseConfig) -> Result<()> {
+    if config.migrate {
+        // the first migrations drop the tables before creating them,
+        // never run them on a database whose schema was applied by hand
+        let unmanaged: i64 = sqlx::query_scalar(
+            r#"
+            select
+                exists(select 1 from information_schema.tables
+                    where table_schema = database() and table_name = 'user')
+                and not exists(select 1 from information_schema.tables
+                    where table_schema = database() and table_name = '_sqlx_migrations')
+            "#,
+        )
+        .fetch_one(pool)
+        .await?;
+        if unmanaged != 0 {
+            return Err(anyhow!(
+                "the tables exist but no migration is recorded, set DATABASE_MIGRATE=false"
+            )
+            .into());
+        }
+        let mut migrator = sqlx::migrate!();
+        // the seed data was a migration, it's `FIXTURES` now
+        migrator.set_ignore_missing(true);
+        migrator.run(pool).await.map_err(sqlx::Error::from)?;
+    }
+    if config.fixtures {
+        let users: i64 = sqlx::query_scalar("select count(*) from user")
+            .fetch_one(pool)
+            .await?;
+        if users == 0 {
+            sqlx::query(FIXTURES).execute(pool).await?;
+            info!("the fixtures are loaded");
+        }
+    }
+    Ok(())
+}
+
+/// all the stores share the pool
+pub fn storage(db: Arc<Pool<MySql>>) -> Storage {
+    Storage {
+        pool: db.clone(),
+        users: Arc::new(MySqlUserStore::new(db.clone())),
+        friends: Arc::new(MySqlFriendStore::new(db.clone())),
+        groups: Arc::new(MySqlGroupStore::new(db.clone())),
+        messages: Arc::new(MySqlMessageStore::new(db.clone())),
+        conversations: Arc::new(MySqlConversationStore::new(db.clone())),
+        reactions: Arc::new(MySqlReactionStore::new(db.clone())),
+        files: Arc::new(MySqlFileStore::new(db)),
+    }
+}
+
+// the queries are checked at runtime like the sqlite stores, so the crate builds without a database.
+// the ids are `bigint unsigned`, they are decoded as u64 directly
+
+fn to_user(row: MySqlRow) -> sqlx::Result<User> {
+    Ok(User {
+        uid: row.try_get("uid")?,
+        username: row.try_get("username")?,
+        mail: row.try_get("mail")?,
+        password: row.try_get("password")?,
+        salt: row.try_get("salt")?,
+        create_time: row.try_get("create_time")?,
+        display_name: row.try_get("display_name")?,
+        avatar: row.try_get("avatar")?,
+        bio: row.try_get("bio")?,
+        mail_verified: row.try_get("mail_verified")?,
+        last_seen: row.try_get("last_seen")?,
+    })
+}
+
+fn to_view_user(row: MySqlRow) -> sqlx::Result<ViewUser> {
+    Ok(ViewUser {
+        uid: row.try_get("uid")?,
+        username: row.try_get("username")?,
+        mail: row.try_get("mail")?,
+        create_time: row.try_get("create_time")?,
+        display_name: row.try_get("display_name")?,
+        avatar: row.try_get("avatar")?,
+        bio: row.try_get("bio")?,
+    })
+}
+
+fn to_public_user(row: MySqlRow) -> sqlx::Result<PublicUser> {
+    Ok(PublicUser {
+        uid: row.try_get("uid")?,
+        username: row.try_get("username")?,
+        display_name: row.try_get("display_name")?,
+        avatar: row.try_get("avatar")?,
+        bio: row.try_get("bio")?,
+    })
+}
+
+fn to_group(row: MySqlRow) -> sqlx::Result<Group> {
+    Ok(Group {
+        gid: row.try_get("gid")?,
+        owner: row.try_get("owner")?,
+        name: row.try_get("name")?,
+        create_time: row.try_get("create_time")?,
+        dissolve_time: row.try_get("dissolve_time")?,
+    })
+}
+
+fn to_file(row: MySqlRow) -> sqlx::Result<File> {
+    Ok(File {
+        fid: row.try_get("fid")?,
+        sha256: row.try_get("sha256")?,
+        name: row.try_get("name")?,
+        uploader: row.try_get("uploader")?,
+        size: row.try_get("size")?,
+        content_type: row.try_get("content_type")?,
+        create_time: row.try_get("create_time")?,
+    })
+}
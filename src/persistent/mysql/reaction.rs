@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use eChat::err::Result;
+use sqlx::{MySql, Pool, QueryBuilder};
+
+use crate::modles::reaction::*;
+use crate::persistent::ReactionStore;
+
+#[derive(Clone, Debug)]
+pub struct MySqlReactionStore {
+    db: Arc<Pool<MySql>>,
+}
+
+impl MySqlReactionStore {
+    pub fn new(db: Arc<Pool<MySql>>) -> Self {
+        MySqlReactionStore { db }
+    }
+}
+
+#[async_trait]
+impl ReactionStore for MySqlReactionStore {
+    async fn add(&self, mid: u64, uid: u64, emoji: &str) -> Result<bool> {
+        let result = sqlx::query(
+            "insert ignore into reaction (mid, uid, emoji, create_time) values (?, ?, ?, ?)",
+        )
+        .bind(mid)
+        .bind(uid)
+        .bind(emoji)
+        .bind(chrono::Local::now().naive_local())
+        .execute(&*self.db)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn remove(&self, mid: u64, uid: u64, emoji: &str) -> Result<bool> {
+        let result = sqlx::query("delete from reaction where mid = ? and uid = ? and emoji = ?")
+            .bind(mid)
+            .bind(uid)
+            .bind(emoji)
+            .execute(&*self.db)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn get_counts(&self, mids: &[u64], uid: u64) -> Result<HashMap<u64, Vec<ReactionCount>>> {
+        let mut counts: HashMap<u64, Vec<ReactionCount>> = HashMap::new();
+        if mids.is_empty() {
+            return Ok(counts);
+        }
+        // a list can't be bound, so build the `in` clause by hand
+        let mut query = QueryBuilder::<MySql>::new("select mid, emoji, count(*), cast(max(uid = ");
+        query.push_bind(uid);
+        query.push(") as signed) from reaction where mid in (");
+        let mut separated = query.separated(", ");
+        for mid in mids {
+            separated.push_bind(*mid);
+        }
+        separated.push_unseparated(") group by mid, emoji order by min(create_time)");
+        let rows = query
+            .build_query_as::<(u64, String, i64, i64)>()
+            .fetch_all(&*self.db)
+            .await?;
+        for (mid, emoji, count, reacted) in rows {
+            counts.entry(mid).or_default().push(ReactionCount {
+                emoji,
+                count,
+                reacted: reacted > 0,
+            });
+        }
+        Ok(counts)
+    }
+}
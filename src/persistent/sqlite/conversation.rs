@@ -0,0 +1,207 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use eChat::err::Result;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Pool, Row, Sqlite};
+
+use super::{get_optional_u64, get_u64};
+use crate::modles::conversation::*;
+use crate::modles::group::GroupStatus;
+use crate::modles::message::{ReceiverType, MENTION_ALL};
+use crate::persistent::{preview, ConversationStore};
+
+#[derive(Clone, Debug)]
+pub struct SqliteConversationStore {
+    db: Arc<Pool<Sqlite>>,
+}
+
+impl SqliteConversationStore {
+    pub fn new(db: Arc<Pool<Sqlite>>) -> Self {
+        SqliteConversationStore { db }
+    }
+}
+
+/// the last message of the conversation in the row, which is joined as `m`
+fn last_message(row: &SqliteRow) -> sqlx::Result<Option<MessagePreview>> {
+    Ok(preview(
+        get_optional_u64(row, "mid")?,
+        get_optional_u64(row, "sender_uid")?,
+        row.try_get("content")?,
+        row.try_get("create_time")?,
+        row.try_get("recall_time")?,
+    ))
+}
+
+#[async_trait]
+impl ConversationStore for SqliteConversationStore {
+    async fn read(&self, uid: u64, read: &ReadMessage) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            insert into
+                read_cursor (uid, receiver_type, receiver_id, last_read_mid, update_time)
+            values (?, ?, ?, ?, ?)
+            on conflict (uid, receiver_type, receiver_id) do update set
+                last_read_mid = excluded.last_read_mid, update_time = excluded.update_time
+            where
+                excluded.last_read_mid > read_cursor.last_read_mid
+            "#,
+        )
+        .bind(uid as i64)
+        .bind(read.receiver_type)
+        .bind(read.receiver_id as i64)
+        .bind(read.mid as i64)
+        .bind(chrono::Local::now().naive_local())
+        .execute(&*self.db)
+        .await?;
+        // the row is not counted if the `where` of the upsert is false
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn get_conversations(&self, uid: u64) -> Result<Vec<Conversation>> {
+        let uid = uid as i64;
+        let direct = sqlx::query(
+            r#"
+            select
+                c.peer_uid,
+                c.update_time,
+                u.username,
+                u.display_name,
+                u.avatar,
+                m.mid,
+                m.sender_uid,
+                m.content,
+                m.create_time,
+                m.recall_time,
+                coalesce(r.last_read_mid, 0) as last_read_mid,
+                (
+                    select count(*) from message m2
+                    where m2.receiver_type = ? and m2.receiver_id = c.uid and m2.sender_uid = c.peer_uid
+                        and m2.mid > coalesce(r.last_read_mid, 0)
+                ) as unread
+            from
+                conversation c
+            left join
+                user u on u.uid = c.peer_uid
+            left join
+                message m on m.mid = c.last_mid
+            left join
+                read_cursor r on r.uid = c.uid and r.receiver_type = ? and r.receiver_id = c.peer_uid
+            where
+                c.uid = ?
+            "#,
+        )
+        .bind(ReceiverType::User)
+        .bind(ReceiverType::User)
+        .bind(uid)
+        .try_map(|row: SqliteRow| {
+            let display_name: Option<String> = row.try_get("display_name")?;
+            let username: Option<String> = row.try_get("username")?;
+            Ok(Conversation {
+                receiver_type: ReceiverType::User,
+                receiver_id: get_u64(&row, "peer_uid")?,
+                name: display_name
+                    .or(username)
+                    .unwrap_or_else(|| "已注销用户".to_string()),
+                avatar: row.try_get("avatar")?,
+                last_message: last_message(&row)?,
+                last_active_time: row.try_get("update_time")?,
+                last_read_mid: get_u64(&row, "last_read_mid")?,
+                unread: row.try_get("unread")?,
+                mentions: 0,
+            })
+        })
+        .fetch_all(&*self.db)
+        .await?;
+
+        let group = sqlx::query(
+            r#"
+            select
+                g.gid,
+                g.name,
+                g.create_time as group_create_time,
+                m.mid,
+                m.sender_uid,
+                m.content,
+                m.create_time,
+                m.recall_time,
+                coalesce(r.last_read_mid, 0) as last_read_mid,
+                (
+                    select count(*) from message m2
+                    where m2.receiver_type = ? and m2.receiver_id = g.gid and m2.sender_uid <> ?
+                        and m2.mid > coalesce(r.last_read_mid, 0)
+                ) as unread,
+                (
+                    select count(distinct mt.mid) from mention mt
+                    join message m3 on m3.mid = mt.mid
+                    where mt.gid = g.gid and mt.uid in (?, ?) and m3.sender_uid <> ?
+                        and m3.recall_time is null and mt.mid > coalesce(r.last_read_mid, 0)
+                ) as mentions
+            from
+                `group` g
+            left join
+                message m on m.mid = g.last_mid
+            left join
+                read_cursor r on r.uid = ? and r.receiver_type = ? and r.receiver_id = g.gid
+            where
+                g.dissolve_time is null
+                and (g.owner = ? or exists (
+                    select 1 from group_user gu where gu.gid = g.gid and gu.uid = ? and gu.status = ?
+                ))
+            "#,
+        )
+        .bind(ReceiverType::Group)
+        .bind(uid)
+        .bind(uid)
+        .bind(MENTION_ALL as i64)
+        .bind(uid)
+        .bind(uid)
+        .bind(ReceiverType::Group)
+        .bind(uid)
+        .bind(uid)
+        .bind(GroupStatus::Agree)
+        .try_map(|row: SqliteRow| {
+            let last_message = last_message(&row)?;
+            Ok(Conversation {
+                receiver_type: ReceiverType::Group,
+                receiver_id: get_u64(&row, "gid")?,
+                name: row.try_get("name")?,
+                avatar: None,
+                last_active_time: match &last_message {
+                    Some(message) => message.create_time,
+                    None => row.try_get("group_create_time")?,
+                },
+                last_message,
+                last_read_mid: get_u64(&row, "last_read_mid")?,
+                unread: row.try_get("unread")?,
+                mentions: row.try_get("mentions")?,
+            })
+        })
+        .fetch_all(&*self.db)
+        .await?;
+
+        let mut conversations: Vec<Conversation> = direct.into_iter().chain(group).collect();
+        conversations.sort_by_key(|c| std::cmp::Reverse(c.last_active_time));
+        Ok(conversations)
+    }
+
+    async fn get_read_count(&self, gid: u64, mid: u64, sender_uid: u64) -> Result<i64> {
+        let count = sqlx::query_scalar(
+            r#"
+            select
+                count(*)
+            from
+                read_cursor
+            where
+                receiver_type = ? and receiver_id = ? and last_read_mid >= ? and uid <> ?
+            "#,
+        )
+        .bind(ReceiverType::Group)
+        .bind(gid as i64)
+        .bind(mid as i64)
+        .bind(sender_uid as i64)
+        .fetch_one(&*self.db)
+        .await?;
+        Ok(count)
+    }
+}
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use eChat::err::{Result, ResultExt};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Pool, Row, Sqlite};
+
+use super::{get_u64, to_view_user};
+use crate::modles::friend::*;
+use crate::modles::presence::FriendLastSeen;
+use crate::modles::user::ViewUser;
+use crate::persistent::FriendStore;
+
+#[derive(Clone, Debug)]
+pub struct SqliteFriendStore {
+    db: Arc<Pool<Sqlite>>,
+}
+
+impl SqliteFriendStore {
+    pub fn new(db: Arc<Pool<Sqlite>>) -> Self {
+        SqliteFriendStore { db }
+    }
+
+    /// status only be changed from Pending to Agree or Refused
+    async fn change_status(&self, uid: u64, friend_id: u64, status: FriendStatus) -> Result<()> {
+        sqlx::query("update friend set status = ? where uid = ? and friend_id = ? and status = ?")
+            .bind(status)
+            .bind(uid as i64)
+            .bind(friend_id as i64)
+            .bind(FriendStatus::Pending)
+            .execute(&*self.db)
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl FriendStore for SqliteFriendStore {
+    async fn create_friend(&self, uid: u64, friend_id: u64) -> Result<()> {
+        let mut tx = self.db.begin().await?;
+        let requests = [
+            (uid, friend_id, FriendStatus::Agree),
+            (friend_id, uid, FriendStatus::Pending),
+        ];
+        for (uid, friend_id, status) in requests {
+            sqlx::query("insert into friend (uid, friend_id, status) values (?, ?, ?)")
+                .bind(uid as i64)
+                .bind(friend_id as i64)
+                .bind(status)
+                .execute(&mut tx)
+                .await
+                .on_duplicated("已经申请添加好友了".into())?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn agree_friend(&self, uid: u64, friend_id: u64) -> Result<()> {
+        self.change_status(uid, friend_id, FriendStatus::Agree)
+            .await
+    }
+
+    async fn refuse_friend(&self, uid: u64, friend_id: u64) -> Result<()> {
+        self.change_status(uid, friend_id, FriendStatus::Refused)
+            .await
+    }
+
+    async fn get_friends(&self, uid: u64) -> Result<Vec<ViewUser>> {
+        let friends = sqlx::query(
+            r#"
+            select
+                u.uid, u.mail, u.username, u.create_time, u.display_name, u.avatar, u.bio
+            from
+                friend f1
+            join
+                friend f2 on f1.uid = f2.friend_id and f1.friend_id = f2.uid
+            join
+                user u on u.uid = f1.friend_id
+            where
+                f1.uid = ? and f1.status = ? and f2.status = ?
+            "#,
+        )
+        .bind(uid as i64)
+        .bind(FriendStatus::Agree)
+        .bind(FriendStatus::Agree)
+        .try_map(to_view_user)
+        .fetch_all(&*self.db)
+        .await?;
+        Ok(friends)
+    }
+
+    async fn is_friend(&self, uid: u64, friend_id: u64) -> Result<bool> {
+        let count: i64 = sqlx::query_scalar(
+            r#"
+            select
+                count(*)
+            from
+                friend f1 join friend f2 on f1.uid = f2.friend_id and f1.friend_id = f2.uid
+            where
+                f1.uid = ? and f1.friend_id = ? and f1.status = ? and f2.status = ?
+            "#,
+        )
+        .bind(uid as i64)
+        .bind(friend_id as i64)
+        .bind(FriendStatus::Agree)
+        .bind(FriendStatus::Agree)
+        .fetch_one(&*self.db)
+        .await?;
+        Ok(count > 0)
+    }
+
+    async fn get_friends_last_seen(&self, uid: u64) -> Result<Vec<FriendLastSeen>> {
+        let friends = sqlx::query(
+            r#"
+            select
+                u.uid, u.last_seen
+            from
+                friend f1
+            join
+                friend f2 on f1.uid = f2.friend_id and f1.friend_id = f2.uid
+            join
+                user u on u.uid = f1.friend_id
+            where
+                f1.uid = ? and f1.status = ? and f2.status = ?
+            "#,
+        )
+        .bind(uid as i64)
+        .bind(FriendStatus::Agree)
+        .bind(FriendStatus::Agree)
+        .try_map(|row: SqliteRow| {
+            Ok(FriendLastSeen {
+                uid: get_u64(&row, "uid")?,
+                last_seen: row.try_get("last_seen")?,
+            })
+        })
+        .fetch_all(&*self.db)
+        .await?;
+        Ok(friends)
+    }
+}
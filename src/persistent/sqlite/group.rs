@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use eChat::err::{Error, Result};
+use sqlx::{Pool, Sqlite};
+
+use super::to_group;
+use crate::modles::group::*;
+use crate::persistent::GroupStore;
+
+#[derive(Clone, Debug)]
+pub struct SqliteGroupStore {
+    db: Arc<Pool<Sqlite>>,
+}
+
+impl SqliteGroupStore {
+    pub fn new(db: Arc<Pool<Sqlite>>) -> Self {
+        SqliteGroupStore { db }
+    }
+}
+
+#[async_trait]
+impl GroupStore for SqliteGroupStore {
+    async fn create_group(&self, group: Group) -> Result<()> {
+        sqlx::query("insert into `group` (owner, name, create_time) values (?, ?, ?)")
+            .bind(group.owner as i64)
+            .bind(group.name)
+            .bind(group.create_time)
+            .execute(&*self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn get_group(&self, gid: u64) -> Result<Option<Group>> {
+        let group = sqlx::query(
+            "select gid, owner, name, create_time, dissolve_time from `group` where gid = ?",
+        )
+        .bind(gid as i64)
+        .try_map(to_group)
+        .fetch_optional(&*self.db)
+        .await?;
+        Ok(group)
+    }
+
+    async fn get_members(&self, gid: u64) -> Result<Vec<u64>> {
+        let members: Vec<i64> = sqlx::query_scalar(
+            r#"
+            select owner from `group` where gid = ?
+            union
+            select uid from group_user where gid = ? and status = ?
+            "#,
+        )
+        .bind(gid as i64)
+        .bind(gid as i64)
+        .bind(GroupStatus::Agree)
+        .fetch_all(&*self.db)
+        .await?;
+        Ok(members.into_iter().map(|uid| uid as u64).collect())
+    }
+
+    async fn get_groups_of_user(&self, uid: u64) -> Result<Vec<Group>> {
+        let groups = sqlx::query(
+            r#"
+            select
+                g.gid, g.owner, g.name, g.create_time, g.dissolve_time
+            from
+                `group` g
+            where
+                g.dissolve_time is null
+                and (g.owner = ? or exists (
+                    select 1 from group_user gu where gu.gid = g.gid and gu.uid = ? and gu.status = ?
+                ))
+            "#,
+        )
+        .bind(uid as i64)
+        .bind(uid as i64)
+        .bind(GroupStatus::Agree)
+        .try_map(to_group)
+        .fetch_all(&*self.db)
+        .await?;
+        Ok(groups)
+    }
+
+    async fn join(&self, uid: u64, gid: u64) -> Result<()> {
+        match self.get_group(gid).await? {
+            Some(group) if !group.is_dissolved() => (),
+            _ => return Err(Error::unprocessable_entity([("msg", "群不存在或已解散")])),
+        }
+        sqlx::query("insert into group_user (uid, gid, status) values (?, ?, ?)")
+            .bind(uid as i64)
+            .bind(gid as i64)
+            .bind(GroupStatus::Pending)
+            .execute(&*self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn agree(&self, user_id: u64, uid: u64, gid: u64) -> Result<()> {
+        let mut tx = self.db.begin().await?;
+        let owner_id: Option<i64> =
+            sqlx::query_scalar("select owner from `group` where gid = ? and dissolve_time is null")
+                .bind(gid as i64)
+                .fetch_optional(&mut tx)
+                .await?;
+        if owner_id != Some(user_id as i64) {
+            return Err(Error::unprocessable_entity([("msg", "没有权限同意")]));
+        }
+
+        sqlx::query("update group_user set status = ? where uid = ? and gid = ?")
+            .bind(GroupStatus::Agree)
+            .bind(uid as i64)
+            .bind(gid as i64)
+            .execute(&mut tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn dissolve(&self, user_id: u64, gid: u64) -> Result<Vec<u64>> {
+        let gid = gid as i64;
+        let mut tx = self.db.begin().await?;
+        let owner_id: Option<i64> =
+            sqlx::query_scalar("select owner from `group` where gid = ? and dissolve_time is null")
+                .bind(gid)
+                .fetch_optional(&mut tx)
+                .await?;
+        if owner_id != Some(user_id as i64) {
+            return Err(Error::unprocessable_entity([("msg", "没有权限解散")]));
+        }
+
+        let members: Vec<i64> =
+            sqlx::query_scalar("select uid from group_user where gid = ? and status = ?")
+                .bind(gid)
+                .bind(GroupStatus::Agree)
+                .fetch_all(&mut tx)
+                .await?;
+
+        sqlx::query("delete from group_user where gid = ?")
+            .bind(gid)
+            .execute(&mut tx)
+            .await?;
+
+        sqlx::query("update `group` set dissolve_time = ? where gid = ?")
+            .bind(chrono::Local::now().naive_local())
+            .bind(gid)
+            .execute(&mut tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(members.into_iter().map(|uid| uid as u64).collect())
+    }
+}
@@ -0,0 +1,382 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use eChat::err::Result;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Pool, QueryBuilder, Row, Sqlite};
+
+use super::{get_u64, to_message, MESSAGE_COLUMNS};
+use crate::modles::conversation::MessagePreview;
+use crate::modles::group::GroupStatus;
+use crate::modles::message::*;
+use crate::persistent::{escape_like, MessageStore};
+
+#[derive(Clone, Debug)]
+pub struct SqliteMessageStore {
+    db: Arc<Pool<Sqlite>>,
+}
+
+impl SqliteMessageStore {
+    pub fn new(db: Arc<Pool<Sqlite>>) -> Self {
+        SqliteMessageStore { db }
+    }
+}
+
+#[async_trait]
+impl MessageStore for SqliteMessageStore {
+    async fn create_message(&self, message: Message) -> Result<u64> {
+        let mut tx = self.db.begin().await?;
+        let mid = sqlx::query(
+            r#"
+            insert into
+                message (sender_uid, receiver_type, receiver_id, content, create_time,
+                    reply_to_mid, kind, payload)
+            values (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(message.sender_uid as i64)
+        .bind(message.receiver_type)
+        .bind(message.receiver_id as i64)
+        .bind(message.content)
+        .bind(message.create_time)
+        .bind(message.reply_to_mid.map(|mid| mid as i64))
+        .bind(message.kind)
+        .bind(message.payload)
+        .execute(&mut tx)
+        .await?
+        .last_insert_rowid();
+
+        match message.receiver_type {
+            ReceiverType::User => {
+                // both of the sender and the receiver have the conversation
+                let participants = [
+                    (message.sender_uid, message.receiver_id),
+                    (message.receiver_id, message.sender_uid),
+                ];
+                for (uid, peer_uid) in participants {
+                    sqlx::query(
+                        r#"
+                        insert into
+                            conversation (uid, peer_uid, last_mid, update_time)
+                        values (?, ?, ?, ?)
+                        on conflict (uid, peer_uid) do update set
+                            last_mid = excluded.last_mid, update_time = excluded.update_time
+                        "#,
+                    )
+                    .bind(uid as i64)
+                    .bind(peer_uid as i64)
+                    .bind(mid)
+                    .bind(message.create_time)
+                    .execute(&mut tx)
+                    .await?;
+                }
+            }
+            ReceiverType::Group => {
+                sqlx::query("update `group` set last_mid = ? where gid = ?")
+                    .bind(mid)
+                    .bind(message.receiver_id as i64)
+                    .execute(&mut tx)
+                    .await?;
+            }
+        }
+
+        tx.commit().await?;
+        Ok(mid as u64)
+    }
+
+    async fn create_mentions(&self, mid: u64, gid: u64, uids: &[u64]) -> Result<()> {
+        if uids.is_empty() {
+            return Ok(());
+        }
+        let mut query_builder: QueryBuilder<Sqlite> =
+            QueryBuilder::new("insert or ignore into mention (mid, uid, gid) ");
+        query_builder.push_values(uids, |mut b, uid| {
+            b.push_bind(mid as i64)
+                .push_bind(*uid as i64)
+                .push_bind(gid as i64);
+        });
+        query_builder.build().execute(&*self.db).await?;
+        Ok(())
+    }
+
+    async fn get_message_by_receiver_id(
+        &self,
+        id: u64,
+        mtype: ReceiverType,
+    ) -> Result<Vec<Message>> {
+        let sql = format!(
+            "select {} from message where receiver_id = ? and receiver_type = ?",
+            MESSAGE_COLUMNS
+        );
+        let messages = sqlx::query(&sql)
+            .bind(id as i64)
+            .bind(mtype)
+            .try_map(to_message)
+            .fetch_all(&*self.db)
+            .await?;
+        Ok(messages)
+    }
+
+    async fn get_message(&self, mid: u64) -> Result<Option<Message>> {
+        let sql = format!("select {} from message where mid = ?", MESSAGE_COLUMNS);
+        let message = sqlx::query(&sql)
+            .bind(mid as i64)
+            .try_map(to_message)
+            .fetch_optional(&*self.db)
+            .await?;
+        Ok(message)
+    }
+
+    async fn get_history(&self, uid: u64, history: &MessageHistory) -> Result<Vec<Message>> {
+        // the mids are saved as i64, so the max of i64 means no limit
+        let before = history.before.map_or(i64::MAX, |mid| mid as i64);
+        let uid = uid as i64;
+        let receiver_id = history.receiver_id as i64;
+        let messages = match history.receiver_type {
+            ReceiverType::User => {
+                let sql = format!(
+                    r#"
+                    select {} from message
+                    where
+                        receiver_type = ?
+                        and ((sender_uid = ? and receiver_id = ?) or (sender_uid = ? and receiver_id = ?))
+                        and mid < ?
+                    order by mid desc
+                    limit ?
+                    "#,
+                    MESSAGE_COLUMNS
+                );
+                sqlx::query(&sql)
+                    .bind(ReceiverType::User)
+                    .bind(uid)
+                    .bind(receiver_id)
+                    .bind(receiver_id)
+                    .bind(uid)
+                    .bind(before)
+                    .bind(history.limit())
+                    .try_map(to_message)
+                    .fetch_all(&*self.db)
+                    .await?
+            }
+            ReceiverType::Group => {
+                let sql = format!(
+                    r#"
+                    select {} from message
+                    where receiver_type = ? and receiver_id = ? and mid < ?
+                    order by mid desc
+                    limit ?
+                    "#,
+                    MESSAGE_COLUMNS
+                );
+                sqlx::query(&sql)
+                    .bind(ReceiverType::Group)
+                    .bind(receiver_id)
+                    .bind(before)
+                    .bind(history.limit())
+                    .try_map(to_message)
+                    .fetch_all(&*self.db)
+                    .await?
+            }
+        };
+        Ok(messages)
+    }
+
+    /// sqlite has no ngram parser, every term is matched by `like`
+    async fn search_messages(&self, uid: u64, search: &SearchMessage) -> Result<Vec<Message>> {
+        let uid = uid as i64;
+        let mut query = QueryBuilder::<Sqlite>::new(format!(
+            "select {} from message m where m.recall_time is null and ((m.receiver_type = ",
+            MESSAGE_COLUMNS
+        ));
+        query
+            .push_bind(ReceiverType::User)
+            .push(" and (m.sender_uid = ")
+            .push_bind(uid)
+            .push(" or m.receiver_id = ")
+            .push_bind(uid)
+            .push(")) or (m.receiver_type = ")
+            .push_bind(ReceiverType::Group)
+            .push(
+                " and exists (select 1 from `group` g where g.gid = m.receiver_id and (g.owner = ",
+            )
+            .push_bind(uid)
+            .push(" or exists (select 1 from group_user gu where gu.gid = g.gid and gu.uid = ")
+            .push_bind(uid)
+            .push(" and gu.status = ")
+            .push_bind(GroupStatus::Agree)
+            .push(")))))");
+
+        for term in search.terms() {
+            query
+                .push(" and m.content like ")
+                .push_bind(format!("%{}%", escape_like(term)))
+                .push(" escape '\\'");
+        }
+
+        if let (Some(receiver_type), Some(receiver_id)) = (search.receiver_type, search.receiver_id)
+        {
+            let receiver_id = receiver_id as i64;
+            query
+                .push(" and m.receiver_type = ")
+                .push_bind(receiver_type);
+            match receiver_type {
+                ReceiverType::User => {
+                    query
+                        .push(" and (m.sender_uid = ")
+                        .push_bind(receiver_id)
+                        .push(" or m.receiver_id = ")
+                        .push_bind(receiver_id)
+                        .push(")");
+                }
+                ReceiverType::Group => {
+                    query.push(" and m.receiver_id = ").push_bind(receiver_id);
+                }
+            }
+        }
+        if let Some(sender_uid) = search.sender_uid {
+            query
+                .push(" and m.sender_uid = ")
+                .push_bind(sender_uid as i64);
+        }
+        if let Some(from) = search.from {
+            query.push(" and m.create_time >= ").push_bind(from);
+        }
+        if let Some(to) = search.to {
+            query.push(" and m.create_time < ").push_bind(to);
+        }
+        query
+            .push(" order by m.mid desc limit ")
+            .push_bind(search.limit())
+            .push(" offset ")
+            .push_bind(search.offset());
+
+        let messages = query
+            .build()
+            .try_map(to_message)
+            .fetch_all(&*self.db)
+            .await?;
+        Ok(messages)
+    }
+
+    async fn edit_message(&self, mid: u64, content: &str, edit_time: NaiveDateTime) -> Result<()> {
+        let mut tx = self.db.begin().await?;
+        sqlx::query(
+            r#"
+            insert into
+                message_edit (mid, content, edit_time)
+            select
+                mid, content, ?
+            from
+                message
+            where
+                mid = ? and recall_time is null
+            "#,
+        )
+        .bind(edit_time)
+        .bind(mid as i64)
+        .execute(&mut tx)
+        .await?;
+        sqlx::query(
+            "update message set content = ?, edit_time = ? where mid = ? and recall_time is null",
+        )
+        .bind(content)
+        .bind(edit_time)
+        .bind(mid as i64)
+        .execute(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn recall_message(&self, mid: u64, recall_time: NaiveDateTime) -> Result<()> {
+        let mut tx = self.db.begin().await?;
+        sqlx::query(
+            "update message set content = '', payload = null, recall_time = ? where mid = ? and recall_time is null",
+        )
+        .bind(recall_time)
+        .bind(mid as i64)
+        .execute(&mut tx)
+        .await?;
+        sqlx::query("delete from message_edit where mid = ?")
+            .bind(mid as i64)
+            .execute(&mut tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn get_thread(&self, root_mid: u64) -> Result<Vec<Message>> {
+        let sql = format!(
+            "select {} from message where reply_to_mid = ? order by mid",
+            MESSAGE_COLUMNS
+        );
+        let messages = sqlx::query(&sql)
+            .bind(root_mid as i64)
+            .try_map(to_message)
+            .fetch_all(&*self.db)
+            .await?;
+        Ok(messages)
+    }
+
+    async fn get_reply_previews(
+        &self,
+        messages: &[Message],
+    ) -> Result<HashMap<u64, MessagePreview>> {
+        let mids: Vec<u64> = messages.iter().filter_map(|m| m.reply_to_mid).collect();
+        if mids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let mut query = QueryBuilder::<Sqlite>::new(format!(
+            "select {} from message where mid in (",
+            MESSAGE_COLUMNS
+        ));
+        let mut separated = query.separated(", ");
+        for mid in mids {
+            separated.push_bind(mid as i64);
+        }
+        separated.push_unseparated(")");
+        let previews = query
+            .build()
+            .try_map(to_message)
+            .fetch_all(&*self.db)
+            .await?
+            .iter()
+            .map(|message| (message.mid, MessagePreview::from(message)))
+            .collect();
+        Ok(previews)
+    }
+
+    async fn get_edits(&self, mid: u64) -> Result<Vec<MessageEdit>> {
+        let edits = sqlx::query(
+            "select mid, content, edit_time from message_edit where mid = ? order by id",
+        )
+        .bind(mid as i64)
+        .try_map(|row: SqliteRow| {
+            Ok(MessageEdit {
+                mid: get_u64(&row, "mid")?,
+                content: row.try_get("content")?,
+                edit_time: row.try_get("edit_time")?,
+            })
+        })
+        .fetch_all(&*self.db)
+        .await?;
+        Ok(edits)
+    }
+
+    async fn get_messages_of_user(&self, uid: u64) -> Result<Vec<Message>> {
+        let sql = format!(
+            "select {} from message where sender_uid = ? or (receiver_type = ? and receiver_id = ?) order by mid",
+            MESSAGE_COLUMNS
+        );
+        let messages = sqlx::query(&sql)
+            .bind(uid as i64)
+            .bind(ReceiverType::User)
+            .bind(uid as i64)
+            .try_map(to_message)
+            .fetch_all(&*self.db)
+            .await?;
+        Ok(messages)
+    }
+}
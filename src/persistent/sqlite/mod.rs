@@ -0,0 +1,271 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use eChat::err::Result;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions, SqliteRow};
+use sqlx::{Pool, Row, Sqlite};
+use tracing::info;
+
+use super::{Storage, FIXTURES};
+use crate::config::DatabaseConfig;
+use crate::modles::file::File;
+use crate::modles::group::Group;
+use crate::modles::message::Message;
+use crate::modles::user::{PublicUser, User, ViewUser};
+
+mod conversation;
+mod file;
+mod friend;
+mod group;
+mod message;
+mod reaction;
+mod user;
+
+pub use conversation::SqliteConversationStore;
+pub use file::SqliteFileStore;
+pub use friend::SqliteFriendStore;
+pub use group::SqliteGroupStore;
+pub use message::SqliteMessageStore;
+pub use reaction::SqliteReactionStore;
+pub use user::SqliteUserStore;
+
+/// the columns of `message` read by `to_message`
+const MESSAGE_COLUMNS: &str = "mid, content, sender_uid, receiver_id, create_time, receiver_type, \
+    edit_time, recall_time, reply_to_mid, kind, payload";
+
+/// the size of the pool if `max_connections` is not set, the writes are serialized anyway
+const DEFAULT_MAX_CONNECTIONS: u32 = 5;
+
+/// the database file is created if it doesn't exist, the schema is in `migrations/sqlite`
+pub async fn connect(config: &DatabaseConfig) -> Result<Storage> {
+    // the deletes rely on the cascades of the foreign keys
+    let options = SqliteConnectOptions::from_str(&config.url)?
+        .create_if_missing(true)
+        .foreign_keys(true);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(config.max_connections.unwrap_or(DEFAULT_MAX_CONNECTIONS))
+        .connect_with(options)
+        .await?;
+    prepare(&pool, config).await?;
+    Ok(storage(Arc::new(pool)))
+}
+
+async fn prepare(pool: &Pool<Sqlite>, config: &DatabaseConfig) -> Result<()> {
+    if config.migrate {
+        sqlx::migrate!("./migrations/sqlite")
+            .run(pool)
+            .await
+            .map_err(sqlx::Error::from)?;
+    }
+    if config.fixtures {
+        let users: i64 = sqlx::query_scalar("select count(*) from user")
+            .fetch_one(pool)
+            .await?;
+        if users == 0 {
+            sqlx::query(FIXTURES).execute(pool).await?;
+            info!("the fixtures are loaded");
+        }
+    }
+    Ok(())
+}
+
+/// a empty database with the schema, it's dropped with the storage
+#[cfg(test)]
+pub async fn memory() -> Result<Storage> {
+    Ok(storage(Arc::new(memory_pool().await?)))
+}
+
+#[cfg(test)]
+async fn memory_pool() -> Result<Pool<Sqlite>> {
+    // every connection has its own in-memory database, so keep the only one forever
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect_with(SqliteConnectOptions::from_str("sqlite::memory:")?.foreign_keys(true))
+        .await?;
+    prepare(&pool, &DatabaseConfig::default()).await?;
+    Ok(pool)
+}
+
+/// all the stores share the pool
+pub fn storage(db: Arc<Pool<Sqlite>>) -> Storage {
+    Storage {
+        pool: db.clone(),
+        users: Arc::new(SqliteUserStore::new(db.clone())),
+        friends: Arc::new(SqliteFriendStore::new(db.clone())),
+        groups: Arc::new(SqliteGroupStore::new(db.clone())),
+        messages: Arc::new(SqliteMessageStore::new(db.clone())),
+        conversations: Arc::new(SqliteConversationStore::new(db.clone())),
+        reactions: Arc::new(SqliteReactionStore::new(db.clone())),
+        files: Arc::new(SqliteFileStore::new(db)),
+    }
+}
+
+// sqlite has no unsigned 64-bit integer, the ids are saved as i64,
+// so the rows are decoded by hand instead of `FromRow`
+
+fn get_u64(row: &SqliteRow, column: &str) -> sqlx::Result<u64> {
+    Ok(row.try_get::<i64, _>(column)? as u64)
+}
+
+fn get_optional_u64(row: &SqliteRow, column: &str) -> sqlx::Result<Option<u64>> {
+    Ok(row.try_get::<Option<i64>, _>(column)?.map(|id| id as u64))
+}
+
+fn to_user(row: SqliteRow) -> sqlx::Result<User> {
+    Ok(User {
+        uid: get_u64(&row, "uid")?,
+        username: row.try_get("username")?,
+        mail: row.try_get("mail")?,
+        password: row.try_get("password")?,
+        salt: row.try_get("salt")?,
+        create_time: row.try_get("create_time")?,
+        display_name: row.try_get("display_name")?,
+        avatar: row.try_get("avatar")?,
+        bio: row.try_get("bio")?,
+        mail_verified: row.try_get("mail_verified")?,
+        last_seen: row.try_get("last_seen")?,
+    })
+}
+
+fn to_view_user(row: SqliteRow) -> sqlx::Result<ViewUser> {
+    Ok(ViewUser {
+        uid: get_u64(&row, "uid")?,
+        username: row.try_get("username")?,
+        mail: row.try_get("mail")?,
+        create_time: row.try_get("create_time")?,
+        display_name: row.try_get("display_name")?,
+        avatar: row.try_get("avatar")?,
+        bio: row.try_get("bio")?,
+    })
+}
+
+fn to_public_user(row: SqliteRow) -> sqlx::Result<PublicUser> {
+    Ok(PublicUser {
+        uid: get_u64(&row, "uid")?,
+        username: row.try_get("username")?,
+        display_name: row.try_get("display_name")?,
+        avatar: row.try_get("avatar")?,
+        bio: row.try_get("bio")?,
+    })
+}
+
+fn to_group(row: SqliteRow) -> sqlx::Result<Group> {
+    Ok(Group {
+        gid: get_u64(&row, "gid")?,
+        owner: get_u64(&row, "owner")?,
+        name: row.try_get("name")?,
+        create_time: row.try_get("create_time")?,
+        dissolve_time: row.try_get("dissolve_time")?,
+    })
+}
+
+fn to_message(row: SqliteRow) -> sqlx::Result<Message> {
+    Ok(Message {
+        mid: get_u64(&row, "mid")?,
+        content: row.try_get("content")?,
+        sender_uid: get_u64(&row, "sender_uid")?,
+        receiver_id: get_u64(&row, "receiver_id")?,
+        create_time: row.try_get("create_time")?,
+        receiver_type: row.try_get("receiver_type")?,
+        edit_time: row.try_get("edit_time")?,
+        recall_time: row.try_get("recall_time")?,
+        reply_to_mid: get_optional_u64(&row, "reply_to_mid")?,
+        kind: row.try_get("kind")?,
+        payload: row.try_get("payload")?,
+    })
+}
+
+fn to_file(row: SqliteRow) -> sqlx::Result<File> {
+    Ok(File {
+        fid: get_u64(&row, "fid")?,
+        sha256: row.try_get("sha256")?,
+        name: row.try_get("name")?,
+        uploader: get_u64(&row, "uploader")?,
+        size: get_u64(&row, "size")?,
+        content_type: row.try_get("content_type")?,
+        create_time: row.try_get("create_time")?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::modles::friend::FriendStatus;
+    use crate::modles::group::GroupStatus;
+    use crate::modles::message::ReceiverType;
+    use crate::modles::payload::MessageKind;
+
+    /// the values of the enums are inserted by the same statements in both schemas
+    const MYSQL_SCHEMA: &str = include_str!("../../../migrations/20221128093406_constraint.up.sql");
+    const SQLITE_SCHEMA: &str =
+        include_str!("../../../migrations/sqlite/20221128093406_constraint.up.sql");
+
+    async fn values(pool: &Pool<Sqlite>, table: &str) -> Vec<(i64, String)> {
+        sqlx::query_as(&format!("select id, name from `{}` order by id", table))
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    fn variants<T: std::fmt::Debug + Copy>(values: &[T], to_id: fn(T) -> i64) -> Vec<(i64, String)> {
+        values
+            .iter()
+            .map(|value| (to_id(*value), format!("{:?}", value)))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn enum_should_match_schema() -> Result<()> {
+        let pool = memory_pool().await?;
+        assert_eq!(
+            values(&pool, "receiver_type").await,
+            variants(&[ReceiverType::User, ReceiverType::Group], |v| v as i64)
+        );
+        assert_eq!(
+            values(&pool, "friend_status").await,
+            variants(
+                &[FriendStatus::Pending, FriendStatus::Agree, FriendStatus::Refused],
+                |v| v as i64
+            )
+        );
+        assert_eq!(
+            values(&pool, "group_status").await,
+            variants(
+                &[GroupStatus::Pending, GroupStatus::Agree, GroupStatus::Refused],
+                |v| v as i64
+            )
+        );
+        let kinds = [
+            MessageKind::Text,
+            MessageKind::Image,
+            MessageKind::File,
+            MessageKind::Voice,
+            MessageKind::Location,
+            MessageKind::Card,
+            MessageKind::System,
+        ];
+        assert_eq!(values(&pool, "message_kind").await, variants(&kinds, |v| v as i64));
+
+        let inserts = |schema: &'static str| {
+            schema
+                .lines()
+                .filter(|line| line.starts_with("INSERT INTO `") && line.contains("VALUES"))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(inserts(MYSQL_SCHEMA).len(), 4);
+        assert_eq!(inserts(MYSQL_SCHEMA), inserts(SQLITE_SCHEMA));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn foreign_key_should_work() -> Result<()> {
+        let pool = memory_pool().await?;
+        let result = sqlx::query("insert into friend (uid, friend_id, status) values (1, 2, 0)")
+            .execute(&pool)
+            .await;
+        assert!(result.is_err());
+        Ok(())
+    }
+}
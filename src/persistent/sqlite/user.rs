@@ -0,0 +1,218 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use eChat::err::{Result, ResultExt};
+use sqlx::{Pool, Sqlite};
+
+use super::{to_public_user, to_user};
+use crate::modles::group::GroupStatus;
+use crate::modles::message::{MessagePolicy, DELETED_USER_ID};
+use crate::modles::user::*;
+use crate::persistent::{escape_like, UserStore};
+
+#[derive(Clone, Debug)]
+pub struct SqliteUserStore {
+    db: Arc<Pool<Sqlite>>,
+}
+
+impl SqliteUserStore {
+    pub fn new(db: Arc<Pool<Sqlite>>) -> Self {
+        SqliteUserStore { db }
+    }
+}
+
+#[async_trait]
+impl UserStore for SqliteUserStore {
+    async fn create_user(&self, user: User) -> Result<u64> {
+        let id = sqlx::query(
+            "insert into user (username, mail, password, salt, create_time) values (?, ?, ?, ?, ?)",
+        )
+        .bind(user.username)
+        .bind(user.mail)
+        .bind(user.password)
+        .bind(user.salt)
+        .bind(user.create_time)
+        .execute(&*self.db)
+        .await
+        .on_duplicated("用户名或者邮箱已经存在".into())?
+        .last_insert_rowid();
+        Ok(id as u64)
+    }
+
+    async fn update_user(&self, user: UpdateUser) -> Result<()> {
+        // sqlite evaluates the assignments with the old values, the order doesn't matter
+        sqlx::query(
+            "update user set
+                mail_verified = case when ? is not null and ? <> mail then 0 else mail_verified end,
+                username = coalesce(?, username),
+                mail = coalesce(?, mail),
+                display_name = coalesce(?, display_name),
+                avatar = coalesce(?, avatar),
+                bio = coalesce(?, bio)
+                where uid = ?",
+        )
+        .bind(user.mail.clone())
+        .bind(user.mail.clone())
+        .bind(user.username)
+        .bind(user.mail)
+        .bind(user.display_name)
+        .bind(user.avatar)
+        .bind(user.bio)
+        .bind(user.uid as i64)
+        .execute(&*self.db)
+        .await
+        .on_duplicated("用户名或者邮箱已经存在".into())?;
+        Ok(())
+    }
+
+    async fn delete_user(&self, uid: u64, policy: MessagePolicy) -> Result<Vec<(u64, Vec<u64>)>> {
+        let uid = uid as i64;
+        let mut tx = self.db.begin().await?;
+
+        match policy {
+            MessagePolicy::Anonymize => {
+                sqlx::query("update message set sender_uid = ? where sender_uid = ?")
+                    .bind(DELETED_USER_ID as i64)
+                    .bind(uid)
+                    .execute(&mut tx)
+                    .await?;
+            }
+            MessagePolicy::Remove => {
+                for sql in [
+                    "delete from reaction where mid in (select mid from message where sender_uid = ?)",
+                    "delete from mention where mid in (select mid from message where sender_uid = ?)",
+                    "delete from message where sender_uid = ?",
+                ] {
+                    sqlx::query(sql).bind(uid).execute(&mut tx).await?;
+                }
+            }
+        }
+
+        sqlx::query("delete from friend where uid = ? or friend_id = ?")
+            .bind(uid)
+            .bind(uid)
+            .execute(&mut tx)
+            .await?;
+
+        // the conversations of the peers are kept, they still have the messages
+        for sql in [
+            "delete from conversation where uid = ?",
+            "delete from read_cursor where uid = ?",
+            "delete from reaction where uid = ?",
+            "delete from mention where uid = ?",
+            "delete from group_user where uid = ?",
+        ] {
+            sqlx::query(sql).bind(uid).execute(&mut tx).await?;
+        }
+
+        let owned_groups: Vec<i64> =
+            sqlx::query_scalar("select gid from `group` where owner = ? and dissolve_time is null")
+                .bind(uid)
+                .fetch_all(&mut tx)
+                .await?;
+        let mut dissolved = Vec::new();
+        for gid in owned_groups {
+            let new_owner: Option<i64> = sqlx::query_scalar(
+                "select uid from group_user where gid = ? and status = ? order by guid limit 1",
+            )
+            .bind(gid)
+            .bind(GroupStatus::Agree)
+            .fetch_optional(&mut tx)
+            .await?;
+            match new_owner {
+                Some(new_owner) => {
+                    // the owner is not a row of group_user
+                    sqlx::query("update `group` set owner = ? where gid = ?")
+                        .bind(new_owner)
+                        .bind(gid)
+                        .execute(&mut tx)
+                        .await?;
+                    sqlx::query("delete from group_user where gid = ? and uid = ?")
+                        .bind(gid)
+                        .bind(new_owner)
+                        .execute(&mut tx)
+                        .await?;
+                }
+                None => {
+                    // only pending members are left, they are notified as well
+                    let members: Vec<i64> =
+                        sqlx::query_scalar("select uid from group_user where gid = ?")
+                            .bind(gid)
+                            .fetch_all(&mut tx)
+                            .await?;
+                    sqlx::query("delete from group_user where gid = ?")
+                        .bind(gid)
+                        .execute(&mut tx)
+                        .await?;
+                    sqlx::query("update `group` set dissolve_time = ? where gid = ?")
+                        .bind(chrono::Local::now().naive_local())
+                        .bind(gid)
+                        .execute(&mut tx)
+                        .await?;
+                    let members = members.into_iter().map(|uid| uid as u64).collect();
+                    dissolved.push((gid as u64, members));
+                }
+            }
+        }
+
+        sqlx::query("delete from user where uid = ?")
+            .bind(uid)
+            .execute(&mut tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(dissolved)
+    }
+
+    async fn get_user(&self, id: u64) -> Result<User> {
+        let user = sqlx::query("select * from user where uid = ?")
+            .bind(id as i64)
+            .try_map(to_user)
+            .fetch_one(&*self.db)
+            .await?;
+        Ok(user)
+    }
+
+    async fn get_user_by_username(&self, name: &str) -> Result<Option<User>> {
+        let user = sqlx::query("select * from user where username = ?")
+            .bind(name)
+            .try_map(to_user)
+            .fetch_optional(&*self.db)
+            .await?;
+        Ok(user)
+    }
+
+    async fn update_last_seen(&self, uid: u64, last_seen: NaiveDateTime) -> Result<()> {
+        sqlx::query("update user set last_seen = ? where uid = ?")
+            .bind(last_seen)
+            .bind(uid as i64)
+            .execute(&*self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn search_users(&self, search: &SearchUser) -> Result<Vec<PublicUser>> {
+        let pattern = format!("{}%", escape_like(&search.q));
+        let users = sqlx::query(
+            r#"
+            select
+                uid, username, display_name, avatar, bio
+            from
+                user
+            where
+                username like ? escape '\'
+            order by
+                username
+            limit ? offset ?
+            "#,
+        )
+        .bind(pattern)
+        .bind(search.limit())
+        .bind(search.offset())
+        .try_map(to_public_user)
+        .fetch_all(&*self.db)
+        .await?;
+        Ok(users)
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use eChat::err::Result;
+
+use crate::modles::conversation::{Conversation, MessagePreview, ReadMessage};
+use crate::modles::file::{File, ImageMeta, ThumbnailBlob};
+use crate::modles::group::Group;
+use crate::modles::message::*;
+use crate::modles::presence::FriendLastSeen;
+use crate::modles::reaction::ReactionCount;
+use crate::modles::user::{PublicUser, SearchUser, UpdateUser, User, ViewUser};
+
+#[async_trait]
+pub trait UserStore: Send + Sync {
+    /// return the uid, `Error::Duplicated` if the username or the mail exists
+    async fn create_user(&self, user: User) -> Result<u64>;
+
+    /// the mail is unverified again if it's changed
+    async fn update_user(&self, user: UpdateUser) -> Result<()>;
+
+    /// delete the user and everything belong to him in one transaction:
+    /// - the messages are anonymized or removed according to `policy`
+    /// - the friendships are removed
+    /// - the owned groups are transferred to the earliest member, or dissolved if no member
+    ///
+    /// return the dissolved groups and their members who should be notified
+    async fn delete_user(&self, uid: u64, policy: MessagePolicy) -> Result<Vec<(u64, Vec<u64>)>>;
+
+    async fn get_user(&self, id: u64) -> Result<User>;
+
+    async fn get_user_by_username(&self, name: &str) -> Result<Option<User>>;
+
+    async fn update_last_seen(&self, uid: u64, last_seen: NaiveDateTime) -> Result<()>;
+
+    /// find the users whose username start with `search.q`, order by username
+    async fn search_users(&self, search: &SearchUser) -> Result<Vec<PublicUser>>;
+}
+
+#[async_trait]
+pub trait FriendStore: Send + Sync {
+    /// `uid` asks `friend_id` to be friends, the request of `friend_id` is pending
+    async fn create_friend(&self, uid: u64, friend_id: u64) -> Result<()>;
+
+    /// only a pending request can be agreed
+    async fn agree_friend(&self, uid: u64, friend_id: u64) -> Result<()>;
+
+    /// only a pending request can be refused
+    async fn refuse_friend(&self, uid: u64, friend_id: u64) -> Result<()>;
+
+    async fn get_friends(&self, uid: u64) -> Result<Vec<ViewUser>>;
+
+    /// both of the users have agreed to be friends
+    async fn is_friend(&self, uid: u64, friend_id: u64) -> Result<bool>;
+
+    /// the last seen time of the accepted friends, which is saved when they go offline
+    async fn get_friends_last_seen(&self, uid: u64) -> Result<Vec<FriendLastSeen>>;
+}
+
+#[async_trait]
+pub trait GroupStore: Send + Sync {
+    async fn create_group(&self, group: Group) -> Result<()>;
+
+    async fn get_group(&self, gid: u64) -> Result<Option<Group>>;
+
+    /// the owner and the agreed members of the group
+    async fn get_members(&self, gid: u64) -> Result<Vec<u64>>;
+
+    /// the active groups which the user owns or has joined
+    async fn get_groups_of_user(&self, uid: u64) -> Result<Vec<Group>>;
+
+    /// ask to join the group, the owner must agree
+    async fn join(&self, uid: u64, gid: u64) -> Result<()>;
+
+    /// `user_id` is the id of current user, only the owner can agree
+    async fn agree(&self, user_id: u64, uid: u64, gid: u64) -> Result<()>;
+
+    /// dissolve the group, only the owner can do it.
+    /// the members are removed from `group_user`, while the group itself is kept
+    /// with `dissolve_time` set, so that its messages still point to a existing group.
+    /// return the uid of the members who should be notified
+    async fn dissolve(&self, user_id: u64, gid: u64) -> Result<Vec<u64>>;
+}
+
+#[async_trait]
+pub trait MessageStore: Send + Sync {
+    /// save the message and update the last message of the conversation, return the mid
+    async fn create_message(&self, message: Message) -> Result<u64>;
+
+    /// record the users mentioned by the group message, `MENTION_ALL` for `@all`
+    async fn create_mentions(&self, mid: u64, gid: u64, uids: &[u64]) -> Result<()>;
+
+    async fn get_message_by_receiver_id(
+        &self,
+        id: u64,
+        mtype: ReceiverType,
+    ) -> Result<Vec<Message>>;
+
+    async fn get_message(&self, mid: u64) -> Result<Option<Message>>;
+
+    /// a page of the messages in the conversation of `uid`, the newest first
+    async fn get_history(&self, uid: u64, history: &MessageHistory) -> Result<Vec<Message>>;
+
+    /// the messages which contain all the terms in the conversations of `uid`, the newest first
+    async fn search_messages(&self, uid: u64, search: &SearchMessage) -> Result<Vec<Message>>;
+
+    /// replace the content of the message, the old content is kept in `message_edit`
+    async fn edit_message(&self, mid: u64, content: &str, edit_time: NaiveDateTime) -> Result<()>;
+
+    /// turn the message into a tombstone, the content and the edit history are removed
+    async fn recall_message(&self, mid: u64, recall_time: NaiveDateTime) -> Result<()>;
+
+    /// all the replies to the root message, the oldest first
+    async fn get_thread(&self, root_mid: u64) -> Result<Vec<Message>>;
+
+    /// the previews of the messages replied by `messages`
+    async fn get_reply_previews(
+        &self,
+        messages: &[Message],
+    ) -> Result<HashMap<u64, MessagePreview>>;
+
+    /// the edit history of the message, the oldest first
+    async fn get_edits(&self, mid: u64) -> Result<Vec<MessageEdit>>;
+
+    /// the messages sent by the user and the direct messages sent to the user
+    async fn get_messages_of_user(&self, uid: u64) -> Result<Vec<Message>>;
+}
+
+#[async_trait]
+pub trait ConversationStore: Send + Sync {
+    /// move the read cursor of the user forward, a smaller mid is ignored.
+    /// return true if the cursor is moved
+    async fn read(&self, uid: u64, read: &ReadMessage) -> Result<bool>;
+
+    /// all the conversations of the user, the latest active one first
+    async fn get_conversations(&self, uid: u64) -> Result<Vec<Conversation>>;
+
+    /// how many members have read the group message, the sender is excluded
+    async fn get_read_count(&self, gid: u64, mid: u64, sender_uid: u64) -> Result<i64>;
+}
+
+#[async_trait]
+pub trait ReactionStore: Send + Sync {
+    /// return false if the user has reacted with the emoji
+    async fn add(&self, mid: u64, uid: u64, emoji: &str) -> Result<bool>;
+
+    /// return false if the user has not reacted with the emoji
+    async fn remove(&self, mid: u64, uid: u64, emoji: &str) -> Result<bool>;
+
+    /// the reactions of every message, the earliest emoji first
+    async fn get_counts(&self, mids: &[u64], uid: u64) -> Result<HashMap<u64, Vec<ReactionCount>>>;
+}
+
+#[async_trait]
+pub trait FileStore: Send + Sync {
+    /// whether the content has been uploaded, it's unnecessary to store it again
+    async fn blob_exists(&self, sha256: &str) -> Result<bool>;
+
+    /// save the file, the blob is saved if it doesn't exist. return the fid
+    async fn create_file(&self, file: &File) -> Result<u64>;
+
+    async fn get_file(&self, fid: u64) -> Result<Option<File>>;
+
+    /// save the result of processing the image of the blob
+    async fn save_image(
+        &self,
+        sha256: &str,
+        meta: &ImageMeta,
+        thumbnails: &[ThumbnailBlob],
+    ) -> Result<()>;
+
+    /// `None` if the blob is not a image or it has not been processed
+    async fn get_image(&self, sha256: &str) -> Result<Option<(ImageMeta, Vec<ThumbnailBlob>)>>;
+
+    /// the sha256 of the thumbnail blob
+    async fn get_thumbnail(&self, sha256: &str, max_side: u32) -> Result<Option<String>>;
+
+    /// the file is shared by the message, the users in the conversation can download it
+    async fn share(&self, fid: u64, mid: u64) -> Result<()>;
+
+    /// the uploader, and the users in the conversations where the file is shared,
+    /// can access the file. the share is cancelled if the message is recalled
+    async fn can_access(&self, uid: u64, file: &File) -> Result<bool>;
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use chrono::Local;
+use dashmap::DashMap;
+use eChat::err::Result;
+use tracing::{debug, warn};
+
+use crate::http::send_to_users;
+use crate::modles::frame::Event;
+use crate::modles::presence::*;
+use crate::ApiContext;
+
+/// the presence of the connected users, a user who is not in it is offline.
+/// the last seen time of the offline users is saved in the `user` table
+#[derive(Clone, Default)]
+pub struct PresenceManage {
+    states: Arc<DashMap<u64, PresenceView>>,
+}
+
+impl PresenceManage {
+    pub fn new() -> Self {
+        PresenceManage::default()
+    }
+
+    pub fn get(&self, uid: u64) -> Option<PresenceView> {
+        self.states.get(&uid).map(|view| view.clone())
+    }
+
+    /// refresh the last seen time of a connected user
+    pub fn heartbeat(&self, uid: u64) {
+        if let Some(mut view) = self.states.get_mut(&uid) {
+            view.last_seen = Some(Local::now().naive_local());
+        }
+    }
+
+    /// change the status of the user, and tell his friends if the status is changed
+    pub async fn set_status(&self, ctx: &ApiContext, uid: u64, status: PresenceStatus) -> Result<()> {
+        let now = Local::now().naive_local();
+        let view = PresenceView {
+            uid,
+            status,
+            last_seen: Some(now),
+        };
+        let changed = if status == PresenceStatus::Offline {
+            ctx.storage.users
+                .update_last_seen(uid, now)
+                .await?;
+            self.states.remove(&uid).is_some()
+        } else {
+            self.states
+                .insert(uid, view.clone())
+                .is_none_or(|old| old.status != status)
+        };
+        if changed {
+            debug!(uid = uid, status = ?status, "presence changed");
+            self.broadcast(ctx, view).await?;
+        }
+        Ok(())
+    }
+
+    /// save the last seen time of the users still online, it's called on shutdown
+    pub async fn save_all(&self, ctx: &ApiContext) -> Result<()> {
+        let now = Local::now().naive_local();
+        let uids: Vec<u64> = self.states.iter().map(|view| *view.key()).collect();
+        for uid in uids {
+            ctx.storage.users.update_last_seen(uid, now).await?;
+            self.states.remove(&uid);
+        }
+        Ok(())
+    }
+
+    /// the user is set offline when the guard is dropped, even if the session panics
+    pub fn offline_guard(&self, ctx: &ApiContext, uid: u64) -> OfflineGuard {
+        OfflineGuard {
+            ctx: ctx.clone(),
+            uid,
+        }
+    }
+
+    async fn broadcast(&self, ctx: &ApiContext, view: PresenceView) -> Result<()> {
+        let friends: Vec<u64> = ctx.storage.friends
+            .get_friends(view.uid)
+            .await?
+            .into_iter()
+            .map(|friend| friend.uid)
+            .collect();
+        send_to_users(ctx, &friends, Event::Presence(view).into()).await;
+        Ok(())
+    }
+}
+
+/// set the user offline when the session is gone, see `PresenceManage::offline_guard`
+pub struct OfflineGuard {
+    ctx: ApiContext,
+    uid: u64,
+}
+
+impl Drop for OfflineGuard {
+    fn drop(&mut self) {
+        let ctx = self.ctx.clone();
+        let uid = self.uid;
+        // the drop can't wait for saving and broadcasting the status
+        tokio::spawn(async move {
+            // the user may have connected again, he is still online in that case
+            if ctx.connections.is_online(uid) {
+                return;
+            }
+            if let Err(e) = ctx.presence.set_status(&ctx, uid, PresenceStatus::Offline).await {
+                warn!(error = ?e, "update presence failed");
+            }
+        });
+    }
+}
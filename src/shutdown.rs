@@ -0,0 +1,96 @@
+//! stop the server gracefully. the websocket sessions stop reading once the shutdown is
+//! triggered, they tell the client to go away, flush the queue and close the socket
+
+use std::sync::Arc;
+
+use tokio::sync::watch;
+
+/// shared by the sessions through `ApiContext`, cloning it only clones the `Arc`s
+#[derive(Clone)]
+pub struct Shutdown {
+    triggered: Arc<watch::Sender<bool>>,
+    /// the running sessions
+    sessions: Arc<watch::Sender<usize>>,
+}
+
+/// held by a websocket session until it's closed
+pub struct SessionGuard {
+    sessions: Arc<watch::Sender<usize>>,
+}
+
+impl Drop for SessionGuard {
+    fn drop(&mut self) {
+        self.sessions.send_modify(|sessions| *sessions -= 1);
+    }
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Shutdown {
+            triggered: Arc::new(watch::channel(false).0),
+            sessions: Arc::new(watch::channel(0).0),
+        }
+    }
+
+    pub fn trigger(&self) {
+        self.triggered.send_replace(true);
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        *self.triggered.borrow()
+    }
+
+    /// resolves once `trigger` is called
+    pub async fn triggered(&self) {
+        let mut triggered = self.triggered.subscribe();
+        while !*triggered.borrow_and_update() {
+            // the sender is kept by `self`, it's never closed
+            if triggered.changed().await.is_err() {
+                return;
+            }
+        }
+    }
+
+    pub fn session(&self) -> SessionGuard {
+        self.sessions.send_modify(|sessions| *sessions += 1);
+        SessionGuard {
+            sessions: self.sessions.clone(),
+        }
+    }
+
+    pub fn sessions(&self) -> usize {
+        *self.sessions.borrow()
+    }
+
+    /// resolves once all the sessions are closed
+    pub async fn drained(&self) {
+        let mut sessions = self.sessions.subscribe();
+        while *sessions.borrow_and_update() > 0 {
+            if sessions.changed().await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// resolves on ctrl-c or SIGTERM
+pub async fn signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to listen ctrl-c");
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to listen SIGTERM")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+    tokio::select! {
+        _ = ctrl_c => (),
+        _ = terminate => (),
+    }
+}
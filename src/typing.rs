@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::time::sleep;
+
+use crate::http::send_to_users;
+use crate::modles::frame::Event;
+use crate::modles::message::ReceiverType;
+use crate::ApiContext;
+
+/// a typing start within this interval after the last one is ignored
+const TYPING_INTERVAL: Duration = Duration::from_secs(2);
+/// the typing is stopped by the server if the client doesn't refresh it in time
+const TYPING_TIMEOUT: Duration = Duration::from_secs(5);
+
+type Conversation = (ReceiverType, u64);
+
+struct Typing {
+    started: Instant,
+    /// unique for every start, a expire task only works for its own start
+    generation: u64,
+}
+
+/// the typing state of one connection, it is never persisted
+#[derive(Clone, Default)]
+pub struct TypingState {
+    conversations: Arc<Mutex<HashMap<Conversation, Typing>>>,
+    next_generation: Arc<AtomicU64>,
+}
+
+impl TypingState {
+    pub fn new() -> Self {
+        TypingState::default()
+    }
+
+    /// record a typing start, return `None` if it's rate limited
+    pub fn start(&self, conversation: Conversation) -> Option<u64> {
+        let mut conversations = self.conversations.lock().unwrap();
+        if let Some(typing) = conversations.get(&conversation) {
+            if typing.started.elapsed() < TYPING_INTERVAL {
+                return None;
+            }
+        }
+        let generation = self.next_generation.fetch_add(1, Ordering::Relaxed);
+        conversations.insert(
+            conversation,
+            Typing {
+                started: Instant::now(),
+                generation,
+            },
+        );
+        Some(generation)
+    }
+
+    /// return true if the user was typing in the conversation
+    pub fn stop(&self, conversation: Conversation) -> bool {
+        self.conversations
+            .lock()
+            .unwrap()
+            .remove(&conversation)
+            .is_some()
+    }
+
+    /// stop the typing after `TYPING_TIMEOUT`, unless it has been stopped or started again
+    pub fn expire_later(
+        &self,
+        ctx: &ApiContext,
+        uid: u64,
+        conversation: Conversation,
+        generation: u64,
+        recipients: Vec<u64>,
+    ) {
+        let state = self.clone();
+        let ctx = ctx.clone();
+        tokio::spawn(async move {
+            sleep(TYPING_TIMEOUT).await;
+            let expired = {
+                let mut conversations = state.conversations.lock().unwrap();
+                match conversations.get(&conversation) {
+                    Some(typing) if typing.generation == generation => {
+                        conversations.remove(&conversation);
+                        true
+                    }
+                    _ => false,
+                }
+            };
+            if expired {
+                relay(&ctx, uid, conversation, false, &recipients).await;
+            }
+        });
+    }
+}
+
+/// tell the recipients that `uid` starts or stops typing
+pub async fn relay(
+    ctx: &ApiContext,
+    uid: u64,
+    (receiver_type, receiver_id): Conversation,
+    typing: bool,
+    recipients: &[u64],
+) {
+    let event = Event::Typing {
+        uid,
+        receiver_type,
+        receiver_id,
+        typing,
+    };
+    send_to_users(ctx, recipients, event.into()).await;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn typing_start_should_be_rate_limited() {
+        let state = TypingState::new();
+        let conversation = (ReceiverType::User, 1);
+        let first = state.start(conversation).unwrap();
+        assert_eq!(state.start(conversation), None);
+        assert!(state.start((ReceiverType::Group, 1)).is_some());
+        assert!(state.stop(conversation));
+        assert!(!state.stop(conversation));
+        // a new start after stop should not be expired by the task of the first start
+        assert_ne!(state.start(conversation), Some(first));
+    }
+}
@@ -1,6 +1,6 @@
 use std::num::NonZeroU32;
 
-use data_encoding::BASE64;
+use data_encoding::{BASE64, HEXLOWER};
 use ring::{
     digest,
     error::Unspecified,
@@ -10,10 +10,10 @@ use ring::{
 
 const CREDENTIAL_LEN: usize = digest::SHA256_OUTPUT_LEN;
 // given a password, encyption the password with a salt
-// that be generated randomly. and return the encyption password and the salt
-pub fn encyption(password: &str) -> Result<(String, String), Unspecified> {
+// that be generated randomly. and return the encyption password and the salt.
+// `n_iter` 密码迭代加密的次数, verify must use the same one
+pub fn encyption(password: &str, n_iter: NonZeroU32) -> Result<(String, String), Unspecified> {
     let mut salt = [0u8; CREDENTIAL_LEN];
-    let n_iter = NonZeroU32::new(100_000).unwrap(); // 密码迭代加密的次数
     let rng = rand::SystemRandom::new();
     rng.fill(&mut salt)?;
     let mut pbkdf2_hash = [0u8; CREDENTIAL_LEN];
@@ -26,8 +26,7 @@ pub fn encyption(password: &str) -> Result<(String, String), Unspecified> {
     );
     Ok((BASE64.encode(&pbkdf2_hash), BASE64.encode(&salt)))
 }
-pub fn verify(password: &str, encyption_password: &str, salt: &str) -> bool {
-    let n_iter = NonZeroU32::new(100_000).unwrap();
+pub fn verify(password: &str, encyption_password: &str, salt: &str, n_iter: NonZeroU32) -> bool {
     let salt = BASE64.decode(salt.as_bytes()).unwrap();
     let encyption_password = BASE64.decode(encyption_password.as_bytes()).unwrap();
     pbkdf2::verify(
@@ -39,13 +38,22 @@ pub fn verify(password: &str, encyption_password: &str, salt: &str) -> bool {
     )
     .is_ok()
 }
+/// the lowercase hex sha256 of the data
+pub fn sha256_hex(data: &[u8]) -> String {
+    HEXLOWER.encode(digest::digest(&digest::SHA256, data).as_ref())
+}
 #[cfg(test)]
 mod test {
+    use std::num::NonZeroU32;
+
     use super::{encyption, verify};
     #[test]
     fn encyption_should_work() {
         let password = "123";
-        let (encyption_password, salt) = encyption(password).unwrap();
-        assert!(verify(&password, &encyption_password, &salt));
+        let n_iter = NonZeroU32::new(100_000).unwrap();
+        let (encyption_password, salt) = encyption(password, n_iter).unwrap();
+        assert!(verify(password, &encyption_password, &salt, n_iter));
+        // the iterations are not saved, another count never matches
+        assert!(!verify(password, &encyption_password, &salt, NonZeroU32::new(1).unwrap()));
     }
 }
\ No newline at end of file